@@ -0,0 +1,29 @@
+extern crate rand;
+extern crate statrs;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::distributions::Distribution;
+use rand::prelude::*;
+use statrs::distribution::Binomial;
+
+fn bench_binomial_sample(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut group = c.benchmark_group("binomial sample");
+    for &(p, n) in [
+        (0.3, 10),
+        (0.3, 100),
+        (0.5, 10_000),
+        (1e-8, 1_000_000_000),
+        (0.3, 1_000_000_000),
+    ]
+    .iter()
+    {
+        let dist = Binomial::new(p, n).unwrap();
+        group.bench_function(format!("p = {}, n = {}", p, n), |b| {
+            b.iter(|| -> u64 { black_box(&dist).sample(&mut rng) })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_binomial_sample);
+criterion_main!(benches);
@@ -1,6 +1,7 @@
 use crate::distribution::{Binomial, Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::Result;
+use num_complex::Complex;
 use rand::Rng;
 
 /// Implements the
@@ -80,9 +81,15 @@ impl Bernoulli {
     }
 }
 
+impl ::rand::distributions::Distribution<u64> for Bernoulli {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        rng.gen_bool(self.p()) as u64
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Bernoulli {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        rng.gen_bool(self.p()) as u8 as f64
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
     }
 }
 
@@ -100,6 +107,19 @@ impl DiscreteCDF<u64, f64> for Bernoulli {
     fn cdf(&self, x: u64) -> f64 {
         self.b.cdf(x)
     }
+
+    /// Calculates the survival function for the
+    /// bernoulli distribution at `x`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 1 { p }
+    /// else { 0 }
+    /// ```
+    fn sf(&self, x: u64) -> f64 {
+        self.b.sf(x)
+    }
 }
 
 impl Min<u64> for Bernoulli {
@@ -179,6 +199,79 @@ impl Distribution<f64> for Bernoulli {
     fn skewness(&self) -> Option<f64> {
         self.b.skewness()
     }
+    /// Returns the excess kurtosis of the bernoulli distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `p == 0.0` or `p == 1.0` since the distribution is
+    /// then degenerate
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - 6p(1 - p)) / (p(1 - p))
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        self.b.kurtosis()
+    }
+    /// Returns the `k`th raw moment of the bernoulli distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p
+    /// ```
+    ///
+    /// for `k > 0`
+    fn moment(&self, k: u32) -> Option<f64> {
+        self.b.moment(k)
+    }
+    /// Returns the `k`th central moment of the bernoulli distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p(1 - p)^k + (1 - p)(-p)^k
+    /// ```
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        self.b.central_moment(k)
+    }
+}
+
+impl GeneratingFunction<f64> for Bernoulli {
+    /// Returns the moment generating function of the bernoulli distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - p + p * e^t
+    /// ```
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.b.mgf(t)
+    }
+    /// Returns the cumulant generating function of the bernoulli
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(1 - p + p * e^t)
+    /// ```
+    fn cgf(&self, t: f64) -> Option<f64> {
+        self.b.cgf(t)
+    }
+    /// Returns the characteristic function of the bernoulli distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - p + p * e^(it)
+    /// ```
+    fn cf(&self, t: f64) -> Complex<f64> {
+        self.b.cf(t)
+    }
 }
 
 impl Median<f64> for Bernoulli {
@@ -1,5 +1,5 @@
 use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::{beta, gamma};
+use crate::function::{beta, factorial, gamma};
 use crate::is_zero;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -86,10 +86,7 @@ impl Beta {
 
 impl ::rand::distributions::Distribution<f64> for Beta {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        // Generated by sampling two gamma distributions and normalizing.
-        let x = super::gamma::sample_unchecked(rng, self.shape_a, 1.0);
-        let y = super::gamma::sample_unchecked(rng, self.shape_b, 1.0);
-        x / (x + y)
+        sample_unchecked(rng, self.shape_a, self.shape_b)
     }
 }
 
@@ -131,6 +128,71 @@ impl ContinuousCDF<f64, f64> for Beta {
             beta::beta_reg(self.shape_a, self.shape_b, x)
         }
     }
+
+    /// Calculates the survival function for the beta
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(1 - x)(β, α)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I_x` is the regularized
+    /// lower incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else if x >= 1.0 {
+            0.0
+        } else if self.shape_a.is_infinite() && self.shape_b.is_infinite() {
+            if x < 0.5 {
+                1.0
+            } else {
+                0.0
+            }
+        } else if self.shape_a.is_infinite() {
+            1.0
+        } else if self.shape_b.is_infinite() {
+            0.0
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            1.0 - x
+        } else {
+            beta::beta_reg(self.shape_b, self.shape_a, 1.0 - x)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// beta distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I^-1_p(α, β)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I^-1` is the inverse of the
+    /// regularized lower incomplete beta function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if self.shape_a.is_infinite() && self.shape_b.is_infinite() {
+            0.5
+        } else if self.shape_a.is_infinite() {
+            1.0
+        } else if self.shape_b.is_infinite() {
+            0.0
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            p
+        } else {
+            beta::inv_beta_reg(self.shape_a, self.shape_b, p)
+        }
+    }
 }
 
 impl Min<f64> for Beta {
@@ -254,6 +316,95 @@ impl Distribution<f64> for Beta {
         };
         Some(skew)
     }
+    /// Returns the excess kurtosis of the beta distribution
+    ///
+    /// # Remarks
+    ///
+    /// If either shape is infinite the limit of the formula as that
+    /// shape grows is returned
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6((α - β)^2 (α + β + 1) - αβ(α + β + 2)) / (αβ(α + β + 2)(α + β + 3))
+    /// ```
+    ///
+    /// where `α` is shapeA and `β` is shapeB
+    fn kurtosis(&self) -> Option<f64> {
+        let a = self.shape_a;
+        let b = self.shape_b;
+        let kurt = if a.is_infinite() && b.is_infinite() {
+            0.0
+        } else if a.is_infinite() {
+            6.0 / b
+        } else if b.is_infinite() {
+            6.0 / a
+        } else {
+            6.0 * ((a - b) * (a - b) * (a + b + 1.0) - a * b * (a + b + 2.0))
+                / (a * b * (a + b + 2.0) * (a + b + 3.0))
+        };
+        Some(kurt)
+    }
+    /// Returns the `k`th raw moment of the beta distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if both `shapeA` and `shapeB` are positive infinity,
+    /// since the limit of the distribution is not defined
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// prod((α + i) / (α + β + i)) for i in 0..k
+    /// ```
+    ///
+    /// where `α` is shapeA and `β` is shapeB
+    fn moment(&self, k: u32) -> Option<f64> {
+        if k == 0 {
+            return Some(1.0);
+        }
+        let moment = if self.shape_a.is_infinite() && self.shape_b.is_infinite() {
+            return None;
+        } else if self.shape_a.is_infinite() {
+            1.0
+        } else {
+            (0..k).fold(1.0, |acc, i| {
+                let i = i as f64;
+                acc * (self.shape_a + i) / (self.shape_a + self.shape_b + i)
+            })
+        };
+        Some(moment)
+    }
+    /// Returns the `k`th central moment of the beta distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if both `shapeA` and `shapeB` are positive infinity,
+    /// since the limit of the distribution is not defined
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum((k choose j) * E[X^j] * (-μ)^(k - j)) for j in 0..=k
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let mean = self.mean()?;
+        if k == 0 {
+            Some(1.0)
+        } else if self.shape_a.is_infinite() || self.shape_b.is_infinite() {
+            Some(0.0)
+        } else {
+            let mut moment = 0.0;
+            for j in 0..=k {
+                moment += factorial::binomial(k as u64, j as u64)
+                    * self.moment(j)?
+                    * (-mean).powi((k - j) as i32);
+            }
+            Some(moment)
+        }
+    }
 }
 
 impl Mode<Option<f64>> for Beta {
@@ -396,6 +547,14 @@ impl Continuous<f64, f64> for Beta {
     }
 }
 
+/// draws a sample from a beta distribution with the given shapes by
+/// sampling two gamma distributions and normalizing
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, shape_a: f64, shape_b: f64) -> f64 {
+    let x = super::gamma::sample_unchecked(rng, shape_a, 1.0);
+    let y = super::gamma::sample_unchecked(rng, shape_b, 1.0);
+    x / (x + y)
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -528,6 +687,40 @@ mod tests {
         test_case(f64::INFINITY, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Beta| x.kurtosis().unwrap();
+        test_case(1.0, 1.0, -1.2, kurtosis);
+        test_almost(2.0, 3.5, -0.50924369747899159664, 1e-15, kurtosis);
+        test_case(1.0, f64::INFINITY, 6.0, kurtosis);
+        test_case(f64::INFINITY, 2.0, 3.0, kurtosis);
+        test_case(f64::INFINITY, f64::INFINITY, 0.0, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Beta| x.moment(k).unwrap();
+        test_case(2.0, 3.5, 1.0, moment(0));
+        test_almost(2.0, 3.5, 0.36363636363636363636, 1e-16, moment(1));
+        test_almost(2.0, 3.5, 0.16783216783216783217, 1e-16, moment(2));
+        test_almost(2.0, 3.5, 0.022169780683712572257, 1e-17, moment(6));
+        test_case(f64::INFINITY, 2.0, 1.0, moment(3));
+        test_case(2.0, f64::INFINITY, 0.0, moment(3));
+        assert!(try_create(f64::INFINITY, f64::INFINITY).moment(1).is_none());
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Beta| x.central_moment(k).unwrap();
+        test_case(2.0, 3.5, 1.0, central_moment(0));
+        test_almost(2.0, 3.5, 0.0, 1e-17, central_moment(1));
+        test_almost(2.0, 3.5, 0.035600762873490146217, 1e-16, central_moment(2));
+        test_almost(2.0, 3.5, 0.0025891463907992833613, 1e-16, central_moment(3));
+        test_almost(2.0, 3.5, 0.003156820198407682387, 1e-16, central_moment(4));
+        test_almost(2.0, 3.5, 0.00042769997444470982486, 1e-16, central_moment(6));
+        test_case(f64::INFINITY, 2.0, 0.0, central_moment(2));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Beta| x.mode().unwrap();
@@ -667,6 +860,30 @@ mod tests {
         test_case(1.0, 1.0, 1.0, cdf(2.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Beta| x.sf(arg);
+        test_case(1.0, 1.0, 1.0, sf(-1.0));
+        test_case(1.0, 1.0, 0.75, sf(0.25));
+        test_almost(2.0, 5.0, 0.65536, 1e-14, sf(0.2));
+        test_almost(5.0, 2.0, 0.0000149600449760050006220061940516, 1e-17, sf(0.999));
+        test_case(5.0, 2.0, 0.0, sf(1.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Beta| x.inverse_cdf(arg);
+        test_case(2.0, 5.0, 0.0, inverse_cdf(0.0));
+        test_almost(2.0, 5.0, 0.09259525891312874916854, 1e-14, inverse_cdf(0.1));
+        test_almost(2.0, 5.0, 0.2644499832956599623241, 1e-14, inverse_cdf(0.5));
+        test_almost(0.5, 0.5, 0.9755282581475767860582, 1e-14, inverse_cdf(0.9));
+        test_case(1.0, 1.0, 0.3, inverse_cdf(0.3));
+        test_case(2.0, 5.0, 1.0, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(2.0, 5.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.5, 0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(1.0, 1.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.2, 3.4), 0.0, 1.0);
@@ -0,0 +1,550 @@
+use crate::distribution::{internal, Discrete, DiscreteCDF};
+use crate::function::{beta, factorial};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Beta-binomial](https://en.wikipedia.org/wiki/Beta-binomial_distribution)
+/// distribution, the number of successes in `n` trials whose common
+/// probability of success is itself beta distributed
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{BetaBinomial, Discrete};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = BetaBinomial::new(10, 2.0, 3.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 4.0);
+/// assert!(prec::almost_eq(n.pmf(4), 20.0 / 143.0, 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BetaBinomial {
+    n: u64,
+    alpha: f64,
+    beta: f64,
+}
+
+impl BetaBinomial {
+    /// Constructs a new beta-binomial distribution with `n` trials and a
+    /// probability of success following a beta distribution with shapes
+    /// `alpha` (α) and `beta` (β)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` or `beta` are `NaN`, infinite or less
+    /// than or equal to `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::BetaBinomial;
+    ///
+    /// let mut result = BetaBinomial::new(10, 2.0, 3.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = BetaBinomial::new(10, 0.0, 3.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(n: u64, alpha: f64, beta: f64) -> Result<BetaBinomial> {
+        if !alpha.is_finite() || !beta.is_finite() || alpha <= 0.0 || beta <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(BetaBinomial { n, alpha, beta })
+        }
+    }
+
+    /// Returns the number of trials `n` of the beta-binomial distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::BetaBinomial;
+    ///
+    /// let n = BetaBinomial::new(10, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.n(), 10);
+    /// ```
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the shape α of the beta distributed probability of success
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::BetaBinomial;
+    ///
+    /// let n = BetaBinomial::new(10, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.alpha(), 2.0);
+    /// ```
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the shape β of the beta distributed probability of success
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::BetaBinomial;
+    ///
+    /// let n = BetaBinomial::new(10, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.beta(), 3.0);
+    /// ```
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Sums the pmf over `lo..=hi`, stepping the log pmf with the ratio of
+    /// consecutive terms so that only the first term needs the beta function
+    fn sum_pmf(&self, lo: u64, hi: u64) -> f64 {
+        let mut ln_p = self.ln_pmf(lo);
+        let mut sum = ln_p.exp();
+        for k in lo..hi {
+            let k = k as f64;
+            let n = self.n as f64;
+            ln_p += ((n - k) * (k + self.alpha) / ((k + 1.0) * (n - k - 1.0 + self.beta))).ln();
+            sum += ln_p.exp();
+        }
+        sum
+    }
+}
+
+impl ::rand::distributions::Distribution<u64> for BetaBinomial {
+    /// Generates one sample from the beta-binomial distribution by drawing
+    /// the probability of success from the beta distribution and the number
+    /// of successes from the binomial distribution
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let p = super::beta::sample_unchecked(rng, self.alpha, self.beta);
+        super::binomial::sample_unchecked(rng, p, self.n)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for BetaBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
+    }
+}
+
+impl DiscreteCDF<u64, f64> for BetaBinomial {
+    /// Calculates the cumulative distribution function for the
+    /// beta-binomial distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The pmf is summed over whichever side of the mean `x` lies on, so
+    /// that a small lower tail keeps its relative accuracy
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum((n choose k) * B(k + α, n - k + β) / B(α, β), k=0..x)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn cdf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            1.0
+        } else if (x as f64) < self.mean().unwrap() {
+            self.sum_pmf(0, x)
+        } else {
+            1.0 - self.sum_pmf(x + 1, self.n)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// beta-binomial distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The pmf is summed over whichever side of the mean `x` lies on, so
+    /// that a small upper tail keeps its relative accuracy
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum((n choose k) * B(k + α, n - k + β) / B(α, β), k=x+1..n)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            0.0
+        } else if (x as f64) < self.mean().unwrap() {
+            1.0 - self.sum_pmf(0, x)
+        } else {
+            self.sum_pmf(x + 1, self.n)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// beta-binomial distribution at `x`, i.e. the smallest `k` such that
+    /// `cdf(k) >= x`, searching outwards from the mean
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> u64 {
+        internal::discrete_inverse_cdf(self, x, self.mean().unwrap().floor() as u64)
+    }
+}
+
+impl Min<u64> for BetaBinomial {
+    /// Returns the minimum value in the domain of the
+    /// beta-binomial distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> u64 {
+        0
+    }
+}
+
+impl Max<u64> for BetaBinomial {
+    /// Returns the maximum value in the domain of the
+    /// beta-binomial distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n
+    /// ```
+    fn max(&self) -> u64 {
+        self.n
+    }
+}
+
+impl Distribution<f64> for BetaBinomial {
+    /// Returns the mean of the beta-binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * α / (α + β)
+    /// ```
+    fn mean(&self) -> Option<f64> {
+        Some(self.n as f64 * self.alpha / (self.alpha + self.beta))
+    }
+    /// Returns the variance of the beta-binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * α * β * (α + β + n) / ((α + β)^2 * (α + β + 1))
+    /// ```
+    fn variance(&self) -> Option<f64> {
+        let n = self.n as f64;
+        let s = self.alpha + self.beta;
+        Some(n * self.alpha * self.beta * (s + n) / (s * s * (s + 1.0)))
+    }
+    /// Returns the entropy of the beta-binomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -sum(p_k * ln(p_k), k=0..n)
+    /// ```
+    ///
+    /// where `p_k` is the pmf at `k`
+    fn entropy(&self) -> Option<f64> {
+        let entr = (0..=self.n).fold(0.0, |acc, k| {
+            let ln_p = self.ln_pmf(k);
+            acc - ln_p.exp() * ln_p
+        });
+        Some(entr)
+    }
+    /// Returns the skewness of the beta-binomial distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `n == 0` since the distribution is then degenerate
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (α + β + 2n) * (β - α) / (α + β + 2) * sqrt((1 + α + β) / (n * α * β * (n + α + β)))
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        if self.n == 0 {
+            return None;
+        }
+        let n = self.n as f64;
+        let s = self.alpha + self.beta;
+        Some(
+            (s + 2.0 * n) * (self.beta - self.alpha) / (s + 2.0)
+                * ((1.0 + s) / (n * self.alpha * self.beta * (n + s))).sqrt(),
+        )
+    }
+    /// Returns the excess kurtosis of the beta-binomial distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `n == 0` since the distribution is then degenerate
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (α + β)^2 * (1 + α + β) / (n * α * β * (α + β + 2) * (α + β + 3) * (α + β + n))
+    ///     * ((α + β) * (α + β - 1 + 6n) + 3αβ(n - 2) + 6n^2
+    ///         - 3αβn(6 - n) / (α + β) - 18αβn^2 / (α + β)^2) - 3
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        if self.n == 0 {
+            return None;
+        }
+        let n = self.n as f64;
+        let s = self.alpha + self.beta;
+        let ab = self.alpha * self.beta;
+        let scale = s * s * (1.0 + s) / (n * ab * (s + 2.0) * (s + 3.0) * (s + n));
+        let poly = s * (s - 1.0 + 6.0 * n) + 3.0 * ab * (n - 2.0) + 6.0 * n * n
+            - 3.0 * ab * n * (6.0 - n) / s
+            - 18.0 * ab * n * n / (s * s);
+        Some(scale * poly - 3.0)
+    }
+}
+
+impl Discrete<u64, f64> for BetaBinomial {
+    /// Calculates the probability mass function for the beta-binomial
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (n choose x) * B(x + α, n - x + β) / B(α, β)
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn pmf(&self, x: u64) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Calculates the log probability mass function for the beta-binomial
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((n choose x) * B(x + α, n - x + β) / B(α, β))
+    /// ```
+    ///
+    /// where `B` is the beta function
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x > self.n {
+            f64::NEG_INFINITY
+        } else {
+            factorial::ln_binomial(self.n, x)
+                + beta::ln_beta(x as f64 + self.alpha, (self.n - x) as f64 + self.beta)
+                - beta::ln_beta(self.alpha, self.beta)
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+    use crate::statistics::*;
+    use crate::distribution::{BetaBinomial, Discrete, DiscreteCDF};
+    use crate::distribution::internal::*;
+
+    fn try_create(n: u64, alpha: f64, beta: f64) -> BetaBinomial {
+        let dist = BetaBinomial::new(n, alpha, beta);
+        assert!(dist.is_ok());
+        dist.unwrap()
+    }
+
+    fn create_case(n: u64, alpha: f64, beta: f64) {
+        let dist = try_create(n, alpha, beta);
+        assert_eq!(n, dist.n());
+        assert_eq!(alpha, dist.alpha());
+        assert_eq!(beta, dist.beta());
+    }
+
+    fn bad_create_case(n: u64, alpha: f64, beta: f64) {
+        let dist = BetaBinomial::new(n, alpha, beta);
+        assert!(dist.is_err());
+    }
+
+    fn get_value<T, F>(n: u64, alpha: f64, beta: f64, eval: F) -> T
+        where T: PartialEq + Debug,
+              F: Fn(BetaBinomial) -> T
+    {
+        let dist = try_create(n, alpha, beta);
+        eval(dist)
+    }
+
+    fn test_case<T, F>(n: u64, alpha: f64, beta: f64, expected: T, eval: F)
+        where T: PartialEq + Debug,
+              F: Fn(BetaBinomial) -> T
+    {
+        let x = get_value(n, alpha, beta, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(n: u64, alpha: f64, beta: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(BetaBinomial) -> f64
+    {
+        let x = get_value(n, alpha, beta, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0, 1.0, 1.0);
+        create_case(10, 2.0, 3.0);
+        create_case(1000, 0.1, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(10, 0.0, 1.0);
+        bad_create_case(10, 1.0, 0.0);
+        bad_create_case(10, -1.0, 1.0);
+        bad_create_case(10, f64::NAN, 1.0);
+        bad_create_case(10, 1.0, f64::NAN);
+        bad_create_case(10, f64::INFINITY, 1.0);
+        bad_create_case(10, 1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: BetaBinomial| x.mean().unwrap();
+        test_case(10, 2.0, 3.0, 4.0, mean);
+        test_case(20, 0.5, 0.5, 10.0, mean);
+        test_almost(100, 5.0, 1.5, 76.923076923076923077, 1e-13, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: BetaBinomial| x.variance().unwrap();
+        test_case(10, 2.0, 3.0, 6.0, variance);
+        test_case(20, 0.5, 0.5, 52.5, variance);
+        test_almost(100, 5.0, 1.5, 252.07100591715976331, 1e-12, variance);
+        test_case(1, 1.0, 1.0, 0.25, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: BetaBinomial| x.entropy().unwrap();
+        test_almost(10, 2.0, 3.0, 2.2577232124962407627, 1e-12, entropy);
+        test_almost(20, 0.5, 0.5, 2.9202652948405535692, 1e-12, entropy);
+        test_almost(100, 5.0, 1.5, 4.054963789912986124, 1e-12, entropy);
+        test_almost(1, 1.0, 1.0, std::f64::consts::LN_2, 1e-12, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: BetaBinomial| x.skewness().unwrap();
+        test_almost(10, 2.0, 3.0, 0.29160592175990215455, 1e-15, skewness);
+        test_case(20, 0.5, 0.5, 0.0, skewness);
+        test_almost(100, 5.0, 1.5, -0.82393769222080775442, 1e-15, skewness);
+        test_case(0, 2.0, 3.0, None, |x| x.skewness());
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: BetaBinomial| x.kurtosis().unwrap();
+        test_almost(10, 2.0, 3.0, -0.69047619047619047619, 1e-14, kurtosis);
+        test_almost(20, 0.5, 0.5, -1.502380952380952381, 1e-14, kurtosis);
+        test_almost(100, 5.0, 1.5, 0.27818333115306908531, 1e-14, kurtosis);
+        test_almost(1, 1.0, 1.0, -2.0, 1e-15, kurtosis);
+        test_case(0, 2.0, 3.0, None, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: BetaBinomial| x.min();
+        let max = |x: BetaBinomial| x.max();
+        test_case(10, 2.0, 3.0, 0, min);
+        test_case(10, 2.0, 3.0, 10, max);
+    }
+
+    #[test]
+    fn test_pmf() {
+        // ln_beta is built on ln_gamma and accurate to about 1e-13
+        let pmf = |arg: u64| move |x: BetaBinomial| x.pmf(arg);
+        test_almost(10, 2.0, 3.0, 0.065934065934065934066, 1e-12, pmf(0));
+        test_almost(10, 2.0, 3.0, 0.13986013986013986014, 1e-12, pmf(4));
+        test_almost(10, 2.0, 3.0, 0.010989010989010989011, 1e-12, pmf(10));
+        test_case(10, 2.0, 3.0, 0.0, pmf(11));
+        test_almost(20, 0.5, 0.5, 0.12537068761957925744, 1e-12, pmf(0));
+        test_almost(20, 0.5, 0.5, 0.031045401134178973734, 1e-12, pmf(10));
+        test_almost(100, 5.0, 1.5, 0.0061419302085988769665, 1e-12, pmf(50));
+        test_almost(100, 5.0, 1.5, 2.7363743311924883095e-8, 1e-12, pmf(0));
+        test_case(0, 2.0, 3.0, 1.0, pmf(0));
+        test_almost(1, 1.0, 1.0, 0.5, 1e-12, pmf(1));
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let ln_pmf = |arg: u64| move |x: BetaBinomial| x.ln_pmf(arg);
+        test_almost(10, 2.0, 3.0, -2.7191000372887950403, 1e-12, ln_pmf(0));
+        test_almost(10, 2.0, 3.0, -1.9671123567059162867, 1e-12, ln_pmf(4));
+        test_case(10, 2.0, 3.0, f64::NEG_INFINITY, ln_pmf(11));
+        test_almost(100, 5.0, 1.5, -5.0926162200044776186, 1e-12, ln_pmf(50));
+        test_almost(100, 5.0, 1.5, -17.414046936594328786, 1e-12, ln_pmf(0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: u64| move |x: BetaBinomial| x.cdf(arg);
+        test_almost(10, 2.0, 3.0, 0.065934065934065934066, 1e-12, cdf(0));
+        test_almost(10, 2.0, 3.0, 0.59440559440559440559, 1e-12, cdf(4));
+        test_almost(10, 2.0, 3.0, 0.98901098901098901099, 1e-12, cdf(9));
+        test_case(10, 2.0, 3.0, 1.0, cdf(10));
+        test_case(10, 2.0, 3.0, 1.0, cdf(20));
+        test_almost(20, 0.5, 0.5, 0.51552270056708948687, 1e-12, cdf(10));
+        test_almost(100, 5.0, 1.5, 0.072760634185346838077, 1e-12, cdf(50));
+        test_almost(100, 5.0, 1.5, 0.90872519240751549103, 1e-12, cdf(95));
+        test_almost(1000, 20.0, 5.0, 0.014458401877678142427, 1e-12, cdf(600));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: BetaBinomial| x.sf(arg);
+        test_almost(10, 2.0, 3.0, 0.93406593406593406593, 1e-12, sf(0));
+        test_almost(10, 2.0, 3.0, 0.40559440559440559441, 1e-12, sf(4));
+        test_almost(10, 2.0, 3.0, 0.010989010989010989011, 1e-12, sf(9));
+        test_case(10, 2.0, 3.0, 0.0, sf(10));
+        test_almost(20, 0.5, 0.5, 0.48447729943291051313, 1e-12, sf(10));
+        test_almost(100, 5.0, 1.5, 0.92723936581465316192, 1e-12, sf(50));
+        test_almost(100, 5.0, 1.5, 0.091274807592484508971, 1e-12, sf(95));
+        test_almost(1000, 20.0, 5.0, 0.98554159812232185757, 1e-12, sf(600));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_discrete_inverse_cdf(&try_create(10, 2.0, 3.0));
+        tests::check_discrete_inverse_cdf(&try_create(20, 0.5, 0.5));
+        tests::check_discrete_inverse_cdf(&try_create(1000, 20.0, 5.0));
+    }
+
+    #[test]
+    fn test_discrete() {
+        tests::check_discrete_distribution(&try_create(10, 2.0, 3.0), 10);
+        tests::check_discrete_distribution(&try_create(20, 0.5, 0.5), 20);
+        tests::check_discrete_distribution(&try_create(100, 5.0, 1.5), 100);
+    }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(10, 2.0, 3.0));
+        tests::check_discrete_samples(&try_create(0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_sample_goodness_of_fit() {
+        tests::check_goodness_of_fit(&try_create(10, 2.0, 3.0), 0, 10, 10_000);
+        tests::check_goodness_of_fit(&try_create(20, 0.5, 0.5), 0, 20, 10_000);
+        tests::check_goodness_of_fit(&try_create(100, 5.0, 1.5), 0, 100, 50_000);
+    }
+}
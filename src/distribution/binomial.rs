@@ -1,8 +1,9 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{internal, Discrete, DiscreteCDF};
 use crate::function::{beta, factorial};
 use crate::is_zero;
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -87,16 +88,20 @@ impl Binomial {
     }
 }
 
+impl ::rand::distributions::Distribution<u64> for Binomial {
+    /// Generates one sample from the binomial distribution either by
+    /// sequential inversion if `n * min(p, 1 - p) < 30` or by the BTPE
+    /// algorithm of V. Kachitvichyanukul and B. W. Schmeiser from
+    /// Communications of the ACM Vol. 31 No. 2 (1988) pp. 216 - 222
+    /// otherwise
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        sample_unchecked(rng, self.p, self.n)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Binomial {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        (0..self.n).fold(0.0, |acc, _| {
-            let n: f64 = rng.gen();
-            if n < self.p {
-                acc + 1.0
-            } else {
-                acc
-            }
-        })
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
     }
 }
 
@@ -119,6 +124,57 @@ impl DiscreteCDF<u64, f64> for Binomial {
             beta::beta_reg((self.n - k) as f64, k as f64 + 1.0, 1.0 - self.p)
         }
     }
+
+    /// Calculates the survival function for the
+    /// binomial distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(p)(x + 1, n - x)
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            0.0
+        } else {
+            let k = x;
+            beta::beta_reg(k as f64 + 1.0, (self.n - k) as f64, self.p)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// binomial distribution at `x`, i.e. the smallest `k` such that
+    /// `cdf(k) >= x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the Cornish-Fisher approximation
+    ///
+    /// ```ignore
+    /// np + σ * (z + γ * (z^2 - 1) / 6)
+    /// ```
+    ///
+    /// where `σ = sqrt(np(1 - p))`, `γ = (1 - 2p) / σ` and `z` is the
+    /// standard normal quantile of `x`, so that only a handful of cdf
+    /// evaluations are needed even for very large `n`
+    fn inverse_cdf(&self, x: f64) -> u64 {
+        let mean = self.p * self.n as f64;
+        let std_dev = (mean * (1.0 - self.p)).sqrt();
+        let guess = if std_dev > 0.0 && x > 0.0 && x < 1.0 {
+            let z = super::normal::inverse_cdf_unchecked(x, 0.0, 1.0);
+            let skew = (1.0 - 2.0 * self.p) / std_dev;
+            mean + std_dev * (z + skew * (z * z - 1.0) / 6.0)
+        } else {
+            mean
+        };
+        internal::discrete_inverse_cdf(self, x, guess.max(0.0).floor() as u64)
+    }
 }
 
 impl Min<u64> for Binomial {
@@ -200,6 +256,97 @@ impl Distribution<f64> for Binomial {
     fn skewness(&self) -> Option<f64> {
         Some((1.0 - 2.0 * self.p) / (self.n as f64 * self.p * (1.0 - self.p)).sqrt())
     }
+    /// Returns the excess kurtosis of the binomial distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `n == 0`, `p == 0.0` or `p == 1.0` since the
+    /// distribution is then degenerate
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - 6p(1 - p)) / (np(1 - p))
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        if self.n == 0 || self.p == 0.0 || self.p == 1.0 {
+            return None;
+        }
+        let pq = self.p * (1.0 - self.p);
+        Some((1.0 - 6.0 * pq) / (self.n as f64 * pq))
+    }
+    /// Returns the `k`th central moment of the binomial distribution
+    ///
+    /// # Remarks
+    ///
+    /// The cumulants of the binomial distribution are `n` times those of a
+    /// single bernoulli trial, so they are found from the bernoulli central
+    /// moments and converted back into central moments
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// κ_k = n * κ'_k
+    /// ```
+    ///
+    /// where `κ'_k` are the cumulants of the bernoulli distribution with
+    /// central moments `p(1 - p)^k + (1 - p)(-p)^k`
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let q = 1.0 - self.p;
+        let trial: Vec<f64> = (0..=k as i32)
+            .map(|j| match j {
+                0 => 1.0,
+                1 => 0.0,
+                _ => self.p * q.powi(j) + q * (-self.p).powi(j),
+            })
+            .collect();
+        let cumulants: Vec<f64> = internal::cumulants_from_central_moments(&trial)
+            .into_iter()
+            .map(|c| self.n as f64 * c)
+            .collect();
+        Some(internal::central_moments_from_cumulants(&cumulants)[k as usize])
+    }
+}
+
+impl GeneratingFunction<f64> for Binomial {
+    /// Returns the moment generating function of the binomial distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - p + p * e^t)^n
+    /// ```
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the binomial distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * ln(1 - p + p * e^t)
+    /// ```
+    fn cgf(&self, t: f64) -> Option<f64> {
+        if self.n == 0 {
+            Some(0.0)
+        } else {
+            Some(self.n as f64 * (self.p * t.exp_m1()).ln_1p())
+        }
+    }
+    /// Returns the characteristic function of the binomial distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - p + p * e^(it))^n
+    /// ```
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let s = (t / 2.0).sin();
+        Complex::new(1.0 - 2.0 * self.p * s * s, self.p * t.sin()).powf(self.n as f64)
+    }
 }
 
 impl Median<f64> for Binomial {
@@ -298,6 +445,162 @@ impl Discrete<u64, f64> for Binomial {
     }
 }
 
+/// Generates one sample from the binomial distribution either by
+/// sequential inversion if `n * min(p, 1 - p) < 30` or by the BTPE
+/// algorithm of V. Kachitvichyanukul and B. W. Schmeiser from
+/// Communications of the ACM Vol. 31 No. 2 (1988) pp. 216 - 222
+/// otherwise
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, p: f64, n: u64) -> u64 {
+    if p <= 0.0 || n == 0 {
+        return 0;
+    }
+    if p >= 1.0 {
+        return n;
+    }
+    // both algorithms assume p <= 0.5 and sample the number of failures
+    // otherwise
+    let q = p.min(1.0 - p);
+    let x = if n as f64 * q < 30.0 {
+        sample_inversion(rng, q, n)
+    } else {
+        sample_btpe(rng, q, n)
+    };
+    if p > 0.5 {
+        n - x
+    } else {
+        x
+    }
+}
+
+/// Draws by walking the pmf from zero until the uniform variate is used up.
+/// Walks past 110 are vanishingly rare for means below 30 and only happen
+/// through rounding, so they are restarted instead of running on to `n`
+fn sample_inversion<R: Rng + ?Sized>(rng: &mut R, p: f64, n: u64) -> u64 {
+    let s = p / (1.0 - p);
+    let a = (n as f64 + 1.0) * s;
+    let r0 = (n as f64 * (-p).ln_1p()).exp();
+    let bound = n.min(110);
+    'draw: loop {
+        let mut u: f64 = rng.gen();
+        let mut r = r0;
+        let mut x = 0;
+        while u > r {
+            u -= r;
+            x += 1;
+            if x > bound {
+                continue 'draw;
+            }
+            r *= a / x as f64 - s;
+        }
+        return x;
+    }
+}
+
+/// BTPE for `p <= 0.5` and `n * p >= 30`. The target is covered by a
+/// triangle around the mode, two parallelograms and two exponential tails.
+/// Candidates are accepted through a squeeze on the log ratio of the pmf
+/// at the candidate to the pmf at the mode, falling back to an explicit
+/// product near the mode and to Stirling's series elsewhere
+fn sample_btpe<R: Rng + ?Sized>(rng: &mut R, p: f64, n: u64) -> u64 {
+    let nf = n as f64;
+    let q = 1.0 - p;
+    let npq = nf * p * q;
+    let f_m = nf * p + p;
+    let m = f_m.floor();
+    let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+    let x_m = m + 0.5;
+    let x_l = x_m - p1;
+    let x_r = x_m + p1;
+    let c = 0.134 + 20.5 / (15.3 + m);
+    let a = (f_m - x_l) / (f_m - x_l * p);
+    let lambda_l = a * (1.0 + 0.5 * a);
+    let a = (x_r - f_m) / (x_r * q);
+    let lambda_r = a * (1.0 + 0.5 * a);
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let p3 = p2 + c / lambda_l;
+    let p4 = p3 + c / lambda_r;
+
+    loop {
+        let u = rng.gen::<f64>() * p4;
+        let mut v: f64 = rng.gen();
+        let y = if u <= p1 {
+            // triangular region, always accepted
+            return (x_m - p1 * v + u).floor() as u64;
+        } else if u <= p2 {
+            let x = x_l + (u - p1) / c;
+            v = v * c + 1.0 - (m - x + 0.5).abs() / p1;
+            if v > 1.0 {
+                continue;
+            }
+            x.floor()
+        } else if u <= p3 {
+            let y = (x_l + v.ln() / lambda_l).floor();
+            if y < 0.0 {
+                continue;
+            }
+            v *= (u - p2) * lambda_l;
+            y
+        } else {
+            let y = (x_r - v.ln() / lambda_r).floor();
+            if y > nf {
+                continue;
+            }
+            v *= (u - p3) * lambda_r;
+            y
+        };
+
+        let k = (y - m).abs();
+        if k <= 20.0 || k >= npq / 2.0 - 1.0 {
+            // evaluate f(y) / f(m) by the pmf recurrence
+            let s = p / q;
+            let a = s * (nf + 1.0);
+            let mut f = 1.0;
+            if m < y {
+                let mut i = m + 1.0;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1.0;
+                }
+            } else if m > y {
+                let mut i = y + 1.0;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1.0;
+                }
+            }
+            if v <= f {
+                return y as u64;
+            }
+            continue;
+        }
+
+        let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / npq + 0.5);
+        let t = -k * k / (2.0 * npq);
+        let alpha = v.ln();
+        if alpha < t - rho {
+            return y as u64;
+        }
+        if alpha > t + rho {
+            continue;
+        }
+
+        let x1 = y + 1.0;
+        let f1 = m + 1.0;
+        let z = nf + 1.0 - m;
+        let w = nf - y + 1.0;
+        let bound = x_m * (f1 / x1).ln()
+            + (nf - m + 0.5) * (z / w).ln()
+            + (y - m) * (w * p / (x1 * q)).ln()
+            + internal::stirling_tail(f1)
+            + internal::stirling_tail(z)
+            - internal::stirling_tail(x1)
+            - internal::stirling_tail(w);
+        if alpha <= bound {
+            return y as u64;
+        }
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -393,6 +696,79 @@ mod tests {
         test_case(1.0, 2, f64::NEG_INFINITY, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Binomial| x.kurtosis().unwrap();
+        test_almost(0.3, 20, -0.061904761904761904762, 1e-16, kurtosis);
+        test_case(0.5, 10, -0.2, kurtosis);
+        test_case(0.0, 4, None, |x| x.kurtosis());
+        test_case(1.0, 4, None, |x| x.kurtosis());
+        test_case(0.3, 0, None, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Binomial| x.central_moment(k).unwrap();
+        test_case(0.3, 20, 1.0, central_moment(0));
+        test_case(0.3, 20, 0.0, central_moment(1));
+        test_almost(0.3, 20, 4.2, 1e-14, central_moment(2));
+        test_almost(0.3, 20, 1.68, 1e-14, central_moment(3));
+        test_almost(0.3, 20, 51.828, 1e-13, central_moment(4));
+        test_almost(0.3, 20, 68.0064, 1e-12, central_moment(5));
+        test_almost(0.3, 20, 1070.7144, 1e-11, central_moment(6));
+        test_almost(0.0, 20, 0.0, 1e-15, central_moment(4));
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Binomial| x.moment(k).unwrap();
+        test_case(0.3, 20, 1.0, moment(0));
+        test_almost(0.3, 20, 6.0, 1e-15, moment(1));
+        test_almost(0.3, 20, 40.2, 1e-13, moment(2));
+        test_almost(0.3, 20, 2295.348, 1e-11, moment(4));
+        test_almost(0.3, 20, 167067.6648, 1e-9, moment(6));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Binomial| x.mgf(t).unwrap();
+        test_almost(0.3, 10, 0.049640998380907711989, 1e-16, mgf(-2.0));
+        test_almost(0.3, 10, 2.7130406432832330747, 1e-14, mgf(0.3));
+        test_almost(0.3, 10, 4968.3307768085259416, 1e-11, mgf(1.7));
+        test_almost(0.9, 50, 1.9872335010075504704e-33, 1e-16, mgf(-2.0));
+        test_almost(0.9, 50, 879405.48077430197842, 1e-9, mgf(0.3));
+        test_almost(0.9, 50, 1.1574754940461305851e+35, 1e21, mgf(1.7));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Binomial| x.cgf(t).unwrap();
+        test_almost(0.3, 10, -3.0029382064203495539, 1e-14, cgf(-2.0));
+        test_almost(0.3, 10, 0.99807001450200621957, 1e-15, cgf(0.3));
+        test_almost(0.3, 10, 8.5108392028800637766, 1e-14, cgf(1.7));
+        test_almost(0.9, 50, -75.298564597791066824, 1e-13, cgf(-2.0));
+        test_almost(0.9, 50, 13.687001368111406332, 1e-13, cgf(0.3));
+        test_almost(0.9, 50, 80.736719590095305568, 1e-13, cgf(1.7));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Binomial| x.cf(t).re;
+        let im = |t| move |x: Binomial| x.cf(t).im;
+        test_almost(0.3, 10, -0.003058404870739005814, 1e-15, re(-2.0));
+        test_almost(0.3, 10, 0.010488801387464630529, 1e-15, im(-2.0));
+        test_almost(0.3, 10, 0.56816170855739866544, 1e-15, re(0.3));
+        test_almost(0.3, 10, 0.71040396567122434489, 1e-15, im(0.3));
+        test_almost(0.3, 10, -0.018758213707713361504, 1e-15, re(1.7));
+        test_almost(0.3, 10, -0.035579147203167584939, 1e-15, im(1.7));
+        test_almost(0.9, 50, 0.00056785656280844086326, 1e-14, re(-2.0));
+        test_almost(0.9, 50, -0.00029227857606568830446, 1e-14, im(-2.0));
+        test_almost(0.9, 50, 0.4754959822544534479, 1e-14, re(0.3));
+        test_almost(0.9, 50, 0.66469345808054210618, 1e-14, im(0.3));
+        test_almost(0.9, 50, -0.0021409631442439888689, 1e-14, re(1.7));
+        test_almost(0.9, 50, -0.0026651014130319198001, 1e-14, im(1.7));
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Binomial| x.median();
@@ -510,9 +886,56 @@ mod tests {
         test_case(0.5, 3, 1.0, cdf(5));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Binomial| x.sf(arg);
+        test_almost(0.3, 100, 0.450876399231209459984793975309, 1e-14, sf(30));
+        test_almost(0.3, 100, 7.42112949282592470264036404475e-26, 1e-36, sf(80));
+        test_case(0.3, 100, 0.0, sf(100));
+        test_case(0.3, 100, 0.0, sf(200));
+        test_almost(0.5, 10, 0.0009765625, 1e-17, sf(9));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Binomial| x.inverse_cdf(arg);
+        test_case(0.3, 10000, 0, inverse_cdf(0.0));
+        test_case(0.3, 10000, 2711, inverse_cdf(1e-10));
+        test_case(0.3, 10000, 2859, inverse_cdf(0.001));
+        test_case(0.3, 10000, 2941, inverse_cdf(0.1));
+        test_case(0.3, 10000, 3000, inverse_cdf(0.5));
+        test_case(0.3, 10000, 3059, inverse_cdf(0.9));
+        test_case(0.3, 10000, 3219, inverse_cdf(0.999999));
+        test_case(0.3, 10000, 10000, inverse_cdf(1.0));
+        test_case(0.0, 10, 0, inverse_cdf(0.5));
+        test_case(1.0, 10, 10, inverse_cdf(0.5));
+        tests::check_discrete_inverse_cdf(&try_create(0.3, 10));
+        tests::check_discrete_inverse_cdf(&try_create(0.01, 1000));
+        tests::check_discrete_inverse_cdf(&try_create(0.9, 1000000));
+    }
+
     #[test]
     fn test_discrete() {
         tests::check_discrete_distribution(&try_create(0.3, 5), 5);
         tests::check_discrete_distribution(&try_create(0.7, 10), 10);
     }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(0.3, 10));
+        tests::check_discrete_samples(&try_create(0.0, 5));
+        tests::check_discrete_samples(&try_create(1.0, 5));
+    }
+
+    #[test]
+    fn test_sample_goodness_of_fit() {
+        // sequential inversion
+        tests::check_goodness_of_fit(&try_create(0.3, 10), 0, 10, 10_000);
+        tests::check_goodness_of_fit(&try_create(1e-8, 1_000_000_000), 0, 40, 10_000);
+        tests::check_goodness_of_fit(&try_create(0.99, 1000), 960, 1000, 10_000);
+        // BTPE
+        tests::check_goodness_of_fit(&try_create(0.5, 100), 0, 100, 50_000);
+        tests::check_goodness_of_fit(&try_create(0.7, 1000), 600, 800, 50_000);
+        tests::check_goodness_of_fit(&try_create(0.3, 1_000_000_000), 299_900_000, 300_100_000, 50_000);
+    }
 }
@@ -1,6 +1,7 @@
 use crate::distribution::{Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -25,6 +26,7 @@ use std::f64;
 pub struct Categorical {
     norm_pmf: Vec<f64>,
     cdf: Vec<f64>,
+    alias: AliasTable,
 }
 
 impl Categorical {
@@ -65,7 +67,12 @@ impl Categorical {
                 .iter_mut()
                 .zip(prob_mass.iter())
                 .for_each(|(np, pm)| *np = *pm / sum);
-            Ok(Categorical { norm_pmf, cdf })
+            let alias = AliasTable::new(prob_mass);
+            Ok(Categorical {
+                norm_pmf,
+                cdf,
+                alias,
+            })
         }
     }
 
@@ -74,9 +81,17 @@ impl Categorical {
     }
 }
 
+impl ::rand::distributions::Distribution<u64> for Categorical {
+    /// Generates one sample from the categorical distribution in constant
+    /// time using the alias table built on construction
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.alias.sample(rng) as u64
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for Categorical {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        sample_unchecked(rng, &self.cdf)
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
     }
 }
 
@@ -98,6 +113,25 @@ impl DiscreteCDF<u64, f64> for Categorical {
             self.cdf.get(x as usize).unwrap() / self.cdf_max()
         }
     }
+    /// Calculates the survival function for the categorical
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(p_j) from x+1..n
+    /// ```
+    ///
+    /// where `p_j` is the probability mass for the `j`th category
+    /// and `n` is the number of categories
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.cdf.len() as u64 {
+            0.0
+        } else {
+            self.norm_pmf[x as usize + 1..].iter().sum()
+        }
+    }
+
     /// Calculates the inverse cumulative distribution function for the
     /// categorical
     /// distribution at `x`
@@ -217,6 +251,87 @@ impl Distribution<f64> for Categorical {
             .sum::<f64>();
         Some(entr)
     }
+    /// Returns the excess kurtosis of the categorical distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if all of the mass lies in a single category since
+    /// the variance is then zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(p_j * (j - μ)^4) / σ^4 - 3
+    /// ```
+    ///
+    /// where `p_j` is the `j`th probability mass, `μ` is the mean and
+    /// `σ` the standard deviation
+    fn kurtosis(&self) -> Option<f64> {
+        let var = self.variance()?;
+        if var == 0.0 {
+            return None;
+        }
+        Some(self.central_moment(4)? / (var * var) - 3.0)
+    }
+    /// Returns the `k`th central moment of the categorical distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(p_j * (j - μ)^k)
+    /// ```
+    ///
+    /// where `p_j` is the `j`th probability mass and `μ` is the mean
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let mu = self.mean()?;
+        let moment = self
+            .norm_pmf
+            .iter()
+            .enumerate()
+            .fold(0.0, |acc, (idx, &val)| {
+                acc + (idx as f64 - mu).powi(k as i32) * val
+            });
+        Some(moment)
+    }
+}
+
+impl GeneratingFunction<f64> for Categorical {
+    /// Returns the moment generating function of the categorical
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(p_j * e^(tj))
+    /// ```
+    ///
+    /// where `p_j` is the `j`th probability mass
+    fn mgf(&self, t: f64) -> Option<f64> {
+        Some(
+            self.norm_pmf
+                .iter()
+                .enumerate()
+                .map(|(j, &p)| p * (t * j as f64).exp())
+                .sum(),
+        )
+    }
+    /// Returns the characteristic function of the categorical
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(p_j * e^(itj))
+    /// ```
+    ///
+    /// where `p_j` is the `j`th probability mass
+    fn cf(&self, t: f64) -> Complex<f64> {
+        self.norm_pmf
+            .iter()
+            .enumerate()
+            .map(|(j, &p)| Complex::from_polar(p, t * j as f64))
+            .sum()
+    }
 }
 impl Median<f64> for Categorical {
     /// Returns the median of the categorical distribution
@@ -253,13 +368,78 @@ impl Discrete<u64, f64> for Categorical {
 
 /// Draws a sample from the categorical distribution described by `cdf`
 /// without doing any bounds checking
+///
+/// # Remarks
+///
+/// Finds the first entry of `cdf` that is at least a uniform draw by
+/// binary search, so each sample costs `O(log k)` for `k` categories.
+/// Use `AliasTable` for constant time draws when many samples are needed
 pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, cdf: &[f64]) -> f64 {
     let draw = rng.gen::<f64>() * cdf.last().unwrap();
-    cdf.iter()
-        .enumerate()
-        .find(|(_, val)| **val >= draw)
-        .map(|(i, _)| i)
-        .unwrap() as f64
+    let mut low = 0;
+    let mut high = cdf.len() - 1;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if cdf[mid] < draw {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low as f64
+}
+
+/// Alias table for drawing indices proportionally to a set of
+/// probability masses in constant time, built in linear time by
+/// Vose's method from M. D. Vose, IEEE Transactions on Software
+/// Engineering Vol. 17 No. 9 (1991) pp. 972 - 974
+///
+/// Each of the `k` columns holds the probability of keeping its own index
+/// and the index it otherwise defers to, so a draw picks a column uniformly
+/// and then flips one biased coin
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Builds the alias table for the given probability masses. Performs
+    /// no parameter checking; the masses need not be normalized but must
+    /// have a positive sum
+    pub fn new(prob_mass: &[f64]) -> AliasTable {
+        let k = prob_mass.len();
+        let sum: f64 = prob_mass.iter().sum();
+        let mut scaled: Vec<f64> = prob_mass.iter().map(|p| p * k as f64 / sum).collect();
+        let mut prob = vec![1.0; k];
+        let mut alias: Vec<usize> = (0..k).collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..k).partition(|&i| scaled[i] < 1.0);
+        while let (Some(&l), Some(&g)) = (small.last(), large.last()) {
+            small.pop();
+            prob[l] = scaled[l];
+            alias[l] = g;
+            scaled[g] -= 1.0 - scaled[l];
+            if scaled[g] < 1.0 {
+                large.pop();
+                small.push(g);
+            }
+        }
+        // whatever is left over differs from one only by rounding and
+        // keeps its default of always being chosen
+        AliasTable { prob, alias }
+    }
+
+    /// Draws an index with probability proportional to its mass
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let i = rng.gen_range(0, self.prob.len());
+        if rng.gen::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
 }
 
 /// Computes the cdf from the given probability masses. Performs
@@ -305,6 +485,47 @@ fn test_prob_mass_to_cdf() {
     assert_eq!(res, [0.0, 0.5, 1.0, 4.0, 5.1]);
 }
 
+#[test]
+fn test_sample_unchecked() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    // matches a linear scan for the first cdf entry at least the draw, which
+    // never lands on a category without mass
+    let cdf = prob_mass_to_cdf(&[0.0, 1.0, 0.0, 2.0, 0.5, 0.0]);
+    let mut rng = StdRng::seed_from_u64(0x5eed);
+    let mut scan_rng = StdRng::seed_from_u64(0x5eed);
+    for _ in 0..1000 {
+        let draw = scan_rng.gen::<f64>() * cdf.last().unwrap();
+        let expected = cdf.iter().position(|&c| c >= draw).unwrap();
+        assert_eq!(sample_unchecked(&mut rng, &cdf), expected as f64);
+    }
+}
+
+#[test]
+fn test_alias_table() {
+    // every mass is recovered from the columns it owns and the columns
+    // that defer to it
+    let check = |arr: &[f64]| {
+        let table = AliasTable::new(arr);
+        let k = arr.len() as f64;
+        let sum: f64 = arr.iter().sum();
+        let mut mass = vec![0.0; arr.len()];
+        for (i, (&p, &a)) in table.prob.iter().zip(table.alias.iter()).enumerate() {
+            mass[i] += p / k;
+            mass[a] += (1.0 - p) / k;
+        }
+        for (m, p) in mass.iter().zip(arr.iter()) {
+            assert_almost_eq!(*m, p / sum, 1e-15);
+        }
+    };
+    check(&[1.0]);
+    check(&[0.0, 1.0, 2.0]);
+    check(&[0.1, 0.2, 0.3, 0.4]);
+    check(&[5.0, 0.0, 0.0, 1e-10, 3.0, 1.0, 1.0]);
+    check(&(1..=1000).map(|i| (i as f64).sqrt()).collect::<Vec<_>>());
+}
+
 #[test]
 fn test_binary_index() {
     let arr = [0.0, 3.0, 5.0, 9.0, 10.0];
@@ -392,6 +613,45 @@ mod tests {
         test_case(&[1.0, 0.0, 1.0], 1.0, variance);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Categorical| x.kurtosis().unwrap();
+        test_almost(&[1.0, 2.0, 3.0, 4.0], -0.8, 1e-15, kurtosis);
+        test_almost(&[0.0, 1.0, 0.0, 1.0], -2.0, 1e-15, kurtosis);
+        test_case(&[1.0], None, |x| x.kurtosis());
+        test_case(&[0.0, 1.0, 0.0], None, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Categorical| x.central_moment(k).unwrap();
+        test_case(&[1.0, 2.0, 3.0, 4.0], 1.0, central_moment(0));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 1.0, 1e-15, central_moment(2));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], -0.6, 1e-15, central_moment(3));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 7.0, 1e-14, central_moment(6));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Categorical| x.mgf(t).unwrap();
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 0.23409130055241784908, 1e-15, mgf(-1.0));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 3.037904430812965129, 1e-15, mgf(0.5));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 179.32877362682345081, 1e-12, mgf(2.0));
+        test_case(&[0.0, 1.0], 1.0f64.exp(), mgf(1.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Categorical| x.cf(t).re;
+        let im = |t| move |x: Categorical| x.cf(t).im;
+        test_almost(&[1.0, 2.0, 3.0, 4.0], -0.31278058843069295553, 1e-15, re(-1.0));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], -0.49753142823323069879, 1e-15, im(-1.0));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 0.46590208480559762248, 1e-15, re(0.5));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 0.74732439780483132443, 1e-15, im(0.5));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 0.20474566109163435643, 1e-15, re(2.0));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], -0.15694746250681248546, 1e-15, im(2.0));
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: Categorical| x.entropy().unwrap();
@@ -486,9 +746,29 @@ mod tests {
         get_value(&[4.0, 2.5, 2.5, 1.0], inverse_cdf(1.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Categorical| x.sf(arg);
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 0.9, 1e-15, sf(0));
+        test_almost(&[1.0, 2.0, 3.0, 4.0], 0.4, 1e-15, sf(2));
+        test_case(&[1.0, 2.0, 3.0, 4.0], 0.0, sf(3));
+        test_case(&[1.0, 2.0, 3.0, 4.0], 0.0, sf(10));
+        test_almost(&[1.0, 1e-20], 1e-20, 1e-35, sf(0));
+    }
+
     #[test]
     fn test_discrete() {
         tests::check_discrete_distribution(&try_create(&[1.0, 2.0, 3.0, 4.0]), 4);
         tests::check_discrete_distribution(&try_create(&[0.0, 1.0, 2.0, 3.0, 4.0]), 5);
     }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(&[1.0, 2.0, 3.0, 4.0]));
+        tests::check_discrete_samples(&try_create(&[0.0, 1.0, 0.0]));
+        tests::check_goodness_of_fit(&try_create(&[1.0, 2.0, 3.0, 4.0]), 0, 3, 10_000);
+        tests::check_goodness_of_fit(&try_create(&[0.0, 5.0, 1e-3, 0.0, 3.0, 1.0]), 0, 5, 10_000);
+        let masses: Vec<f64> = (1..=500).map(|i| (i % 7) as f64).collect();
+        tests::check_goodness_of_fit(&try_create(&masses), 0, 499, 100_000);
+    }
 }
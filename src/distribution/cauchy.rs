@@ -1,6 +1,7 @@
 use crate::distribution::{Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -99,6 +100,65 @@ impl ContinuousCDF<f64, f64> for Cauchy {
     fn cdf(&self, x: f64) -> f64 {
         (1.0 / f64::consts::PI) * ((x - self.location) / self.scale).atan() + 0.5
     }
+
+    /// Calculates the survival function for the
+    /// cauchy distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// In the upper tail the equivalent form `arctan(γ / (x - x_0)) / π`
+    /// is used to avoid cancellation
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) - (1 / π) * arctan((x - x_0) / γ)
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z > 1.0 {
+            (1.0 / z).atan() / f64::consts::PI
+        } else {
+            0.5 - z.atan() / f64::consts::PI
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// cauchy distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Each half of the support is evaluated through `1 / tan(π * p)` or
+    /// `1 / tan(π * (1 - p))` so that tail probabilities keep their
+    /// precision
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0 + γ * tan(π * (p - 1 / 2))
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 {
+            f64::NEG_INFINITY
+        } else if p == 1.0 {
+            f64::INFINITY
+        } else if p < 0.5 {
+            self.location - self.scale / (f64::consts::PI * p).tan()
+        } else {
+            self.location + self.scale / (f64::consts::PI * (1.0 - p)).tan()
+        }
+    }
 }
 
 impl Min<f64> for Cauchy {
@@ -142,6 +202,50 @@ impl Distribution<f64> for Cauchy {
     fn entropy(&self) -> Option<f64> {
         Some((4.0 * f64::consts::PI * self.scale).ln())
     }
+    /// Returns the `k`th raw moment of the cauchy distribution, which
+    /// only exists for `k = 0`
+    fn moment(&self, k: u32) -> Option<f64> {
+        if k == 0 {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+    /// Returns the `k`th central moment of the cauchy distribution, which
+    /// only exists for `k = 0`
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        self.moment(k)
+    }
+}
+
+impl GeneratingFunction<f64> for Cauchy {
+    /// Returns the moment generating function of the cauchy distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// The integral diverges for every `t != 0`, so this returns `None`
+    /// everywhere except at the origin where it is trivially `1`
+    fn mgf(&self, t: f64) -> Option<f64> {
+        if t == 0.0 {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+    /// Returns the characteristic function of the cauchy distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(ix_0 t - γ|t|)
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn cf(&self, t: f64) -> Complex<f64> {
+        Complex::from_polar((-self.scale * t.abs()).exp(), self.location * t)
+    }
 }
 
 impl Median<f64> for Cauchy {
@@ -277,6 +381,33 @@ mod tests {
         test_case(10.0, 11.0, 4.92891951976766133704, entropy);
     }
 
+    #[test]
+    fn test_mgf() {
+        assert_eq!(Some(1.0), try_create(1.0, 2.0).mgf(0.0));
+        assert_eq!(None, try_create(1.0, 2.0).mgf(-1.0));
+        assert_eq!(None, try_create(1.0, 2.0).mgf(0.7));
+        assert_eq!(None, try_create(-3.0, 0.5).mgf(1e-300));
+        assert_eq!(None, try_create(-3.0, 0.5).cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Cauchy| x.cf(t).re;
+        let im = |t| move |x: Cauchy| x.cf(t).im;
+        test_almost(1.0, 2.0, 0.073121965598059632366, 1e-15, re(-1.0));
+        test_almost(1.0, 2.0, -0.11388071406436808923, 1e-15, im(-1.0));
+        test_almost(1.0, 2.0, 0.18860776127881244411, 1e-15, re(0.7));
+        test_almost(1.0, 2.0, 0.15886212579029802556, 1e-15, im(0.7));
+        test_almost(1.0, 2.0, -0.0076219951828865475553, 1e-15, re(2.0));
+        test_almost(1.0, 2.0, 0.016654363312194378309, 1e-15, im(2.0));
+        test_almost(-3.0, 0.5, -0.60046080207362518509, 1e-15, re(-1.0));
+        test_almost(-3.0, 0.5, 0.085593611587203412156, 1e-15, im(-1.0));
+        test_almost(-3.0, 0.5, -0.3557590370524071267, 1e-15, re(0.7));
+        test_almost(-3.0, 0.5, -0.60829335961109549983, 1e-15, im(0.7));
+        test_almost(-3.0, 0.5, 0.35322690848236023715, 1e-15, re(2.0));
+        test_almost(-3.0, 0.5, 0.10279121733206099859, 1e-15, im(2.0));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Cauchy| x.mode().unwrap();
@@ -410,9 +541,43 @@ mod tests {
         test_case(f64::INFINITY, 1.0, 0.0, cdf(5.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Cauchy| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, 0.75, 1e-15, sf(-1.0));
+        test_case(0.0, 1.0, 0.5, sf(0.0));
+        test_almost(0.0, 1.0, 0.25, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 3.18309886183790671536706493791e-11, 1e-24, sf(1e10));
+        test_case(0.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Cauchy| x.inverse_cdf(arg);
+        test_case(1.0, 2.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(1.0, 2.0, -6366197722.675813430546, 1e-5, inverse_cdf(1e-10));
+        test_almost(1.0, 2.0, -5.155367074350506805141, 1e-14, inverse_cdf(0.1));
+        test_almost(1.0, 2.0, 1.0, 1e-15, inverse_cdf(0.5));
+        test_almost(1.0, 2.0, 3.0, 1e-15, inverse_cdf(0.75));
+        test_case(1.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(1.0, 2.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(-1.2, 3.4), -1500.0, 1500.0);
         tests::check_continuous_distribution(&try_create(-4.5, 6.7), -5000.0, 5000.0);
     }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(0.0, 1.0);
+        assert_eq!(n.moment(0), Some(1.0));
+        assert_eq!(n.central_moment(0), Some(1.0));
+        assert!(n.moment(1).is_none());
+        assert!(n.central_moment(2).is_none());
+        assert!(n.kurtosis().is_none());
+    }
 }
@@ -99,6 +99,49 @@ impl ContinuousCDF<f64, f64> for Chi {
             gamma::gamma_lr(self.freedom / 2.0, x * x / 2.0)
         }
     }
+
+    /// Calculates the survival function for the chi
+    /// distribution at `x`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q(k / 2, x^2 / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Q` is
+    /// the upper regularized Gamma function
+    fn sf(&self, x: f64) -> f64 {
+        if self.freedom == f64::INFINITY || x == f64::INFINITY {
+            0.0
+        } else if x <= 0.0 {
+            1.0
+        } else {
+            gamma::gamma_ur(self.freedom / 2.0, x * x / 2.0)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(2 * P^-1(k / 2, p))
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `P^-1` is the inverse
+    /// of the lower regularized gamma function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        (2.0 * gamma::inv_gamma_lr(self.freedom / 2.0, p)).sqrt()
+    }
 }
 
 impl Min<f64> for Chi {
@@ -212,6 +255,27 @@ impl Distribution<f64> for Chi {
         let skew = self.mean()? * (1.0 - 2.0 * sigma * sigma) / (sigma * sigma * sigma);
         Some(skew)
     }
+    /// Returns the excess kurtosis of the chi distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom` is `INF`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * (1 - μ * σ * γ - σ^2) / σ^2
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` the standard deviation and `γ` the
+    /// skewness of the distribution
+    fn kurtosis(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let sigma = self.std_dev()?;
+        let skew = self.skewness()?;
+        let var = sigma * sigma;
+        Some(2.0 * (1.0 - mean * sigma * skew - var) / var)
+    }
 }
 
 impl Mode<Option<f64>> for Chi {
@@ -409,6 +473,13 @@ mod tests {
         test_almost(3.0, 0.485692828049590809, 1e-12, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Chi| x.kurtosis().unwrap();
+        test_almost(3.0, 0.10816384281629467411, 1e-11, kurtosis);
+        test_almost(1.0, 0.86917730360597411666, 1e-13, kurtosis);
+    }
+
     #[test]
     #[should_panic]
     fn test_skewness_degen() {
@@ -544,6 +615,25 @@ mod tests {
         test_case(1.0, 0.0, cdf(-1.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Chi| x.sf(arg);
+        test_case(2.0, 1.0, sf(0.0));
+        test_almost(2.0, 1.92874984796391778301734281653e-22, 1e-34, sf(10.0));
+        test_case(2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Chi| x.inverse_cdf(arg);
+        test_case(2.0, 0.0, inverse_cdf(0.0));
+        test_almost(2.0, 1.177410022515474691012, 1e-14, inverse_cdf(0.5));
+        test_almost(5.0, 3.039137525644589590598, 1e-14, inverse_cdf(0.9));
+        test_case(2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(5.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0), 0.0, 10.0);
@@ -1,6 +1,7 @@
 use crate::distribution::{Continuous, ContinuousCDF, Gamma};
 use crate::statistics::*;
 use crate::Result;
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -117,6 +118,40 @@ impl ContinuousCDF<f64, f64> for ChiSquared {
     fn cdf(&self, x: f64) -> f64 {
         self.g.cdf(x)
     }
+
+    /// Calculates the survival function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / Γ(k / 2)) * Γ(k / 2, x / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `Γ` is the gamma function,
+    /// and `Γ(a, x)` is the upper incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        self.g.sf(x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi-squared distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * P^-1(k / 2, p)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `P^-1` is the inverse
+    /// of the lower regularized gamma function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.g.inverse_cdf(p)
+    }
 }
 
 impl Min<f64> for ChiSquared {
@@ -199,6 +234,86 @@ impl Distribution<f64> for ChiSquared {
     fn skewness(&self) -> Option<f64> {
         self.g.skewness()
     }
+    /// Returns the excess kurtosis of the chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 / k
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom
+    fn kurtosis(&self) -> Option<f64> {
+        self.g.kurtosis()
+    }
+    /// Returns the `n`th raw moment of the chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// prod(k + 2i) for i in 0..n
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom
+    fn moment(&self, n: u32) -> Option<f64> {
+        self.g.moment(n)
+    }
+    /// Returns the `n`th central moment of the chi-squared distribution,
+    /// computed from the cumulants `2^(j - 1) (j - 1)! k` where `k` is the
+    /// degrees of freedom
+    fn central_moment(&self, n: u32) -> Option<f64> {
+        self.g.central_moment(n)
+    }
+}
+
+impl GeneratingFunction<f64> for ChiSquared {
+    /// Returns the moment generating function of the chi-squared distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= 1 / 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - 2t)^(-k / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.g.mgf(t)
+    }
+    /// Returns the cumulant generating function of the chi-squared distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= 1 / 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -k / 2 * ln(1 - 2t)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom
+    fn cgf(&self, t: f64) -> Option<f64> {
+        self.g.cgf(t)
+    }
+    /// Returns the characteristic function of the chi-squared distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - 2it)^(-k / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom
+    fn cf(&self, t: f64) -> Complex<f64> {
+        self.g.cf(t)
+    }
 }
 
 impl Median<f64> for ChiSquared {
@@ -267,8 +382,8 @@ impl Continuous<f64, f64> for ChiSquared {
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use crate::statistics::Median;
-    use crate::distribution::ChiSquared;
+    use crate::statistics::{Distribution, Median};
+    use crate::distribution::{ChiSquared, ContinuousCDF};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -304,6 +419,25 @@ mod tests {
         test_case(3.0, 3.0 - 2.0 / 3.0, median);
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: ChiSquared| x.sf(arg);
+        test_case(4.0, 1.0, sf(0.0));
+        test_almost(4.0, 3.7572767357810443225892927619e-42, 1e-54, sf(200.0));
+        test_case(4.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: ChiSquared| x.inverse_cdf(arg);
+        test_case(1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 3.841458820694125958361, 1e-13, inverse_cdf(0.95));
+        test_almost(10.0, 3.940299136119060031321, 1e-13, inverse_cdf(0.05));
+        test_case(1.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(10.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         // TODO: figure out why this test fails:
@@ -311,4 +445,20 @@ mod tests {
         tests::check_continuous_distribution(&try_create(2.0), 0.0, 10.0);
         tests::check_continuous_distribution(&try_create(5.0), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: ChiSquared| x.kurtosis().unwrap();
+        test_case(3.0, 4.0, kurtosis);
+        test_case(12.0, 1.0, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: ChiSquared| x.moment(k).unwrap();
+        let central_moment = |k| move |x: ChiSquared| x.central_moment(k).unwrap();
+        test_case(3.0, 135135.0, moment(6));
+        test_case(3.0, 6.0, central_moment(2));
+        test_case(3.0, 33480.0, central_moment(6));
+    }
 }
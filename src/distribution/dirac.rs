@@ -1,6 +1,7 @@
 use crate::distribution::{Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 
 /// Implements the [Dirac Delta](https://en.wikipedia.org/wiki/Dirac_delta_function#As_a_distribution)
@@ -64,6 +65,33 @@ impl ContinuousCDF<f64, f64> for Dirac {
             1.0
         }
     }
+
+    /// Calculates the survival function for the
+    /// dirac distribution at `x`
+    ///
+    /// Where the value is 0 if x >= `v`, 1 otherwise.
+    fn sf(&self, x: f64) -> f64 {
+        if x < self.0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// dirac distribution at `p`
+    ///
+    /// Where the value is `v` for every `p`.
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.0
+    }
 }
 
 impl Min<f64> for Dirac {
@@ -138,6 +166,69 @@ impl Distribution<f64> for Dirac {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the `k`th raw moment of the dirac distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// v^k
+    /// ```
+    ///
+    /// where `v` is the point of the dirac distribution
+    fn moment(&self, k: u32) -> Option<f64> {
+        Some(self.0.powi(k as i32))
+    }
+    /// Returns the `k`th central moment of the dirac distribution, which is
+    /// zero for every `k > 0`
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        if k == 0 {
+            Some(1.0)
+        } else {
+            Some(0.0)
+        }
+    }
+}
+
+impl GeneratingFunction<f64> for Dirac {
+    /// Returns the moment generating function of the dirac distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(tv)
+    /// ```
+    ///
+    /// where `v` is the point of the dirac distribution
+    fn mgf(&self, t: f64) -> Option<f64> {
+        Some((t * self.0).exp())
+    }
+    /// Returns the cumulant generating function of the dirac distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// tv
+    /// ```
+    ///
+    /// where `v` is the point of the dirac distribution
+    fn cgf(&self, t: f64) -> Option<f64> {
+        Some(t * self.0)
+    }
+    /// Returns the characteristic function of the dirac distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(itv)
+    /// ```
+    ///
+    /// where `v` is the point of the dirac distribution
+    fn cf(&self, t: f64) -> Complex<f64> {
+        Complex::from_polar(1.0, t * self.0)
+    }
 }
 
 impl Median<f64> for Dirac {
@@ -200,6 +291,13 @@ mod tests {
         assert_eq!(expected, x);
     }
 
+    fn test_almost<F>(v: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Dirac) -> f64
+    {
+        let x = eval(try_create(v));
+        assert_almost_eq!(expected, x, acc);
+    }
+
     #[test]
     fn test_create() {
         create_case(10.0);
@@ -238,6 +336,49 @@ mod tests {
         test_case(f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Dirac| x.moment(k).unwrap();
+        let central_moment = |k| move |x: Dirac| x.central_moment(k).unwrap();
+        test_case(2.0, 1.0, moment(0));
+        test_case(2.0, 8.0, moment(3));
+        test_case(-1.5, 2.25, moment(2));
+        test_case(2.0, 1.0, central_moment(0));
+        test_case(2.0, 0.0, central_moment(5));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Dirac| x.mgf(t).unwrap();
+        test_almost(0.5, 0.6065306597126334236, 1e-15, mgf(-1.0));
+        test_almost(0.5, 2.7182818284590452354, 1e-14, mgf(2.0));
+        test_almost(-3.0, 20.085536923187667741, 1e-13, mgf(-1.0));
+        test_almost(-3.0, 0.002478752176666358423, 1e-16, mgf(2.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Dirac| x.cgf(t).unwrap();
+        test_almost(0.5, -0.5, 1e-15, cgf(-1.0));
+        test_almost(0.5, 1.0, 1e-14, cgf(2.0));
+        test_almost(-3.0, 3.0, 1e-14, cgf(-1.0));
+        test_almost(-3.0, -6.0, 1e-14, cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Dirac| x.cf(t).re;
+        let im = |t| move |x: Dirac| x.cf(t).im;
+        test_almost(0.5, 0.87758256189037271612, 1e-15, re(-1.0));
+        test_almost(0.5, -0.47942553860420300027, 1e-15, im(-1.0));
+        test_almost(0.5, 0.5403023058681397174, 1e-15, re(2.0));
+        test_almost(0.5, 0.84147098480789650665, 1e-15, im(2.0));
+        test_almost(-3.0, -0.98999249660044545727, 1e-15, re(-1.0));
+        test_almost(-3.0, 0.1411200080598672221, 1e-15, im(-1.0));
+        test_almost(-3.0, 0.96017028665036602055, 1e-15, re(2.0));
+        test_almost(-3.0, 0.27941549819892587281, 1e-15, im(2.0));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Dirac| x.mode().unwrap();
@@ -275,4 +416,12 @@ mod tests {
         test_case(f64::INFINITY, 0.0, cdf(1.0));
         test_case(f64::INFINITY, 1.0, cdf(f64::INFINITY));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Dirac| x.inverse_cdf(arg);
+        test_case(0.0, 0.0, inverse_cdf(0.0));
+        test_case(3.0, 3.0, inverse_cdf(0.5));
+        test_case(3.0, 3.0, inverse_cdf(1.0));
+    }
 }
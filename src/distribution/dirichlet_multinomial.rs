@@ -0,0 +1,375 @@
+use crate::distribution::{Dirichlet, Discrete};
+use crate::function::beta;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use ::nalgebra::{DMatrix, DVector};
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Dirichlet-multinomial](https://en.wikipedia.org/wiki/Dirichlet-multinomial_distribution)
+/// distribution, the counts of `n` trials over `k` categories whose
+/// probabilities are themselves dirichlet distributed. It generalizes the
+/// beta-binomial distribution to more than two categories
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{DirichletMultinomial, Discrete};
+/// use statrs::statistics::MeanN;
+/// use statrs::prec;
+/// use nalgebra::DVector;
+///
+/// let n = DirichletMultinomial::new(&[1.0, 2.0, 3.0], 6).unwrap();
+/// assert_eq!(n.mean().unwrap(), DVector::from_vec(vec![1.0, 2.0, 3.0]));
+/// assert!(prec::almost_eq(n.pmf(&[1, 2, 3]), 5.0 / 77.0, 1e-12));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirichletMultinomial {
+    dirichlet: Dirichlet,
+    n: u64,
+}
+
+impl DirichletMultinomial {
+    /// Constructs a new dirichlet-multinomial distribution with
+    /// concentration parameters `alpha` and `n` number of trials
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alpha` has fewer than two elements or if any
+    /// element of `alpha` is `NaN`, infinite or less than or equal to `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::DirichletMultinomial;
+    ///
+    /// let mut result = DirichletMultinomial::new(&[1.0, 2.0, 3.0], 6);
+    /// assert!(result.is_ok());
+    ///
+    /// result = DirichletMultinomial::new(&[0.0, 2.0, 3.0], 6);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(alpha: &[f64], n: u64) -> Result<DirichletMultinomial> {
+        if alpha.iter().any(|a| a.is_infinite()) {
+            return Err(StatsError::BadParams);
+        }
+        Ok(DirichletMultinomial {
+            dirichlet: Dirichlet::new(alpha.to_vec())?,
+            n,
+        })
+    }
+
+    /// Returns the concentration parameters of the dirichlet-multinomial
+    /// distribution as a slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::DirichletMultinomial;
+    ///
+    /// let n = DirichletMultinomial::new(&[1.0, 2.0, 3.0], 6).unwrap();
+    /// assert_eq!(n.alpha(), [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn alpha(&self) -> &[f64] {
+        self.dirichlet.alpha().as_slice()
+    }
+
+    /// Returns the number of trials of the dirichlet-multinomial
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::DirichletMultinomial;
+    ///
+    /// let n = DirichletMultinomial::new(&[1.0, 2.0, 3.0], 6).unwrap();
+    /// assert_eq!(n.n(), 6);
+    /// ```
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    fn alpha_sum(&self) -> f64 {
+        self.alpha().iter().sum()
+    }
+}
+
+impl ::rand::distributions::Distribution<Vec<f64>> for DirichletMultinomial {
+    /// Generates one sample from the dirichlet-multinomial distribution by
+    /// drawing the category probabilities from the dirichlet distribution
+    /// and then the count of each category in turn from the binomial
+    /// distribution of the trials left over
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let p = self.dirichlet.sample(rng);
+        let last = p.len() - 1;
+        let mut res = vec![0.0; p.len()];
+        let mut trials = self.n;
+        let mut mass = 1.0;
+        for (i, &pi) in p.iter().enumerate() {
+            let count = if i == last {
+                trials
+            } else {
+                super::binomial::sample_unchecked(rng, (pi / mass).min(1.0), trials)
+            };
+            res[i] = count as f64;
+            trials -= count;
+            mass -= pi;
+        }
+        res
+    }
+}
+
+impl MeanN<DVector<f64>> for DirichletMultinomial {
+    /// Returns the mean of the dirichlet-multinomial distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * α_i / α_0 for i in 1...k
+    /// ```
+    ///
+    /// where `n` is the number of trials, `α_i` is the `i`th concentration
+    /// parameter, `α_0` is the sum of all concentration parameters and `k`
+    /// is the number of categories
+    fn mean(&self) -> Option<DVector<f64>> {
+        let sum = self.alpha_sum();
+        Some(DVector::from_iterator(
+            self.alpha().len(),
+            self.alpha().iter().map(|a| self.n as f64 * a / sum),
+        ))
+    }
+}
+
+impl VarianceN<DMatrix<f64>> for DirichletMultinomial {
+    /// Returns the covariance matrix of the dirichlet-multinomial
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * (α_i / α_0) * (δ_ij - α_j / α_0) * (n + α_0) / (1 + α_0)
+    /// ```
+    ///
+    /// for the `i`th row and `j`th column where `n` is the number of trials,
+    /// `α_i` is the `i`th concentration parameter, `α_0` is the sum of all
+    /// concentration parameters and `δ_ij` is the Kronecker delta
+    fn variance(&self) -> Option<DMatrix<f64>> {
+        let sum = self.alpha_sum();
+        let n = self.n as f64;
+        let scale = n * (n + sum) / (1.0 + sum);
+        let p = self.alpha();
+        let dim = p.len();
+        Some(DMatrix::from_fn(dim, dim, |i, j| {
+            let delta = if i == j { 1.0 } else { 0.0 };
+            scale * p[i] / sum * (delta - p[j] / sum)
+        }))
+    }
+}
+
+impl Discrete<&[u64], f64> for DirichletMultinomial {
+    /// Calculates the probability mass function for the
+    /// dirichlet-multinomial distribution with the given `x`'s
+    /// corresponding to the concentration parameters for this distribution
+    ///
+    /// # Panics
+    ///
+    /// If the length of `x` is not equivalent to the length of `alpha`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// n * B(α_0, n) / Π(x_i * B(α_i, x_i))
+    /// ```
+    ///
+    /// where `n` is the number of trials, `α_i` is the `i`th concentration
+    /// parameter, `α_0` is the sum of all concentration parameters, `x_i` is
+    /// the `i`th `x` value, `B` is the beta function and the product runs
+    /// over all `i` with `x_i > 0`
+    fn pmf(&self, x: &[u64]) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Calculates the log probability mass function for the
+    /// dirichlet-multinomial distribution with the given `x`'s
+    /// corresponding to the concentration parameters for this distribution
+    ///
+    /// # Panics
+    ///
+    /// If the length of `x` is not equivalent to the length of `alpha`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(n * B(α_0, n) / Π(x_i * B(α_i, x_i)))
+    /// ```
+    ///
+    /// where `n` is the number of trials, `α_i` is the `i`th concentration
+    /// parameter, `α_0` is the sum of all concentration parameters, `x_i` is
+    /// the `i`th `x` value, `B` is the beta function and the product runs
+    /// over all `i` with `x_i > 0`
+    fn ln_pmf(&self, x: &[u64]) -> f64 {
+        if self.alpha().len() != x.len() {
+            panic!("Expected x and alpha to have equal lengths.");
+        }
+        if x.iter().sum::<u64>() != self.n {
+            return f64::NEG_INFINITY;
+        }
+        if self.n == 0 {
+            return 0.0;
+        }
+        let n = self.n as f64;
+        self.alpha()
+            .iter()
+            .zip(x.iter())
+            .filter(|&(_, &xi)| xi > 0)
+            .fold(
+                n.ln() + beta::ln_beta(self.alpha_sum(), n),
+                |acc, (&ai, &xi)| acc - (xi as f64).ln() - beta::ln_beta(ai, xi as f64),
+            )
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{BetaBinomial, Discrete, DirichletMultinomial};
+    use nalgebra::{DMatrix, DVector};
+
+    fn try_create(alpha: &[f64], n: u64) -> DirichletMultinomial {
+        let dist = DirichletMultinomial::new(alpha, n);
+        assert!(dist.is_ok());
+        dist.unwrap()
+    }
+
+    fn create_case(alpha: &[f64], n: u64) {
+        let dist = try_create(alpha, n);
+        assert_eq!(dist.alpha(), alpha);
+        assert_eq!(dist.n(), n);
+    }
+
+    fn bad_create_case(alpha: &[f64], n: u64) {
+        let dist = DirichletMultinomial::new(alpha, n);
+        assert!(dist.is_err());
+    }
+
+    fn test_almost<F>(alpha: &[f64], n: u64, expected: f64, acc: f64, eval: F)
+        where F: Fn(DirichletMultinomial) -> f64
+    {
+        let dist = try_create(alpha, n);
+        let x = eval(dist);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(&[1.0, 2.0, 3.0], 6);
+        create_case(&[0.5, 0.5, 0.5, 0.5], 0);
+        create_case(&[1e-3, 1e3], 100);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(&[1.0], 6);
+        bad_create_case(&[0.0, 1.0], 6);
+        bad_create_case(&[-1.0, 1.0], 6);
+        bad_create_case(&[f64::NAN, 1.0], 6);
+        bad_create_case(&[f64::INFINITY, 1.0], 6);
+    }
+
+    #[test]
+    fn test_mean() {
+        let dist = try_create(&[1.0, 2.0, 3.0], 6);
+        assert_eq!(dist.mean().unwrap(), DVector::from_vec(vec![1.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_variance() {
+        // the diagonal matches the beta-binomial variance of each category
+        let dist = try_create(&[1.0, 2.0, 3.0], 6);
+        let expected = DMatrix::from_row_slice(3, 3, &[
+            10.0 / 7.0, -4.0 / 7.0, -6.0 / 7.0,
+            -4.0 / 7.0, 16.0 / 7.0, -12.0 / 7.0,
+            -6.0 / 7.0, -12.0 / 7.0, 18.0 / 7.0,
+        ]);
+        let variance = dist.variance().unwrap();
+        for (x, e) in variance.iter().zip(expected.iter()) {
+            assert_almost_eq!(*e, *x, 1e-14);
+        }
+        let marginal = BetaBinomial::new(6, 2.0, 4.0).unwrap();
+        assert_almost_eq!(marginal.variance().unwrap(), variance[(1, 1)], 1e-14);
+    }
+
+    #[test]
+    fn test_pmf() {
+        // ln_beta is built on ln_gamma and accurate to about 1e-13
+        let pmf = |arg: &'static [u64]| move |x: DirichletMultinomial| x.pmf(arg);
+        test_almost(&[1.0, 2.0, 3.0], 6, 0.064935064935064935065, 1e-12, pmf(&[1, 2, 3]));
+        test_almost(&[0.5, 0.5, 0.5, 0.5], 10, 0.0024275346235795454545, 1e-12, pmf(&[0, 3, 3, 4]));
+        test_almost(&[0.1, 5.0, 5.0], 10, 1.3274289789502700172e-7, 1e-12, pmf(&[10, 0, 0]));
+        test_almost(&[1.0, 1.0], 0, 1.0, 1e-12, pmf(&[0, 0]));
+        test_almost(&[1.0, 2.0, 3.0], 6, 0.0, 1e-12, pmf(&[1, 2, 4]));
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let ln_pmf = |arg: &'static [u64]| move |x: DirichletMultinomial| x.ln_pmf(arg);
+        test_almost(&[1.0, 2.0, 3.0], 6, -2.7343675094195834746, 1e-12, ln_pmf(&[1, 2, 3]));
+        test_almost(&[0.5, 0.5, 0.5, 0.5], 10, -6.0208790948399357564, 1e-12, ln_pmf(&[0, 3, 3, 4]));
+        test_almost(&[0.1, 5.0, 5.0], 10, -15.834851678104668379, 1e-12, ln_pmf(&[10, 0, 0]));
+        assert_eq!(try_create(&[1.0, 2.0], 6).ln_pmf(&[1, 2]), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_pmf_two_categories_is_beta_binomial() {
+        let dist = try_create(&[2.0, 3.0], 10);
+        let marginal = BetaBinomial::new(10, 2.0, 3.0).unwrap();
+        for k in 0..=10 {
+            assert_almost_eq!(marginal.pmf(k), dist.pmf(&[k, 10 - k]), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_pmf_sums_to_one() {
+        let dist = try_create(&[0.5, 2.0, 3.5], 8);
+        let mut sum = 0.0;
+        for i in 0..=8 {
+            for j in 0..=8 - i {
+                sum += dist.pmf(&[i, j, 8 - i - j]);
+            }
+        }
+        assert_almost_eq!(1.0, sum, 1e-14);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pmf_x_wrong_length() {
+        let dist = try_create(&[1.0, 2.0, 3.0], 6);
+        dist.pmf(&[1, 5]);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let dist = try_create(&[1.0, 2.0, 3.0], 6);
+        let count = 20_000;
+        let mut sum = vec![0.0; 3];
+        for _ in 0..count {
+            let x = dist.sample(&mut rng);
+            assert_eq!(x.iter().sum::<f64>(), 6.0);
+            for (s, xi) in sum.iter_mut().zip(x) {
+                *s += xi;
+            }
+        }
+        let mean = dist.mean().unwrap();
+        let variance = dist.variance().unwrap();
+        for i in 0..3 {
+            let std_err = (variance[(i, i)] / count as f64).sqrt();
+            assert!((sum[i] / count as f64 - mean[i]).abs() < 5.0 * std_err);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::distribution::{Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 
 /// Implements the [Discrete
@@ -51,9 +52,15 @@ impl DiscreteUniform {
     }
 }
 
+impl ::rand::distributions::Distribution<i64> for DiscreteUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> i64 {
+        rng.gen_range(self.min, self.max + 1)
+    }
+}
+
 impl ::rand::distributions::Distribution<f64> for DiscreteUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        rng.gen_range(self.min, self.max + 1) as f64
+        <Self as ::rand::distributions::Distribution<i64>>::sample(self, rng) as f64
     }
 }
 
@@ -82,6 +89,51 @@ impl DiscreteCDF<i64, f64> for DiscreteUniform {
             }
         }
     }
+
+    /// Calculates the survival function for the
+    /// discrete uniform distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - floor(x)) / (max - min + 1)
+    /// ```
+    fn sf(&self, x: i64) -> f64 {
+        if x < self.min {
+            1.0
+        } else if x >= self.max {
+            0.0
+        } else {
+            let lower = self.min as f64;
+            let upper = self.max as f64;
+            (upper - x as f64) / (upper - lower + 1.0)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// discrete uniform distribution at `x`, i.e. the smallest `k` such that
+    /// `cdf(k) >= x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + ceil(x * (max - min + 1)) - 1
+    /// ```
+    fn inverse_cdf(&self, x: f64) -> i64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        // the number of points overflows an i64 for supports wider than
+        // i64::MAX
+        let min = i128::from(self.min);
+        let max = i128::from(self.max);
+        let k = min + (x * (max - min + 1) as f64).ceil() as i128 - 1;
+        k.max(min).min(max) as i64
+    }
 }
 
 impl Min<i64> for DiscreteUniform {
@@ -151,6 +203,70 @@ impl Distribution<f64> for DiscreteUniform {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the discrete uniform distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `min == max`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -6(n^2 + 1) / (5(n^2 - 1))
+    /// ```
+    ///
+    /// where `n = max - min + 1`
+    fn kurtosis(&self) -> Option<f64> {
+        if self.min == self.max {
+            None
+        } else {
+            let n = (self.max - self.min) as f64 + 1.0;
+            Some(-6.0 * (n * n + 1.0) / (5.0 * (n * n - 1.0)))
+        }
+    }
+}
+
+impl GeneratingFunction<f64> for DiscreteUniform {
+    /// Returns the moment generating function of the discrete uniform
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (e^(t * min) - e^(t * (max + 1))) / (n * (1 - e^t))
+    /// ```
+    ///
+    /// where `n = max - min + 1`
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let n = self.max as f64 - self.min as f64 + 1.0;
+        if t == 0.0 {
+            Some(1.0)
+        } else if t > 0.0 {
+            Some((t * self.max as f64).exp() * (-n * t).exp_m1() / ((-t).exp_m1() * n))
+        } else {
+            Some((t * self.min as f64).exp() * (n * t).exp_m1() / (t.exp_m1() * n))
+        }
+    }
+    /// Returns the characteristic function of the discrete uniform
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(it * (min + max) / 2) * sin(nt / 2) / (n * sin(t / 2))
+    /// ```
+    ///
+    /// where `n = max - min + 1`
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let n = self.max as f64 - self.min as f64 + 1.0;
+        let s = (t / 2.0).sin();
+        let d = if s == 0.0 {
+            1.0
+        } else {
+            (n * t / 2.0).sin() / (n * s)
+        };
+        Complex::from_polar(d, t * (self.min as f64 + self.max as f64) / 2.0)
+    }
 }
 
 impl Median<f64> for DiscreteUniform {
@@ -233,6 +349,7 @@ mod tests {
     use crate::statistics::*;
     use crate::distribution::{DiscreteCDF, Discrete, DiscreteUniform};
     use crate::consts::ACC;
+    use crate::distribution::internal::*;
 
     fn try_create(min: i64, max: i64) -> DiscreteUniform {
         let n = DiscreteUniform::new(min, max);
@@ -267,6 +384,13 @@ mod tests {
         assert_eq!(expected, x);
     }
 
+    fn test_almost<F>(min: i64, max: i64, expected: f64, acc: f64, eval: F)
+        where F: Fn(DiscreteUniform) -> f64
+    {
+        let x = get_value(min, max, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
     #[test]
     fn test_create() {
         create_case(-10, 10);
@@ -317,6 +441,54 @@ mod tests {
         test_case(20, 20, 0.0, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: DiscreteUniform| x.kurtosis();
+        test_case(-3, 6, Some(-1.2242424242424242424), kurtosis);
+        test_case(0, 1, Some(-2.0), kurtosis);
+        test_case(20, 20, None, kurtosis);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: DiscreteUniform| x.mgf(t).unwrap();
+        test_almost(-3, 4, 58.321558095217490957, 1e-13, mgf(-2.0));
+        test_almost(-3, 4, 1.4559871447970440347, 1e-14, mgf(0.3));
+        test_almost(-3, 4, 137.31617499125275129, 1e-12, mgf(1.7));
+        test_almost(5, 5, 4.5399929762484851536e-5, 1e-16, mgf(-2.0));
+        test_almost(5, 5, 4.4816890703380648226, 1e-14, mgf(0.3));
+        test_almost(5, 5, 4914.7688402991343754, 1e-11, mgf(1.7));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: DiscreteUniform| x.cgf(t).unwrap();
+        test_almost(-3, 4, 4.0659718036538420774, 1e-14, cgf(-2.0));
+        test_almost(-3, 4, 0.37568412061260797488, 1e-15, cgf(0.3));
+        test_almost(-3, 4, 4.9222861134917597868, 1e-14, cgf(1.7));
+        test_almost(5, 5, -10.0, 1e-13, cgf(-2.0));
+        test_almost(5, 5, 1.5, 1e-14, cgf(0.3));
+        test_almost(5, 5, 8.5, 1e-14, cgf(1.7));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: DiscreteUniform| x.cf(t).re;
+        let im = |t| move |x: DiscreteUniform| x.cf(t).im;
+        test_almost(-3, 4, 0.079407453083826238994, 1e-15, re(-2.0));
+        test_almost(-3, 4, -0.12366978082792272223, 1e-15, im(-2.0));
+        test_almost(-3, 4, 0.77086523738607141707, 1e-15, re(0.3));
+        test_almost(-3, 4, 0.1165048857459032917, 1e-15, im(0.3));
+        test_almost(-3, 4, 0.054258450253226796871, 1e-15, re(1.7));
+        test_almost(-3, 4, 0.061764168892326020973, 1e-15, im(1.7));
+        test_almost(5, 5, -0.83907152907645245226, 1e-15, re(-2.0));
+        test_almost(5, 5, 0.5440211108893698134, 1e-15, im(-2.0));
+        test_almost(5, 5, 0.070737201667702910088, 1e-15, re(0.3));
+        test_almost(5, 5, 0.99749498660405443094, 1e-15, im(0.3));
+        test_almost(5, 5, -0.60201190268482361535, 1e-15, re(1.7));
+        test_almost(5, 5, 0.79848711262349028667, 1e-15, im(1.7));
+    }
+
     #[test]
     fn test_median() {
         let median = |x: DiscreteUniform| x.median();
@@ -375,4 +547,44 @@ mod tests {
         let cdf = |arg: i64| move |x: DiscreteUniform| x.cdf(arg);
         test_case(0, 3, 1.0, cdf(5));
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: i64| move |x: DiscreteUniform| x.sf(arg);
+        test_case(-10, 10, 1.0, sf(-11));
+        test_case(-10, 10, 20.0 / 21.0, sf(-10));
+        test_case(-10, 10, 10.0 / 21.0, sf(0));
+        test_case(-10, 10, 0.0, sf(10));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: DiscreteUniform| x.inverse_cdf(arg);
+        test_case(-10, 10, -10, inverse_cdf(0.0));
+        test_case(-10, 10, -10, inverse_cdf(0.01));
+        test_case(-10, 10, -5, inverse_cdf(0.2857142857142857142857));
+        test_case(-10, 10, 0, inverse_cdf(0.5));
+        test_case(-10, 10, 10, inverse_cdf(0.99));
+        test_case(-10, 10, 10, inverse_cdf(1.0));
+        test_case(-10, -10, -10, inverse_cdf(0.5));
+    }
+
+    #[test]
+    fn test_inverse_cdf_wide_range() {
+        let inverse_cdf = |arg: f64| move |x: DiscreteUniform| x.inverse_cdf(arg);
+        test_case(i64::MIN + 1, i64::MAX - 1, i64::MIN + 1, inverse_cdf(0.0));
+        test_case(i64::MIN + 1, i64::MAX - 1, -4611686018427387904, inverse_cdf(0.25));
+        test_case(i64::MIN + 1, i64::MAX - 1, 0, inverse_cdf(0.5));
+        test_case(i64::MIN + 1, i64::MAX - 1, 4611686018427387904, inverse_cdf(0.75));
+        test_case(i64::MIN + 1, i64::MAX - 1, i64::MAX - 1, inverse_cdf(1.0));
+        test_case(i64::MIN, i64::MAX, i64::MIN, inverse_cdf(1e-300));
+        test_case(i64::MIN, i64::MAX, -1, inverse_cdf(0.5));
+        test_case(i64::MIN, i64::MAX, i64::MAX, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(-10, 10));
+        tests::check_discrete_samples(&try_create(5, 5));
+    }
 }
@@ -3,6 +3,7 @@ use crate::statistics::*;
 use crate::{Result, StatsError};
 use ::num_traits::float::Float;
 use core::cmp::Ordering;
+use num_complex::Complex;
 use rand::Rng;
 use std::collections::BTreeMap;
 
@@ -111,47 +112,12 @@ impl Empirical {
             }
         }
     }
-    // Due to issues with rounding and floating-point accuracy the default
-    // implementation may be ill-behaved.
-    // Specialized inverse cdfs should be used whenever possible.
-    // Performs a binary search on the domain of `cdf` to obtain an approximation
-    // of `F^-1(p) := inf { x | F(x) >= p }`. Needless to say, performance may
-    // may be lacking.
-    // This function is identical to the default method implementation in the
-    // `ContinuousCDF` trait and is used to implement the rand trait `Distribution`.
-    fn __inverse_cdf(&self, p: f64) -> f64 {
-        if p == 0.0 {
-            return self.min();
-        };
-        if p == 1.0 {
-            return self.max();
-        };
-        let mut high = 2.0;
-        let mut low = -high;
-        while self.cdf(low) > p {
-            low = low + low;
-        }
-        while self.cdf(high) < p {
-            high = high + high;
-        }
-        let mut i = 16;
-        while i != 0 {
-            let mid = (high + low) / 2.0;
-            if self.cdf(mid) >= p {
-                high = mid;
-            } else {
-                low = mid;
-            }
-            i -= 1;
-        }
-        (high + low) / 2.0
-    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Empirical {
     fn sample<R: ?Sized + Rng>(&self, rng: &mut R) -> f64 {
         let uniform = Uniform::new(0.0, 1.0).unwrap();
-        self.__inverse_cdf(uniform.sample(rng))
+        self.inverse_cdf(uniform.sample(rng))
     }
 }
 
@@ -178,6 +144,53 @@ impl Distribution<f64> for Empirical {
     }
 }
 
+impl GeneratingFunction<f64> for Empirical {
+    /// Returns the moment generating function of the empirical
+    /// distribution at `t`, or `None` if there are no data points
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(e^(t * x_j)) / n
+    /// ```
+    ///
+    /// where `x_j` are the `n` data points
+    fn mgf(&self, t: f64) -> Option<f64> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let sum: f64 = self
+            .data
+            .iter()
+            .map(|(key, &count)| count as f64 * (t * key.0).exp())
+            .sum();
+        Some(sum / self.sum)
+    }
+    /// Returns the characteristic function of the empirical distribution
+    /// at `t`
+    ///
+    /// # Panics
+    ///
+    /// If there are no data points
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(e^(it * x_j)) / n
+    /// ```
+    ///
+    /// where `x_j` are the `n` data points
+    fn cf(&self, t: f64) -> Complex<f64> {
+        if self.data.is_empty() {
+            panic!("empirical distribution has no data points");
+        }
+        self.data
+            .iter()
+            .map(|(key, &count)| Complex::from_polar(count as f64 / self.sum, t * key.0))
+            .sum()
+    }
+}
+
 impl ContinuousCDF<f64, f64> for Empirical {
     fn cdf(&self, x: f64) -> f64 {
         let mut sum = 0;
@@ -189,6 +202,39 @@ impl ContinuousCDF<f64, f64> for Empirical {
         }
         sum as f64 / self.sum
     }
+
+    fn sf(&self, x: f64) -> f64 {
+        let mut sum = 0;
+        for (keys, values) in self.data.iter().rev() {
+            if keys.0 <= x {
+                return sum as f64 / self.sum;
+            }
+            sum += values;
+        }
+        sum as f64 / self.sum
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// empirical distribution at `p`, i.e. the smallest data point `x`
+    /// such that `cdf(x) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`, or if there are no data points
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        let target = p * self.sum;
+        let mut sum = 0;
+        for (keys, values) in &self.data {
+            sum += values;
+            if sum as f64 >= target {
+                return keys.0;
+            }
+        }
+        self.max()
+    }
 }
 
 #[cfg(test)]
@@ -221,4 +267,33 @@ mod tests {
         // due to the mean and variance being calculated in a streaming way
         assert_eq!(unchanged, empirical);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let samples = vec![5.0, 10.0, 2.0, 2.0];
+        let empirical = Empirical::from_vec(samples);
+        assert_eq!(empirical.inverse_cdf(0.0), 2.0);
+        assert_eq!(empirical.inverse_cdf(0.25), 2.0);
+        assert_eq!(empirical.inverse_cdf(0.5), 2.0);
+        assert_eq!(empirical.inverse_cdf(0.6), 5.0);
+        assert_eq!(empirical.inverse_cdf(0.75), 5.0);
+        assert_eq!(empirical.inverse_cdf(0.8), 10.0);
+        assert_eq!(empirical.inverse_cdf(1.0), 10.0);
+        for &p in [0.1, 0.3, 0.5, 0.7, 0.9].iter() {
+            assert!(empirical.cdf(empirical.inverse_cdf(p)) >= p);
+        }
+    }
+
+    #[test]
+    fn test_generating_function() {
+        let empirical = Empirical::from_vec(vec![5.0, 10.0, 2.0, 2.0]);
+        assert_almost_eq!(empirical.mgf(-1.0).unwrap(), 0.069363478350518333934, 1e-16);
+        assert_almost_eq!(empirical.mgf(2.0).unwrap(), 121296832.76797128774, 1e-7);
+        assert_almost_eq!(empirical.cgf(0.5).unwrap(), 3.7258874850339200327, 1e-15);
+        assert_almost_eq!(empirical.cf(-0.7).re, 0.03934496321324764165, 1e-16);
+        assert_almost_eq!(empirical.cf(-0.7).im, -0.56927570775152236163, 1e-15);
+        assert_almost_eq!(empirical.cf(1.5).re, -0.59825939705617159435, 1e-15);
+        assert_almost_eq!(empirical.cf(1.5).im, 0.46763195826289754199, 1e-15);
+        assert_eq!(Empirical::new().unwrap().mgf(1.0), None);
+    }
 }
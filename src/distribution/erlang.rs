@@ -1,6 +1,7 @@
 use crate::distribution::{Continuous, ContinuousCDF, Gamma};
 use crate::statistics::*;
 use crate::Result;
+use num_complex::Complex;
 use rand::Rng;
 
 /// Implements the [Erlang](https://en.wikipedia.org/wiki/Erlang_distribution)
@@ -100,6 +101,40 @@ impl ContinuousCDF<f64, f64> for Erlang {
     fn cdf(&self, x: f64) -> f64 {
         self.g.cdf(x)
     }
+
+    /// Calculates the survival function for the erlang
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Γ(k, λx)  (k - 1)!
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the rate, and `Γ` is the upper
+    /// incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        self.g.sf(x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// erlang distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P^-1(k, p) / λ
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the rate, and `P^-1` is the inverse
+    /// of the lower regularized gamma function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        self.g.inverse_cdf(p)
+    }
 }
 
 impl Min<f64> for Erlang {
@@ -187,6 +222,86 @@ impl Distribution<f64> for Erlang {
     fn skewness(&self) -> Option<f64> {
         self.g.skewness()
     }
+    /// Returns the excess kurtosis of the erlang distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / k
+    /// ```
+    ///
+    /// where `k` is the shape
+    fn kurtosis(&self) -> Option<f64> {
+        self.g.kurtosis()
+    }
+    /// Returns the `n`th raw moment of the erlang distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// prod(k + i) / λ^n for i in 0..n
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the rate
+    fn moment(&self, n: u32) -> Option<f64> {
+        self.g.moment(n)
+    }
+    /// Returns the `n`th central moment of the erlang distribution,
+    /// computed from the cumulants `(j - 1)! k / λ^j` where `k` is the
+    /// shape and `λ` is the rate
+    fn central_moment(&self, n: u32) -> Option<f64> {
+        self.g.central_moment(n)
+    }
+}
+
+impl GeneratingFunction<f64> for Erlang {
+    /// Returns the moment generating function of the erlang distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= λ`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - t / λ)^(-k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the rate
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.g.mgf(t)
+    }
+    /// Returns the cumulant generating function of the erlang distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= λ`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -k * ln(1 - t / λ)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the rate
+    fn cgf(&self, t: f64) -> Option<f64> {
+        self.g.cgf(t)
+    }
+    /// Returns the characteristic function of the erlang distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - it / λ)^(-k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the rate
+    fn cf(&self, t: f64) -> Complex<f64> {
+        self.g.cf(t)
+    }
 }
 
 impl Mode<Option<f64>> for Erlang {
@@ -253,7 +368,8 @@ impl Continuous<f64, f64> for Erlang {
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use crate::distribution::Erlang;
+    use crate::statistics::Distribution;
+    use crate::distribution::{ContinuousCDF, Erlang};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -291,10 +407,30 @@ mod tests {
         bad_create_case(1, -1.0);
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(3, 2.0);
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.337030156861780158957, 1e-14);
+        assert_almost_eq!(n.inverse_cdf(0.99), 4.202973457442732763665, 1e-13);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+        tests::check_inverse_cdf(&n, 1e-12);
+        tests::check_inverse_cdf(&try_create(1, 0.5), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1, 2.5), 0.0, 20.0);
         tests::check_continuous_distribution(&try_create(2, 1.5), 0.0, 20.0);
         tests::check_continuous_distribution(&try_create(3, 0.5), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(3, 2.0);
+        assert_eq!(n.kurtosis(), Some(2.0));
+        assert_eq!(n.moment(6), Some(315.0));
+        assert_eq!(n.central_moment(2), Some(0.75));
+        assert_almost_eq!(n.central_moment(6).unwrap(), 30.234375, 1e-13);
+    }
 }
@@ -1,6 +1,7 @@
 use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -91,6 +92,84 @@ impl ContinuousCDF<f64, f64> for Exp {
             1.0 - (-self.rate * x).exp()
         }
     }
+
+    /// Calculates the survival function for the
+    /// exponential distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-λ * x)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else {
+            (-self.rate * x).exp()
+        }
+    }
+
+    /// Calculates the log of the cumulative distribution function for the
+    /// exponential distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(1 - e^(-λ * x))
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let t = self.rate * x;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if t < f64::consts::LN_2 {
+            (-(-t).exp_m1()).ln()
+        } else {
+            (-(-t).exp()).ln_1p()
+        }
+    }
+
+    /// Calculates the log of the survival function for the
+    /// exponential distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -λ * x
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            -self.rate * x
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// exponential distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(1 - p) / λ
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        -(-p).ln_1p() / self.rate
+    }
 }
 
 impl Min<f64> for Exp {
@@ -168,6 +247,103 @@ impl Distribution<f64> for Exp {
     fn skewness(&self) -> Option<f64> {
         Some(2.0)
     }
+    /// Returns the excess kurtosis of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(6.0)
+    }
+    /// Returns the `k`th raw moment of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k! / λ^k
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn moment(&self, k: u32) -> Option<f64> {
+        Some((1..=k).fold(1.0, |acc, i| acc * i as f64 / self.rate))
+    }
+    /// Returns the `k`th central moment of the exponential distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k! / λ^k * sum((-1)^j / j!) for j in 0..=k
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        // the sum times k! is the number of derangements of k items
+        let derangements = (1..=k).fold(1.0, |acc, i| {
+            i as f64 * acc + if i % 2 == 0 { 1.0 } else { -1.0 }
+        });
+        Some(derangements / self.rate.powi(k as i32))
+    }
+}
+
+impl GeneratingFunction<f64> for Exp {
+    /// Returns the moment generating function of the exponential
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= λ` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ / (λ - t)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn mgf(&self, t: f64) -> Option<f64> {
+        if t >= self.rate {
+            None
+        } else {
+            Some(self.rate / (self.rate - t))
+        }
+    }
+    /// Returns the cumulant generating function of the exponential
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= λ` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(1 - t / λ)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn cgf(&self, t: f64) -> Option<f64> {
+        if t >= self.rate {
+            None
+        } else {
+            Some(-(-t / self.rate).ln_1p())
+        }
+    }
+    /// Returns the characteristic function of the exponential distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ / (λ - it)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let x = t / self.rate;
+        Complex::new(1.0, x) / (1.0 + x * x)
+    }
 }
 
 impl Median<f64> for Exp {
@@ -336,6 +512,75 @@ mod tests {
         test_case(10.0, 2.0, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Exp| x.kurtosis().unwrap();
+        test_case(0.1, 6.0, kurtosis);
+        test_case(2.0, 6.0, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Exp| x.moment(k).unwrap();
+        test_case(2.0, 1.0, moment(0));
+        test_case(2.0, 0.5, moment(1));
+        test_case(2.0, 0.75, moment(3));
+        test_case(2.0, 11.25, moment(6));
+        test_almost(0.1, 3628800e10, 1e-3, moment(10));
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Exp| x.central_moment(k).unwrap();
+        test_case(2.0, 1.0, central_moment(0));
+        test_case(2.0, 0.0, central_moment(1));
+        test_case(2.0, 0.25, central_moment(2));
+        test_case(2.0, 0.25, central_moment(3));
+        test_case(2.0, 0.5625, central_moment(4));
+        test_case(2.0, 1.375, central_moment(5));
+        test_case(2.0, 4.140625, central_moment(6));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Exp| x.mgf(t).unwrap();
+        test_almost(0.5, 0.33333333333333333333, 1e-15, mgf(-1.0));
+        test_almost(0.5, 2.0, 1e-14, mgf(0.25));
+        test_almost(3.0, 0.75, 1e-15, mgf(-1.0));
+        test_almost(3.0, 1.0909090909090909091, 1e-14, mgf(0.25));
+        assert_eq!(None, try_create(0.5).mgf(3.0));
+        assert_eq!(None, try_create(3.0).mgf(3.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Exp| x.cgf(t).unwrap();
+        test_almost(0.5, -1.0986122886681096914, 1e-14, cgf(-1.0));
+        test_almost(0.5, 0.69314718055994530942, 1e-15, cgf(0.25));
+        test_almost(3.0, -0.28768207245178092744, 1e-15, cgf(-1.0));
+        test_almost(3.0, 0.087011376989629766168, 1e-16, cgf(0.25));
+        assert_eq!(None, try_create(0.5).cgf(3.0));
+        assert_eq!(None, try_create(3.0).cgf(3.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Exp| x.cf(t).re;
+        let im = |t| move |x: Exp| x.cf(t).im;
+        test_almost(0.5, 0.2, 1e-15, re(-1.0));
+        test_almost(0.5, -0.4, 1e-15, im(-1.0));
+        test_almost(0.5, 0.8, 1e-15, re(0.25));
+        test_almost(0.5, 0.4, 1e-15, im(0.25));
+        test_almost(0.5, 0.027027027027027027027, 1e-15, re(3.0));
+        test_almost(0.5, 0.16216216216216216216, 1e-15, im(3.0));
+        test_almost(3.0, 0.9, 1e-15, re(-1.0));
+        test_almost(3.0, -0.3, 1e-15, im(-1.0));
+        test_almost(3.0, 0.99310344827586206897, 1e-15, re(0.25));
+        test_almost(3.0, 0.082758620689655172414, 1e-15, im(0.25));
+        test_almost(3.0, 0.5, 1e-15, re(3.0));
+        test_almost(3.0, 0.5, 1e-15, im(3.0));
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Exp| x.median();
@@ -445,6 +690,51 @@ mod tests {
         test_case(0.1, 0.0, cdf(-1.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Exp| x.sf(arg);
+        test_case(0.1, 1.0, sf(-1.0));
+        test_case(0.1, 1.0, sf(0.0));
+        test_almost(0.1, 0.9900498337491680535739, 1e-15, sf(0.1));
+        test_almost(1.0, 0.9048374180359595681414, 1e-15, sf(0.1));
+        test_case(10.0, 0.3678794411714423215955, sf(0.1));
+        test_almost(10.0, 4.539992976248485153559e-5, 1e-20, sf(1.0));
+        test_almost(1.0, 3.720075976020835962959e-44, 1e-58, sf(100.0));
+        test_case(0.1, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let ln_cdf = |arg: f64| move |x: Exp| x.ln_cdf(arg);
+        test_case(0.1, f64::NEG_INFINITY, ln_cdf(-1.0));
+        test_case(0.1, f64::NEG_INFINITY, ln_cdf(0.0));
+        test_almost(1.0, -23.02585092999045684017, 1e-12, ln_cdf(1e-10));
+        test_almost(1.0, -0.4586751453870818910217, 1e-15, ln_cdf(1.0));
+        test_almost(1.0, -3.720075976020835962959e-44, 1e-58, ln_cdf(100.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let ln_sf = |arg: f64| move |x: Exp| x.ln_sf(arg);
+        test_case(0.1, 0.0, ln_sf(-1.0));
+        test_case(0.1, 0.0, ln_sf(0.0));
+        test_case(1.0, -1.0, ln_sf(1.0));
+        test_case(2.0, -2000.0, ln_sf(1000.0));
+        test_case(0.1, f64::NEG_INFINITY, ln_sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Exp| x.inverse_cdf(arg);
+        test_case(2.0, 0.0, inverse_cdf(0.0));
+        test_almost(2.0, 5.000000000250000000017e-11, 1e-25, inverse_cdf(1e-10));
+        test_almost(2.0, 0.3465735902799726547086, 1e-15, inverse_cdf(0.5));
+        test_almost(2.0, 2.302585092994045684018, 1e-14, inverse_cdf(0.99));
+        test_case(2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.1), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(0.5), 0.0, 10.0);
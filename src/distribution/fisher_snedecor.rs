@@ -123,6 +123,56 @@ impl ContinuousCDF<f64, f64> for FisherSnedecor {
             )
         }
     }
+
+    /// Calculates the survival function for the fisher-snedecor
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(d2 / (d1 * x + d2))(d2 / 2, d1 / 2)
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is
+    /// the second degree of freedom, and `I` is the regularized incomplete
+    /// beta function
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            beta::beta_reg(
+                self.freedom_2 / 2.0,
+                self.freedom_1 / 2.0,
+                self.freedom_2 / (self.freedom_1 * x + self.freedom_2),
+            )
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// fisher-snedecor distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (d2 * y) / (d1 * (1 - y))
+    /// ```
+    ///
+    /// where `y = I^-1_p(d1 / 2, d2 / 2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, and `I^-1` is the
+    /// inverse of the regularized incomplete beta function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        let y = beta::inv_beta_reg(self.freedom_1 / 2.0, self.freedom_2 / 2.0, p);
+        self.freedom_2 * y / (self.freedom_1 * (1.0 - y))
+    }
 }
 
 impl Min<f64> for FisherSnedecor {
@@ -241,6 +291,33 @@ impl Distribution<f64> for FisherSnedecor {
             Some(val)
         }
     }
+    /// Returns the excess kurtosis of the fisher-snedecor distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom_2 <= 8.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 * (d1 * (5d2 - 22) * (d1 + d2 - 2) + (d2 - 4) * (d2 - 2)^2)
+    ///     / (d1 * (d2 - 6) * (d2 - 8) * (d1 + d2 - 2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom and `d2` is the second
+    /// degree of freedom
+    fn kurtosis(&self) -> Option<f64> {
+        if self.freedom_2 <= 8.0 {
+            None
+        } else {
+            let d1 = self.freedom_1;
+            let d2 = self.freedom_2;
+            let val = 12.0
+                * (d1 * (5.0 * d2 - 22.0) * (d1 + d2 - 2.0) + (d2 - 4.0) * (d2 - 2.0) * (d2 - 2.0))
+                / (d1 * (d2 - 6.0) * (d2 - 8.0) * (d1 + d2 - 2.0));
+            Some(val)
+        }
+    }
 }
 
 impl Mode<Option<f64>> for FisherSnedecor {
@@ -443,6 +520,14 @@ mod tests {
         test_case(10.0, 10.0, 3.614784456460255759501, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: FisherSnedecor| x.kurtosis().unwrap();
+        test_almost(5.0, 12.0, 24.333333333333333333, 1e-13, kurtosis);
+        test_almost(10.0, 100.0, 1.8478569226025285230, 1e-14, kurtosis);
+        assert!(try_create(5.0, 8.0).kurtosis().is_none());
+    }
+
     #[test]
     #[should_panic]
     fn test_skewness_with_low_d2() {
@@ -536,6 +621,27 @@ mod tests {
         test_case(0.1, 0.1, 0.0, cdf(-1.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: FisherSnedecor| x.sf(arg);
+        test_case(10.0, 5.0, 1.0, sf(0.0));
+        test_almost(10.0, 5.0, 0.534880573462199589444946992636, 1e-14, sf(1.0));
+        test_almost(10.0, 5.0, 0.000222445940094663562886133199736, 1e-17, sf(50.0));
+        test_case(10.0, 5.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: FisherSnedecor| x.inverse_cdf(arg);
+        test_case(5.0, 10.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 1.0, 0.02508563093691659842402, 1e-14, inverse_cdf(0.1));
+        test_almost(5.0, 10.0, 0.9319331608510479451995, 1e-14, inverse_cdf(0.5));
+        test_almost(5.0, 10.0, 3.325834530413012011302, 1e-13, inverse_cdf(0.95));
+        test_case(5.0, 10.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(5.0, 10.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(1.0, 1.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(10.0, 10.0), 0.0, 10.0);
@@ -1,7 +1,8 @@
-use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::gamma;
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::{factorial, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -115,6 +116,53 @@ impl ContinuousCDF<f64, f64> for Gamma {
             gamma::gamma_lr(self.shape, x * self.rate)
         }
     }
+
+    /// Calculates the survival function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / Γ(α)) * Γ(α, β * x)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, `Γ` is the gamma function,
+    /// and `Γ(a, x)` is the upper incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if ulps_eq!(x, self.shape) && self.rate.is_infinite() {
+            0.0
+        } else if self.rate.is_infinite() {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            gamma::gamma_ur(self.shape, x * self.rate)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// gamma distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P^-1(α, p) / β
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `P^-1` is the inverse
+    /// of the lower regularized gamma function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        gamma::inv_gamma_lr(self.shape, p) / self.rate
+    }
 }
 
 impl Min<f64> for Gamma {
@@ -200,6 +248,112 @@ impl Distribution<f64> for Gamma {
     fn skewness(&self) -> Option<f64> {
         Some(2.0 / self.shape.sqrt())
     }
+    /// Returns the excess kurtosis of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / α
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn kurtosis(&self) -> Option<f64> {
+        Some(6.0 / self.shape)
+    }
+    /// Returns the `k`th raw moment of the gamma distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// prod(α + i) / β^k for i in 0..k
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate
+    fn moment(&self, k: u32) -> Option<f64> {
+        Some((0..k).fold(1.0, |acc, i| acc * (self.shape + i as f64) / self.rate))
+    }
+    /// Returns the `k`th central moment of the gamma distribution
+    ///
+    /// # Remarks
+    ///
+    /// Computed from the cumulants, all of which are positive, so there is
+    /// no cancellation even when the distribution is concentrated far from
+    /// the origin
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// κ_j = α (j - 1)! / β^j
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let cumulants: Vec<f64> = (0..=k)
+            .map(|j| match j {
+                0 => 0.0,
+                _ => self.shape * factorial::factorial(j as u64 - 1) / self.rate.powi(j as i32),
+            })
+            .collect();
+        Some(internal::central_moments_from_cumulants(&cumulants)[k as usize])
+    }
+}
+
+impl GeneratingFunction<f64> for Gamma {
+    /// Returns the moment generating function of the gamma distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= β` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - t / β)^(-α)
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the gamma distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= β` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -α * ln(1 - t / β)
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate
+    fn cgf(&self, t: f64) -> Option<f64> {
+        if t >= self.rate {
+            None
+        } else {
+            Some(-self.shape * (-t / self.rate).ln_1p())
+        }
+    }
+    /// Returns the characteristic function of the gamma distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - it / β)^(-α)
+    /// ```
+    ///
+    /// where `α` is the shape and `β` is the rate
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let x = t / self.rate;
+        Complex::from_polar(
+            (-self.shape / 2.0 * (x * x).ln_1p()).exp(),
+            self.shape * x.atan(),
+        )
+    }
 }
 
 impl Mode<Option<f64>> for Gamma {
@@ -313,7 +467,7 @@ pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, shape: f64, rate: f64) ->
         v *= v * v;
         x *= x;
         let u: f64 = rng.gen();
-        if u < 1.0 - 0.0331 * x * x || u.ln() < 0.5 * x + d * (1.0 - v - v.ln()) {
+        if u < 1.0 - 0.0331 * x * x || u.ln() < 0.5 * x + d * (1.0 - v + v.ln()) {
             return afix * d * v / rate;
         }
     }
@@ -441,6 +595,87 @@ mod tests {
         test_case(10.0, f64::INFINITY, 0.63245553203367586639977870888654370674391102786504337, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Gamma| x.kurtosis().unwrap();
+        test_case(2.5, 1.5, 2.4, kurtosis);
+        test_case(1.0, 0.1, 6.0, kurtosis);
+        test_case(10.0, f64::INFINITY, 0.6, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Gamma| x.moment(k).unwrap();
+        test_case(2.5, 1.5, 1.0, moment(0));
+        test_almost(2.5, 1.5, 1.6666666666666666667, 1e-15, moment(1));
+        test_almost(2.5, 1.5, 42.777777777777777778, 1e-13, moment(4));
+        test_almost(2.5, 1.5, 926.85185185185185185, 1e-12, moment(6));
+        test_case(2.5, f64::INFINITY, 0.0, moment(2));
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Gamma| x.central_moment(k).unwrap();
+        test_case(2.5, 1.5, 1.0, central_moment(0));
+        test_case(2.5, 1.5, 0.0, central_moment(1));
+        test_almost(2.5, 1.5, 1.1111111111111111111, 1e-15, central_moment(2));
+        test_almost(2.5, 1.5, 1.4814814814814814815, 1e-15, central_moment(3));
+        test_almost(2.5, 1.5, 24.362139917695473251, 1e-13, central_moment(5));
+        test_almost(2.5, 1.5, 118.24417009602194787, 1e-12, central_moment(6));
+        // no cancellation far from the origin
+        test_case(1e6, 1.0, 2e6, central_moment(3));
+        test_case(1e6, 1.0, 3000006e6, central_moment(4));
+        test_case(2.5, f64::INFINITY, 0.0, central_moment(4));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Gamma| x.mgf(t).unwrap();
+        test_almost(3.0, 2.0, 0.125, 1e-15, mgf(-2.0));
+        test_almost(3.0, 2.0, 8.0, 1e-14, mgf(1.0));
+        test_almost(3.0, 2.0, 7999.9999999999786837, 1e-11, mgf(1.9));
+        test_almost(0.5, 1.5, 0.6546536707079771438, 1e-15, mgf(-2.0));
+        test_almost(0.5, 1.5, 1.7320508075688772935, 1e-14, mgf(1.0));
+        assert_eq!(None, try_create(3.0, 2.0).mgf(3.0));
+        assert_eq!(None, try_create(0.5, 1.5).mgf(1.9));
+        assert_eq!(None, try_create(0.5, 1.5).mgf(3.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Gamma| x.cgf(t).unwrap();
+        test_almost(3.0, 2.0, -2.0794415416798359283, 1e-14, cgf(-2.0));
+        test_almost(3.0, 2.0, 2.0794415416798359283, 1e-14, cgf(1.0));
+        test_almost(3.0, 2.0, 8.9871968206619703158, 1e-14, cgf(1.9));
+        test_almost(0.5, 1.5, -0.42364893019360180686, 1e-15, cgf(-2.0));
+        test_almost(0.5, 1.5, 0.5493061443340548457, 1e-15, cgf(1.0));
+        assert_eq!(None, try_create(3.0, 2.0).cgf(3.0));
+        assert_eq!(None, try_create(0.5, 1.5).cgf(1.9));
+        assert_eq!(None, try_create(0.5, 1.5).cgf(3.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Gamma| x.cf(t).re;
+        let im = |t| move |x: Gamma| x.cf(t).im;
+        test_almost(3.0, 2.0, -0.25, 1e-15, re(-2.0));
+        test_almost(3.0, 2.0, -0.25, 1e-15, im(-2.0));
+        test_almost(3.0, 2.0, 0.128, 1e-15, re(1.0));
+        test_almost(3.0, 2.0, 0.704, 1e-15, im(1.0));
+        test_almost(3.0, 2.0, -0.24796290520319365044, 1e-15, re(1.9));
+        test_almost(3.0, 2.0, 0.28936871682606958579, 1e-15, im(1.9));
+        test_almost(3.0, 2.0, -0.16750113791533909877, 1e-15, re(3.0));
+        test_almost(3.0, 2.0, 0.032771961766044606281, 1e-15, im(3.0));
+        test_almost(0.5, 1.5, 0.69282032302755091741, 1e-15, re(-2.0));
+        test_almost(0.5, 1.5, -0.34641016151377545871, 1e-15, im(-2.0));
+        test_almost(0.5, 1.5, 0.87302863259045977029, 1e-15, re(1.0));
+        test_almost(0.5, 1.5, 0.26433180099086770242, 1e-15, im(1.0));
+        test_almost(0.5, 1.5, 0.70837960619558402916, 1e-15, re(1.9));
+        test_almost(0.5, 1.5, 0.34328213193408422195, 1e-15, im(1.9));
+        test_almost(0.5, 1.5, 0.56886448100578310728, 1e-15, re(3.0));
+        test_almost(0.5, 1.5, 0.35157758425414292849, 1e-15, im(3.0));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Gamma| x.mode().unwrap();
@@ -520,6 +755,33 @@ mod tests {
         test_case(1.0, 0.1, 0.0, |x| x.cdf(0.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Gamma| x.sf(arg);
+        test_case(1.0, 1.0, 1.0, sf(0.0));
+        test_almost(1.0, 1.0, 1.92874984796391778301734281653e-22, 1e-34, sf(50.0));
+        test_almost(10.0, 1.0, 0.968171942693795188262813425819, 1e-14, sf(5.0));
+        test_almost(10.0, 1.0, 1.12534739608427338852750004583e-31, 1e-43, sf(100.0));
+        test_almost(0.5, 2.0, 0.0455002638963584144005652743331, 1e-14, sf(1.0));
+        test_case(10.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Gamma| x.inverse_cdf(arg);
+        test_case(2.0, 0.5, 0.0, inverse_cdf(0.0));
+        test_almost(2.0, 0.5, 1.063623216779224075823, 1e-14, inverse_cdf(0.1));
+        test_almost(2.0, 0.5, 3.356693980033321306826, 1e-14, inverse_cdf(0.5));
+        test_almost(2.0, 0.5, 18.46682695290317146086, 1e-13, inverse_cdf(0.999));
+        test_almost(0.1, 1.0, 6.073048362407898081916e-21, 1e-33, inverse_cdf(0.01));
+        test_almost(0.1, 1.0, 0.2661545537388377721214, 1e-14, inverse_cdf(0.9));
+        test_almost(100.0, 3.0, 33.2222883064384962479, 1e-12, inverse_cdf(0.5));
+        test_case(2.0, 0.5, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.1, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.0, 0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(100.0, 3.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0, 0.5), 0.0, 20.0);
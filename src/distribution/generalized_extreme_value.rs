@@ -0,0 +1,884 @@
+use crate::consts::EULER_MASCHERONI;
+use crate::distribution::{internal, ziggurat, Continuous, ContinuousCDF};
+use crate::function::{evaluate, gamma};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Taylor coefficients of the skewness in the shape `ξ` around `ξ = 0`
+const SKEWNESS_SERIES: [f64; 16] = [
+    1.1395470994046486575,
+    5.9666124126670912441,
+    12.2797866589837108,
+    36.224267851107843355,
+    106.07709592394812956,
+    315.54882661360032911,
+    942.55877797800903709,
+    2821.4944204247211239,
+    8454.6466289948298321,
+    25347.822283127815364,
+    76016.422384055545602,
+    228003.02573922277099,
+    683928.7923850838043,
+    2051645.2105818472488,
+    6154684.7690424983951,
+    18463604.479026982676,
+];
+
+/// Taylor coefficients of the excess kurtosis in the shape `ξ` around
+/// `ξ = 0`
+const KURTOSIS_SERIES: [f64; 16] = [
+    2.4,
+    28.917688340799624724,
+    152.61603300554223354,
+    666.44832981596100277,
+    2877.8767676592599869,
+    12120.83074935344703,
+    50326.428802160439515,
+    206829.8527490019882,
+    843896.16299716876927,
+    3425316.7692335545256,
+    13850467.80832962107,
+    55849479.499548640591,
+    224740752.03701661057,
+    902991524.08113322795,
+    3624051664.8463748812,
+    14532463396.019110229,
+];
+
+/// Below this magnitude of the shape the skewness and kurtosis are
+/// evaluated from their Taylor series, since the closed forms lose
+/// `1 / ξ` and `1 / ξ^2` digits to cancellation
+const SERIES_THRESHOLD: f64 = 0.02;
+
+/// Implements the [Generalized extreme
+/// value](https://en.wikipedia.org/wiki/Generalized_extreme_value_distribution)
+/// distribution, the limit distribution of normalized block maxima. It
+/// covers the Gumbel (`ξ = 0`), Fréchet (`ξ > 0`) and reversed Weibull
+/// (`ξ < 0`) families.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedExtremeValue, Continuous};
+/// use statrs::statistics::Distribution;
+///
+/// let n = GeneralizedExtremeValue::new(0.0, 1.0, 0.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.5772156649015328606065);
+/// assert_eq!(n.pdf(0.0), 0.36787944117144233);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedExtremeValue {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedExtremeValue {
+    /// Constructs a new generalized extreme value distribution with the
+    /// given location, scale and shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN`, if `scale <= 0.0` or if
+    /// the scale or shape are infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let mut result = GeneralizedExtremeValue::new(0.0, 1.0, 0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedExtremeValue::new(0.0, -1.0, 0.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedExtremeValue> {
+        if location.is_nan()
+            || scale.is_nan()
+            || shape.is_nan()
+            || scale <= 0.0
+            || scale.is_infinite()
+            || shape.is_infinite()
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(GeneralizedExtremeValue {
+                location,
+                scale,
+                shape,
+            })
+        }
+    }
+
+    /// Returns the location of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let n = GeneralizedExtremeValue::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let n = GeneralizedExtremeValue::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedExtremeValue;
+    ///
+    /// let n = GeneralizedExtremeValue::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.shape(), 0.5);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `ln(t(x))` where `t(x) = (1 + ξz)^(-1 / ξ)`, or `e^(-z)` for
+    /// `ξ = 0`, with `z = (x - μ) / σ`. The cdf is `e^(-t(x))`
+    fn ln_t(&self, x: f64) -> f64 {
+        -internal::ln_1p_scaled((x - self.location) / self.scale, self.shape)
+    }
+
+    /// Returns `(ln Γ(1 - jξ) - j * ln Γ(1 - ξ)) / ξ^2`. The terms linear
+    /// in `ξ` cancel analytically in the series, so this stays accurate as
+    /// `ξ → 0`
+    fn ln_moment_ratio(&self, j: f64) -> f64 {
+        let xi = self.shape;
+        if (j * xi).abs() <= 0.25 {
+            // (jξ)^k / ξ^2 and ξ^(k - 2) for k = 2, 3, ...
+            let mut a = j * j;
+            let mut b = 1.0;
+            let mut sum = 0.0;
+            for (i, z) in internal::ZETA.iter().enumerate() {
+                sum += z * (a - j * b) / (i + 2) as f64;
+                a *= j * xi;
+                b *= xi;
+            }
+            sum
+        } else {
+            (gamma::ln_gamma(1.0 - j * xi) - j * gamma::ln_gamma(1.0 - xi)) / (xi * xi)
+        }
+    }
+
+    /// Returns `E[V^j] / E[V]^j - 1` for `V = e^(-ξ * ln(E))` with `E`
+    /// standard exponential, so that `X = μ + σ * (V - 1) / ξ`
+    fn moment_ratio_m1(&self, j: f64) -> f64 {
+        (self.ln_moment_ratio(j) * self.shape * self.shape).exp_m1()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GeneralizedExtremeValue {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let e = ziggurat::sample_exp_1(r);
+        self.location + self.scale * internal::exp_m1_scaled(-e.ln(), self.shape)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedExtremeValue {
+    /// Calculates the cumulative distribution function for the
+    /// generalized extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-t(x))
+    /// ```
+    ///
+    /// where `t(x) = (1 + ξ(x - μ) / σ)^(-1 / ξ)`, or `e^(-(x - μ) / σ)`
+    /// for `ξ = 0`, `μ` is the location, `σ` is the scale and `ξ` is the
+    /// shape
+    fn cdf(&self, x: f64) -> f64 {
+        if x < self.min() {
+            0.0
+        } else if x > self.max() {
+            1.0
+        } else {
+            (-self.ln_t(x).exp()).exp()
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// generalized extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-t(x))
+    /// ```
+    ///
+    /// where `t(x) = (1 + ξ(x - μ) / σ)^(-1 / ξ)`, or `e^(-(x - μ) / σ)`
+    /// for `ξ = 0`, `μ` is the location, `σ` is the scale and `ξ` is the
+    /// shape
+    fn sf(&self, x: f64) -> f64 {
+        if x < self.min() {
+            1.0
+        } else if x > self.max() {
+            0.0
+        } else {
+            -(-self.ln_t(x).exp()).exp_m1()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized extreme value distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * ((-ln(p))^(-ξ) - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `μ - σ * ln(-ln(p))` for `ξ = 0`
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 {
+            self.min()
+        } else if p == 1.0 {
+            self.max()
+        } else {
+            self.location + self.scale * internal::exp_m1_scaled(-(-p.ln()).ln(), self.shape)
+        }
+    }
+}
+
+impl Min<f64> for GeneralizedExtremeValue {
+    /// Returns the minimum value in the domain of the generalized extreme
+    /// value distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ > 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     NEG_INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn min(&self) -> f64 {
+        if self.shape > 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Max<f64> for GeneralizedExtremeValue {
+    /// Returns the maximum value in the domain of the generalized extreme
+    /// value distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedExtremeValue {
+    /// Returns the mean of the generalized extreme value distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1` since the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (Γ(1 - ξ) - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `ξ` is the shape and
+    /// `Γ` is the gamma function, and `μ + σγ` for `ξ = 0` where `γ` is
+    /// the Euler-Mascheroni constant
+    fn mean(&self) -> Option<f64> {
+        if self.shape >= 1.0 {
+            None
+        } else if self.shape == 0.0 {
+            Some(self.location + self.scale * EULER_MASCHERONI)
+        } else {
+            Some(
+                self.location
+                    + self.scale * internal::ln_gamma_1m(self.shape).exp_m1() / self.shape,
+            )
+        }
+    }
+    /// Returns the variance of the generalized extreme value distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 2` since the variance is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 * (Γ(1 - 2ξ) - Γ(1 - ξ)^2) / ξ^2
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `Γ` is the gamma
+    /// function, and `σ^2 * π^2 / 6` for `ξ = 0`
+    fn variance(&self) -> Option<f64> {
+        if self.shape >= 0.5 {
+            return None;
+        }
+        let r = self.ln_moment_ratio(2.0);
+        let h = r * self.shape * self.shape;
+        let v = if h == 0.0 {
+            r
+        } else {
+            h.exp_m1() / (self.shape * self.shape)
+        };
+        Some(self.scale * self.scale * (2.0 * internal::ln_gamma_1m(self.shape)).exp() * v)
+    }
+    /// Returns the entropy of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + γξ + γ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `γ` is the
+    /// Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + EULER_MASCHERONI * (self.shape + 1.0) + 1.0)
+    }
+    /// Returns the skewness of the generalized extreme value distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 3` since the third moment is infinite.
+    /// For `|ξ| < 0.02` a Taylor series around `ξ = 0` is used instead
+    /// of the closed form
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sgn(ξ) * (g_3 - 3g_1 * g_2 + 2g_1^3) / (g_2 - g_1^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `g_k = Γ(1 - kξ)`, `ξ` is the shape and `Γ` is the gamma
+    /// function, and `12 * sqrt(6) * ζ(3) / π^3` for `ξ = 0`
+    fn skewness(&self) -> Option<f64> {
+        if self.shape >= 1.0 / 3.0 {
+            None
+        } else if self.shape.abs() < SERIES_THRESHOLD {
+            Some(evaluate::polynomial(self.shape, &SKEWNESS_SERIES))
+        } else {
+            let m2 = self.moment_ratio_m1(2.0);
+            let m3 = self.moment_ratio_m1(3.0) - 3.0 * m2;
+            Some(self.shape.signum() * m3 / (m2 * m2.sqrt()))
+        }
+    }
+    /// Returns the excess kurtosis of the generalized extreme value
+    /// distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 4` since the fourth moment is infinite.
+    /// For `|ξ| < 0.02` a Taylor series around `ξ = 0` is used instead
+    /// of the closed form
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (g_4 - 4g_1 * g_3 + 6g_1^2 * g_2 - 3g_1^4) / (g_2 - g_1^2)^2 - 3
+    /// ```
+    ///
+    /// where `g_k = Γ(1 - kξ)`, `ξ` is the shape and `Γ` is the gamma
+    /// function, and `12 / 5` for `ξ = 0`
+    fn kurtosis(&self) -> Option<f64> {
+        if self.shape >= 0.25 {
+            None
+        } else if self.shape.abs() < SERIES_THRESHOLD {
+            Some(evaluate::polynomial(self.shape, &KURTOSIS_SERIES))
+        } else {
+            let m2 = self.moment_ratio_m1(2.0);
+            let m3 = self.moment_ratio_m1(3.0);
+            let m4 = self.moment_ratio_m1(4.0) - 4.0 * m3 + 6.0 * m2;
+            Some(m4 / (m2 * m2) - 3.0)
+        }
+    }
+}
+
+impl Median<f64> for GeneralizedExtremeValue {
+    /// Returns the median of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (ln(2)^(-ξ) - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `μ - σ * ln(ln(2))` for `ξ = 0`
+    fn median(&self) -> f64 {
+        self.location + self.scale * internal::exp_m1_scaled(-f64::consts::LN_2.ln(), self.shape)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedExtremeValue {
+    /// Returns the mode of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ > -1 {
+    ///     μ + σ * ((1 + ξ)^(-ξ) - 1) / ξ
+    /// } else {
+    ///     μ - σ / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `μ` for `ξ = 0`
+    fn mode(&self) -> Option<f64> {
+        if self.shape > -1.0 {
+            Some(
+                self.location
+                    + self.scale * internal::exp_m1_scaled(-self.shape.ln_1p(), self.shape),
+            )
+        } else {
+            Some(self.max())
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedExtremeValue {
+    /// Calculates the probability density function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / σ) * t(x)^(ξ + 1) * e^(-t(x))
+    /// ```
+    ///
+    /// where `t(x) = (1 + ξ(x - μ) / σ)^(-1 / ξ)`, or `e^(-(x - μ) / σ)`
+    /// for `ξ = 0`, `μ` is the location, `σ` is the scale and `ξ` is the
+    /// shape
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (ξ + 1) * ln(t(x)) - t(x) - ln(σ)
+    /// ```
+    ///
+    /// where `t(x) = (1 + ξ(x - μ) / σ)^(-1 / ξ)`, or `e^(-(x - μ) / σ)`
+    /// for `ξ = 0`, `μ` is the location, `σ` is the scale and `ξ` is the
+    /// shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            return f64::NEG_INFINITY;
+        }
+        let ln_t = self.ln_t(x);
+        if ln_t == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else if self.shape == -1.0 {
+            -ln_t.exp() - self.scale.ln()
+        } else {
+            (self.shape + 1.0) * ln_t - ln_t.exp() - self.scale.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GeneralizedExtremeValue};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> GeneralizedExtremeValue {
+        let n = GeneralizedExtremeValue::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, shape: f64) {
+        let n = try_create(location, scale, shape);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(shape, n.shape());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, shape: f64) {
+        let n = GeneralizedExtremeValue::new(location, scale, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(GeneralizedExtremeValue) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedExtremeValue) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    fn test_none<F>(location: f64, scale: f64, shape: f64, eval: F)
+        where F: Fn(GeneralizedExtremeValue) -> Option<f64>
+    {
+        let n = try_create(location, scale, shape);
+        assert!(eval(n).is_none());
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1, 0.0);
+        create_case(0.0, 1.0, 0.5);
+        create_case(-5.0, 10.0, -0.5);
+        create_case(10.0, 100.0, 3.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 0.0);
+        bad_create_case(1.0, f64::NAN, 0.0);
+        bad_create_case(1.0, 1.0, f64::NAN);
+        bad_create_case(1.0, 0.0, 0.0);
+        bad_create_case(1.0, -1.0, 0.0);
+        bad_create_case(1.0, f64::INFINITY, 0.0);
+        bad_create_case(1.0, 1.0, f64::INFINITY);
+        bad_create_case(1.0, 1.0, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: GeneralizedExtremeValue| x.mean().unwrap();
+        let mean_opt = |x: GeneralizedExtremeValue| x.mean();
+        test_almost(0.0, 1.0, 0.0, 0.5772156649015328606065, 1e-13, mean);
+        test_almost(-2.0, 0.5, 1e-10, -1.711392167499780769926, 1e-13, mean);
+        test_almost(3.0, 10.0, -0.01, 8.674148808493962862648, 1e-13, mean);
+        test_almost(0.0, 1.0, 0.1, 0.6862870211931935556593, 1e-13, mean);
+        test_almost(1.0, 2.0, -0.5, 1.455092298188967945404, 1e-13, mean);
+        test_almost(0.0, 1.0, -1.5, -0.2195602587860913469824, 1e-13, mean);
+        test_almost(0.0, 1.0, 0.3, 0.9935177754918592637643, 1e-13, mean);
+        test_almost(0.0, 1.0, 0.6, 2.030265906262813569973, 1e-13, mean);
+        test_none(0.0, 1.0, 1.0, mean_opt);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: GeneralizedExtremeValue| x.variance().unwrap();
+        let variance_opt = |x: GeneralizedExtremeValue| x.variance();
+        test_almost(0.0, 1.0, 0.0, 1.644934066848226436472, 1e-13, variance);
+        test_almost(-2.0, 0.5, 1e-10, 0.4112335168196335398611, 1e-13, variance);
+        test_almost(3.0, 10.0, -0.01, 160.3049162002611259973, 1e-11, variance);
+        test_almost(0.0, 1.0, 0.1, 2.22624107320823953577, 1e-13, variance);
+        test_almost(1.0, 2.0, -0.5, 3.433629385640827046149, 1e-13, variance);
+        test_almost(0.0, 1.0, -1.5, 1.881268503269218357051, 1e-13, variance);
+        test_almost(0.0, 1.0, 0.3, 5.924576634921403162571, 1e-13, variance);
+        test_none(0.0, 1.0, 0.6, variance_opt);
+        test_none(0.0, 1.0, 1.0, variance_opt);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: GeneralizedExtremeValue| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 0.0, 1.577215664901532860607, 1e-13, entropy);
+        test_almost(-2.0, 0.5, 1e-10, 0.8840684843993091176794, 1e-13, entropy);
+        test_almost(3.0, 10.0, -0.01, 3.874028601246563215898, 1e-13, entropy);
+        test_almost(0.0, 1.0, 0.1, 1.634937231391686149871, 1e-13, entropy);
+        test_almost(1.0, 2.0, -0.5, 1.98175501301071173972, 1e-13, entropy);
+        test_almost(0.0, 1.0, -1.5, 0.7113921675492335696967, 1e-13, entropy);
+        test_almost(0.0, 1.0, 0.3, 1.75038036437199271238, 1e-13, entropy);
+        test_almost(0.0, 1.0, 0.6, 1.923545063842452564154, 1e-13, entropy);
+        test_almost(0.0, 1.0, 1.0, 2.154431329803065721213, 1e-13, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: GeneralizedExtremeValue| x.skewness().unwrap();
+        let skewness_opt = |x: GeneralizedExtremeValue| x.skewness();
+        test_almost(0.0, 1.0, 0.0, 1.139547099404648657493, 1e-13, skewness);
+        test_almost(-2.0, 0.5, 1e-10, 1.139547100001309898882, 1e-13, skewness);
+        test_almost(3.0, 10.0, -0.01, 1.081073759807266273859, 1e-13, skewness);
+        test_almost(0.0, 1.0, 0.1, 1.910339134168519941363, 1e-12, skewness);
+        test_almost(1.0, 2.0, -0.5, -0.6311106578189371381919, 1e-13, skewness);
+        test_almost(0.0, 1.0, -1.5, -3.802310903649233682533, 1e-13, skewness);
+        test_almost(0.0, 1.0, 0.3, 13.48355240322114898388, 1e-12, skewness);
+        test_none(0.0, 1.0, 0.6, skewness_opt);
+        test_none(0.0, 1.0, 1.0, skewness_opt);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: GeneralizedExtremeValue| x.kurtosis().unwrap();
+        let kurtosis_opt = |x: GeneralizedExtremeValue| x.kurtosis();
+        test_almost(0.0, 1.0, 0.0, 2.4, 1e-13, kurtosis);
+        test_almost(-2.0, 0.5, 1e-10, 2.400000002891768835606, 1e-13, kurtosis);
+        test_almost(3.0, 10.0, -0.01, 2.12544588658657137693, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, 0.1, 7.978566239354003542919, 1e-11, kurtosis);
+        test_almost(1.0, 2.0, -0.5, 0.2450893006876380628487, 1e-12, kurtosis);
+        test_almost(0.0, 1.0, -1.5, 24.67884500432796922069, 1e-12, kurtosis);
+        test_none(0.0, 1.0, 0.3, kurtosis_opt);
+        test_none(0.0, 1.0, 0.6, kurtosis_opt);
+        test_none(0.0, 1.0, 1.0, kurtosis_opt);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: GeneralizedExtremeValue| x.median();
+        test_almost(0.0, 1.0, 0.0, 0.3665129205816643270124, 1e-13, median);
+        test_almost(-2.0, 0.5, 1e-10, -1.81674353970580954347, 1e-13, median);
+        test_almost(3.0, 10.0, -0.01, 6.658420817974293814083, 1e-13, median);
+        test_almost(0.0, 1.0, 0.1, 0.3733123212357060606606, 1e-13, median);
+        test_almost(1.0, 2.0, -0.5, 1.669781555369208974587, 1e-13, median);
+        test_almost(0.0, 1.0, -1.5, 0.2819447457425734770267, 1e-13, median);
+        test_almost(0.0, 1.0, 0.3, 0.3874219487710332559105, 1e-13, median);
+        test_almost(0.0, 1.0, 0.6, 0.4099363137294650225598, 1e-13, median);
+        test_almost(0.0, 1.0, 1.0, 0.4426950408889634073599, 1e-13, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: GeneralizedExtremeValue| x.mode().unwrap();
+        test_almost(0.0, 1.0, 0.0, 0.0, 1e-13, mode);
+        test_almost(-2.0, 0.5, 1e-10, -2.000000000049999999998, 1e-13, mode);
+        test_almost(3.0, 10.0, -0.01, 3.100498308241667903089, 1e-13, mode);
+        test_almost(0.0, 1.0, 0.1, -0.09485741785478217934145, 1e-13, mode);
+        test_almost(1.0, 2.0, -0.5, 2.171572875253809902397, 1e-13, mode);
+        test_almost(0.0, 1.0, -1.5, 0.6666666666666666666667, 1e-13, mode);
+        test_almost(0.0, 1.0, 0.3, -0.2523046633514039164733, 1e-13, mode);
+        test_almost(0.0, 1.0, 0.6, -0.4095465965530910152209, 1e-13, mode);
+        test_almost(0.0, 1.0, 1.0, -0.5, 1e-13, mode);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: GeneralizedExtremeValue| x.pdf(arg);
+        test_case(0.0, 1.0, 0.25, 0.0, pdf(-10.0));
+        test_almost(0.0, 1.0, 0.25, 3.601125591016291664441e-6, 1e-20, pdf(-2.0));
+        test_almost(0.0, 1.0, 0.25, 0.178693458254981184689, 1e-15, pdf(-1.0));
+        test_almost(0.0, 1.0, 0.25, 0.3678794411714423215955, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.25, 0.2175519173297679723771, 1e-15, pdf(1.0));
+        test_almost(0.0, 1.0, 0.25, 0.01667792111199699479655, 1e-16, pdf(5.0));
+        test_almost(0.0, 1.0, 0.25, 2.253316382729921337749e-5, 1e-19, pdf(30.0));
+        test_almost(-2.0, 0.5, -0.5, 1.195141895924532192126e-34, 1e-46, pdf(-10.0));
+        test_almost(-2.0, 0.5, -0.5, 0.735758882342884643191, 1e-15, pdf(-2.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(-1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(0.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(5.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(30.0));
+        test_almost(3.0, 10.0, 0.0, 0.009354649742660435828188, 1e-17, pdf(-10.0));
+        test_almost(3.0, 10.0, 0.0, 0.03170419210779421760947, 1e-16, pdf(-2.0));
+        test_almost(3.0, 10.0, 0.0, 0.03356035596434450096074, 1e-16, pdf(-1.0));
+        test_almost(3.0, 10.0, 0.0, 0.03499871611584216534284, 1e-16, pdf(0.0));
+        test_almost(3.0, 10.0, 0.0, 0.03600894672892270393977, 1e-16, pdf(1.0));
+        test_almost(3.0, 10.0, 0.0, 0.03610529147709300160661, 1e-16, pdf(5.0));
+        test_almost(3.0, 10.0, 0.0, 0.006283735781295471890449, 1e-17, pdf(30.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: GeneralizedExtremeValue| x.ln_pdf(arg);
+        test_case(0.0, 1.0, 0.25, f64::NEG_INFINITY, ln_pdf(-10.0));
+        test_almost(0.0, 1.0, 0.25, -12.53426409720027345291, 1e-13, ln_pdf(-2.0));
+        test_almost(0.0, 1.0, 0.25, -1.722083464901589189964, 1e-14, ln_pdf(-1.0));
+        test_almost(0.0, 1.0, 0.25, -1.0, 1e-14, ln_pdf(0.0));
+        test_almost(0.0, 1.0, 0.25, -1.525317756571048778831, 1e-14, ln_pdf(1.0));
+        test_almost(0.0, 1.0, 0.25, -4.093669523392267200362, 1e-14, ln_pdf(5.0));
+        test_almost(0.0, 1.0, 0.25, -10.70052238606889471215, 1e-13, ln_pdf(30.0));
+        test_almost(-2.0, 0.5, -0.5, -78.10962824210383530779, 1e-13, ln_pdf(-10.0));
+        test_almost(-2.0, 0.5, -0.5, -0.3068528194400546905828, 1e-15, ln_pdf(-2.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(-1.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(1.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(5.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(30.0));
+        test_almost(3.0, 10.0, 0.0, -4.671881760613289904475, 1e-14, ln_pdf(-10.0));
+        test_almost(3.0, 10.0, 0.0, -3.451306363694173830867, 1e-14, ln_pdf(-2.0));
+        test_almost(3.0, 10.0, 0.0, -3.394409790635316001843, 1e-14, ln_pdf(-1.0));
+        test_almost(3.0, 10.0, 0.0, -3.352443900570048788002, 1e-14, ln_pdf(0.0));
+        test_almost(3.0, 10.0, 0.0, -3.323987851154215517939, 1e-14, ln_pdf(1.0));
+        test_almost(3.0, 10.0, 0.0, -3.321315846072027542688, 1e-14, ln_pdf(5.0));
+        test_almost(3.0, 10.0, 0.0, -5.069790605733795449145, 1e-14, ln_pdf(30.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: GeneralizedExtremeValue| x.cdf(arg);
+        test_case(0.0, 1.0, 0.25, 0.0, cdf(-10.0));
+        test_almost(0.0, 1.0, 0.25, 1.125351747192591145138e-7, 1e-21, cdf(-2.0));
+        test_almost(0.0, 1.0, 0.25, 0.04240479526949260535101, 1e-16, cdf(-1.0));
+        test_almost(0.0, 1.0, 0.25, 0.3678794411714423215955, 1e-15, cdf(0.0));
+        test_almost(0.0, 1.0, 0.25, 0.663915763335473548514, 1e-15, cdf(1.0));
+        test_almost(0.0, 1.0, 0.25, 0.9617329724046001423256, 1e-15, cdf(5.0));
+        test_almost(0.0, 1.0, 0.25, 0.9998084497605493502663, 1e-15, cdf(30.0));
+        test_almost(-2.0, 0.5, -0.5, 6.639677199580734400702e-36, 1e-48, cdf(-10.0));
+        test_almost(-2.0, 0.5, -0.5, 0.3678794411714423215955, 1e-15, cdf(-2.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(-1.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(0.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(1.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(5.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(30.0));
+        test_almost(3.0, 10.0, 0.0, 0.02549439467572413155463, 1e-16, cdf(-10.0));
+        test_almost(3.0, 10.0, 0.0, 0.1922956455479649280743, 1e-15, cdf(-2.0));
+        test_almost(3.0, 10.0, 0.0, 0.2249617935499184786315, 1e-15, cdf(-1.0));
+        test_almost(3.0, 10.0, 0.0, 0.259276865990827567958, 1e-15, cdf(0.0));
+        test_almost(3.0, 10.0, 0.0, 0.2948163207291581687007, 1e-15, cdf(1.0));
+        test_almost(3.0, 10.0, 0.0, 0.4409910259429826452437, 1e-15, cdf(5.0));
+        test_almost(3.0, 10.0, 0.0, 0.9350030265566081784575, 1e-15, cdf(30.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: GeneralizedExtremeValue| x.sf(arg);
+        test_almost(0.0, 1.0, 0.25, 1.0, 1e-14, sf(-10.0));
+        test_almost(0.0, 1.0, 0.25, 0.9999998874648252807409, 1e-15, sf(-2.0));
+        test_almost(0.0, 1.0, 0.25, 0.957595204730507394649, 1e-15, sf(-1.0));
+        test_almost(0.0, 1.0, 0.25, 0.6321205588285576784045, 1e-15, sf(0.0));
+        test_almost(0.0, 1.0, 0.25, 0.336084236664526451486, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 0.25, 0.03826702759539985767436, 1e-16, sf(5.0));
+        test_almost(0.0, 1.0, 0.25, 0.0001915502394506497337495, 1e-18, sf(30.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, sf(-10.0));
+        test_almost(-2.0, 0.5, -0.5, 0.6321205588285576784045, 1e-15, sf(-2.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(-1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(0.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(5.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(30.0));
+        test_almost(3.0, 10.0, 0.0, 0.9745056053242758684454, 1e-15, sf(-10.0));
+        test_almost(3.0, 10.0, 0.0, 0.8077043544520350719257, 1e-15, sf(-2.0));
+        test_almost(3.0, 10.0, 0.0, 0.7750382064500815213685, 1e-15, sf(-1.0));
+        test_almost(3.0, 10.0, 0.0, 0.740723134009172432042, 1e-15, sf(0.0));
+        test_almost(3.0, 10.0, 0.0, 0.7051836792708418312993, 1e-15, sf(1.0));
+        test_almost(3.0, 10.0, 0.0, 0.5590089740570173547563, 1e-15, sf(5.0));
+        test_almost(3.0, 10.0, 0.0, 0.06499697344339182154247, 1e-16, sf(30.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: GeneralizedExtremeValue| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 0.25, -2.173977732914277256221, 1e-14, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, 0.25, -0.752822200168864619748, 1e-14, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 0.25, 0.3838292097871692425809, 1e-14, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 0.25, 1.461749676788036845114, 1e-14, inverse_cdf(0.75));
+        test_almost(0.0, 1.0, 0.25, 18.49084030461105643287, 1e-13, inverse_cdf(0.999));
+        test_almost(-2.0, 0.5, -0.5, -5.798525912188081203771, 1e-14, inverse_cdf(1e-10));
+        test_almost(-2.0, 0.5, -0.5, -2.517427129385146332572, 1e-14, inverse_cdf(0.1));
+        test_almost(-2.0, 0.5, -0.5, -1.832554611157697756353, 1e-14, inverse_cdf(0.5));
+        test_almost(-2.0, 0.5, -0.5, -1.536360021302651645943, 1e-14, inverse_cdf(0.75));
+        test_almost(-2.0, 0.5, -0.5, -1.031630686580969664263, 1e-14, inverse_cdf(0.999));
+        test_almost(3.0, 10.0, 0.0, -28.36617538242001482239, 1e-13, inverse_cdf(1e-10));
+        test_almost(3.0, 10.0, 0.0, -5.34032445247955775695, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 10.0, 0.0, 6.665129205816643270124, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 10.0, 0.0, 15.45899323707238198381, 1e-13, inverse_cdf(0.75));
+        test_almost(3.0, 10.0, 0.0, 72.07255070523715611295, 1e-13, inverse_cdf(0.999));
+    }
+    #[test]
+    fn test_min_max() {
+        let min = |x: GeneralizedExtremeValue| x.min();
+        let max = |x: GeneralizedExtremeValue| x.max();
+        test_case(0.0, 1.0, 0.25, -4.0, min);
+        test_case(0.0, 1.0, 0.25, f64::INFINITY, max);
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, min);
+        test_case(-2.0, 0.5, -0.5, -1.0, max);
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, min);
+        test_case(3.0, 10.0, 0.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_boundary() {
+        test_case(0.0, 1.0, 0.25, 0.0, |x| x.pdf(-4.0));
+        test_case(0.0, 1.0, 0.25, 0.0, |x| x.cdf(-4.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, |x| x.pdf(-1.0));
+        test_case(-2.0, 0.5, -0.5, 1.0, |x| x.cdf(-1.0));
+        test_case(0.0, 1.0, -1.0, 1.0, |x| x.pdf(1.0));
+        test_case(0.0, 1.0, -2.0, f64::INFINITY, |x| x.pdf(0.5));
+    }
+
+    #[test]
+    fn test_small_shape() {
+        let gumbel = crate::distribution::Gumbel::new(1.0, 2.0).unwrap();
+        for &shape in [1e-12, -1e-12, 1e-9, -1e-9].iter() {
+            let n = try_create(1.0, 2.0, shape);
+            for &x in [-5.0, 0.0, 1.0, 3.0, 20.0].iter() {
+                assert_almost_eq!(n.pdf(x), gumbel.pdf(x), 1e-8);
+                assert_almost_eq!(n.cdf(x), gumbel.cdf(x), 1e-8);
+                assert_almost_eq!(n.sf(x), gumbel.sf(x), 1e-8);
+            }
+            for &p in [1e-5, 0.3, 0.5, 0.99].iter() {
+                assert_almost_eq!(n.inverse_cdf(p), gumbel.inverse_cdf(p), 1e-7);
+            }
+            assert_almost_eq!(n.mean().unwrap(), gumbel.mean().unwrap(), 1e-8);
+            assert_almost_eq!(n.variance().unwrap(), gumbel.variance().unwrap(), 1e-7);
+            assert_almost_eq!(n.skewness().unwrap(), gumbel.skewness().unwrap(), 1e-7);
+            assert_almost_eq!(n.kurtosis().unwrap(), gumbel.kurtosis().unwrap(), 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, 0.25), -3.0, 10000.0);
+        tests::check_continuous_distribution(&try_create(-2.0, 0.5, -0.5), -10.0, -1.0);
+        tests::check_continuous_distribution(&try_create(3.0, 10.0, 0.0), -30.0, 200.0);
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, -1.0), -20.0, 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, 0.25), 1e-12);
+        tests::check_inverse_cdf(&try_create(-2.0, 0.5, -0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(3.0, 10.0, 0.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, 1e-10), 1e-12);
+    }
+}
@@ -0,0 +1,788 @@
+use crate::distribution::{internal, ziggurat, Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Generalized
+/// Pareto](https://en.wikipedia.org/wiki/Generalized_Pareto_distribution)
+/// distribution, the limit distribution of threshold exceedances. It
+/// reduces to the exponential distribution for `ξ = 0` and to a uniform
+/// distribution for `ξ = -1`.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedPareto, Continuous};
+/// use statrs::statistics::Distribution;
+///
+/// let n = GeneralizedPareto::new(0.0, 1.0, 0.5).unwrap();
+/// assert_eq!(n.mean().unwrap(), 2.0);
+/// assert_eq!(n.pdf(0.0), 1.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedPareto {
+    /// Constructs a new generalized Pareto distribution with the given
+    /// location, scale and shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN`, if `scale <= 0.0` or if
+    /// the scale or shape are infinite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let mut result = GeneralizedPareto::new(0.0, 1.0, 0.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedPareto::new(0.0, -1.0, 0.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedPareto> {
+        if location.is_nan()
+            || scale.is_nan()
+            || shape.is_nan()
+            || scale <= 0.0
+            || scale.is_infinite()
+            || shape.is_infinite()
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(GeneralizedPareto {
+                location,
+                scale,
+                shape,
+            })
+        }
+    }
+
+    /// Returns the location of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape of the generalized Pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.5).unwrap();
+    /// assert_eq!(n.shape(), 0.5);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `ln(1 + ξz) / ξ`, or `z` for `ξ = 0`, with
+    /// `z = (x - μ) / σ`. The survival function is `e^(-l(x))`
+    fn l(&self, x: f64) -> f64 {
+        internal::ln_1p_scaled((x - self.location) / self.scale, self.shape)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GeneralizedPareto {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let e = ziggurat::sample_exp_1(r);
+        self.location + self.scale * internal::exp_m1_scaled(e, self.shape)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedPareto {
+    /// Calculates the cumulative distribution function for the
+    /// generalized Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - (1 + ξ(x - μ) / σ)^(-1 / ξ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `1 - e^(-(x - μ) / σ)` for `ξ = 0`
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            -(-self.l(x)).exp_m1()
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// generalized Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 + ξ(x - μ) / σ)^(-1 / ξ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `e^(-(x - μ) / σ)` for `ξ = 0`
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            (-self.l(x)).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized Pareto distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * ((1 - p)^(-ξ) - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `μ - σ * ln(1 - p)` for `ξ = 0`
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 {
+            self.min()
+        } else if p == 1.0 {
+            self.max()
+        } else {
+            self.location + self.scale * internal::exp_m1_scaled(-(-p).ln_1p(), self.shape)
+        }
+    }
+}
+
+impl Min<f64> for GeneralizedPareto {
+    /// Returns the minimum value in the domain of the generalized Pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for GeneralizedPareto {
+    /// Returns the maximum value in the domain of the generalized Pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ < 0 {
+    ///     μ - σ / ξ
+    /// } else {
+    ///     INF
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedPareto {
+    /// Returns the mean of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1` since the mean is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ / (1 - ξ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mean(&self) -> Option<f64> {
+        if self.shape >= 1.0 {
+            None
+        } else {
+            Some(self.location + self.scale / (1.0 - self.shape))
+        }
+    }
+    /// Returns the variance of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 2` since the variance is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 / ((1 - ξ)^2 * (1 - 2ξ))
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn variance(&self) -> Option<f64> {
+        if self.shape >= 0.5 {
+            None
+        } else {
+            let a = self.scale / (1.0 - self.shape);
+            Some(a * a / (1.0 - 2.0 * self.shape))
+        }
+    }
+    /// Returns the entropy of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + ξ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + self.shape + 1.0)
+    }
+    /// Returns the skewness of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 3` since the third moment is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2(1 + ξ) * sqrt(1 - 2ξ) / (1 - 3ξ)
+    /// ```
+    ///
+    /// where `ξ` is the shape
+    fn skewness(&self) -> Option<f64> {
+        let xi = self.shape;
+        if xi >= 1.0 / 3.0 {
+            None
+        } else {
+            Some(2.0 * (1.0 + xi) * (1.0 - 2.0 * xi).sqrt() / (1.0 - 3.0 * xi))
+        }
+    }
+    /// Returns the excess kurtosis of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ >= 1 / 4` since the fourth moment is infinite
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3(1 - 2ξ)(2ξ^2 + ξ + 3) / ((1 - 3ξ)(1 - 4ξ)) - 3
+    /// ```
+    ///
+    /// where `ξ` is the shape
+    fn kurtosis(&self) -> Option<f64> {
+        let xi = self.shape;
+        if xi >= 0.25 {
+            None
+        } else {
+            Some(
+                3.0 * (1.0 - 2.0 * xi) * (2.0 * xi * xi + xi + 3.0)
+                    / ((1.0 - 3.0 * xi) * (1.0 - 4.0 * xi))
+                    - 3.0,
+            )
+        }
+    }
+}
+
+impl Median<f64> for GeneralizedPareto {
+    /// Returns the median of the generalized Pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (2^ξ - 1) / ξ
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `μ + σ * ln(2)` for `ξ = 0`
+    fn median(&self) -> f64 {
+        self.location + self.scale * internal::exp_m1_scaled(f64::consts::LN_2, self.shape)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedPareto {
+    /// Returns the mode of the generalized Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `ξ = -1` since the distribution is uniform
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if ξ > -1 {
+    ///     μ
+    /// } else {
+    ///     μ - σ / ξ
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mode(&self) -> Option<f64> {
+        if self.shape > -1.0 {
+            Some(self.location)
+        } else if self.shape == -1.0 {
+            None
+        } else {
+            Some(self.max())
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedPareto {
+    /// Calculates the probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / σ) * (1 + ξ(x - μ) / σ)^(-1 / ξ - 1)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `(1 / σ) * e^(-(x - μ) / σ)` for `ξ = 0`
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// Pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(1 + ξ) * ln(1 + ξ(x - μ) / σ) / ξ - ln(σ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape,
+    /// and `-(x - μ) / σ - ln(σ)` for `ξ = 0`
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            f64::NEG_INFINITY
+        } else if self.shape == -1.0 {
+            -self.scale.ln()
+        } else {
+            -(1.0 + self.shape) * self.l(x) - self.scale.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GeneralizedPareto};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> GeneralizedPareto {
+        let n = GeneralizedPareto::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, shape: f64) {
+        let n = try_create(location, scale, shape);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(shape, n.shape());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, shape: f64) {
+        let n = GeneralizedPareto::new(location, scale, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    fn test_none<F>(location: f64, scale: f64, shape: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> Option<f64>
+    {
+        let n = try_create(location, scale, shape);
+        assert!(eval(n).is_none());
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1, 0.0);
+        create_case(0.0, 1.0, 0.5);
+        create_case(-5.0, 10.0, -0.5);
+        create_case(10.0, 100.0, 3.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 0.0);
+        bad_create_case(1.0, f64::NAN, 0.0);
+        bad_create_case(1.0, 1.0, f64::NAN);
+        bad_create_case(1.0, 0.0, 0.0);
+        bad_create_case(1.0, -1.0, 0.0);
+        bad_create_case(1.0, f64::INFINITY, 0.0);
+        bad_create_case(1.0, 1.0, f64::INFINITY);
+        bad_create_case(1.0, 1.0, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: GeneralizedPareto| x.mean().unwrap();
+        let mean_opt = |x: GeneralizedPareto| x.mean();
+        test_almost(0.0, 1.0, 0.0, 1.0, 1e-13, mean);
+        test_almost(-2.0, 0.5, 1e-10, -1.499999999949999999995, 1e-13, mean);
+        test_almost(3.0, 10.0, -0.01, 12.90099009900990098806, 1e-12, mean);
+        test_almost(0.0, 1.0, 0.1, 1.111111111111111117964, 1e-13, mean);
+        test_almost(1.0, 2.0, -0.5, 2.333333333333333333333, 1e-13, mean);
+        test_almost(0.0, 1.0, -1.0, 0.5, 1e-13, mean);
+        test_almost(0.0, 1.0, -1.5, 0.4, 1e-13, mean);
+        test_almost(0.0, 1.0, 0.3, 1.428571428571428548771, 1e-13, mean);
+        test_almost(0.0, 1.0, 0.6, 2.499999999999999861222, 1e-13, mean);
+        test_none(0.0, 1.0, 1.0, mean_opt);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: GeneralizedPareto| x.variance().unwrap();
+        let variance_opt = |x: GeneralizedPareto| x.variance();
+        test_almost(0.0, 1.0, 0.0, 1.0, 1e-13, variance);
+        test_almost(-2.0, 0.5, 1e-10, 0.2500000001000000000275, 1e-13, variance);
+        test_almost(3.0, 10.0, -0.01, 96.10745582420793032398, 1e-12, variance);
+        test_almost(0.0, 1.0, 0.1, 1.543209876543209916996, 1e-13, variance);
+        test_almost(1.0, 2.0, -0.5, 0.8888888888888888888889, 1e-13, variance);
+        test_almost(0.0, 1.0, -1.0, 0.08333333333333333333333, 1e-13, variance);
+        test_almost(0.0, 1.0, -1.5, 0.04, 1e-13, variance);
+        test_almost(0.0, 1.0, 0.3, 5.102040816326530167185, 1e-13, variance);
+        test_none(0.0, 1.0, 0.6, variance_opt);
+        test_none(0.0, 1.0, 1.0, variance_opt);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: GeneralizedPareto| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 0.0, 1.0, 1e-13, entropy);
+        test_almost(-2.0, 0.5, 1e-10, 0.3068528195400546905828, 1e-13, entropy);
+        test_almost(3.0, 10.0, -0.01, 3.29258509299404568381, 1e-13, entropy);
+        test_almost(0.0, 1.0, 0.1, 1.100000000000000005551, 1e-13, entropy);
+        test_almost(1.0, 2.0, -0.5, 1.193147180559945309417, 1e-13, entropy);
+        test_almost(0.0, 1.0, -1.0, 0.0, 1e-13, entropy);
+        test_almost(0.0, 1.0, -1.5, -0.5, 1e-13, entropy);
+        test_almost(0.0, 1.0, 0.3, 1.299999999999999988898, 1e-13, entropy);
+        test_almost(0.0, 1.0, 0.6, 1.599999999999999977796, 1e-13, entropy);
+        test_almost(0.0, 1.0, 1.0, 2.0, 1e-13, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: GeneralizedPareto| x.skewness().unwrap();
+        let skewness_opt = |x: GeneralizedPareto| x.skewness();
+        test_almost(0.0, 1.0, 0.0, 2.0, 1e-13, skewness);
+        test_almost(-2.0, 0.5, 1e-10, 2.00000000060000000015, 1e-13, skewness);
+        test_almost(3.0, 10.0, -0.01, 1.941458230869603333535, 1e-13, skewness);
+        test_almost(0.0, 1.0, 0.1, 2.8110568859997356799, 1e-13, skewness);
+        test_almost(1.0, 2.0, -0.5, 0.5656854249492380195207, 1e-13, skewness);
+        test_almost(0.0, 1.0, -1.0, 0.0, 1e-13, skewness);
+        test_almost(0.0, 1.0, -1.5, -0.3636363636363636363636, 1e-13, skewness);
+        test_almost(0.0, 1.0, 0.3, 16.44384383287556736547, 1e-12, skewness);
+        test_none(0.0, 1.0, 0.6, skewness_opt);
+        test_none(0.0, 1.0, 1.0, skewness_opt);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: GeneralizedPareto| x.kurtosis().unwrap();
+        let kurtosis_opt = |x: GeneralizedPareto| x.kurtosis();
+        test_almost(0.0, 1.0, 0.0, 6.0, 1e-13, kurtosis);
+        test_almost(-2.0, 0.5, 1e-10, 6.00000000480000000228, 1e-13, kurtosis);
+        test_almost(3.0, 10.0, -0.01, 5.541833457804331581637, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, 0.1, 14.82857142857142945236, 1e-12, kurtosis);
+        test_almost(1.0, 2.0, -0.5, -0.6, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, -1.0, -1.2, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, -1.5, -1.12987012987012987013, 1e-13, kurtosis);
+        test_none(0.0, 1.0, 0.3, kurtosis_opt);
+        test_none(0.0, 1.0, 0.6, kurtosis_opt);
+        test_none(0.0, 1.0, 1.0, kurtosis_opt);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: GeneralizedPareto| x.median();
+        test_almost(0.0, 1.0, 0.0, 0.6931471805599453094172, 1e-13, median);
+        test_almost(-2.0, 0.5, 1e-10, -1.653426409708016019943, 1e-13, median);
+        test_almost(3.0, 10.0, -0.01, 9.907504562964098466292, 1e-13, median);
+        test_almost(0.0, 1.0, 0.1, 0.7177346253629316435268, 1e-13, median);
+        test_almost(1.0, 2.0, -0.5, 2.171572875253809902397, 1e-13, median);
+        test_almost(0.0, 1.0, -1.0, 0.5, 1e-13, median);
+        test_almost(0.0, 1.0, -1.5, 0.4309644062711508251997, 1e-13, median);
+        test_almost(0.0, 1.0, 0.3, 0.7704813778163876119307, 1e-13, median);
+        test_almost(0.0, 1.0, 0.6, 0.8595276108506634635071, 1e-13, median);
+        test_almost(0.0, 1.0, 1.0, 1.0, 1e-13, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: GeneralizedPareto| x.mode().unwrap();
+        let mode_opt = |x: GeneralizedPareto| x.mode();
+        test_almost(0.0, 1.0, 0.0, 0.0, 1e-13, mode);
+        test_almost(-2.0, 0.5, 1e-10, -2.0, 1e-13, mode);
+        test_almost(3.0, 10.0, -0.01, 3.0, 1e-13, mode);
+        test_almost(0.0, 1.0, 0.1, 0.0, 1e-13, mode);
+        test_almost(1.0, 2.0, -0.5, 1.0, 1e-13, mode);
+        test_none(0.0, 1.0, -1.0, mode_opt);
+        test_almost(0.0, 1.0, -1.5, 0.6666666666666666666667, 1e-13, mode);
+        test_almost(0.0, 1.0, 0.3, 0.0, 1e-13, mode);
+        test_almost(0.0, 1.0, 0.6, 0.0, 1e-13, mode);
+        test_almost(0.0, 1.0, 1.0, 0.0, 1e-13, mode);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: GeneralizedPareto| x.pdf(arg);
+        test_case(0.0, 1.0, 0.5, 0.0, pdf(-2.0));
+        test_case(0.0, 1.0, 0.5, 0.0, pdf(-1.5));
+        test_case(0.0, 1.0, 0.5, 0.0, pdf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1.0, 1e-14, pdf(0.0));
+        test_almost(0.0, 1.0, 0.5, 0.512, 1e-15, pdf(0.5));
+        test_almost(0.0, 1.0, 0.5, 0.2962962962962962962963, 1e-15, pdf(1.0));
+        test_almost(0.0, 1.0, 0.5, 0.02332361516034985422741, 1e-16, pdf(5.0));
+        test_almost(0.0, 1.0, 0.5, 0.000244140625, 1e-18, pdf(30.0));
+        test_almost(-2.0, 0.5, -0.5, 2.0, 1e-14, pdf(-2.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, pdf(-1.5));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(-1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(0.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(0.5));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(5.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, pdf(30.0));
+        test_case(3.0, 10.0, 0.0, 0.0, pdf(-2.0));
+        test_case(3.0, 10.0, 0.0, 0.0, pdf(-1.5));
+        test_case(3.0, 10.0, 0.0, 0.0, pdf(-1.0));
+        test_case(3.0, 10.0, 0.0, 0.0, pdf(0.0));
+        test_case(3.0, 10.0, 0.0, 0.0, pdf(0.5));
+        test_case(3.0, 10.0, 0.0, 0.0, pdf(1.0));
+        test_almost(3.0, 10.0, 0.0, 0.08187307530779818586699, 1e-16, pdf(5.0));
+        test_almost(3.0, 10.0, 0.0, 0.006720551273974976512655, 1e-17, pdf(30.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: GeneralizedPareto| x.ln_pdf(arg);
+        test_case(0.0, 1.0, 0.5, f64::NEG_INFINITY, ln_pdf(-2.0));
+        test_case(0.0, 1.0, 0.5, f64::NEG_INFINITY, ln_pdf(-1.5));
+        test_case(0.0, 1.0, 0.5, f64::NEG_INFINITY, ln_pdf(-1.0));
+        test_case(0.0, 1.0, 0.5, 0.0, ln_pdf(0.0));
+        test_almost(0.0, 1.0, 0.5, -0.6694306539426292672989, 1e-15, ln_pdf(0.5));
+        test_almost(0.0, 1.0, 0.5, -1.216395324324493145934, 1e-14, ln_pdf(1.0));
+        test_almost(0.0, 1.0, 0.5, -3.758288905486103987064, 1e-14, ln_pdf(5.0));
+        test_almost(0.0, 1.0, 0.5, -8.317766166719343713007, 1e-14, ln_pdf(30.0));
+        test_almost(-2.0, 0.5, -0.5, 0.6931471805599453094172, 1e-15, ln_pdf(-2.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, ln_pdf(-1.5));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(-1.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(0.5));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(1.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(5.0));
+        test_case(-2.0, 0.5, -0.5, f64::NEG_INFINITY, ln_pdf(30.0));
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, ln_pdf(-2.0));
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, ln_pdf(-1.5));
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, ln_pdf(-1.0));
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, ln_pdf(0.5));
+        test_case(3.0, 10.0, 0.0, f64::NEG_INFINITY, ln_pdf(1.0));
+        test_almost(3.0, 10.0, 0.0, -2.502585092994045684018, 1e-14, ln_pdf(5.0));
+        test_almost(3.0, 10.0, 0.0, -5.002585092994045684018, 1e-14, ln_pdf(30.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: GeneralizedPareto| x.cdf(arg);
+        test_case(0.0, 1.0, 0.5, 0.0, cdf(-2.0));
+        test_case(0.0, 1.0, 0.5, 0.0, cdf(-1.5));
+        test_case(0.0, 1.0, 0.5, 0.0, cdf(-1.0));
+        test_case(0.0, 1.0, 0.5, 0.0, cdf(0.0));
+        test_almost(0.0, 1.0, 0.5, 0.36, 1e-15, cdf(0.5));
+        test_almost(0.0, 1.0, 0.5, 0.5555555555555555555556, 1e-15, cdf(1.0));
+        test_almost(0.0, 1.0, 0.5, 0.9183673469387755102041, 1e-15, cdf(5.0));
+        test_almost(0.0, 1.0, 0.5, 0.99609375, 1e-15, cdf(30.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, cdf(-2.0));
+        test_almost(-2.0, 0.5, -0.5, 0.75, 1e-15, cdf(-1.5));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(-1.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(0.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(0.5));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(1.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(5.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, cdf(30.0));
+        test_case(3.0, 10.0, 0.0, 0.0, cdf(-2.0));
+        test_case(3.0, 10.0, 0.0, 0.0, cdf(-1.5));
+        test_case(3.0, 10.0, 0.0, 0.0, cdf(-1.0));
+        test_case(3.0, 10.0, 0.0, 0.0, cdf(0.0));
+        test_case(3.0, 10.0, 0.0, 0.0, cdf(0.5));
+        test_case(3.0, 10.0, 0.0, 0.0, cdf(1.0));
+        test_almost(3.0, 10.0, 0.0, 0.1812692469220181413301, 1e-15, cdf(5.0));
+        test_almost(3.0, 10.0, 0.0, 0.9327944872602502348734, 1e-15, cdf(30.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: GeneralizedPareto| x.sf(arg);
+        test_almost(0.0, 1.0, 0.5, 1.0, 1e-14, sf(-2.0));
+        test_almost(0.0, 1.0, 0.5, 1.0, 1e-14, sf(-1.5));
+        test_almost(0.0, 1.0, 0.5, 1.0, 1e-14, sf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1.0, 1e-14, sf(0.0));
+        test_almost(0.0, 1.0, 0.5, 0.64, 1e-15, sf(0.5));
+        test_almost(0.0, 1.0, 0.5, 0.4444444444444444444444, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 0.5, 0.08163265306122448979592, 1e-16, sf(5.0));
+        test_almost(0.0, 1.0, 0.5, 0.00390625, 1e-17, sf(30.0));
+        test_almost(-2.0, 0.5, -0.5, 1.0, 1e-14, sf(-2.0));
+        test_almost(-2.0, 0.5, -0.5, 0.25, 1e-15, sf(-1.5));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(-1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(0.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(0.5));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(1.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(5.0));
+        test_case(-2.0, 0.5, -0.5, 0.0, sf(30.0));
+        test_almost(3.0, 10.0, 0.0, 1.0, 1e-14, sf(-2.0));
+        test_almost(3.0, 10.0, 0.0, 1.0, 1e-14, sf(-1.5));
+        test_almost(3.0, 10.0, 0.0, 1.0, 1e-14, sf(-1.0));
+        test_almost(3.0, 10.0, 0.0, 1.0, 1e-14, sf(0.0));
+        test_almost(3.0, 10.0, 0.0, 1.0, 1e-14, sf(0.5));
+        test_almost(3.0, 10.0, 0.0, 1.0, 1e-14, sf(1.0));
+        test_almost(3.0, 10.0, 0.0, 0.8187307530779818586699, 1e-15, sf(5.0));
+        test_almost(3.0, 10.0, 0.0, 0.06720551273974976512655, 1e-16, sf(30.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: GeneralizedPareto| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 0.5, 1.000000000075000036438e-10, 1e-14, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, 0.5, 0.1081851067789195611675, 1e-14, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 0.5, 0.8284271247461900976034, 1e-14, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 0.5, 2.0, 1e-14, inverse_cdf(0.75));
+        test_almost(0.0, 1.0, 0.5, 61.24555320336755855331, 1e-13, inverse_cdf(0.999));
+        test_almost(-2.0, 0.5, -0.5, -1.999999999949999999999, 1e-14, inverse_cdf(1e-10));
+        test_almost(-2.0, 0.5, -0.5, -1.948683298050513796674, 1e-14, inverse_cdf(0.1));
+        test_almost(-2.0, 0.5, -0.5, -1.707106781186547524401, 1e-14, inverse_cdf(0.5));
+        test_almost(-2.0, 0.5, -0.5, -1.5, 1e-14, inverse_cdf(0.75));
+        test_almost(-2.0, 0.5, -0.5, -1.031622776601683807363, 1e-14, inverse_cdf(0.999));
+        test_almost(3.0, 10.0, 0.0, 3.00000000100000000005, 1e-14, inverse_cdf(1e-10));
+        test_almost(3.0, 10.0, 0.0, 4.053605156578263073954, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 10.0, 0.0, 9.931471805599453094172, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 10.0, 0.0, 16.86294361119890618834, 1e-13, inverse_cdf(0.75));
+        test_almost(3.0, 10.0, 0.0, 72.07755278982136163876, 1e-13, inverse_cdf(0.999));
+    }
+    #[test]
+    fn test_min_max() {
+        let min = |x: GeneralizedPareto| x.min();
+        let max = |x: GeneralizedPareto| x.max();
+        test_case(0.0, 1.0, 0.5, 0.0, min);
+        test_case(0.0, 1.0, 0.5, f64::INFINITY, max);
+        test_case(-2.0, 0.5, -0.5, -2.0, min);
+        test_case(-2.0, 0.5, -0.5, -1.0, max);
+        test_case(3.0, 10.0, 0.0, 3.0, min);
+        test_case(3.0, 10.0, 0.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_uniform_shape() {
+        test_case(1.0, 2.0, -1.0, 0.5, |x| x.pdf(1.0));
+        test_case(1.0, 2.0, -1.0, 0.5, |x| x.pdf(2.0));
+        test_case(1.0, 2.0, -1.0, 0.5, |x| x.pdf(3.0));
+        test_case(1.0, 2.0, -1.0, 0.0, |x| x.pdf(3.5));
+        test_almost(1.0, 2.0, -1.0, 0.25, 1e-15, |x| x.cdf(1.5));
+        test_almost(1.0, 2.0, -1.0, 2.5, 1e-15, |x| x.inverse_cdf(0.75));
+    }
+
+    #[test]
+    fn test_small_shape() {
+        let exp = crate::distribution::Exp::new(0.5).unwrap();
+        for &shape in [1e-12, -1e-12, 1e-9, -1e-9].iter() {
+            let n = try_create(0.0, 2.0, shape);
+            for &x in [0.5, 1.0, 3.0, 20.0].iter() {
+                assert_almost_eq!(n.pdf(x), exp.pdf(x), 1e-8);
+                assert_almost_eq!(n.cdf(x), exp.cdf(x), 1e-8);
+                assert_almost_eq!(n.sf(x), exp.sf(x), 1e-8);
+            }
+            for &p in [1e-5, 0.3, 0.5, 0.99].iter() {
+                assert_almost_eq!(n.inverse_cdf(p), exp.inverse_cdf(p), 1e-7);
+            }
+            assert_almost_eq!(n.mean().unwrap(), exp.mean().unwrap(), 1e-8);
+            assert_almost_eq!(n.variance().unwrap(), exp.variance().unwrap(), 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, 0.5), 0.0, 1000.0);
+        tests::check_continuous_distribution(&try_create(-2.0, 0.5, -0.5), -2.0, -1.0);
+        tests::check_continuous_distribution(&try_create(3.0, 10.0, 0.0), 3.0, 300.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, 0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(-2.0, 0.5, -0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(3.0, 10.0, 0.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, 1e-10), 1e-12);
+    }
+}
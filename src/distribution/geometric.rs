@@ -1,6 +1,7 @@
 use crate::distribution::{Discrete, DiscreteCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::distributions::OpenClosed01;
 use rand::Rng;
 use std::{f64, u64};
@@ -68,17 +69,24 @@ impl Geometric {
     }
 }
 
-impl ::rand::distributions::Distribution<f64> for Geometric {
-    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+impl ::rand::distributions::Distribution<u64> for Geometric {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> u64 {
         if ulps_eq!(self.p, 1.0) {
-            1.0
+            1
         } else {
             let x: f64 = r.sample(OpenClosed01);
-            x.log(1.0 - self.p).ceil()
+            // the float to integer cast saturates at u64::MAX
+            x.log(1.0 - self.p).ceil().max(1.0) as u64
         }
     }
 }
 
+impl ::rand::distributions::Distribution<f64> for Geometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
+    }
+}
+
 impl DiscreteCDF<u64, f64> for Geometric {
     /// Calculates the cumulative distribution function for the geometric
     /// distribution at `x`
@@ -95,6 +103,34 @@ impl DiscreteCDF<u64, f64> for Geometric {
             1.0 - (1.0 - self.p).powf(x as f64)
         }
     }
+
+    /// Calculates the survival function for the geometric
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - p) ^ x
+    /// ```
+    fn sf(&self, x: u64) -> f64 {
+        self.ln_sf(x).exp()
+    }
+
+    /// Calculates the log of the survival function for the geometric
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x * ln(1 - p)
+    /// ```
+    fn ln_sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            0.0
+        } else {
+            x as f64 * (-self.p).ln_1p()
+        }
+    }
 }
 
 impl Min<u64> for Geometric {
@@ -172,6 +208,78 @@ impl Distribution<f64> for Geometric {
         };
         Some((2.0 - self.p) / (1.0 - self.p).sqrt())
     }
+    /// Returns the excess kurtosis of the geometric distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `p == 1.0` since the distribution is then degenerate
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 + p^2 / (1 - p)
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        if self.p == 1.0 {
+            None
+        } else {
+            Some(6.0 + self.p * self.p / (1.0 - self.p))
+        }
+    }
+}
+
+impl GeneratingFunction<f64> for Geometric {
+    /// Returns the moment generating function of the geometric distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= -ln(1 - p)` since the series diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p * e^t / (1 - (1 - p) * e^t)
+    /// ```
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let x = t + (-self.p).ln_1p();
+        if x >= 0.0 {
+            None
+        } else {
+            Some(self.p * t.exp() / -x.exp_m1())
+        }
+    }
+    /// Returns the cumulant generating function of the geometric
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= -ln(1 - p)` since the series diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(p) + t - ln(1 - (1 - p) * e^t)
+    /// ```
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let x = t + (-self.p).ln_1p();
+        if x >= 0.0 {
+            None
+        } else {
+            Some(self.p.ln() + t - (-x.exp_m1()).ln())
+        }
+    }
+    /// Returns the characteristic function of the geometric distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p * e^(it) / (1 - (1 - p) * e^(it))
+    /// ```
+    fn cf(&self, t: f64) -> Complex<f64> {
+        Complex::from_polar(self.p, t) / (1.0 - Complex::from_polar(1.0 - self.p, t))
+    }
 }
 
 impl Mode<Option<u64>> for Geometric {
@@ -337,6 +445,54 @@ mod tests {
         test_case(1.0, f64::INFINITY, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Geometric| x.kurtosis().unwrap();
+        test_almost(0.3, 6.1285714285714285714, 1e-15, kurtosis);
+        test_case(0.5, 6.5, kurtosis);
+        test_case(1.0, None, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Geometric| x.mgf(t).unwrap();
+        test_almost(0.3, 0.044849377186111454604, 1e-16, mgf(-2.0));
+        test_almost(0.3, 7.3496589265677486747, 1e-14, mgf(0.3));
+        test_almost(0.9, 0.12347277724094284385, 1e-15, mgf(-2.0));
+        test_almost(0.9, 1.4044544473822206407, 1e-14, mgf(0.3));
+        test_almost(0.9, 10.884877130127998705, 1e-13, mgf(1.7));
+        assert_eq!(None, try_create(0.3).mgf(1.7));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Geometric| x.cgf(t).unwrap();
+        test_almost(0.3, -3.1044455771710435941, 1e-14, cgf(-2.0));
+        test_almost(0.3, 1.99465390759941531, 1e-14, cgf(0.3));
+        test_almost(0.9, -2.0917345744105941702, 1e-14, cgf(-2.0));
+        test_almost(0.9, 0.33964893370206378552, 1e-15, cgf(0.3));
+        test_almost(0.9, 2.3873744066235356561, 1e-14, cgf(1.7));
+        assert_eq!(None, try_create(0.3).cgf(1.7));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Geometric| x.cf(t).re;
+        let im = |t| move |x: Geometric| x.cf(t).im;
+        test_almost(0.3, -0.16155705437758100977, 1e-15, re(-2.0));
+        test_almost(0.3, -0.131616566047460572, 1e-15, im(-2.0));
+        test_almost(0.3, 0.50220606778138771952, 1e-15, re(0.3));
+        test_almost(0.3, 0.58124101825621577253, 1e-15, im(0.3));
+        test_almost(0.3, -0.14886014385898007613, 1e-15, re(1.7));
+        test_almost(0.3, 0.17810260834188394429, 1e-15, im(1.7));
+        test_almost(0.9, -0.42491737487412978834, 1e-15, re(-2.0));
+        test_almost(0.9, -0.74857821113717131523, 1e-15, im(-2.0));
+        test_almost(0.9, 0.94000744892546500549, 1e-15, re(0.3));
+        test_almost(0.9, 0.32477416677690505417, 1e-15, im(0.3));
+        test_almost(0.9, -0.19884748913858707632, 1e-15, re(1.7));
+        test_almost(0.9, 0.86167708876980659871, 1e-15, im(1.7));
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Geometric| x.median();
@@ -410,10 +566,41 @@ mod tests {
         test_case(0.3, 0.0, cdf(0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Geometric| x.sf(arg);
+        test_case(0.5, 1.0, sf(0));
+        test_almost(0.5, 0.5, 1e-16, sf(1));
+        test_almost(0.5, 7.88860905221011805411728565283e-31, 1e-44, sf(100));
+        test_almost(0.5, -69.31471805599453094172321214582, 1e-12, |x: Geometric| x.ln_sf(100));
+        test_case(1.0, 0.0, sf(1));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Geometric| x.inverse_cdf(arg);
+        test_case(0.5, 1, inverse_cdf(0.0));
+        test_case(0.5, 1, inverse_cdf(0.5));
+        test_case(0.5, 2, inverse_cdf(0.75));
+        test_case(0.5, 4, inverse_cdf(0.9));
+        test_case(1e-6, 693147, inverse_cdf(0.5));
+        test_case(0.5, u64::MAX, inverse_cdf(1.0));
+        tests::check_discrete_inverse_cdf(&try_create(0.5));
+        tests::check_discrete_inverse_cdf(&try_create(1e-6));
+        tests::check_discrete_inverse_cdf(&try_create(1.0));
+    }
+
     #[test]
     fn test_discrete() {
         tests::check_discrete_distribution(&try_create(0.3), 100);
         tests::check_discrete_distribution(&try_create(0.6), 100);
         tests::check_discrete_distribution(&try_create(1.0), 1);
     }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(0.3));
+        tests::check_discrete_samples(&try_create(1e-6));
+        tests::check_discrete_samples(&try_create(1.0));
+    }
 }
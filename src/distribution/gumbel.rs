@@ -0,0 +1,651 @@
+use crate::consts::EULER_MASCHERONI;
+use crate::distribution::{internal, ziggurat, Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use num_complex::Complex;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Gumbel](https://en.wikipedia.org/wiki/Gumbel_distribution)
+/// distribution, also known as the type I extreme value distribution.
+/// This is the distribution of the maximum, for the minimum negate the
+/// samples.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Gumbel, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Gumbel::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.36787944117144233);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gumbel {
+    location: f64,
+    scale: f64,
+}
+
+impl Gumbel {
+    /// Constructs a new gumbel distribution with the given
+    /// location and scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if location or scale are `NaN` or if
+    /// `scale <= 0.0` or `scale == INF`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let mut result = Gumbel::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Gumbel::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Gumbel> {
+        if location.is_nan() || scale.is_nan() || scale <= 0.0 || scale.is_infinite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Gumbel { location, scale })
+        }
+    }
+
+    /// Returns the location of the gumbel distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let n = Gumbel::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.location(), 0.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the gumbel distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let n = Gumbel::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Gumbel {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        self.location - self.scale * ziggurat::sample_exp_1(r).ln()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Gumbel {
+    /// Calculates the cumulative distribution function for the
+    /// gumbel distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-e^(-(x - μ) / β))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        (-(-(x - self.location) / self.scale).exp()).exp()
+    }
+
+    /// Calculates the survival function for the
+    /// gumbel distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-e^(-(x - μ) / β))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        -(-(-(x - self.location) / self.scale).exp()).exp_m1()
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// gumbel distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β * ln(-ln(p))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.location - self.scale * (-p.ln()).ln()
+    }
+}
+
+impl Min<f64> for Gumbel {
+    /// Returns the minimum value in the domain of the gumbel
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Gumbel {
+    /// Returns the maximum value in the domain of the gumbel
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Gumbel {
+    /// Returns the mean of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + βγ
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the scale and `γ` is the
+    /// Euler-Mascheroni constant
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * EULER_MASCHERONI)
+    }
+    /// Returns the variance of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// π^2 * β^2 / 6
+    /// ```
+    ///
+    /// where `β` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(f64::consts::PI * f64::consts::PI * self.scale * self.scale / 6.0)
+    }
+    /// Returns the entropy of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(β) + γ + 1
+    /// ```
+    ///
+    /// where `β` is the scale and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + EULER_MASCHERONI + 1.0)
+    }
+    /// Returns the skewness of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 * sqrt(6) * ζ(3) / π^3
+    /// ```
+    ///
+    /// where `ζ` is the Riemann zeta function
+    fn skewness(&self) -> Option<f64> {
+        Some(1.1395470994046486574927930193898)
+    }
+    /// Returns the excess kurtosis of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 / 5
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(2.4)
+    }
+}
+
+impl GeneratingFunction<f64> for Gumbel {
+    /// Returns the moment generating function of the gumbel distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= 1 / β` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Γ(1 - βt) * e^(μt)
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the scale and `Γ` is the gamma
+    /// function
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the gumbel distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= 1 / β` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μt + ln(Γ(1 - βt))
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the scale and `Γ` is the gamma
+    /// function
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let x = self.scale * t;
+        if x >= 1.0 {
+            None
+        } else {
+            Some(self.location * t + internal::ln_gamma_1m(x))
+        }
+    }
+    /// Returns the characteristic function of the gumbel distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Γ(1 - iβt) * e^(iμt)
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the scale and `Γ` is the gamma
+    /// function
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let z = Complex::new(1.0, -self.scale * t);
+        (gamma::ln_gamma_complex(z) + Complex::new(0.0, self.location * t)).exp()
+    }
+}
+
+impl Median<f64> for Gumbel {
+    /// Returns the median of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β * ln(ln(2))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn median(&self) -> f64 {
+        self.location - self.scale * f64::consts::LN_2.ln()
+    }
+}
+
+impl Mode<Option<f64>> for Gumbel {
+    /// Returns the mode of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Gumbel {
+    /// Calculates the probability density function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / β) * e^(-(z + e^(-z)))
+    /// ```
+    ///
+    /// where `z = (x - μ) / β`, `μ` is the location and `β` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(z + e^(-z)) - ln(β)
+    /// ```
+    ///
+    /// where `z = (x - μ) / β`, `μ` is the location and `β` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z == f64::NEG_INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            -(z + (-z).exp()) - self.scale.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Gumbel};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Gumbel {
+        let n = Gumbel::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Gumbel::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Gumbel) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Gumbel) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(0.0, 1.0);
+        create_case(-5.0, 10.0);
+        create_case(10.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(f64::NAN, f64::NAN);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Gumbel| x.mean().unwrap();
+        test_almost(0.0, 1.0, 0.5772156649015328606065, 1e-14, mean);
+        test_almost(-2.0, 0.5, -1.711392167549233569697, 1e-14, mean);
+        test_almost(3.0, 10.0, 8.772156649015328606065, 1e-14, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Gumbel| x.variance().unwrap();
+        test_almost(0.0, 1.0, 1.644934066848226436472, 1e-14, variance);
+        test_almost(-2.0, 0.5, 0.4112335167120566091181, 1e-14, variance);
+        test_almost(3.0, 10.0, 164.4934066848226436472, 1e-13, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Gumbel| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 1.577215664901532860607, 1e-14, entropy);
+        test_almost(-2.0, 0.5, 0.8840684843415875511893, 1e-14, entropy);
+        test_almost(3.0, 10.0, 3.879800757895578544625, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Gumbel| x.skewness().unwrap();
+        test_almost(0.0, 1.0, 1.139547099404648657493, 1e-14, skewness);
+        test_almost(-2.0, 0.5, 1.139547099404648657493, 1e-14, skewness);
+        test_almost(3.0, 10.0, 1.139547099404648657493, 1e-14, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Gumbel| x.kurtosis().unwrap();
+        test_almost(0.0, 1.0, 2.4, 1e-14, kurtosis);
+        test_almost(-2.0, 0.5, 2.4, 1e-14, kurtosis);
+        test_almost(3.0, 10.0, 2.4, 1e-14, kurtosis);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: Gumbel| x.median();
+        test_almost(0.0, 1.0, 0.3665129205816643270124, 1e-14, median);
+        test_almost(-2.0, 0.5, -1.816743539709167836494, 1e-14, median);
+        test_almost(3.0, 10.0, 6.665129205816643270124, 1e-14, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Gumbel| x.mode().unwrap();
+        test_case(0.0, 1.0, 0.0, mode);
+        test_case(-2.0, 0.5, -2.0, mode);
+        test_case(3.0, 10.0, 3.0, mode);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Gumbel| x.mgf(t).unwrap();
+        test_almost(0.0, 1.0, 120.0, 1e-12, mgf(-5.0));
+        test_almost(0.0, 1.0, 1.0686287021193193594, 1e-15, mgf(0.1));
+        test_almost(0.0, 1.0, 99.432585119150514904, 1e-12, mgf(0.99));
+        test_almost(2.0, 0.5, 0.00015087990063441792133, 1e-18, mgf(-5.0));
+        test_almost(2.0, 0.5, 2.0270775322224879853, 1e-15, mgf(0.3));
+        test_almost(2.0, 0.5, 870.33587559873811335, 1e-11, mgf(1.9));
+        test_almost(-1.0, 3.0, 194076084032345.30705, 1e2, mgf(-5.0));
+        assert_eq!(None, try_create(0.0, 1.0).mgf(1.0));
+        assert_eq!(None, try_create(-1.0, 3.0).mgf(0.5));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Gumbel| x.cgf(t).unwrap();
+        test_almost(0.0, 1.0, 4.7874917427820459942, 1e-14, cgf(-5.0));
+        test_almost(0.0, 1.0, 5.7721567312620324699e-9, 1e-24, cgf(1e-8));
+        test_almost(0.0, 1.0, 0.26086724653166650084, 1e-15, cgf(0.3));
+        test_almost(0.0, 1.0, 4.5994798780420208294, 1e-13, cgf(0.99));
+        test_almost(2.0, 0.5, 2.2886078345069340668e-8, 1e-23, cgf(1e-8));
+        test_almost(2.0, 0.5, 0.23096879523797290998, 1e-15, cgf(0.1));
+        test_almost(-1.0, 3.0, 32.899271383840891566, 1e-13, cgf(-5.0));
+        test_almost(-1.0, 3.0, 0.16086724653166652915, 1e-15, cgf(0.1));
+        assert_eq!(None, try_create(2.0, 0.5).cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Gumbel| x.cf(t).re;
+        let im = |t| move |x: Gumbel| x.cf(t).im;
+        test_almost(0.0, 1.0, 0.15190400267003613745, 1e-15, re(-2.0));
+        test_almost(0.0, 1.0, 0.019804880161854981972, 1e-15, im(-2.0));
+        test_almost(0.0, 1.0, 3.918929270881377214e-7, 1e-18, re(10.0));
+        test_almost(0.0, 1.0, -1.1284479695846292885e-6, 1e-18, im(10.0));
+        test_almost(2.0, 0.5, 0.39859685309204100008, 1e-15, re(0.5));
+        test_almost(2.0, 0.5, 0.86328341039262513945, 1e-15, im(0.5));
+        test_almost(2.0, 0.5, -0.0019338564415056862625, 1e-16, re(10.0));
+        test_almost(2.0, 0.5, -0.00099731322303747936449, 1e-16, im(10.0));
+        test_almost(-1.0, 3.0, 0.00016146437436497982543, 1e-16, re(-2.0));
+        test_almost(-1.0, 3.0, 0.00046844573783653662796, 1e-16, im(-2.0));
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Gumbel| x.min();
+        let max = |x: Gumbel| x.max();
+        test_case(0.0, 1.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Gumbel| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.004566281420127915643827, 1e-17, pdf(-2.0));
+        test_almost(0.0, 1.0, 0.179374078734017181962, 1e-15, pdf(-1.0));
+        test_almost(0.0, 1.0, 0.3678794411714423215955, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.2546463800435824958194, 1e-15, pdf(1.0));
+        test_almost(0.0, 1.0, 0.006692699677535514818896, 1e-17, pdf(5.0));
+        test_almost(0.0, 1.0, 9.35762296883929895384e-14, 1e-27, pdf(30.0));
+        test_almost(-2.0, 0.5, 0.735758882342884643191, 1e-15, pdf(-2.0));
+        test_almost(-2.0, 0.5, 0.2364099031862862919975, 1e-15, pdf(-1.0));
+        test_almost(-2.0, 0.5, 0.03596645939342728713173, 1e-16, pdf(0.0));
+        test_almost(-2.0, 0.5, 0.004945231146029817479804, 1e-17, pdf(1.0));
+        test_almost(-2.0, 0.5, 1.663056055327689332154e-6, 1e-20, pdf(5.0));
+        test_almost(-2.0, 0.5, 3.207621781097275705952e-28, 1e-42, pdf(30.0));
+        test_almost(3.0, 10.0, 0.03170419210779421760947, 1e-16, pdf(-2.0));
+        test_almost(3.0, 10.0, 0.03356035596434450096074, 1e-16, pdf(-1.0));
+        test_almost(3.0, 10.0, 0.03499871611584216534284, 1e-16, pdf(0.0));
+        test_almost(3.0, 10.0, 0.03600894672892270393977, 1e-16, pdf(1.0));
+        test_almost(3.0, 10.0, 0.03610529147709300160661, 1e-16, pdf(5.0));
+        test_almost(3.0, 10.0, 0.006283735781295471890449, 1e-17, pdf(30.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Gumbel| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -5.38905609893065022723, 1e-14, ln_pdf(-2.0));
+        test_almost(0.0, 1.0, -1.71828182845904523536, 1e-14, ln_pdf(-1.0));
+        test_almost(0.0, 1.0, -1.0, 1e-14, ln_pdf(0.0));
+        test_almost(0.0, 1.0, -1.367879441171442321596, 1e-14, ln_pdf(1.0));
+        test_almost(0.0, 1.0, -5.006737946999085467097, 1e-14, ln_pdf(5.0));
+        test_almost(0.0, 1.0, -30.00000000000009357623, 1e-13, ln_pdf(30.0));
+        test_almost(-2.0, 0.5, -0.3068528194400546905828, 1e-15, ln_pdf(-2.0));
+        test_almost(-2.0, 0.5, -1.442188102676667382477, 1e-14, ln_pdf(-1.0));
+        test_almost(-2.0, 0.5, -3.325168458328788870876, 1e-14, ln_pdf(0.0));
+        test_almost(-2.0, 0.5, -5.309331571616721049006, 1e-14, ln_pdf(1.0));
+        test_almost(-2.0, 0.5, -13.30685365096877379415, 1e-13, ln_pdf(5.0));
+        test_almost(-2.0, 0.5, -63.30685281944005469058, 1e-13, ln_pdf(30.0));
+        test_almost(3.0, 10.0, -3.451306363694173830867, 1e-14, ln_pdf(-2.0));
+        test_almost(3.0, 10.0, -3.394409790635316001843, 1e-14, ln_pdf(-1.0));
+        test_almost(3.0, 10.0, -3.352443900570048788002, 1e-14, ln_pdf(0.0));
+        test_almost(3.0, 10.0, -3.323987851154215517939, 1e-14, ln_pdf(1.0));
+        test_almost(3.0, 10.0, -3.321315846072027542688, 1e-14, ln_pdf(5.0));
+        test_almost(3.0, 10.0, -5.069790605733795449145, 1e-14, ln_pdf(30.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Gumbel| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.0006179789893310934986195, 1e-18, cdf(-2.0));
+        test_almost(0.0, 1.0, 0.06598803584531253707679, 1e-16, cdf(-1.0));
+        test_almost(0.0, 1.0, 0.3678794411714423215955, 1e-15, cdf(0.0));
+        test_almost(0.0, 1.0, 0.6922006275553463538654, 1e-15, cdf(1.0));
+        test_almost(0.0, 1.0, 0.9932847020678414896029, 1e-15, cdf(5.0));
+        test_almost(0.0, 1.0, 0.9999999999999064237703, 1e-15, cdf(30.0));
+        test_almost(-2.0, 0.5, 0.3678794411714423215955, 1e-15, cdf(-2.0));
+        test_almost(-2.0, 0.5, 0.873423018493116642989, 1e-15, cdf(-1.0));
+        test_almost(-2.0, 0.5, 0.9818510730616664829201, 1e-15, cdf(0.0));
+        test_almost(-2.0, 0.5, 0.9975243173927525414766, 1e-15, cdf(1.0));
+        test_almost(-2.0, 0.5, 0.9999991684716266163416, 1e-15, cdf(5.0));
+        test_almost(-2.0, 0.5, 1.0, 1e-14, cdf(30.0));
+        test_almost(3.0, 10.0, 0.1922956455479649280743, 1e-15, cdf(-2.0));
+        test_almost(3.0, 10.0, 0.2249617935499184786315, 1e-15, cdf(-1.0));
+        test_almost(3.0, 10.0, 0.259276865990827567958, 1e-15, cdf(0.0));
+        test_almost(3.0, 10.0, 0.2948163207291581687007, 1e-15, cdf(1.0));
+        test_almost(3.0, 10.0, 0.4409910259429826452437, 1e-15, cdf(5.0));
+        test_almost(3.0, 10.0, 0.9350030265566081784575, 1e-15, cdf(30.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Gumbel| x.sf(arg);
+        test_almost(0.0, 1.0, 0.9993820210106689065014, 1e-15, sf(-2.0));
+        test_almost(0.0, 1.0, 0.9340119641546874629232, 1e-15, sf(-1.0));
+        test_almost(0.0, 1.0, 0.6321205588285576784045, 1e-15, sf(0.0));
+        test_almost(0.0, 1.0, 0.3077993724446536461346, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 0.006715297932158510397112, 1e-17, sf(5.0));
+        test_almost(0.0, 1.0, 9.357622968839736779378e-14, 1e-28, sf(30.0));
+        test_almost(-2.0, 0.5, 0.6321205588285576784045, 1e-15, sf(-2.0));
+        test_almost(-2.0, 0.5, 0.126576981506883357011, 1e-15, sf(-1.0));
+        test_almost(-2.0, 0.5, 0.01814892693833351707992, 1e-16, sf(0.0));
+        test_almost(-2.0, 0.5, 0.00247568260724745852336, 1e-17, sf(1.0));
+        test_almost(-2.0, 0.5, 8.31528373383658362405e-7, 1e-21, sf(5.0));
+        test_almost(-2.0, 0.5, 1.603810890548637852976e-28, 1e-42, sf(30.0));
+        test_almost(3.0, 10.0, 0.8077043544520350719257, 1e-15, sf(-2.0));
+        test_almost(3.0, 10.0, 0.7750382064500815213685, 1e-15, sf(-1.0));
+        test_almost(3.0, 10.0, 0.740723134009172432042, 1e-15, sf(0.0));
+        test_almost(3.0, 10.0, 0.7051836792708418312993, 1e-15, sf(1.0));
+        test_almost(3.0, 10.0, 0.5590089740570173547563, 1e-15, sf(5.0));
+        test_almost(3.0, 10.0, 0.06499697344339182154247, 1e-16, sf(30.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Gumbel| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, -3.136617538242001482239, 1e-14, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, -0.834032445247955775695, 1e-14, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 0.3665129205816643270124, 1e-14, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 1.245899323707238198381, 1e-14, inverse_cdf(0.75));
+        test_almost(0.0, 1.0, 6.907255070523715611295, 1e-14, inverse_cdf(0.999));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        test_case(-2.0, 0.5, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(-2.0, 0.5, -3.568308769121000741119, 1e-14, inverse_cdf(1e-10));
+        test_almost(-2.0, 0.5, -2.417016222623977887848, 1e-14, inverse_cdf(0.1));
+        test_almost(-2.0, 0.5, -1.816743539709167836494, 1e-14, inverse_cdf(0.5));
+        test_almost(-2.0, 0.5, -1.37705033814638090081, 1e-14, inverse_cdf(0.75));
+        test_almost(-2.0, 0.5, 1.453627535261857805648, 1e-14, inverse_cdf(0.999));
+        test_case(-2.0, 0.5, f64::INFINITY, inverse_cdf(1.0));
+        test_case(3.0, 10.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(3.0, 10.0, -28.36617538242001482239, 1e-13, inverse_cdf(1e-10));
+        test_almost(3.0, 10.0, -5.34032445247955775695, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 10.0, 6.665129205816643270124, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 10.0, 15.45899323707238198381, 1e-13, inverse_cdf(0.75));
+        test_almost(3.0, 10.0, 72.07255070523715611295, 1e-13, inverse_cdf(0.999));
+        test_case(3.0, 10.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(-2.0, 0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(3.0, 10.0), 1e-12);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -5.0, 50.0);
+        tests::check_continuous_distribution(&try_create(-2.0, 0.5), -5.0, 30.0);
+    }
+}
@@ -0,0 +1,633 @@
+use crate::distribution::{internal, Discrete, DiscreteCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements a [hurdle](https://en.wikipedia.org/wiki/Hurdle_model)
+/// distribution, which is zero with probability `π` and otherwise draws
+/// from a discrete parent distribution such as `Poisson`,
+/// `NegativeBinomial` or `Binomial` conditioned on being positive
+///
+/// # Remarks
+///
+/// Unlike a zero-inflated distribution all zeros come from the hurdle, so
+/// `π` may also be smaller than the parent's probability of zero. The
+/// moments are implemented through `Distribution` or
+/// `DiscreteDistribution`, whichever the parent implements
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Discrete, Hurdle, Poisson};
+/// use statrs::statistics::Distribution;
+///
+/// let n = Hurdle::new(Poisson::new(2.0).unwrap(), 0.25).unwrap();
+/// assert_eq!(n.pmf(0), 0.25);
+/// assert!((n.mean().unwrap() - 1.5 / (1.0 - (-2.0f64).exp())).abs() < 1e-15);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Hurdle<D> {
+    dist: D,
+    pi: f64,
+    // the parent's cdf and survival function at zero
+    cdf_zero: f64,
+    sf_zero: f64,
+}
+
+impl<D> Hurdle<D>
+where
+    D: Discrete<u64, f64> + DiscreteCDF<u64, f64>,
+{
+    /// Constructs a new hurdle distribution that is zero with probability
+    /// `pi` and otherwise draws from `dist` conditioned on being positive
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pi` is `NaN`, less than `0.0` or greater than
+    /// `1.0`, or if `dist` has no mass above zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Binomial, Hurdle};
+    ///
+    /// let mut result = Hurdle::new(Binomial::new(0.5, 10).unwrap(), 0.25);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Hurdle::new(Binomial::new(0.0, 10).unwrap(), 0.25);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, pi: f64) -> Result<Hurdle<D>> {
+        if !(0.0..=1.0).contains(&pi) {
+            return Err(StatsError::BadParams);
+        }
+        let sf_zero = dist.sf(0);
+        if sf_zero.is_nan() || sf_zero <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Ok(Hurdle {
+            cdf_zero: dist.cdf(0),
+            dist,
+            pi,
+            sf_zero,
+        })
+    }
+}
+
+impl<D> Hurdle<D> {
+    /// Returns the parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Hurdle, Poisson};
+    ///
+    /// let d = Poisson::new(2.0).unwrap();
+    /// let n = Hurdle::new(d, 0.25).unwrap();
+    /// assert_eq!(*n.parent(), d);
+    /// ```
+    pub fn parent(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the probability `π` of a zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Hurdle, Poisson};
+    ///
+    /// let n = Hurdle::new(Poisson::new(2.0).unwrap(), 0.25).unwrap();
+    /// assert_eq!(n.pi(), 0.25);
+    /// ```
+    pub fn pi(&self) -> f64 {
+        self.pi
+    }
+
+    /// Returns the factor `(1 - π) / S(0)` by which the parent's positive
+    /// probabilities are scaled, where `S` is its survival function
+    fn weight(&self) -> f64 {
+        (1.0 - self.pi) / self.sf_zero
+    }
+
+    /// Returns the central moments up to `moments.len() - 1` from the
+    /// parent's mean and central moments
+    fn central_moments(&self, mean: f64, moments: &[f64]) -> Vec<f64> {
+        internal::zero_mixture_central_moments(self.weight(), mean, moments)
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<u64> for Hurdle<D>
+where
+    D: ::rand::distributions::Distribution<u64> + DiscreteCDF<u64, f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // rejection of the parent's zeros accepts at least a quarter of its
+        // draws, below that inversion is cheaper
+        if self.sf_zero >= 0.25 {
+            if rng.gen::<f64>() < self.pi {
+                return 0;
+            }
+            loop {
+                let x = self.dist.sample(rng);
+                if x > 0 {
+                    return x;
+                }
+            }
+        }
+        self.inverse_cdf(rng.gen::<f64>())
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<f64> for Hurdle<D>
+where
+    D: ::rand::distributions::Distribution<u64> + DiscreteCDF<u64, f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
+    }
+}
+
+impl<D> DiscreteCDF<u64, f64> for Hurdle<D>
+where
+    D: DiscreteCDF<u64, f64>,
+{
+    /// Calculates the cumulative distribution function for the
+    /// hurdle distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Computed from the parent's survival function instead when its cdf
+    /// at zero exceeds one half
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// π + (1 - π) * (F(x) - F(0)) / (1 - F(0))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution
+    fn cdf(&self, x: u64) -> f64 {
+        if x == 0 {
+            self.pi
+        } else if self.cdf_zero < 0.5 {
+            self.pi + self.weight() * (self.dist.cdf(x) - self.cdf_zero)
+        } else {
+            1.0 - self.weight() * self.dist.sf(x)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// hurdle distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * S(x) / S(0)
+    /// ```
+    ///
+    /// where `S` is the survival function of the parent distribution
+    fn sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            1.0 - self.pi
+        } else {
+            self.weight() * self.dist.sf(x)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// hurdle distribution at `p`, i.e. the smallest `k` such that
+    /// `cdf(k) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the parent quantile at
+    /// `F(0) + (p - π) * S(0) / (1 - π)`
+    fn inverse_cdf(&self, p: f64) -> u64 {
+        let guess = if p > self.pi && p < 1.0 {
+            let q = self.cdf_zero + (p - self.pi) / self.weight();
+            self.dist.inverse_cdf(q.min(1.0))
+        } else {
+            self.min()
+        };
+        internal::discrete_inverse_cdf(self, p, guess)
+    }
+}
+
+impl<D> Min<u64> for Hurdle<D> {
+    /// Returns the minimum value in the domain of the
+    /// hurdle distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> u64 {
+        0
+    }
+}
+
+impl<D> Max<u64> for Hurdle<D>
+where
+    D: Max<u64>,
+{
+    /// Returns the maximum value of the parent distribution
+    fn max(&self) -> u64 {
+        self.dist.max()
+    }
+}
+
+impl<D> Distribution<f64> for Hurdle<D>
+where
+    D: Distribution<f64> + ::rand::distributions::Distribution<u64> + DiscreteCDF<u64, f64>,
+{
+    /// Returns the mean of the hurdle distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * μ / (1 - F(0))
+    /// ```
+    ///
+    /// where `μ` and `F` are the mean and cdf of the parent distribution
+    fn mean(&self) -> Option<f64> {
+        self.dist.mean().map(|m| self.weight() * m)
+    }
+    /// Returns the variance of the hurdle distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// w * (σ^2 + (1 - w) * μ^2)
+    /// ```
+    ///
+    /// where `w = (1 - π) / (1 - F(0))` and `μ`, `σ^2` and `F` are the
+    /// mean, variance and cdf of the parent distribution
+    fn variance(&self) -> Option<f64> {
+        self.central_moment(2)
+    }
+    /// Returns the skewness of the hurdle distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_3 / m_2^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the hurdle distribution
+    fn skewness(&self) -> Option<f64> {
+        let variance = self.central_moment(2)?;
+        if variance == 0.0 {
+            return None;
+        }
+        Some(self.central_moment(3)? / (variance * variance.sqrt()))
+    }
+    /// Returns the excess kurtosis of the hurdle distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_4 / m_2^2 - 3
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the hurdle distribution
+    fn kurtosis(&self) -> Option<f64> {
+        let variance = self.central_moment(2)?;
+        if variance == 0.0 {
+            return None;
+        }
+        Some(self.central_moment(4)? / (variance * variance) - 3.0)
+    }
+    /// Returns the `k`th central moment of the hurdle distribution, if the
+    /// parent's central moments up to order `k` are known
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// w * Σ_j C(k, j) * c_j * ((1 - w) * μ)^(k - j) + (1 - w) * (-w * μ)^k
+    /// ```
+    ///
+    /// where `w = (1 - π) / (1 - F(0))` and `μ`, `c_j` and `F` are the
+    /// mean, `j`th central moment and cdf of the parent distribution
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let mean = self.dist.mean()?;
+        let moments = (0..=k)
+            .map(|j| self.dist.central_moment(j))
+            .collect::<Option<Vec<f64>>>()?;
+        Some(self.central_moments(mean, &moments)[k as usize])
+    }
+}
+
+impl<D> DiscreteDistribution<f64> for Hurdle<D>
+where
+    D: DiscreteDistribution<f64> + DiscreteCDF<u64, f64>,
+{
+    /// Returns the mean of the hurdle distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * μ / (1 - F(0))
+    /// ```
+    ///
+    /// where `μ` and `F` are the mean and cdf of the parent distribution
+    fn mean(&self) -> Option<f64> {
+        self.dist.mean().map(|m| self.weight() * m)
+    }
+    /// Returns the variance of the hurdle distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// w * (σ^2 + (1 - w) * μ^2)
+    /// ```
+    ///
+    /// where `w = (1 - π) / (1 - F(0))` and `μ`, `σ^2` and `F` are the
+    /// mean, variance and cdf of the parent distribution
+    fn variance(&self) -> Option<f64> {
+        let moments = [1.0, 0.0, self.dist.variance()?];
+        Some(self.central_moments(self.dist.mean()?, &moments)[2])
+    }
+    /// Returns the skewness of the hurdle distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_3 / m_2^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the hurdle distribution
+    fn skewness(&self) -> Option<f64> {
+        let variance = self.dist.variance()?;
+        let third = self.dist.skewness()? * variance * variance.sqrt();
+        let m = self.central_moments(self.dist.mean()?, &[1.0, 0.0, variance, third]);
+        if m[2] == 0.0 {
+            return None;
+        }
+        Some(m[3] / (m[2] * m[2].sqrt()))
+    }
+    /// Returns the excess kurtosis of the hurdle distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_4 / m_2^2 - 3
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the hurdle distribution
+    fn kurtosis(&self) -> Option<f64> {
+        let variance = self.dist.variance()?;
+        let third = self.dist.skewness()? * variance * variance.sqrt();
+        let fourth = (self.dist.kurtosis()? + 3.0) * variance * variance;
+        let m = self.central_moments(self.dist.mean()?, &[1.0, 0.0, variance, third, fourth]);
+        if m[2] == 0.0 {
+            return None;
+        }
+        Some(m[4] / (m[2] * m[2]) - 3.0)
+    }
+}
+
+impl<D> Discrete<u64, f64> for Hurdle<D>
+where
+    D: Discrete<u64, f64>,
+{
+    /// Calculates the probability mass function for the hurdle
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x == 0 {
+    ///     π
+    /// } else {
+    ///     (1 - π) * p(x) / (1 - F(0))
+    /// }
+    /// ```
+    ///
+    /// where `p` and `F` are the pmf and cdf of the parent distribution
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            self.pi
+        } else {
+            self.weight() * self.dist.pmf(x)
+        }
+    }
+
+    /// Calculates the log probability mass function for the hurdle
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x == 0 {
+    ///     ln(π)
+    /// } else {
+    ///     ln(1 - π) + ln(p(x)) - ln(1 - F(0))
+    /// }
+    /// ```
+    ///
+    /// where `p` and `F` are the pmf and cdf of the parent distribution
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            self.pi.ln()
+        } else {
+            (-self.pi).ln_1p() + self.dist.ln_pmf(x) - self.sf_zero.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{Binomial, Discrete, DiscreteCDF, Hurdle, NegativeBinomial, Poisson};
+    use crate::distribution::internal::*;
+
+    fn try_create<D>(dist: D, pi: f64) -> Hurdle<D>
+        where D: Discrete<u64, f64> + DiscreteCDF<u64, f64>
+    {
+        let n = Hurdle::new(dist, pi);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case<D>(dist: D, pi: f64)
+        where D: Discrete<u64, f64> + DiscreteCDF<u64, f64>
+    {
+        let n = Hurdle::new(dist, pi);
+        assert!(n.is_err());
+    }
+
+    fn poisson() -> Hurdle<Poisson> {
+        try_create(Poisson::new(2.5).unwrap(), 0.3)
+    }
+
+    fn negative_binomial() -> Hurdle<NegativeBinomial> {
+        try_create(NegativeBinomial::new(3.0, 0.4).unwrap(), 0.2)
+    }
+
+    fn binomial() -> Hurdle<Binomial> {
+        try_create(Binomial::new(0.6, 10).unwrap(), 0.15)
+    }
+
+    // fewer zeros than the parent
+    fn deflated() -> Hurdle<Poisson> {
+        try_create(Poisson::new(0.5).unwrap(), 0.1)
+    }
+
+    #[test]
+    fn test_create() {
+        let n = poisson();
+        assert_eq!(*n.parent(), Poisson::new(2.5).unwrap());
+        assert_eq!(n.pi(), 0.3);
+        try_create(Poisson::new(2.5).unwrap(), 0.0);
+        try_create(Poisson::new(2.5).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(Poisson::new(2.5).unwrap(), -0.1);
+        bad_create_case(Poisson::new(2.5).unwrap(), 1.1);
+        bad_create_case(Poisson::new(2.5).unwrap(), f64::NAN);
+        bad_create_case(Binomial::new(0.0, 10).unwrap(), 0.5);
+        bad_create_case(Binomial::new(0.5, 0).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = poisson();
+        assert_almost_eq!(n.mean().unwrap(), 1.9064946072092410234, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 3.0380094379144253675, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), 0.72053195764610997837, 1e-14);
+        assert_almost_eq!(n.kurtosis().unwrap(), 0.032841495772073454629, 1e-13);
+
+        let n = negative_binomial();
+        assert_almost_eq!(n.mean().unwrap(), 3.8461538461538461538, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 12.130177514792899408, 1e-13);
+        assert_almost_eq!(n.skewness().unwrap(), 1.1668033437956956819, 1e-13);
+        assert_almost_eq!(n.kurtosis().unwrap(), 1.8095895300416418798, 1e-12);
+
+        let n = binomial();
+        assert_almost_eq!(n.mean().unwrap(), 5.1005348298409735329, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 6.6279673605613417793, 1e-13);
+        assert_almost_eq!(n.skewness().unwrap(), -0.83078684073977602737, 1e-13);
+        assert_almost_eq!(n.kurtosis().unwrap(), -0.17176780800934314564, 1e-12);
+
+        let n = deflated();
+        assert_almost_eq!(n.mean().unwrap(), 1.1436723371415592279, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 0.40752209096950252687, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), 0.96472858952114561035, 1e-13);
+        assert_almost_eq!(n.kurtosis().unwrap(), 2.7072545083688058246, 1e-12);
+    }
+
+    #[test]
+    fn test_moments_all_zeros() {
+        let n = try_create(Poisson::new(2.5).unwrap(), 1.0);
+        assert_eq!(n.variance(), Some(0.0));
+        assert_eq!(n.skewness(), None);
+        assert_eq!(n.kurtosis(), None);
+        let n = try_create(NegativeBinomial::new(3.0, 0.4).unwrap(), 1.0);
+        assert_eq!(n.variance(), Some(0.0));
+        assert_eq!(n.skewness(), None);
+        assert_eq!(n.kurtosis(), None);
+    }
+
+    #[test]
+    fn test_pmf() {
+        let n = poisson();
+        assert_eq!(n.pmf(0), 0.3);
+        assert_almost_eq!(n.pmf(3), 0.1630152158429593994, 1e-15);
+        let n = negative_binomial();
+        assert_almost_eq!(n.pmf(5), 0.089324307692307692308, 1e-14);
+        let n = binomial();
+        assert_almost_eq!(n.pmf(1), 0.0013370746024338321658, 1e-17);
+        assert_almost_eq!(n.pmf(10), 0.0051401639452549059608, 1e-16);
+        assert_eq!(n.pmf(11), 0.0);
+        let n = deflated();
+        assert_almost_eq!(n.pmf(1), 0.69367233714155922786, 1e-15);
+        assert_almost_eq!(n.pmf(10), 3.7335449280192015733e-10, 1e-24);
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let n = poisson();
+        assert_almost_eq!(n.ln_pmf(0), -1.2039728043259359926, 1e-15);
+        assert_almost_eq!(n.ln_pmf(3), -1.813911733802284003, 1e-14);
+        let n = deflated();
+        assert_almost_eq!(n.ln_pmf(10), -21.708492764765606119, 1e-13);
+        assert_eq!(binomial().ln_pmf(11), f64::NEG_INFINITY);
+        assert_eq!(try_create(Poisson::new(2.5).unwrap(), 0.0).ln_pmf(0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = poisson();
+        assert_eq!(n.cdf(0), 0.3);
+        assert_almost_eq!(n.cdf(3), 0.81512808206375170211, 1e-15);
+        assert_almost_eq!(n.sf(3), 0.18487191793624829789, 1e-15);
+        assert_almost_eq!(n.sf(20), 3.1407997514098745859e-13, 1e-25);
+        let n = negative_binomial();
+        assert_almost_eq!(n.cdf(10), 0.95051076056615384615, 1e-14);
+        assert_almost_eq!(n.sf(10), 0.049489239433846153846, 1e-14);
+        let n = binomial();
+        assert_almost_eq!(n.cdf(5), 0.46180579728756966106, 1e-15);
+        assert_eq!(n.cdf(10), 1.0);
+        assert_eq!(n.sf(10), 0.0);
+        let n = deflated();
+        assert_almost_eq!(n.cdf(3), 0.99599343547451400265, 1e-15);
+        assert_almost_eq!(n.sf(10), 1.7705970839347534537e-11, 1e-23);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_discrete_inverse_cdf(&poisson());
+        tests::check_discrete_inverse_cdf(&negative_binomial());
+        tests::check_discrete_inverse_cdf(&binomial());
+        tests::check_discrete_inverse_cdf(&deflated());
+        assert_eq!(poisson().inverse_cdf(0.3), 0);
+        assert_eq!(poisson().inverse_cdf(0.31), 1);
+    }
+
+    #[test]
+    fn test_discrete() {
+        tests::check_discrete_distribution(&poisson(), 20);
+        tests::check_discrete_distribution(&negative_binomial(), 60);
+        tests::check_discrete_distribution(&binomial(), 10);
+        tests::check_discrete_distribution(&deflated(), 10);
+    }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&poisson());
+        tests::check_discrete_samples(&binomial());
+        tests::check_goodness_of_fit(&poisson(), 0, 12, 10_000);
+        tests::check_goodness_of_fit(&negative_binomial(), 0, 30, 10_000);
+        tests::check_goodness_of_fit(&binomial(), 0, 10, 10_000);
+        tests::check_goodness_of_fit(&deflated(), 0, 6, 10_000);
+        // sampled by inversion
+        tests::check_goodness_of_fit(&try_create(Poisson::new(0.1).unwrap(), 0.5), 0, 5, 10_000);
+    }
+}
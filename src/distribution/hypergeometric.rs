@@ -1,7 +1,8 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{internal, Discrete, DiscreteCDF};
 use crate::function::factorial;
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::cmp;
 use std::f64;
@@ -110,17 +111,17 @@ impl Hypergeometric {
     }
 }
 
-impl ::rand::distributions::Distribution<f64> for Hypergeometric {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+impl ::rand::distributions::Distribution<u64> for Hypergeometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
         let mut population = self.population as f64;
         let mut successes = self.successes as f64;
         let mut draws = self.draws;
-        let mut x = 0.0;
+        let mut x = 0;
         loop {
             let p = successes / population;
             let next: f64 = rng.gen();
             if next < p {
-                x += 1.0;
+                x += 1;
                 successes -= 1.0;
             }
             population -= 1.0;
@@ -133,6 +134,12 @@ impl ::rand::distributions::Distribution<f64> for Hypergeometric {
     }
 }
 
+impl ::rand::distributions::Distribution<f64> for Hypergeometric {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
+    }
+}
+
 impl DiscreteCDF<u64, f64> for Hypergeometric {
     /// Calculates the cumulative distribution function for the hypergeometric
     /// distribution at `x`
@@ -164,6 +171,59 @@ impl DiscreteCDF<u64, f64> for Hypergeometric {
             })
         }
     }
+
+    /// Calculates the survival function for the hypergeometric
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum((K choose i) * (N-K choose n-i) / (N choose n)) for i in x+1..=max
+    /// ```
+    ///
+    /// where `N` is population, `K` is successes, `n` is draws
+    fn sf(&self, x: u64) -> f64 {
+        if x < self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            let ln_denom = factorial::ln_binomial(self.population, self.draws);
+            (x + 1..self.max() + 1).fold(0.0, |acc, i| {
+                acc + (factorial::ln_binomial(self.successes, i)
+                    + factorial::ln_binomial(self.population - self.successes, self.draws - i)
+                    - ln_denom)
+                    .exp()
+            })
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// hypergeometric distribution at `x`, i.e. the smallest `k` such that
+    /// `cdf(k) >= x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the normal approximation `μ + σ * z` where
+    /// `μ` and `σ` are the mean and standard deviation of the distribution
+    /// and `z` is the standard normal quantile of `x`
+    fn inverse_cdf(&self, x: f64) -> u64 {
+        let (population, successes, draws) = self.values_f64();
+        let mean = draws * successes / population;
+        let var = mean * (population - successes) * (population - draws)
+            / (population * (population - 1.0));
+        let guess = if var > 0.0 && x > 0.0 && x < 1.0 {
+            let z = super::normal::inverse_cdf_unchecked(x, 0.0, 1.0);
+            mean + var.sqrt() * z
+        } else {
+            mean
+        };
+        internal::discrete_inverse_cdf(self, x, guess.max(0.0).floor() as u64)
+    }
 }
 
 impl Min<u64> for Hypergeometric {
@@ -271,6 +331,41 @@ impl Distribution<f64> for Hypergeometric {
             Some(val)
         }
     }
+    /// Returns the excess kurtosis of the hypergeometric distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `N <= 3`, or if the distribution is degenerate,
+    /// i.e. if any of `n`, `K`, `N - K` or `N - n` is zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ((N - 1)N^2 (N(N + 1) - 6K(N - K) - 6n(N - n)) + 6nK(N - K)(N - n)(5N - 6))
+    ///     / (nK(N - K)(N - n)(N - 2)(N - 3))
+    /// ```
+    ///
+    /// where `N` is population, `K` is successes, and `n` is draws
+    fn kurtosis(&self) -> Option<f64> {
+        if self.population <= 3 {
+            return None;
+        }
+        let (population, successes, draws) = self.values_f64();
+        let spread = draws * successes * (population - successes) * (population - draws);
+        if spread == 0.0 {
+            None
+        } else {
+            let val = ((population - 1.0)
+                * population
+                * population
+                * (population * (population + 1.0)
+                    - 6.0 * successes * (population - successes)
+                    - 6.0 * draws * (population - draws))
+                + 6.0 * spread * (5.0 * population - 6.0))
+                / (spread * (population - 2.0) * (population - 3.0));
+            Some(val)
+        }
+    }
 }
 
 impl Mode<Option<u64>> for Hypergeometric {
@@ -288,6 +383,45 @@ impl Mode<Option<u64>> for Hypergeometric {
     }
 }
 
+impl GeneratingFunction<f64> for Hypergeometric {
+    /// Returns the moment generating function of the hypergeometric
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(p_k * e^(tk))
+    /// ```
+    ///
+    /// where `p_k` is the probability mass at `k` and the sum runs over
+    /// `max(0, n + K - N)..=min(K, n)`, with `N` population, `K` successes
+    /// and `n` draws
+    fn mgf(&self, t: f64) -> Option<f64> {
+        Some(
+            (self.min()..=self.max())
+                .map(|k| (self.ln_pmf(k) + t * k as f64).exp())
+                .sum(),
+        )
+    }
+    /// Returns the characteristic function of the hypergeometric
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ(p_k * e^(itk))
+    /// ```
+    ///
+    /// where `p_k` is the probability mass at `k` and the sum runs over
+    /// `max(0, n + K - N)..=min(K, n)`, with `N` population, `K` successes
+    /// and `n` draws
+    fn cf(&self, t: f64) -> Complex<f64> {
+        (self.min()..=self.max())
+            .map(|k| Complex::from_polar(self.pmf(k), t * k as f64))
+            .sum()
+    }
+}
+
 impl Discrete<u64, f64> for Hypergeometric {
     /// Calculates the probability mass function for the hypergeometric
     /// distribution at `x`
@@ -433,6 +567,53 @@ mod tests {
         test_case(10, 5, 3, 0.0, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Hypergeometric| x.kurtosis();
+        test_almost(50, 20, 10, -0.1316212322695035461, 1e-15, |x| kurtosis(x).unwrap());
+        test_almost(1000, 300, 500, -0.0035248602951712279696, 1e-15, |x| kurtosis(x).unwrap());
+        test_case(3, 2, 1, None, kurtosis);
+        test_case(10, 4, 0, None, kurtosis);
+        test_case(10, 0, 5, None, kurtosis);
+        test_case(10, 10, 5, None, kurtosis);
+        test_case(10, 4, 10, None, kurtosis);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Hypergeometric| x.mgf(t).unwrap();
+        test_almost(50, 20, 10, 0.045936742836321042996, 1e-15, mgf(-1.0));
+        test_almost(50, 20, 10, 9.4749806838099564753, 1e-12, mgf(0.5));
+        test_almost(50, 20, 10, 147682.42547757542146, 1e-8, mgf(2.0));
+        test_almost(10, 7, 8, 0.0043619184130544863231, 1e-16, mgf(-1.0));
+        test_almost(10, 7, 8, 166404.87231076341593, 1e-8, mgf(2.0));
+        test_case(0, 0, 0, 1.0, mgf(3.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Hypergeometric| x.cgf(t).unwrap();
+        test_almost(50, 20, 10, -3.0804899846195630533, 1e-13, cgf(-1.0));
+        test_almost(10, 7, 8, 2.8487456907591515634, 1e-14, cgf(0.5));
+        test_almost(1000, 300, 500, -124.12309946438951815, 1e-11, cgf(-1.0));
+        test_almost(1000, 300, 500, 81.543816199769664493, 1e-11, cgf(0.5));
+        test_almost(1000, 300, 500, 399.00302309048628712, 1e-10, cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Hypergeometric| x.cf(t).re;
+        let im = |t| move |x: Hypergeometric| x.cf(t).im;
+        test_almost(50, 20, 10, -0.57706474639811482702, 1e-15, re(-0.7));
+        test_almost(50, 20, 10, -0.21444562784143820034, 1e-15, im(-0.7));
+        test_almost(50, 20, 10, 0.089548910297186689547, 1e-15, re(1.5));
+        test_almost(50, 20, 10, -0.041086192864055082479, 1e-15, im(1.5));
+        test_almost(10, 7, 8, -0.65336734606652656528, 1e-15, re(-0.7));
+        test_almost(10, 7, 8, 0.63593104088978570382, 1e-15, im(-0.7));
+        test_almost(1000, 300, 500, -5.5926692925216518202e-7, 1e-13, re(-0.7));
+        test_almost(1000, 300, 500, 2.2526163241240295209e-6, 1e-13, im(-0.7));
+    }
+
     #[test]
     #[should_panic]
     fn test_skewness_with_pop_lte_2() {
@@ -525,9 +706,40 @@ mod tests {
         test_case(2, 2, 2, 0.0, cdf(0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Hypergeometric| x.sf(arg);
+        test_almost(10, 5, 5, 0.103174603174603174603174603175, 1e-15, sf(3));
+        test_case(10, 5, 5, 0.0, sf(5));
+        test_case(10, 5, 5, 0.0, sf(6));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Hypergeometric| x.inverse_cdf(arg);
+        test_case(1000, 300, 500, 0, inverse_cdf(0.0));
+        test_case(1000, 300, 500, 104, inverse_cdf(1e-10));
+        test_case(1000, 300, 500, 128, inverse_cdf(0.001));
+        test_case(1000, 300, 500, 141, inverse_cdf(0.1));
+        test_case(1000, 300, 500, 150, inverse_cdf(0.5));
+        test_case(1000, 300, 500, 159, inverse_cdf(0.9));
+        test_case(1000, 300, 500, 184, inverse_cdf(0.999999));
+        test_case(1000, 300, 500, 300, inverse_cdf(1.0));
+        test_case(10, 8, 5, 3, inverse_cdf(0.0));
+        tests::check_discrete_inverse_cdf(&try_create(10, 8, 5));
+        tests::check_discrete_inverse_cdf(&try_create(1000, 300, 500));
+        tests::check_discrete_inverse_cdf(&try_create(1, 1, 1));
+    }
+
     #[test]
     fn test_discrete() {
         tests::check_discrete_distribution(&try_create(5, 4, 3), 4);
         tests::check_discrete_distribution(&try_create(3, 2, 1), 2);
     }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(10, 8, 5));
+        tests::check_discrete_samples(&try_create(1000, 300, 500));
+    }
 }
@@ -1,3 +1,13 @@
+use crate::consts;
+use crate::distribution::{gamma as gamma_dist, normal, ContinuousCDF, DiscreteCDF};
+use crate::function::{factorial, gamma};
+use crate::{Result, StatsError};
+use ::num_traits::{float::Float, Bounded, Num};
+use nalgebra::{Cholesky, DMatrix, DVector, Dynamic};
+use rand::Rng;
+use std::cmp::Ordering;
+use std::f64;
+
 /// Returns true if there are no elements in `x` in `arr`
 /// such that `x <= 0.0` or `x` is `f64::NAN` and `sum(arr) > 0.0`.
 /// IF `incl_zero` is true, it tests for `x < 0.0` instead of `x <= 0.0`
@@ -12,11 +22,878 @@ pub fn is_valid_multinomial(arr: &[f64], incl_zero: bool) -> bool {
     sum != 0.0
 }
 
+/// Converts the cumulants `κ_0, ..., κ_k` of a distribution into its central
+/// moments `μ_0, ..., μ_k` by the recurrence
+/// `μ_n = sum(C(n - 1, m - 1) * κ_m * μ_(n - m)) for m in 2..=n`.
+/// `κ_0` and `κ_1` are ignored since the central moments do not depend on them
+pub fn central_moments_from_cumulants(cumulants: &[f64]) -> Vec<f64> {
+    let mut moments = vec![0.0; cumulants.len()];
+    if let Some(first) = moments.first_mut() {
+        *first = 1.0;
+    }
+    for n in 2..cumulants.len() {
+        moments[n] = (2..=n)
+            .map(|m| {
+                factorial::binomial(n as u64 - 1, m as u64 - 1) * cumulants[m] * moments[n - m]
+            })
+            .sum();
+    }
+    moments
+}
+
+/// Inverts `central_moments_from_cumulants`, returning the cumulants
+/// `κ_0, ..., κ_k` with `κ_0` and `κ_1` set to zero
+pub fn cumulants_from_central_moments(moments: &[f64]) -> Vec<f64> {
+    let mut cumulants = vec![0.0; moments.len()];
+    for n in 2..moments.len() {
+        cumulants[n] = moments[n]
+            - (2..n)
+                .map(|m| {
+                    factorial::binomial(n as u64 - 1, m as u64 - 1) * cumulants[m] * moments[n - m]
+                })
+                .sum::<f64>();
+    }
+    cumulants
+}
+
+/// Returns the central moments `μ_0, ..., μ_k` of a distribution whose raw
+/// moments are `weight` times those of a parent with the given `mean` and
+/// central moments `moments`, i.e. the parent mixed with a point mass at
+/// zero. This covers zero-inflated and hurdle distributions, where `weight`
+/// may exceed one when zeros are deflated
+pub fn zero_mixture_central_moments(weight: f64, mean: f64, moments: &[f64]) -> Vec<f64> {
+    let shift = (1.0 - weight) * mean;
+    (0..moments.len())
+        .map(|n| {
+            let mut binomial = 1.0;
+            let mut sum = (1.0 - weight) * (-weight * mean).powi(n as i32);
+            for (m, &moment) in moments.iter().enumerate().take(n + 1) {
+                if m != 1 {
+                    sum += weight * binomial * moment * shift.powi((n - m) as i32);
+                }
+                binomial *= (n - m) as f64 / (m + 1) as f64;
+            }
+            sum
+        })
+        .collect()
+}
+
+/// Computes `ln(1 + ξz) / ξ` without cancellation for small `ξ`,
+/// returning its limit `z` when `ξ = 0`. Values of `ξz` below `-1`, which
+/// can arise from rounding at a support boundary, are clamped to `-1`
+pub fn ln_1p_scaled(z: f64, xi: f64) -> f64 {
+    if xi == 0.0 {
+        z
+    } else {
+        let u = if xi * z < -1.0 { -1.0 } else { xi * z };
+        u.ln_1p() / xi
+    }
+}
+
+/// Computes `(e^(ξa) - 1) / ξ` without cancellation for small `ξ`,
+/// returning its limit `a` when `ξ = 0`. This inverts `ln_1p_scaled`
+pub fn exp_m1_scaled(a: f64, xi: f64) -> f64 {
+    if xi == 0.0 {
+        a
+    } else {
+        (xi * a).exp_m1() / xi
+    }
+}
+
+/// The argument above which `ln_scaled_gamma_ratio` and
+/// `digamma_difference` use the Stirling series. Smaller arguments are
+/// shifted up to it by recurrence
+const STIRLING_ASYMPTOTIC: f64 = 10.0;
+
+/// Coefficients `B_2k / (2k * (2k - 1))` of the Stirling series
+/// `ln(Γ(x)) = (x - 1/2) * ln(x) - x + ln(2π) / 2 + Σ c_k / x^(2k - 1)`
+const STIRLING_COEFFS: [f64; 10] = [
+    1.0 / 12.0,
+    -1.0 / 360.0,
+    1.0 / 1260.0,
+    -1.0 / 1680.0,
+    1.0 / 1188.0,
+    -691.0 / 360360.0,
+    1.0 / 156.0,
+    -3617.0 / 122400.0,
+    43867.0 / 244188.0,
+    -174611.0 / 125400.0,
+];
+
+/// Leading terms of the Stirling series correction `ln(x!) - ln(Stirling(x))`,
+/// which is also `ln(Γ(x)) - (x - 1/2) * ln(x) + x - ln(2π) / 2`. The first
+/// omitted term is below `1e-19` for `x >= 10`
+pub fn stirling_tail(x: f64) -> f64 {
+    let inv2 = 1.0 / (x * x);
+    STIRLING_COEFFS
+        .iter()
+        .rev()
+        .fold(0.0, |acc, &c| acc * inv2 + c)
+        / x
+}
+
+/// Derivative of `stirling_tail`, the correction `ψ(x) - ln(x) + 1 / (2x)`
+/// to the leading terms of the digamma function
+fn stirling_tail_derivative(x: f64) -> f64 {
+    let inv2 = 1.0 / (x * x);
+    -STIRLING_COEFFS
+        .iter()
+        .enumerate()
+        .rev()
+        .fold(0.0, |acc, (k, &c)| acc * inv2 + (2 * k + 1) as f64 * c)
+        * inv2
+}
+
+/// Computes `ln(1 + v) - v` without cancellation for small `v`, summing
+/// the series of `2 * atanh(v / (2 + v))` beyond its leading term
+fn ln_1p_mx(v: f64) -> f64 {
+    if !(-0.5..=1.0).contains(&v) {
+        return v.ln_1p() - v;
+    }
+    let s = v / (2.0 + v);
+    let s2 = s * s;
+    let mut sum = -v * s;
+    let mut term = 2.0 * s;
+    let mut k = 1.0;
+    loop {
+        term *= s2;
+        let next = sum + term / (2.0 * k + 1.0);
+        if next == sum {
+            return sum;
+        }
+        sum = next;
+        k += 1.0;
+    }
+}
+
+/// Computes `ln(Γ(z + a) / Γ(z)) - a * ln(z)` for `z > 0` and `z + a > 0`
+/// with full relative accuracy. It vanishes as `z` grows, where
+/// subtracting the two log gamma functions would cancel. Returns `0` for
+/// infinite `z`
+pub(crate) fn ln_scaled_gamma_ratio(z: f64, a: f64) -> f64 {
+    if z.is_infinite() {
+        0.0
+    } else if z < STIRLING_ASYMPTOTIC {
+        // Γ(z + a) / Γ(z) = Γ(z + n + a) / Γ(z + n)
+        //     * prod((z + i) / (z + i + a)) for i = 0..n, and each factor
+        //     contributes a * ln(1 + 1 / x) - ln(1 + a / x) for x = z + i,
+        //     in which the terms linear in 1 / x cancel exactly
+        let n = (STIRLING_ASYMPTOTIC - z).ceil();
+        let shifted = (0..n as u64)
+            .map(|i| {
+                let x = z + i as f64;
+                a * ln_1p_mx(1.0 / x) - ln_1p_mx(a / x)
+            })
+            .sum::<f64>();
+        shifted + ln_scaled_gamma_ratio(z + n, a)
+    } else {
+        // (z + a - 1/2) * ln(1 + t) - a with t = a / z
+        let t = a / z;
+        let m = ln_1p_mx(t);
+        z * ((1.0 + t) * m + t * t) - 0.5 * (m + t) + stirling_tail(z + a) - stirling_tail(z)
+    }
+}
+
+/// Computes `ψ(z + a) - ψ(z)` for `z > 0` and `z + a > 0` without the
+/// cancellation of the two digamma functions for large `z`
+pub(crate) fn digamma_difference(z: f64, a: f64) -> f64 {
+    if z < STIRLING_ASYMPTOTIC {
+        // ψ(z + a) - ψ(z) = ψ(z + n + a) - ψ(z + n)
+        //     + Σ 1 / (z + i) - 1 / (z + i + a) for i = 0..n
+        let n = (STIRLING_ASYMPTOTIC - z).ceil();
+        let shifted = (0..n as u64)
+            .map(|i| {
+                let x = z + i as f64;
+                a / (x * (x + a))
+            })
+            .sum::<f64>();
+        shifted + digamma_difference(z + n, a)
+    } else {
+        (a / z).ln_1p() + 0.5 * a / (z * (z + a)) + stirling_tail_derivative(z + a)
+            - stirling_tail_derivative(z)
+    }
+}
+
+/// Values of the Riemann zeta function `ζ(k)` for `k = 2, ..., 30`, used
+/// in the series `ln Γ(1 - x) = γx + Σ(ζ(k) * x^k / k)`
+pub(crate) const ZETA: [f64; 29] = [
+    1.6449340668482264365,
+    1.2020569031595942854,
+    1.0823232337111381915,
+    1.0369277551433699263,
+    1.0173430619844491397,
+    1.0083492773819228268,
+    1.0040773561979443394,
+    1.0020083928260822144,
+    1.0009945751278180853,
+    1.0004941886041194646,
+    1.0002460865533080483,
+    1.0001227133475784891,
+    1.0000612481350587048,
+    1.0000305882363070205,
+    1.0000152822594086519,
+    1.0000076371976378998,
+    1.0000038172932649998,
+    1.0000019082127165539,
+    1.0000009539620338728,
+    1.0000004769329867878,
+    1.0000002384505027277,
+    1.0000001192199259653,
+    1.0000000596081890513,
+    1.0000000298035035147,
+    1.0000000149015548284,
+    1.0000000074507117898,
+    1.0000000037253340248,
+    1.0000000018626597235,
+    1.0000000009313274324,
+];
+
+/// Computes `ln Γ(1 - x)`, summing the series in `ZETA` for `|x| <= 1/4`
+/// where `ln_gamma` would lose the relative accuracy of its small result
+pub(crate) fn ln_gamma_1m(x: f64) -> f64 {
+    if x.abs() <= 0.25 {
+        let mut b = x * x;
+        let mut sum = consts::EULER_MASCHERONI * x;
+        for (i, z) in ZETA.iter().enumerate() {
+            sum += z * b / (i + 2) as f64;
+            b *= x;
+        }
+        sum
+    } else {
+        gamma::ln_gamma(1.0 - x)
+    }
+}
+
+/// Computes the logarithm of the Poisson weight `e^-μ * μ^j / j!`. A mean
+/// of zero is treated as a point mass at `j = 0`.
+///
+/// Following Loader's saddle point expansion, the weight is split into the
+/// deviance `j * ln(j / μ) + μ - j` and the error of Stirling's
+/// approximation to `j!` so that no large terms cancel when `j` and `μ` are
+/// large
+pub fn ln_poisson_weight(j: u64, mean: f64) -> f64 {
+    if j == 0 {
+        return -mean;
+    }
+    if mean == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    let x = j as f64;
+    let stirling = if j > 15 {
+        stirling_tail(x)
+    } else {
+        factorial::ln_factorial(j) - (x + 0.5) * x.ln() + x - consts::LN_SQRT_2PI
+    };
+    -stirling - poisson_deviance(x, mean) - consts::LN_SQRT_2PI - 0.5 * x.ln()
+}
+
+/// Computes the deviance `x * ln(x / μ) + μ - x`, summing its series in
+/// `(x - μ) / (x + μ)` when `x` is close to `μ` to avoid cancellation
+fn poisson_deviance(x: f64, mean: f64) -> f64 {
+    if (x - mean).abs() < 0.1 * (x + mean) {
+        let v = (x - mean) / (x + mean);
+        let v2 = v * v;
+        let mut sum = (x - mean) * v;
+        let mut term = 2.0 * x * v;
+        let mut k = 1.0;
+        loop {
+            term *= v2;
+            let next = sum + term / (2.0 * k + 1.0);
+            if next == sum {
+                return sum;
+            }
+            sum = next;
+            k += 1.0;
+        }
+    } else {
+        x * (x / mean).ln() + mean - x
+    }
+}
+
+/// Computes `ln(e^a + e^b)` without overflow, returning `-INF` if both `a`
+/// and `b` are `-INF`
+pub fn ln_add_exp(a: f64, b: f64) -> f64 {
+    let (hi, lo) = if a > b { (a, b) } else { (b, a) };
+    if hi == f64::NEG_INFINITY {
+        hi
+    } else {
+        hi + (lo - hi).exp().ln_1p()
+    }
+}
+
+/// Terms of a series or quadrature falling more than this many nats below
+/// the running total no longer affect it in double precision
+const LN_SUM_TOLERANCE: f64 = -40.0;
+
+/// The maximum number of nodes visited in each direction by
+/// `ln_integrate_trapezoid`
+const TRAPEZOID_MAX_NODES: usize = 100_000;
+
+/// Accumulates `sum(e^x)` over a stream of logarithms `x`, rescaling by the
+/// largest value seen so far to avoid overflow and underflow
+struct LnAccumulator {
+    max: f64,
+    sum: f64,
+}
+
+impl LnAccumulator {
+    fn new() -> LnAccumulator {
+        LnAccumulator {
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+        }
+    }
+
+    fn add(&mut self, x: f64) {
+        if x > self.max {
+            self.sum = self.sum * (self.max - x).exp() + 1.0;
+            self.max = x;
+        } else if x != f64::NEG_INFINITY {
+            self.sum += (x - self.max).exp();
+        }
+    }
+
+    fn ln(&self) -> f64 {
+        self.max + self.sum.ln()
+    }
+}
+
+/// Returns true once the log-term `x` following `prev` is falling and the
+/// geometric bound `e^x / (1 - e^(x - prev))` on the rest of the series is
+/// negligible relative to `e^total`. `NaN` terms also stop the summation.
+fn is_negligible(x: f64, prev: f64, total: f64) -> bool {
+    let bound = x - (-(x - prev).exp_m1()).ln();
+    x.partial_cmp(&prev) != Some(Ordering::Greater)
+        && bound
+            .partial_cmp(&(total + LN_SUM_TOLERANCE))
+            .is_none_or(|o| o == Ordering::Less)
+}
+
+/// Computes the logarithm of `sum(e^ln_term(j))` for `j = 0, 1, ...` where
+/// the terms form a unimodal sequence, as is the case for the Poisson
+/// mixtures underlying the noncentral distributions.
+///
+/// The summation starts at `start`, which should be close to the largest
+/// term, and walks outwards in both directions until the remaining terms
+/// are negligible. Since every term is nonnegative, the result retains full
+/// relative accuracy in the far tails.
+pub fn ln_sum_terms<F>(start: u64, ln_term: F) -> f64
+where
+    F: Fn(u64) -> f64,
+{
+    let mut acc = LnAccumulator::new();
+    let first = ln_term(start);
+    acc.add(first);
+
+    let mut prev = first;
+    let mut j = start;
+    loop {
+        j += 1;
+        let x = ln_term(j);
+        acc.add(x);
+        if is_negligible(x, prev, acc.ln()) {
+            break;
+        }
+        prev = x;
+    }
+
+    prev = first;
+    j = start;
+    while j > 0 {
+        j -= 1;
+        let x = ln_term(j);
+        acc.add(x);
+        if acc.ln() > f64::NEG_INFINITY && is_negligible(x, prev, acc.ln()) {
+            break;
+        }
+        prev = x;
+    }
+    acc.ln()
+}
+
+/// Returns the positive root of `a * w^2 - b * w - c = 0` for `a, c > 0`,
+/// choosing the form that does not cancel for either sign of `b`
+pub fn quadratic_root(a: f64, b: f64, c: f64) -> f64 {
+    let disc = (b * b + 4.0 * a * c).sqrt();
+    if b >= 0.0 {
+        (b + disc) / (2.0 * a)
+    } else {
+        2.0 * c / (disc - b)
+    }
+}
+
+/// Reduces the angle `x` to the interval `[-π, π)`
+pub fn wrap_angle(x: f64) -> f64 {
+    (x + f64::consts::PI).rem_euclid(2.0 * f64::consts::PI) - f64::consts::PI
+}
+
+/// Computes the cdf at `θ` in `[-π, π]` of a circular distribution on
+/// `[-π, π]` that is symmetric about zero, from its Fourier coefficients
+/// `ρ_n = E[cos(nθ)]` for `n = 1, 2, ...`. The coefficients should decrease
+/// to zero, and the sum stops at the first one that no longer affects the
+/// result
+///
+/// # Formula
+///
+/// ```ignore
+/// 1 / 2 + θ / (2π) + 1 / π * sum(ρ_n * sin(nθ) / n, n=1..)
+/// ```
+pub fn circular_cdf_series<I>(theta: f64, coefficients: I) -> f64
+where
+    I: IntoIterator<Item = f64>,
+{
+    let mut sum = 0.0;
+    for (n, rho) in (1..).zip(coefficients) {
+        let n = n as f64;
+        if rho / n < f64::EPSILON / 4.0 {
+            break;
+        }
+        sum += rho * (n * theta).sin() / n;
+    }
+    0.5 + (theta / 2.0 + sum) / f64::consts::PI
+}
+
+/// Computes the logarithm of `int(e^ln_f(s), s=-INF..INF)` for a smooth
+/// unimodal integrand using the trapezoidal rule with spacing `step` on the
+/// nodes `center + i * step`. The rule converges geometrically for such
+/// integrands, so a step of a fraction of the width of the peak at `center`
+/// already gives close to full precision.
+pub fn ln_integrate_trapezoid<F>(ln_f: F, center: f64, step: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let mut acc = LnAccumulator::new();
+    let first = ln_f(center);
+    acc.add(first);
+    for &dir in &[1.0, -1.0] {
+        let mut prev = first;
+        for i in 1..=TRAPEZOID_MAX_NODES {
+            let x = ln_f(center + dir * i as f64 * step);
+            acc.add(x);
+            if is_negligible(x, prev, acc.ln()) {
+                break;
+            }
+            prev = x;
+        }
+    }
+    acc.ln() + step.ln()
+}
+
+/// The maximum number of iterations spent either expanding the bracket or
+/// refining the root in `inverse_cdf_brent`
+const INVERSE_CDF_MAX_ITER: usize = 2000;
+
+/// Solves `cdf(x) = p` for `x` using Brent's method. The search starts from
+/// the support bounds of `dist`, expanding geometrically towards any bound
+/// that is infinite until the root is bracketed, and stops once the bracket
+/// is narrower than `tol` relative to the current estimate.
+///
+/// Returns an error if `p` is not in `[0, 1]` or if either the bracketing or
+/// the refinement fails to converge.
+pub fn inverse_cdf_brent<K, T, D>(dist: &D, p: T, tol: K) -> Result<K>
+where
+    K: Float,
+    T: Float,
+    D: ContinuousCDF<K, T> + ?Sized,
+{
+    if p.is_nan() || p < T::zero() || p > T::one() {
+        return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+    }
+    if p == T::zero() {
+        return Ok(dist.min());
+    }
+    if p == T::one() {
+        return Ok(dist.max());
+    }
+
+    let f = |x: K| K::from(dist.cdf(x) - p).unwrap();
+    let two = K::one() + K::one();
+    let mut a = dist.min();
+    let mut b = dist.max();
+    match (a.is_finite(), b.is_finite()) {
+        (true, true) => {}
+        (true, false) => b = a + K::one().max(a.abs()),
+        (false, true) => a = b - K::one().max(b.abs()),
+        (false, false) => {
+            a = -K::one();
+            b = K::one();
+        }
+    }
+
+    let mut fa = f(a);
+    if fa >= K::zero() && a == dist.min() {
+        // the support starts with an atom covering `p`
+        return Ok(a);
+    }
+    let mut fb = f(b);
+    let mut i = 0;
+    while fa > K::zero() || fb < K::zero() {
+        if i == INVERSE_CDF_MAX_ITER {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        let width = b - a;
+        if fa > K::zero() {
+            b = a;
+            fb = fa;
+            a = a - two * width;
+            fa = f(a);
+        } else {
+            a = b;
+            fa = fb;
+            b = b + two * width;
+            fb = f(b);
+        }
+        i += 1;
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return Err(StatsError::ComputationFailedToConverge);
+    }
+    let half = K::one() / two;
+    let three = two + K::one();
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = b - a;
+    let mut e = d;
+    for _ in 0..INVERSE_CDF_MAX_ITER {
+        if (fb > K::zero()) == (fc > K::zero()) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol1 = two * K::epsilon() * b.abs() + half * tol * b.abs() + K::min_positive_value();
+        let xm = half * (c - b);
+        if xm.abs() <= tol1 || fb == K::zero() {
+            return Ok(b);
+        }
+
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // inverse quadratic interpolation, or the secant method when
+            // only two distinct points are available
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (two * xm * s, K::one() - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (two * xm * q * (q - r) - (b - a) * (r - K::one())),
+                    (q - K::one()) * (r - K::one()) * (s - K::one()),
+                )
+            };
+            if p > K::zero() {
+                q = -q;
+            }
+            p = p.abs();
+            if two * p < (three * xm * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = xm;
+                e = d;
+            }
+        } else {
+            d = xm;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b = if d.abs() > tol1 {
+            b + d
+        } else if xm > K::zero() {
+            b + tol1
+        } else {
+            b - tol1
+        };
+        fb = f(b);
+    }
+    Err(StatsError::ComputationFailedToConverge)
+}
+
+/// Returns the smallest `k` in the support of `dist` such that
+/// `cdf(k) >= p`, starting the search at `guess`.
+///
+/// The root is bracketed by stepping away from `guess` with exponentially
+/// growing steps, clamped to `min()` and `max()`, and the final step is then
+/// bisected. The number of cdf evaluations is therefore logarithmic in the
+/// distance between `guess` and the result.
+///
+/// # Panics
+///
+/// If `p < 0.0` or `p > 1.0`
+pub fn discrete_inverse_cdf<K, T, D>(dist: &D, p: T, guess: K) -> K
+where
+    K: Bounded + Clone + Num + PartialOrd,
+    T: Float,
+    D: DiscreteCDF<K, T> + ?Sized,
+{
+    if p.is_nan() || p < T::zero() || p > T::one() {
+        panic!("p must be in [0, 1]");
+    }
+    let min = dist.min();
+    let max = dist.max();
+    if p == T::zero() {
+        return min;
+    }
+    if p == T::one() {
+        return max;
+    }
+
+    let one = K::one();
+    let two = K::one() + K::one();
+    let guess = if guess < min {
+        min.clone()
+    } else if guess > max {
+        max.clone()
+    } else {
+        guess
+    };
+
+    // doubles the step, saturating at the largest value of `K`
+    let grow = |step: K| {
+        if step > K::max_value() / two.clone() {
+            K::max_value()
+        } else {
+            step * two.clone()
+        }
+    };
+
+    // establish cdf(low) < p <= cdf(high), never forming a difference of two
+    // points of the support since that overflows for wide signed supports
+    let mut low;
+    let mut high;
+    let mut step = one.clone();
+    if dist.cdf(guess.clone()) >= p {
+        high = guess;
+        loop {
+            if high < K::min_value() + step.clone() || high.clone() - step.clone() <= min {
+                if dist.cdf(min.clone()) >= p {
+                    return min;
+                }
+                low = min;
+                break;
+            }
+            let next = high.clone() - step.clone();
+            if dist.cdf(next.clone()) >= p {
+                high = next;
+                step = grow(step);
+            } else {
+                low = next;
+                break;
+            }
+        }
+    } else {
+        low = guess;
+        loop {
+            if low > K::max_value() - step.clone() || low.clone() + step.clone() >= max {
+                high = max;
+                break;
+            }
+            let next = low.clone() + step.clone();
+            if dist.cdf(next.clone()) < p {
+                low = next;
+                step = grow(step);
+            } else {
+                high = next;
+                break;
+            }
+        }
+    }
+
+    while low.clone() + one.clone() < high {
+        // halving the ends separately cannot overflow, and the result lies
+        // strictly between them since they are at least two apart
+        let mid = low.clone() / two.clone()
+            + high.clone() / two.clone()
+            + (low.clone() % two.clone() + high.clone() % two.clone()) / two.clone();
+        if dist.cdf(mid.clone()) >= p {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
+
+/// Checks that `mean` and the column-major matrix `cov` describe a
+/// location vector and a symmetric scale matrix of the same dimension
+/// without `NaN` entries, and returns them as a vector and a matrix
+///
+/// # Remarks
+///
+/// Positive-definiteness is left to the Cholesky decomposition of the
+/// returned matrix
+pub(crate) fn parse_mean_cov(
+    mean: Vec<f64>,
+    cov: Vec<f64>,
+) -> Result<(DVector<f64>, DMatrix<f64>)> {
+    let dim = mean.len();
+    if cov.len() != dim * dim {
+        return Err(StatsError::BadParams);
+    }
+    let mean = DVector::from_vec(mean);
+    let cov = DMatrix::from_vec(dim, dim, cov);
+    // Check that the provided covariance matrix is symmetric
+    if cov.lower_triangle() != cov.upper_triangle().transpose()
+    // Check that mean and covariance do not contain NaN
+        || mean.iter().any(|f| f.is_nan())
+        || cov.iter().any(|f| f.is_nan())
+    {
+        return Err(StatsError::BadParams);
+    }
+    Ok((mean, cov))
+}
+
+/// Returns the indices in `0..dim` missing from `indices`, in increasing
+/// order
+///
+/// # Errors
+///
+/// Returns an error if `indices` is empty, repeats an index or holds one
+/// that is not less than `dim`
+pub(crate) fn complement_indices(dim: usize, indices: &[usize]) -> Result<Vec<usize>> {
+    let mut seen = vec![false; dim];
+    for &i in indices {
+        if i >= dim || seen[i] {
+            return Err(StatsError::BadParams);
+        }
+        seen[i] = true;
+    }
+    if indices.is_empty() {
+        return Err(StatsError::BadParams);
+    }
+    Ok((0..dim).filter(|&i| !seen[i]).collect())
+}
+
+/// Partitions the location `mu` and scale matrix `sigma` into the
+/// components `observed`, which take `values`, and the components `rest`.
+/// Returns the location and scale of `rest` given the observed values,
+/// as for a normal distribution, along with the squared Mahalanobis
+/// distance of `values` from the observed components' own location
+///
+/// # Formula
+///
+/// ```ignore
+/// μ_a + Σ_ab * inv(Σ_bb) * (x_b - μ_b)
+/// Σ_aa - Σ_ab * inv(Σ_bb) * Σ_ba
+/// transpose(x_b - μ_b) * inv(Σ_bb) * (x_b - μ_b)
+/// ```
+///
+/// where `a` indexes `rest` and `b` indexes `observed`
+pub(crate) fn condition_mean_cov(
+    mu: &DVector<f64>,
+    sigma: &DMatrix<f64>,
+    observed: &[usize],
+    rest: &[usize],
+    values: &[f64],
+) -> Result<(DVector<f64>, DMatrix<f64>, f64)> {
+    if values.len() != observed.len() || values.iter().any(|x| x.is_nan()) {
+        return Err(StatsError::BadParams);
+    }
+    let sigma_b = sigma.select_rows(observed);
+    let cholesky_bb =
+        Cholesky::new(sigma_b.select_columns(observed)).ok_or(StatsError::BadParams)?;
+    // inv(Σ_bb) * Σ_ba and inv(Σ_bb) * (x_b - μ_b)
+    let gain = cholesky_bb.solve(&sigma_b.select_columns(rest));
+    let dv = DVector::from_vec(values.to_vec()) - mu.select_rows(observed);
+    let shift = cholesky_bb.solve(&dv);
+
+    let mean = mu.select_rows(rest) + gain.tr_mul(&dv);
+    let cov =
+        sigma.select_rows(rest).select_columns(rest) - gain.tr_mul(&sigma_b.select_columns(rest));
+    // restore the exact symmetry lost to rounding
+    let cov = (&cov + cov.transpose()) * 0.5;
+    Ok((mean, cov, dv.dot(&shift)))
+}
+
+/// Checks the parameters shared by the Wishart and inverse Wishart
+/// distributions and returns the Cholesky decomposition of `scale`
+pub(crate) fn parse_scale(freedom: f64, scale: &DMatrix<f64>) -> Result<Cholesky<f64, Dynamic>> {
+    let p = scale.nrows();
+    if p == 0
+        || !scale.is_square()
+        || scale.iter().any(|x| !x.is_finite())
+        || scale.lower_triangle() != scale.upper_triangle().transpose()
+        || !freedom.is_finite()
+        || freedom <= (p - 1) as f64
+    {
+        return Err(StatsError::BadParams);
+    }
+    Cholesky::new(scale.clone()).ok_or(StatsError::BadParams)
+}
+
+/// Returns the log determinant of a matrix from its lower triangular
+/// Cholesky factor
+pub(crate) fn ln_det_from_cholesky(l: &DMatrix<f64>) -> f64 {
+    2.0 * l.diagonal().iter().map(|x| x.ln()).sum::<f64>()
+}
+
+/// Returns the Cholesky factor of `x` if it is a symmetric
+/// positive-definite matrix of dimension `p`
+pub(crate) fn support_cholesky(x: &DMatrix<f64>, p: usize) -> Option<Cholesky<f64, Dynamic>> {
+    if x.nrows() != p
+        || x.ncols() != p
+        || x.iter().any(|v| !v.is_finite())
+        || x.lower_triangle() != x.upper_triangle().transpose()
+    {
+        return None;
+    }
+    Cholesky::new(x.clone())
+}
+
+/// Returns the elementwise variance of a Wishart or inverse Wishart
+/// distribution, `c * (a * s_ij^2 + b * s_ii * s_jj)`, where `s` is the
+/// scale matrix
+pub(crate) fn elementwise_variance(scale: &DMatrix<f64>, a: f64, b: f64, c: f64) -> DMatrix<f64> {
+    let p = scale.nrows();
+    DMatrix::from_fn(p, p, |i, j| {
+        c * (a * scale[(i, j)] * scale[(i, j)] + b * scale[(i, i)] * scale[(j, j)])
+    })
+}
+
+/// Draws the lower triangular factor `A` of a Bartlett decomposition, so
+/// that `A * transpose(A)` is a sample from the Wishart distribution with
+/// `freedom` degrees of freedom and a `p` dimensional identity scale matrix
+pub(crate) fn sample_bartlett<R: Rng + ?Sized>(
+    rng: &mut R,
+    freedom: f64,
+    p: usize,
+) -> DMatrix<f64> {
+    let mut a = DMatrix::zeros(p, p);
+    for i in 0..p {
+        // chi-squared with freedom - i degrees of freedom
+        a[(i, i)] = gamma_dist::sample_unchecked(rng, 0.5 * (freedom - i as f64), 0.5).sqrt();
+        for j in 0..i {
+            a[(i, j)] = normal::sample_unchecked(rng, 0.0, 1.0);
+        }
+    }
+    a
+}
+
 #[cfg(test)]
 pub mod tests {
-    use super::is_valid_multinomial;
+    use super::{
+        central_moments_from_cumulants, cumulants_from_central_moments, digamma_difference,
+        is_valid_multinomial, ln_scaled_gamma_ratio,
+    };
     use crate::consts::ACC;
-    use crate::distribution::{Continuous, ContinuousCDF, Discrete, DiscreteCDF};
+    use crate::distribution::{ChiSquared, Continuous, ContinuousCDF, Discrete, DiscreteCDF};
+    use crate::statistics::{Max, Min};
+    use num_traits::ToPrimitive;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::fmt::Debug;
 
     /// cdf should be the integral of the pdf
     fn check_integrate_pdf_is_cdf<D: ContinuousCDF<f64, f64> + Continuous<f64, f64>>(
@@ -50,6 +927,8 @@ pub mod tests {
                 panic!();
             }
 
+            assert_almost_eq!(dist.sf(x), 1.0 - cdf, 1e-10);
+
             if x >= x_max {
                 break;
             } else {
@@ -80,6 +959,7 @@ pub mod tests {
             }
 
             assert_almost_eq!(sum, dist.cdf(i), 1e-10);
+            assert_almost_eq!(1.0 - sum, dist.sf(i), 1e-10);
             // assert_almost_eq!(sum, dist.cdf(i as f64), 1e-10);
             // assert_almost_eq!(sum, dist.cdf(i as f64 + 0.1), 1e-10);
             // assert_almost_eq!(sum, dist.cdf(i as f64 + 0.5), 1e-10);
@@ -103,10 +983,26 @@ pub mod tests {
         assert_eq!(dist.ln_pdf(f64::INFINITY), f64::NEG_INFINITY);
         assert_eq!(dist.cdf(f64::NEG_INFINITY), 0.0);
         assert_eq!(dist.cdf(f64::INFINITY), 1.0);
+        assert_eq!(dist.sf(f64::NEG_INFINITY), 1.0);
+        assert_eq!(dist.sf(f64::INFINITY), 0.0);
 
         check_integrate_pdf_is_cdf(dist, x_min, x_max, (x_max - x_min) / 100000.0);
     }
 
+    /// Checks that the inverse cdf maps the ends of the unit interval onto
+    /// the support and round-trips through the cdf to within `acc`.
+    pub fn check_inverse_cdf<D: ContinuousCDF<f64, f64>>(dist: &D, acc: f64) {
+        assert_eq!(dist.inverse_cdf(0.0), dist.min());
+        assert_eq!(dist.inverse_cdf(1.0), dist.max());
+
+        let probs = [
+            1e-10, 1e-5, 0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999, 0.99999,
+        ];
+        for &p in probs.iter() {
+            assert_almost_eq!(dist.cdf(dist.inverse_cdf(p)), p, acc);
+        }
+    }
+
     /// Does a series of checks that all positive discrete distributions must
     /// obey.
     /// 99% of the probability mass should be between 0 and x_max (inclusive).
@@ -123,6 +1019,261 @@ pub mod tests {
         check_sum_pmf_is_cdf(dist, x_max);
     }
 
+    /// Checks that the inverse cdf maps the ends of the unit interval onto
+    /// the support and returns the smallest `k` with `cdf(k) >= p`.
+    pub fn check_discrete_inverse_cdf<D: DiscreteCDF<u64, f64>>(dist: &D) {
+        assert_eq!(dist.inverse_cdf(0.0), dist.min());
+        assert_eq!(dist.inverse_cdf(1.0), dist.max());
+
+        let probs = [
+            1e-10, 1e-5, 0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999999,
+        ];
+        for &p in probs.iter() {
+            let k = dist.inverse_cdf(p);
+            assert!(dist.cdf(k) >= p, "cdf({}) = {} < {}", k, dist.cdf(k), p);
+            if k > dist.min() {
+                assert!(
+                    dist.cdf(k - 1) < p,
+                    "cdf({}) = {} >= {}",
+                    k - 1,
+                    dist.cdf(k - 1),
+                    p
+                );
+            }
+        }
+    }
+
+    /// Checks that integer samples lie in the support and that the `f64`
+    /// sampler draws the same values from an identically seeded generator.
+    pub fn check_discrete_samples<K, D>(dist: &D)
+    where
+        K: Copy + PartialOrd + Debug + ToPrimitive,
+        D: Distribution<K> + Distribution<f64> + Min<K> + Max<K>,
+    {
+        let mut int_rng = StdRng::seed_from_u64(0x5eed);
+        let mut float_rng = StdRng::seed_from_u64(0x5eed);
+        for _ in 0..1000 {
+            let k: K = dist.sample(&mut int_rng);
+            let x: f64 = dist.sample(&mut float_rng);
+            assert!(k >= dist.min() && k <= dist.max(), "{:?} not in support", k);
+            assert_eq!(k.to_f64().unwrap(), x);
+        }
+    }
+
+    /// Runs a chi-square goodness-of-fit test of `count` seeded samples
+    /// against the pmf. Every value in `[lo, hi]` starts as its own bin and
+    /// the rest of the support forms one more; neighbouring bins are merged
+    /// until each expects at least five samples. Fails if the statistic
+    /// exceeds the 0.999 quantile of the chi-square distribution.
+    pub fn check_goodness_of_fit<D>(dist: &D, lo: u64, hi: u64, count: usize)
+    where
+        D: Distribution<u64> + Discrete<u64, f64>,
+    {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let mut observed = vec![0.0; (hi - lo + 1) as usize];
+        let mut outside = 0.0;
+        for _ in 0..count {
+            let k = dist.sample(&mut rng);
+            if k < lo || k > hi {
+                outside += 1.0;
+            } else {
+                observed[(k - lo) as usize] += 1.0;
+            }
+        }
+
+        let n = count as f64;
+        let mut bins = Vec::new();
+        let (mut obs, mut exp) = (0.0, 0.0);
+        let mut inside = 0.0;
+        for (k, o) in (lo..=hi).zip(observed) {
+            let p = dist.pmf(k);
+            inside += p;
+            obs += o;
+            exp += n * p;
+            if exp >= 5.0 {
+                bins.push((obs, exp));
+                obs = 0.0;
+                exp = 0.0;
+            }
+        }
+        obs += outside;
+        exp += n * (1.0 - inside).max(0.0);
+        if exp >= 5.0 || bins.is_empty() {
+            bins.push((obs, exp));
+        } else {
+            let last = bins.last_mut().unwrap();
+            last.0 += obs;
+            last.1 += exp;
+        }
+        assert!(bins.len() > 1, "too few bins for a goodness-of-fit test");
+
+        let stat: f64 = bins.iter().map(|&(o, e)| (o - e) * (o - e) / e).sum();
+        let critical = ChiSquared::new((bins.len() - 1) as f64)
+            .unwrap()
+            .inverse_cdf(0.999);
+        assert!(
+            stat < critical,
+            "chi-square statistic {} exceeds {} on {} bins",
+            stat,
+            critical,
+            bins.len()
+        );
+    }
+
+    /// Runs a Kolmogorov-Smirnov test of `samples` against the cdf of
+    /// `dist`. Fails if the statistic exceeds the asymptotic 0.999
+    /// critical value.
+    pub fn check_kolmogorov_smirnov<D: ContinuousCDF<f64, f64>>(dist: &D, mut samples: Vec<f64>) {
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = samples.len() as f64;
+        let stat = samples
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| {
+                let c = dist.cdf(x);
+                (c - i as f64 / n).max((i + 1) as f64 / n - c)
+            })
+            .fold(0.0, f64::max);
+        let critical = 1.95 / n.sqrt();
+        assert!(
+            stat < critical,
+            "Kolmogorov-Smirnov statistic {} exceeds {}",
+            stat,
+            critical
+        );
+    }
+
+    /// Hides any specialized inverse cdf of the wrapped distribution so that
+    /// the default root-finding implementation is exercised
+    struct DefaultInverse<D>(D);
+
+    impl<D: Min<f64>> Min<f64> for DefaultInverse<D> {
+        fn min(&self) -> f64 {
+            self.0.min()
+        }
+    }
+
+    impl<D: Max<f64>> Max<f64> for DefaultInverse<D> {
+        fn max(&self) -> f64 {
+            self.0.max()
+        }
+    }
+
+    impl<D: ContinuousCDF<f64, f64>> ContinuousCDF<f64, f64> for DefaultInverse<D> {
+        fn cdf(&self, x: f64) -> f64 {
+            self.0.cdf(x)
+        }
+    }
+
+    /// Compares the default inverse cdf against the specialized one to within
+    /// a relative accuracy of `acc` and checks the round trip through the
+    /// cdf to within `cdf_acc`
+    fn check_default_inverse_cdf<D: ContinuousCDF<f64, f64>>(dist: D, acc: f64, cdf_acc: f64) {
+        let probs = [1e-10, 1e-5, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.99999];
+        let default = DefaultInverse(dist);
+        for &p in probs.iter() {
+            let expected = default.0.inverse_cdf(p);
+            let x = default.inverse_cdf(p);
+            assert!(
+                (x - expected).abs() <= acc * expected.abs() + 1e-16,
+                "p = {}: expected {}, got {}",
+                p,
+                expected,
+                x
+            );
+        }
+        check_inverse_cdf(&default, cdf_acc);
+    }
+
+    #[test]
+    fn test_default_inverse_cdf() {
+        use crate::distribution::{Beta, Cauchy, Exp, Gamma, Normal, Pareto, Uniform};
+
+        check_default_inverse_cdf(Normal::new(0.0, 1.0).unwrap(), 1e-10, 1e-15);
+        check_default_inverse_cdf(Normal::new(1e6, 1e-3).unwrap(), 1e-14, 1e-5);
+        check_default_inverse_cdf(Gamma::new(0.1, 1.0).unwrap(), 1e-11, 1e-15);
+        check_default_inverse_cdf(Pareto::new(100.0, 2.0).unwrap(), 1e-10, 1e-12);
+        check_default_inverse_cdf(Uniform::new(10.0, 20.0).unwrap(), 1e-14, 1e-12);
+        check_default_inverse_cdf(Beta::new(2.0, 5.0).unwrap(), 1e-13, 1e-15);
+        // the relative accuracy in the lower tail is limited by the
+        // cancellation in the cdf of these two
+        check_default_inverse_cdf(Cauchy::new(0.0, 1.0).unwrap(), 1e-6, 1e-15);
+        check_default_inverse_cdf(Exp::new(1e4).unwrap(), 1e-6, 1e-15);
+    }
+
+    #[test]
+    fn test_default_inverse_cdf_with_tolerance() {
+        use crate::distribution::{Dirac, Normal};
+
+        let default = DefaultInverse(Normal::new(0.0, 1.0).unwrap());
+        let x = default.inverse_cdf_with_tolerance(0.975, 1e-6).unwrap();
+        assert_almost_eq!(x, 1.959963984540054, 1e-5);
+        assert!(default.inverse_cdf_with_tolerance(-0.1, 1e-6).is_err());
+        assert!(default.inverse_cdf_with_tolerance(1.1, 1e-6).is_err());
+        assert!(default.inverse_cdf_with_tolerance(f64::NAN, 1e-6).is_err());
+        assert_eq!(default.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(default.inverse_cdf(1.0), f64::INFINITY);
+
+        // a cdf that never reaches `p` cannot be bracketed
+        let broken = DefaultInverse(Dirac::new(f64::INFINITY).unwrap());
+        assert!(broken.inverse_cdf_with_tolerance(0.5, 1e-6).is_err());
+    }
+
+    /// Hides any specialized inverse cdf of the wrapped discrete distribution
+    /// so that the default search is exercised
+    struct DefaultDiscreteInverse<D>(D);
+
+    impl<D: Min<i64>> Min<i64> for DefaultDiscreteInverse<D> {
+        fn min(&self) -> i64 {
+            self.0.min()
+        }
+    }
+
+    impl<D: Max<i64>> Max<i64> for DefaultDiscreteInverse<D> {
+        fn max(&self) -> i64 {
+            self.0.max()
+        }
+    }
+
+    impl<D: DiscreteCDF<i64, f64>> DiscreteCDF<i64, f64> for DefaultDiscreteInverse<D> {
+        fn cdf(&self, x: i64) -> f64 {
+            self.0.cdf(x)
+        }
+    }
+
+    #[test]
+    fn test_default_discrete_inverse_cdf_full_width() {
+        use crate::distribution::DiscreteUniform;
+
+        let bounds = [
+            (i64::MIN + 1, i64::MAX - 1),
+            (i64::MIN, i64::MAX),
+            (-10, 10),
+        ];
+        let probs = [1e-10, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999999];
+        for &(min, max) in bounds.iter() {
+            let default = DefaultDiscreteInverse(DiscreteUniform::new(min, max).unwrap());
+            assert_eq!(default.inverse_cdf(0.0), min);
+            assert_eq!(default.inverse_cdf(1.0), max);
+            for &p in probs.iter() {
+                let k = default.inverse_cdf(p);
+                assert!(
+                    default.cdf(k) >= p,
+                    "cdf({}) = {} < {}",
+                    k,
+                    default.cdf(k),
+                    p
+                );
+                assert!(
+                    k == min || default.cdf(k - 1) < p,
+                    "cdf({}) >= {}",
+                    k - 1,
+                    p
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_is_valid_multinomial() {
         use std::f64;
@@ -142,4 +1293,55 @@ pub mod tests {
         let invalid = [5.2, 0.0, 1e-15, 1000000.12];
         assert!(!is_valid_multinomial(&invalid, false));
     }
+
+    #[test]
+    fn test_cumulant_conversion() {
+        // standard normal: only the second cumulant is non-zero
+        let moments = central_moments_from_cumulants(&[0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(moments, [1.0, 0.0, 1.0, 0.0, 3.0, 0.0, 15.0]);
+        // poisson with unit rate: every cumulant is one
+        let moments = central_moments_from_cumulants(&[1.0; 7]);
+        assert_eq!(moments, [1.0, 0.0, 1.0, 1.0, 4.0, 11.0, 41.0]);
+        assert_eq!(
+            cumulants_from_central_moments(&moments),
+            [0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn test_ln_scaled_gamma_ratio() {
+        let cases = [
+            (0.3, -0.25, 1.572088005152171484956),
+            (2.5, -0.5, 0.1734624954641583729593),
+            (10.0, 0.5, -0.01249480717472882005548),
+            (100.0, -0.5, 0.003762547031721377233005),
+            (1e8, -0.5, 3.750000012500000046875e-9),
+            (3.0, 50.0, 100.7370746891133553149),
+            (1e6, 2.5, 0.000001874998750001171873719),
+        ];
+        for &(z, a, expected) in cases.iter() {
+            assert_almost_eq!(
+                ln_scaled_gamma_ratio(z, a),
+                expected,
+                1e-14 * expected.abs()
+            );
+        }
+        assert_eq!(ln_scaled_gamma_ratio(f64::INFINITY, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_digamma_difference() {
+        let cases = [
+            (0.3, -0.25, -16.99532076909974170405),
+            (2.5, -0.5, -0.2803723055467760478322),
+            (10.0, 0.5, 0.05124844523096526762514),
+            (100.0, -0.5, -0.005037751412523595365103),
+            (1e8, -0.5, -5.00000003750000025e-9),
+            (3.0, 50.0, 3.03804395069744919029),
+            (1e6, 2.5, 0.000002499998125002499996484),
+        ];
+        for &(z, a, expected) in cases.iter() {
+            assert_almost_eq!(digamma_difference(z, a), expected, 1e-15 * expected.abs());
+        }
+    }
 }
@@ -115,6 +115,50 @@ impl ContinuousCDF<f64, f64> for InverseGamma {
             gamma::gamma_ur(self.shape, self.rate / x)
         }
     }
+
+    /// Calculates the survival function for the inverse gamma
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ(α, β / x) / Γ(α)
+    /// ```
+    ///
+    /// where the numerator is the lower incomplete gamma function,
+    /// the denominator is the gamma function, `α` is the shape,
+    /// and `β` is the rate
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            gamma::gamma_lr(self.shape, self.rate / x)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// inverse gamma distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// β / Q^-1(α, p)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `Q^-1` is the inverse
+    /// of the upper regularized gamma function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.rate / gamma::inv_gamma_ur(self.shape, p)
+    }
 }
 
 impl Min<f64> for InverseGamma {
@@ -225,6 +269,26 @@ impl Distribution<f64> for InverseGamma {
             Some(4.0 * (self.shape - 2.0).sqrt() / (self.shape - 3.0))
         }
     }
+    /// Returns the excess kurtosis of the inverse gamma distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if shape is less than or equal to `4.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (30α - 66) / ((α - 3)(α - 4))
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn kurtosis(&self) -> Option<f64> {
+        if self.shape <= 4.0 {
+            None
+        } else {
+            Some((30.0 * self.shape - 66.0) / ((self.shape - 3.0) * (self.shape - 4.0)))
+        }
+    }
 }
 
 impl Mode<Option<f64>> for InverseGamma {
@@ -391,6 +455,14 @@ mod tests {
         test_case(5.0, 0.1, 3.464101615137754587055, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: InverseGamma| x.kurtosis().unwrap();
+        test_case(6.0, 2.0, 19.0, kurtosis);
+        test_almost(10.0, 1.0, 234.0 / 42.0, 1e-15, kurtosis);
+        assert!(try_create(4.0, 1.0).kurtosis().is_none());
+    }
+
     #[test]
     #[should_panic]
     fn test_skewness_with_shape_lte_3() {
@@ -440,6 +512,27 @@ mod tests {
         test_almost(1.0, 1.0, 0.4345982085070782231613, 1e-14, cdf(1.2));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: InverseGamma| x.sf(arg);
+        test_case(3.0, 2.0, 1.0, sf(0.0));
+        test_almost(3.0, 2.0, 1.33133493244482526353155950848e-9, 1e-21, sf(1000.0));
+        test_case(3.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: InverseGamma| x.inverse_cdf(arg);
+        test_case(3.0, 2.0, 0.0, inverse_cdf(0.0));
+        test_almost(3.0, 2.0, 0.03748844366870917270783, 1e-16, inverse_cdf(1e-20));
+        test_almost(3.0, 2.0, 0.3757759535409422271565, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 2.0, 0.7479262863802242961791, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 2.0, 4.586680830736637855952, 1e-13, inverse_cdf(0.99));
+        test_case(3.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(3.0, 2.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.5, 1.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0, 0.5), 0.0, 100.0);
@@ -0,0 +1,597 @@
+use crate::consts;
+use crate::distribution::{normal, ziggurat, Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use num_complex::Complex;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Inverse
+/// Gaussian](https://en.wikipedia.org/wiki/Inverse_Gaussian_distribution)
+/// distribution, also known as the Wald distribution. It describes the
+/// first passage time of a Brownian motion with positive drift
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{InverseGaussian, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = InverseGaussian::new(1.0, 1.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.0);
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.3989422804014327, 1e-16));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InverseGaussian {
+    mean: f64,
+    shape: f64,
+}
+
+impl InverseGaussian {
+    /// Constructs a new inverse gaussian distribution with a mean (μ)
+    /// of `mean` and a shape (λ) of `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `shape` are `NaN`.
+    /// Also returns an error if `mean` or `shape` are not in `(0, +inf)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseGaussian;
+    ///
+    /// let mut result = InverseGaussian::new(1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = InverseGaussian::new(0.0, 2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(mean: f64, shape: f64) -> Result<InverseGaussian> {
+        let is_nan = mean.is_nan() || shape.is_nan();
+        match (mean, shape, is_nan) {
+            (_, _, true) => Err(StatsError::BadParams),
+            (_, _, false) if mean <= 0.0 || shape <= 0.0 => Err(StatsError::BadParams),
+            (_, _, false) if mean.is_infinite() || shape.is_infinite() => {
+                Err(StatsError::BadParams)
+            }
+            (_, _, false) => Ok(InverseGaussian { mean, shape }),
+        }
+    }
+
+    /// Returns the shape (λ) of the inverse gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseGaussian;
+    ///
+    /// let n = InverseGaussian::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.shape(), 2.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the two arguments of the normal cdf appearing in the cdf of
+    /// the inverse gaussian distribution at `x > 0`
+    fn std_args(&self, x: f64) -> (f64, f64) {
+        let r = (self.shape / x).sqrt();
+        let z = x / self.mean;
+        (r * (z - 1.0), -r * (z + 1.0))
+    }
+
+    /// Returns `e^(2λ / μ) * Φ(b)`, computed in log space since the
+    /// exponential overflows for large `λ / μ`
+    fn reflected_term(&self, b: f64) -> f64 {
+        (2.0 * self.shape / self.mean + normal::ln_std_cdf(b)).exp()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for InverseGaussian {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        sample_unchecked(r, self.mean, self.shape)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for InverseGaussian {
+    /// Calculates the cumulative distribution function for the inverse
+    /// gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(sqrt(λ / x) * (x / μ - 1)) + e^(2λ / μ) * Φ(-sqrt(λ / x) * (x / μ + 1))
+    /// ```
+    ///
+    /// where `μ` is the mean, `λ` is the shape and `Φ` is the cdf of the
+    /// standard normal distribution
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            let (a, b) = self.std_args(x);
+            normal::cdf_unchecked(a, 0.0, 1.0) + self.reflected_term(b)
+        }
+    }
+
+    /// Calculates the survival function for the inverse gaussian
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(-sqrt(λ / x) * (x / μ - 1)) - e^(2λ / μ) * Φ(-sqrt(λ / x) * (x / μ + 1))
+    /// ```
+    ///
+    /// where `μ` is the mean, `λ` is the shape and `Φ` is the cdf of the
+    /// standard normal distribution
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            let (a, b) = self.std_args(x);
+            (normal::sf_unchecked(a, 0.0, 1.0) - self.reflected_term(b)).max(0.0)
+        }
+    }
+}
+
+impl Min<f64> for InverseGaussian {
+    /// Returns the minimum value in the domain of the
+    /// inverse gaussian distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for InverseGaussian {
+    /// Returns the maximum value in the domain of the
+    /// inverse gaussian distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for InverseGaussian {
+    /// Returns the mean of the inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn mean(&self) -> Option<f64> {
+        Some(self.mean)
+    }
+    /// Returns the variance of the inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ^3 / λ
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn variance(&self) -> Option<f64> {
+        Some(self.mean * self.mean * self.mean / self.shape)
+    }
+    /// Returns the skewness of the inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3 * sqrt(μ / λ)
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn skewness(&self) -> Option<f64> {
+        Some(3.0 * (self.mean / self.shape).sqrt())
+    }
+    /// Returns the excess kurtosis of the inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 15μ / λ
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn kurtosis(&self) -> Option<f64> {
+        Some(15.0 * self.mean / self.shape)
+    }
+}
+
+impl GeneratingFunction<f64> for InverseGaussian {
+    /// Returns the moment generating function of the inverse gaussian
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t > λ / (2μ^2)` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^((λ / μ) * (1 - sqrt(1 - 2μ^2 * t / λ)))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the inverse gaussian
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t > λ / (2μ^2)` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (λ / μ) * (1 - sqrt(1 - 2μ^2 * t / λ)) = 2μt / (1 + sqrt(1 - 2μ^2 * t / λ))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape. The second form avoids
+    /// the cancellation of the first for small `t`
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let u = 1.0 - 2.0 * self.mean * self.mean * t / self.shape;
+        if u < 0.0 {
+            None
+        } else {
+            Some(2.0 * self.mean * t / (1.0 + u.sqrt()))
+        }
+    }
+    /// Returns the characteristic function of the inverse gaussian
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^((λ / μ) * (1 - sqrt(1 - 2iμ^2 * t / λ)))
+    /// ```
+    ///
+    /// where `μ` is the mean, `λ` is the shape and the square root is the
+    /// principal one
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let u = Complex::new(1.0, -2.0 * self.mean * self.mean * t / self.shape);
+        (Complex::new(0.0, 2.0 * self.mean * t) / (u.sqrt() + 1.0)).exp()
+    }
+}
+
+impl Mode<Option<f64>> for InverseGaussian {
+    /// Returns the mode of the inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ * (sqrt(1 + 9μ^2 / (4λ^2)) - 3μ / (2λ))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn mode(&self) -> Option<f64> {
+        let r = 1.5 * self.mean / self.shape;
+        // μ / (sqrt(1 + r^2) + r) avoids the cancellation for small r
+        Some(self.mean / ((1.0 + r * r).sqrt() + r))
+    }
+}
+
+impl Continuous<f64, f64> for InverseGaussian {
+    /// Calculates the probability density function for the inverse
+    /// gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(λ / (2π * x^3)) * e^(-λ * (x - μ)^2 / (2μ^2 * x))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the inverse
+    /// gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sqrt(λ / (2π * x^3)) * e^(-λ * (x - μ)^2 / (2μ^2 * x)))
+    /// ```
+    ///
+    /// where `μ` is the mean and `λ` is the shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            let d = (x - self.mean) / self.mean;
+            0.5 * (self.shape / x).ln()
+                - consts::LN_SQRT_2PI
+                - x.ln()
+                - 0.5 * self.shape * d * d / x
+        }
+    }
+}
+
+/// Samples from an inverse gaussian distribution with a mean of `mean` and
+/// a shape of `shape` using `rng` as the source of randomness. Implementation
+/// from:
+/// <br />
+/// <div>
+/// <i>"Generating Random Variates Using Transformations with Multiple
+/// Roots"</i> - Michael, J.R., Schucany, W.R. & Haas, R.W.
+/// </div>
+/// <div>
+/// The American Statistician, Vol. 30, No. 2, May 1976, Pages 88-90
+/// </div>
+/// <br />
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, mean: f64, shape: f64) -> f64 {
+    let n = ziggurat::sample_std_normal(rng);
+    let y = mean * n * n;
+    // the smaller root μ + μy / 2λ - μ / 2λ * sqrt(4λy + y^2), rearranged
+    // so the difference does not cancel for large y
+    let s = (y * (4.0 * shape + y)).sqrt();
+    let x = mean * (s - y) / (s + y);
+    if rng.gen::<f64>() * (mean + x) <= mean {
+        x
+    } else {
+        mean * mean / x
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, InverseGaussian};
+    use crate::distribution::internal::*;
+
+    fn try_create(mean: f64, shape: f64) -> InverseGaussian {
+        let n = InverseGaussian::new(mean, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(mean: f64, shape: f64) {
+        let n = try_create(mean, shape);
+        assert_eq!(mean, n.mean().unwrap());
+        assert_eq!(shape, n.shape());
+    }
+
+    fn bad_create_case(mean: f64, shape: f64) {
+        let n = InverseGaussian::new(mean, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(mean: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(InverseGaussian) -> f64
+    {
+        let n = try_create(mean, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(mean: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(InverseGaussian) -> f64
+    {
+        let n = try_create(mean, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(1.0, 1.0);
+        create_case(0.1, 2.0);
+        create_case(3.0, 0.5);
+        create_case(10.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(0.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: InverseGaussian| x.mean().unwrap();
+        test_case(1.0, 1.0, 1.0, mean);
+        test_case(3.0, 0.5, 3.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: InverseGaussian| x.variance().unwrap();
+        test_case(1.0, 1.0, 1.0, variance);
+        test_case(3.0, 0.5, 54.0, variance);
+        test_almost(0.1, 2.0, 0.0005, 1e-18, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: InverseGaussian| x.skewness().unwrap();
+        test_case(1.0, 1.0, 3.0, skewness);
+        test_case(3.0, 0.5, 3.0 * 6.0f64.sqrt(), skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: InverseGaussian| x.kurtosis().unwrap();
+        test_case(1.0, 1.0, 15.0, kurtosis);
+        test_case(3.0, 0.5, 90.0, kurtosis);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: InverseGaussian| x.mgf(t).unwrap();
+        test_almost(1.0, 1.0, 0.19286760478082796585, 1e-16, mgf(-3.0));
+        test_almost(1.0, 1.0, 1.4441840170279413399, 1e-15, mgf(0.3));
+        test_almost(1.0, 1.0, 2.7182818284590452354, 1e-15, mgf(0.5));
+        test_almost(2.0, 5.0, 0.029575845257343063336, 1e-17, mgf(-3.0));
+        test_almost(2.0, 5.0, 12.182493960703473438, 1e-14, mgf(0.625));
+        test_almost(0.5, 0.2, 1.2214027581601698136, 1e-15, mgf(0.3));
+        assert_eq!(None, try_create(1.0, 1.0).mgf(0.6));
+        assert_eq!(None, try_create(2.0, 5.0).mgf(1.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: InverseGaussian| x.cgf(t).unwrap();
+        test_almost(1.0, 1.0, -1.6457513110645905905, 1e-15, cgf(-3.0));
+        test_almost(1.0, 1.0, 1.0000000050000000709e-8, 1e-23, cgf(1e-8));
+        test_almost(1.0, 1.0, 0.36754446796632411605, 1e-15, cgf(0.3));
+        test_case(1.0, 1.0, 1.0, cgf(0.5));
+        test_almost(2.0, 5.0, 2.0000000080000001058e-8, 1e-23, cgf(1e-8));
+        test_almost(2.0, 5.0, 0.69722436226800532265, 1e-15, cgf(0.3));
+        test_almost(0.5, 0.2, -0.76619037896906010815, 1e-15, cgf(-3.0));
+        test_almost(0.5, 0.2, 5.0000000312500004952e-9, 1e-24, cgf(1e-8));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: InverseGaussian| x.cf(t).re;
+        let im = |t| move |x: InverseGaussian| x.cf(t).im;
+        test_almost(1.0, 1.0, 0.17316588526693271989, 1e-15, re(-2.0));
+        test_almost(1.0, 1.0, -0.52049559341810955707, 1e-15, im(-2.0));
+        test_almost(1.0, 1.0, -0.10603952879541662373, 1e-15, re(10.0));
+        test_almost(1.0, 1.0, 0.006089087312650704969, 1e-15, im(10.0));
+        test_almost(2.0, 5.0, 0.50017619368929474139, 1e-15, re(0.5));
+        test_almost(2.0, 5.0, 0.67978926816748304521, 1e-15, im(0.5));
+        test_almost(0.5, 0.2, 0.064690623632929113296, 1e-15, re(10.0));
+        test_almost(0.5, 0.2, 0.34648798326809114982, 1e-15, im(10.0));
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: InverseGaussian| x.mode().unwrap();
+        test_almost(1.0, 1.0, 0.30277563773199464655961, 1e-16, mode);
+        test_almost(3.0, 0.5, 0.166155414412249879721, 1e-16, mode);
+        test_almost(10.0, 100.0, 8.61187420807834218976, 1e-14, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: InverseGaussian| x.min();
+        let max = |x: InverseGaussian| x.max();
+        test_case(1.0, 1.0, 0.0, min);
+        test_case(1.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: InverseGaussian| x.pdf(arg);
+        test_case(1.0, 1.0, 0.0, pdf(0.0));
+        test_case(1.0, 1.0, 0.0, pdf(-1.0));
+        test_case(1.0, 1.0, 0.0, pdf(f64::INFINITY));
+        test_almost(1.0, 1.0, 2.0811768202028318442e-19, 1e-33, pdf(0.01));
+        test_almost(1.0, 1.0, 0.87878257893544479409, 1e-15, pdf(0.5));
+        test_almost(1.0, 1.0, 0.39894228040143267794, 1e-15, pdf(1.0));
+        test_almost(1.0, 1.0, 0.0072041689344307325893, 1e-17, pdf(5.0));
+        test_almost(3.0, 0.5, 0.56382375082060510528, 1e-15, pdf(0.5));
+        test_almost(3.0, 0.5, 0.00087410662255628679735, 1e-18, pdf(30.0));
+        test_almost(0.1, 2.0, 2.0209084334147658898e-14, 1e-27, pdf(0.5));
+        test_almost(10.0, 100.0, 1.5872793339833197835e-7, 1e-21, pdf(2.0));
+        test_almost(10.0, 100.0, 0.00003089809027493865152, 1e-19, pdf(30.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: InverseGaussian| x.ln_pdf(arg);
+        test_case(1.0, 1.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_case(1.0, 1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+        test_almost(1.0, 1.0, -43.01618325422253468, 1e-13, ln_pdf(0.01));
+        test_almost(1.0, 1.0, -0.12921776236475477765, 1e-15, ln_pdf(0.5));
+        test_almost(1.0, 1.0, -0.91893853320467274178, 1e-15, ln_pdf(1.0));
+        test_almost(3.0, 0.5, -7.0423081959778784596, 1e-14, ln_pdf(30.0));
+        test_almost(0.1, 2.0, -2985.7074943487509345, 1e-12, ln_pdf(30.0));
+        test_almost(10.0, 100.0, -4981.713598161228386, 1e-12, ln_pdf(0.01));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: InverseGaussian| x.cdf(arg);
+        test_case(1.0, 1.0, 0.0, cdf(0.0));
+        test_case(1.0, 1.0, 0.0, cdf(-1.0));
+        test_case(1.0, 1.0, 1.0, cdf(f64::INFINITY));
+        // the normal cdf is only accurate to about 1e-10 relative
+        test_almost(1.0, 1.0, 4.122313403318782398e-23, 1e-32, cdf(0.01));
+        test_almost(1.0, 1.0, 0.36497554817295989059, 1e-10, cdf(0.5));
+        test_almost(1.0, 1.0, 0.66810200122317060643, 1e-10, cdf(1.0));
+        test_almost(1.0, 1.0, 0.99999999636863412742, 1e-10, cdf(30.0));
+        test_almost(3.0, 0.5, 1.8158080915533621137e-12, 1e-21, cdf(0.01));
+        test_almost(3.0, 0.5, 0.86196270456343589426, 1e-10, cdf(5.0));
+        test_almost(10.0, 100.0, 2.0555739317770773945e-19, 1e-28, cdf(1.0));
+        test_almost(10.0, 100.0, 0.017453372140657151887, 1e-11, cdf(5.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: InverseGaussian| x.sf(arg);
+        test_case(1.0, 1.0, 1.0, sf(0.0));
+        test_case(1.0, 1.0, 0.0, sf(f64::INFINITY));
+        // the normal cdf is only accurate to about 1e-10 relative
+        test_almost(1.0, 1.0, 0.63502445182704010941, 1e-10, sf(0.5));
+        test_almost(1.0, 1.0, 0.11452457401399357173, 1e-10, sf(2.0));
+        test_almost(1.0, 1.0, 3.6313658725812105342e-9, 1e-18, sf(30.0));
+        test_almost(3.0, 0.5, 0.014074125164383178636, 1e-11, sf(30.0));
+        test_almost(0.1, 2.0, 2.0393834144458202578e-16, 1e-25, sf(0.5));
+        test_almost(0.1, 2.0, 8.0828663732944702306e-82, 1e-91, sf(2.0));
+        test_almost(10.0, 100.0, 0.000062080402517256264888, 1e-13, sf(30.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_inverse_cdf(&try_create(1.0, 1.0), 1e-9);
+        tests::check_inverse_cdf(&try_create(3.0, 0.5), 1e-9);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0, 1.0), 0.01, 40.0);
+        tests::check_continuous_distribution(&try_create(0.5, 4.0), 0.01, 10.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(2.0, 3.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean - 2.0).abs() < 5.0 * (8.0 / 3.0 / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 1.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(1.0)).abs() < 0.015);
+    }
+}
@@ -0,0 +1,339 @@
+use crate::distribution::{internal, Continuous};
+use crate::function::gamma;
+use crate::statistics::{MeanN, Mode, VarianceN};
+use crate::Result;
+use nalgebra::DMatrix;
+use rand::Rng;
+use std::f64;
+use std::f64::consts::LN_2;
+
+/// Implements the [inverse
+/// Wishart](https://en.wikipedia.org/wiki/Inverse-Wishart_distribution)
+/// distribution over symmetric positive-definite matrices using the
+/// "nalgebra" crate for matrix operations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{InverseWishart, Continuous};
+/// use nalgebra::DMatrix;
+/// use statrs::statistics::MeanN;
+///
+/// let w = InverseWishart::new(4., DMatrix::from_vec(2, 2, vec![1., 0., 0., 1.])).unwrap();
+/// assert_eq!(w.mean().unwrap(), DMatrix::from_vec(2, 2, vec![1., 0., 0., 1.]));
+/// let x = DMatrix::from_vec(2, 2, vec![1., 0., 0., 1.]);
+/// assert!((w.ln_pdf(&x) - (-4.2241714275292361)).abs() < 1e-14);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InverseWishart {
+    freedom: f64,
+    scale: DMatrix<f64>,
+    scale_chol_decomp: DMatrix<f64>,
+    ln_pdf_const: f64,
+}
+
+impl InverseWishart {
+    /// Constructs a new inverse Wishart distribution with `freedom` degrees
+    /// of freedom and scale matrix `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is empty, not symmetric or not
+    /// positive-definite, or has non-finite entries, or if `freedom` is not
+    /// finite or not greater than the dimension less one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseWishart;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let mut result = InverseWishart::new(2., DMatrix::from_vec(2, 2, vec![2., 1., 1., 2.]));
+    /// assert!(result.is_ok());
+    ///
+    /// result = InverseWishart::new(1., DMatrix::from_vec(2, 2, vec![2., 1., 1., 2.]));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, scale: DMatrix<f64>) -> Result<Self> {
+        let scale_chol_decomp = internal::parse_scale(freedom, &scale)?.unpack();
+        let p = scale.nrows();
+        let ln_det = internal::ln_det_from_cholesky(&scale_chol_decomp);
+        let ln_pdf_const = 0.5 * freedom * (ln_det - p as f64 * LN_2)
+            - gamma::ln_multivariate_gamma(0.5 * freedom, p as u64);
+        Ok(InverseWishart {
+            freedom,
+            scale,
+            scale_chol_decomp,
+            ln_pdf_const,
+        })
+    }
+
+    /// Returns the degrees of freedom of the inverse Wishart distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseWishart;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let w = InverseWishart::new(3., DMatrix::identity(2, 2)).unwrap();
+    /// assert_eq!(w.freedom(), 3.);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the scale matrix of the inverse Wishart distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::InverseWishart;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let w = InverseWishart::new(3., DMatrix::identity(2, 2)).unwrap();
+    /// assert_eq!(*w.scale(), DMatrix::identity(2, 2));
+    /// ```
+    pub fn scale(&self) -> &DMatrix<f64> {
+        &self.scale
+    }
+
+    fn dim(&self) -> usize {
+        self.scale.nrows()
+    }
+}
+
+impl ::rand::distributions::Distribution<DMatrix<f64>> for InverseWishart {
+    /// Samples from the inverse Wishart distribution by inverting a
+    /// Bartlett decomposition
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// L * inv(transpose(A)) * inv(A) * transpose(L)
+    /// ```
+    ///
+    /// where `L` is the Cholesky decomposition of the scale matrix and `A`
+    /// is lower triangular with square roots of chi-squared variables with
+    /// `ν - i` degrees of freedom on the `i`th diagonal entry and standard
+    /// normal variables below it
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMatrix<f64> {
+        let p = self.dim();
+        let a = internal::sample_bartlett(rng, self.freedom, p);
+        let a_inv_t = a
+            .tr_solve_lower_triangular(&DMatrix::identity(p, p))
+            .unwrap();
+        let m = &self.scale_chol_decomp * a_inv_t;
+        &m * m.transpose()
+    }
+}
+
+impl MeanN<DMatrix<f64>> for InverseWishart {
+    /// Returns the mean of the inverse Wishart distribution
+    ///
+    /// # None
+    ///
+    /// If `ν <= p + 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Ψ / (ν - p - 1)
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension and `Ψ` the
+    /// scale matrix
+    fn mean(&self) -> Option<DMatrix<f64>> {
+        let p = self.dim() as f64;
+        if self.freedom > p + 1.0 {
+            Some(&self.scale / (self.freedom - p - 1.0))
+        } else {
+            None
+        }
+    }
+}
+
+impl VarianceN<DMatrix<f64>> for InverseWishart {
+    /// Returns the variance of each entry of the inverse Wishart
+    /// distribution
+    ///
+    /// # None
+    ///
+    /// If `ν <= p + 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Var(X_ij) = ((ν - p + 1) * Ψ_ij^2 + (ν - p - 1) * Ψ_ii * Ψ_jj)
+    ///     / ((ν - p) * (ν - p - 1)^2 * (ν - p - 3))
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension and `Ψ` the
+    /// scale matrix
+    fn variance(&self) -> Option<DMatrix<f64>> {
+        let k = self.freedom - self.dim() as f64;
+        if k > 3.0 {
+            let c = 1.0 / (k * (k - 1.0) * (k - 1.0) * (k - 3.0));
+            Some(internal::elementwise_variance(
+                &self.scale,
+                k + 1.0,
+                k - 1.0,
+                c,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl Mode<Option<DMatrix<f64>>> for InverseWishart {
+    /// Returns the mode of the inverse Wishart distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Ψ / (ν + p + 1)
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension and `Ψ` the
+    /// scale matrix
+    fn mode(&self) -> Option<DMatrix<f64>> {
+        Some(&self.scale / (self.freedom + self.dim() as f64 + 1.0))
+    }
+}
+
+impl<'a> Continuous<&'a DMatrix<f64>, f64> for InverseWishart {
+    /// Calculates the probability density function for the inverse Wishart
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` is not a symmetric positive-definite matrix of
+    /// the right dimension
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// det(Ψ)^(ν / 2) * det(x)^(-(ν + p + 1) / 2) * e^(-tr(Ψ * inv(x)) / 2)
+    ///     / (2^(ν * p / 2) * Γ_p(ν / 2))
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension, `Ψ` the scale
+    /// matrix and `Γ_p` the multivariate gamma function
+    fn pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the inverse
+    /// Wishart distribution at `x`. Equivalent to pdf(x).ln().
+    fn ln_pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        let p = self.dim();
+        let cholesky_x = match internal::support_cholesky(x, p) {
+            Some(c) => c,
+            None => return f64::NEG_INFINITY,
+        };
+        let ln_det_x = internal::ln_det_from_cholesky(cholesky_x.l_dirty());
+        let trace = cholesky_x.solve(&self.scale).trace();
+        self.ln_pdf_const - 0.5 * (self.freedom + p as f64 + 1.0) * ln_det_x - 0.5 * trace
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::{ChiSquared, Continuous, InverseGamma, InverseWishart};
+    use crate::distribution::internal::*;
+    use crate::statistics::*;
+    use nalgebra::DMatrix;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn try_create(freedom: f64, scale: DMatrix<f64>) -> InverseWishart {
+        let w = InverseWishart::new(freedom, scale);
+        assert!(w.is_ok());
+        w.unwrap()
+    }
+
+    fn bad_create_case(freedom: f64, scale: DMatrix<f64>) {
+        let w = InverseWishart::new(freedom, scale);
+        assert!(w.is_err());
+    }
+
+    macro_rules! mat2 {
+        ($x11:expr, $x12:expr, $x21:expr, $x22:expr) => (DMatrix::from_vec(2,2,vec![$x11, $x12, $x21, $x22]));
+    }
+
+    fn mat3() -> DMatrix<f64> {
+        DMatrix::from_vec(3, 3, vec![2., 1., 0., 1., 3., 1., 0., 1., 4.])
+    }
+
+    #[test]
+    fn test_create() {
+        let w = try_create(3., mat2![2., 1., 1., 2.]);
+        assert_eq!(w.freedom(), 3.);
+        assert_eq!(*w.scale(), mat2![2., 1., 1., 2.]);
+        try_create(1.5, mat2![2., 1., 1., 2.]);
+        try_create(2.5, mat3());
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(1., mat2![2., 1., 1., 2.]);
+        bad_create_case(f64::NAN, mat2![2., 1., 1., 2.]);
+        bad_create_case(f64::INFINITY, mat2![2., 1., 1., 2.]);
+        bad_create_case(3., mat2![2., 1., 0., 2.]);
+        bad_create_case(3., mat2![1., 2., 2., 1.]);
+        bad_create_case(3., DMatrix::from_vec(0, 0, vec![]));
+    }
+
+    #[test]
+    fn test_moments() {
+        let w = try_create(6., mat2![2., 1., 1., 2.]);
+        assert_eq!(w.mean().unwrap(), mat2![2. / 3., 1. / 3., 1. / 3., 2. / 3.]);
+        // ((5 * Ψ_ij^2 + 3 * Ψ_ii * Ψ_jj) / (4 * 9 * 1)
+        assert_eq!(w.variance().unwrap(), mat2![32. / 36., 17. / 36., 17. / 36., 32. / 36.]);
+        assert_eq!(w.mode().unwrap(), mat2![2. / 9., 1. / 9., 1. / 9., 2. / 9.]);
+        let w = try_create(3., mat2![2., 1., 1., 2.]);
+        assert_eq!(w.mean(), None);
+        assert_eq!(w.variance(), None);
+        assert_eq!(try_create(5., mat2![2., 1., 1., 2.]).variance(), None);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let w = try_create(3., mat2![1., 0., 0., 1.]);
+        assert_almost_eq!(w.ln_pdf(&mat2![1., 0., 0., 1.]), -3.5310242469692907930, 1e-14);
+        let w = try_create(4.5, mat2![2., 1., 1., 2.]);
+        assert_almost_eq!(w.ln_pdf(&mat2![3., -1., -1., 5.]), -11.799442328727480874, 1e-14);
+        assert_almost_eq!(w.pdf(&mat2![3., -1., -1., 5.]), 7.5087441586044427785e-6, 1e-19);
+        let w = try_create(2.5, mat3());
+        let x = DMatrix::from_vec(3, 3, vec![4., 2., 1., 2., 6., 0.5, 1., 0.5, 3.]);
+        assert_almost_eq!(w.ln_pdf(&x), -16.370296550216779459, 1e-13);
+        assert_eq!(w.ln_pdf(&mat2![1., 0., 0., 1.]), f64::NEG_INFINITY);
+        assert_eq!(w.pdf(&DMatrix::from_vec(3, 3, vec![1., 0., 0., 0., 1., 0., 0., 0., -1.])), 0.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let w = try_create(4.5, mat3());
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let samples: Vec<DMatrix<f64>> = (0..10_000).map(|_| w.sample(&mut rng)).collect();
+        // diagonal entries are inverse gamma
+        let diagonal = samples.iter().map(|x| x[(1, 1)]).collect();
+        tests::check_kolmogorov_smirnov(&InverseGamma::new(1.25, 1.5).unwrap(), diagonal);
+        // the inverse is Wishart with scale inv(Ψ), so for any vector v,
+        // v' * inv(X) * v / (v' * inv(Ψ) * v) is chi-squared with ν degrees
+        // of freedom
+        let v = DMatrix::from_vec(3, 1, vec![1., -2., 0.5]);
+        let scale = (v.transpose() * mat3().try_inverse().unwrap() * &v)[(0, 0)];
+        let forms = samples
+            .iter()
+            .map(|x| {
+                assert_eq!(*x, x.transpose());
+                (v.transpose() * x.clone().try_inverse().unwrap() * &v)[(0, 0)] / scale
+            })
+            .collect();
+        tests::check_kolmogorov_smirnov(&ChiSquared::new(4.5).unwrap(), forms);
+    }
+}
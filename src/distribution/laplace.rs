@@ -0,0 +1,715 @@
+use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
+use crate::function::factorial;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use num_complex::Complex;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Laplace](https://en.wikipedia.org/wiki/Laplace_distribution)
+/// distribution, also known as the double exponential distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Laplace, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Laplace::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Laplace {
+    location: f64,
+    scale: f64,
+}
+
+impl Laplace {
+    /// Constructs a new laplace distribution with the given
+    /// location and scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if location or scale are `NaN` or if
+    /// `scale <= 0.0` or `scale == INF`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Laplace;
+    ///
+    /// let mut result = Laplace::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Laplace::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Laplace> {
+        if location.is_nan() || scale.is_nan() || scale <= 0.0 || scale.is_infinite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Laplace { location, scale })
+        }
+    }
+
+    /// Returns the location of the laplace distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Laplace;
+    ///
+    /// let n = Laplace::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.location(), 0.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the laplace distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Laplace;
+    ///
+    /// let n = Laplace::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Laplace {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let e = self.scale * ziggurat::sample_exp_1(r);
+        if r.gen::<bool>() {
+            self.location + e
+        } else {
+            self.location - e
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Laplace {
+    /// Calculates the cumulative distribution function for the
+    /// laplace distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < μ {
+    ///     (1 / 2) * e^((x - μ) / b)
+    /// } else {
+    ///     1 - (1 / 2) * e^(-(x - μ) / b)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 {
+            0.5 * z.exp()
+        } else {
+            1.0 - 0.5 * (-z).exp()
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// laplace distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x > μ {
+    ///     (1 / 2) * e^(-(x - μ) / b)
+    /// } else {
+    ///     1 - (1 / 2) * e^((x - μ) / b)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z > 0.0 {
+            0.5 * (-z).exp()
+        } else {
+            1.0 - 0.5 * z.exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// laplace distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if p <= 1 / 2 {
+    ///     μ + b * ln(2p)
+    /// } else {
+    ///     μ - b * ln(2(1 - p))
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p <= 0.5 {
+            self.location + self.scale * (2.0 * p).ln()
+        } else {
+            self.location - self.scale * (2.0 * (1.0 - p)).ln()
+        }
+    }
+}
+
+impl Min<f64> for Laplace {
+    /// Returns the minimum value in the domain of the laplace
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Laplace {
+    /// Returns the maximum value in the domain of the laplace
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Laplace {
+    /// Returns the mean of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * b^2
+    /// ```
+    ///
+    /// where `b` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * self.scale * self.scale)
+    }
+    /// Returns the entropy of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2eb)
+    /// ```
+    ///
+    /// where `b` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some(1.0 + (2.0 * self.scale).ln())
+    }
+    /// Returns the skewness of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    /// Returns the excess kurtosis of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(3.0)
+    }
+    /// Returns the `k`th central moment of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if k is even {
+    ///     k! * b^k
+    /// } else {
+    ///     0
+    /// }
+    /// ```
+    ///
+    /// where `b` is the scale
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        if k % 2 == 1 {
+            Some(0.0)
+        } else {
+            Some(factorial::factorial(u64::from(k)) * self.scale.powi(k as i32))
+        }
+    }
+}
+
+impl GeneratingFunction<f64> for Laplace {
+    /// Returns the moment generating function of the laplace distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `|t| >= 1 / b` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μt) / (1 - b^2 * t^2)
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let x = self.scale * t;
+        if x.abs() >= 1.0 {
+            None
+        } else {
+            Some((self.location * t).exp() / ((1.0 - x) * (1.0 + x)))
+        }
+    }
+    /// Returns the cumulant generating function of the laplace
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `|t| >= 1 / b` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μt - ln(1 - b^2 * t^2)
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let x = self.scale * t;
+        if x.abs() >= 1.0 {
+            None
+        } else {
+            let ln_denom = if x.abs() < 0.5 {
+                (-x * x).ln_1p()
+            } else {
+                ((1.0 - x) * (1.0 + x)).ln()
+            };
+            Some(self.location * t - ln_denom)
+        }
+    }
+    /// Returns the characteristic function of the laplace distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(iμt) / (1 + b^2 * t^2)
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let x = self.scale * t;
+        Complex::from_polar(1.0 / (1.0 + x * x), self.location * t)
+    }
+}
+
+impl Median<f64> for Laplace {
+    /// Returns the median of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for Laplace {
+    /// Returns the mode of the laplace distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Laplace {
+    /// Calculates the probability density function for the laplace
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2b) * e^(-|x - μ| / b)
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        (-(x - self.location).abs() / self.scale).exp() / (2.0 * self.scale)
+    }
+
+    /// Calculates the log probability density function for the laplace
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -|x - μ| / b - ln(2b)
+    /// ```
+    ///
+    /// where `μ` is the location and `b` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        -(x - self.location).abs() / self.scale - (2.0 * self.scale).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Laplace};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Laplace {
+        let n = Laplace::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Laplace::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Laplace) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Laplace) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(0.0, 1.0);
+        create_case(-5.0, 10.0);
+        create_case(10.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(f64::NAN, f64::NAN);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Laplace| x.mean().unwrap();
+        test_case(0.0, 1.0, 0.0, mean);
+        test_case(-2.0, 0.5, -2.0, mean);
+        test_case(3.0, 10.0, 3.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Laplace| x.variance().unwrap();
+        test_almost(0.0, 1.0, 2.0, 1e-14, variance);
+        test_almost(-2.0, 0.5, 0.5, 1e-14, variance);
+        test_almost(3.0, 10.0, 200.0, 1e-13, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Laplace| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 1.693147180559945309417, 1e-14, entropy);
+        test_almost(-2.0, 0.5, 1.0, 1e-14, entropy);
+        test_almost(3.0, 10.0, 3.995732273553990993435, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Laplace| x.skewness().unwrap();
+        test_case(0.0, 1.0, 0.0, skewness);
+        test_case(-2.0, 0.5, 0.0, skewness);
+        test_case(3.0, 10.0, 0.0, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Laplace| x.kurtosis().unwrap();
+        test_case(0.0, 1.0, 3.0, kurtosis);
+        test_case(-2.0, 0.5, 3.0, kurtosis);
+        test_case(3.0, 10.0, 3.0, kurtosis);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: Laplace| x.median();
+        test_case(0.0, 1.0, 0.0, median);
+        test_case(-2.0, 0.5, -2.0, median);
+        test_case(3.0, 10.0, 3.0, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Laplace| x.mode().unwrap();
+        test_case(0.0, 1.0, 0.0, mode);
+        test_case(-2.0, 0.5, -2.0, mode);
+        test_case(3.0, 10.0, 3.0, mode);
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Laplace| x.central_moment(k).unwrap();
+        test_case(3.0, 2.0, 1.0, central_moment(0));
+        test_case(3.0, 2.0, 0.0, central_moment(1));
+        test_case(3.0, 2.0, 8.0, central_moment(2));
+        test_case(3.0, 2.0, 0.0, central_moment(3));
+        test_case(3.0, 2.0, 384.0, central_moment(4));
+        test_case(3.0, 2.0, 46080.0, central_moment(6));
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Laplace| x.moment(k).unwrap();
+        test_case(3.0, 2.0, 3.0, moment(1));
+        test_case(3.0, 2.0, 17.0, moment(2));
+        test_case(3.0, 2.0, 99.0, moment(3));
+        test_case(3.0, 2.0, 897.0, moment(4));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Laplace| x.mgf(t).unwrap();
+        test_almost(0.0, 1.0, 5.2631578947368432124, 1e-14, mgf(-0.9));
+        test_almost(0.0, 1.0, 1.0666666666666666667, 1e-15, mgf(0.25));
+        test_almost(2.0, 0.5, 0.20727133319321195364, 1e-15, mgf(-0.9));
+        test_almost(2.0, 0.5, 1.8640601538521830499, 1e-15, mgf(0.3));
+        test_almost(2.0, 0.5, 458.47368711077719397, 1e-11, mgf(1.9));
+        test_almost(-1.0, 3.0, 3.8990432667458823186, 1e-14, mgf(0.3));
+        assert_eq!(None, try_create(0.0, 1.0).mgf(1.0));
+        assert_eq!(None, try_create(0.0, 1.0).mgf(-1.5));
+        assert_eq!(None, try_create(-1.0, 3.0).mgf(-0.9));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Laplace| x.cgf(t).unwrap();
+        test_almost(0.0, 1.0, 1.6607312068216511184, 1e-15, cgf(-0.9));
+        test_almost(0.0, 1.0, 1.0000000000000000918e-16, 1e-31, cgf(1e-8));
+        test_almost(0.0, 1.0, 0.094310679471241319557, 1e-16, cgf(0.3));
+        test_almost(2.0, 0.5, 2.0000000025000000418e-8, 1e-23, cgf(1e-8));
+        test_almost(2.0, 0.5, 6.1279029009783345164, 1e-14, cgf(1.9));
+        test_almost(-1.0, 3.0, 0.57667857318446793256, 1e-15, cgf(0.25));
+        assert_eq!(None, try_create(2.0, 0.5).cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Laplace| x.cf(t).re;
+        let im = |t| move |x: Laplace| x.cf(t).im;
+        test_case(0.0, 1.0, 0.2, re(-2.0));
+        test_case(0.0, 1.0, 0.0, im(-2.0));
+        test_almost(0.0, 1.0, 0.0099009900990099009901, 1e-17, re(10.0));
+        test_almost(2.0, 0.5, -0.32682181043180595732, 1e-15, re(-2.0));
+        test_almost(2.0, 0.5, 0.37840124765396412569, 1e-15, im(-2.0));
+        test_almost(2.0, 0.5, 0.015695463915899691772, 1e-16, re(10.0));
+        test_almost(2.0, 0.5, 0.035113278874139525168, 1e-16, im(10.0));
+        test_almost(-1.0, 3.0, 0.27002540365857622034, 1e-15, re(0.5));
+        test_almost(-1.0, 3.0, -0.14751555033975476931, 1e-15, im(0.5));
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Laplace| x.min();
+        let max = |x: Laplace| x.max();
+        test_case(0.0, 1.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Laplace| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.003368973499542733548318, 1e-17, pdf(-5.0));
+        test_almost(0.0, 1.0, 0.1839397205857211607978, 1e-15, pdf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.1839397205857211607978, 1e-15, pdf(1.0));
+        test_almost(0.0, 1.0, 0.003368973499542733548318, 1e-17, pdf(5.0));
+        test_almost(0.0, 1.0, 4.678811484420087302458e-14, 1e-28, pdf(30.0));
+        test_almost(-2.0, 0.5, 0.002478752176666358423045, 1e-17, pdf(-5.0));
+        test_almost(-2.0, 0.5, 0.135335283236612691894, 1e-15, pdf(-1.0));
+        test_almost(-2.0, 0.5, 0.01831563888873418029372, 1e-16, pdf(0.0));
+        test_almost(-2.0, 0.5, 0.002478752176666358423045, 1e-17, pdf(1.0));
+        test_almost(-2.0, 0.5, 8.31528719103567884064e-7, 1e-21, pdf(5.0));
+        test_almost(-2.0, 0.5, 1.603810890548637852976e-28, 1e-42, pdf(30.0));
+        test_almost(3.0, 10.0, 0.02246644820586107957151, 1e-16, pdf(-5.0));
+        test_almost(3.0, 10.0, 0.03351600230178196503722, 1e-16, pdf(-1.0));
+        test_almost(3.0, 10.0, 0.03704091103408589330334, 1e-16, pdf(0.0));
+        test_almost(3.0, 10.0, 0.0409365376538990929335, 1e-16, pdf(1.0));
+        test_almost(3.0, 10.0, 0.0409365376538990929335, 1e-16, pdf(5.0));
+        test_almost(3.0, 10.0, 0.003360275636987488256328, 1e-17, pdf(30.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Laplace| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -5.693147180559945309417, 1e-14, ln_pdf(-5.0));
+        test_almost(0.0, 1.0, -1.693147180559945309417, 1e-14, ln_pdf(-1.0));
+        test_almost(0.0, 1.0, -0.6931471805599453094172, 1e-15, ln_pdf(0.0));
+        test_almost(0.0, 1.0, -1.693147180559945309417, 1e-14, ln_pdf(1.0));
+        test_almost(0.0, 1.0, -5.693147180559945309417, 1e-14, ln_pdf(5.0));
+        test_almost(0.0, 1.0, -30.69314718055994530942, 1e-13, ln_pdf(30.0));
+        test_almost(-2.0, 0.5, -6.0, 1e-14, ln_pdf(-5.0));
+        test_almost(-2.0, 0.5, -2.0, 1e-14, ln_pdf(-1.0));
+        test_almost(-2.0, 0.5, -4.0, 1e-14, ln_pdf(0.0));
+        test_almost(-2.0, 0.5, -6.0, 1e-14, ln_pdf(1.0));
+        test_almost(-2.0, 0.5, -14.0, 1e-13, ln_pdf(5.0));
+        test_almost(-2.0, 0.5, -64.0, 1e-13, ln_pdf(30.0));
+        test_almost(3.0, 10.0, -3.795732273553990993435, 1e-14, ln_pdf(-5.0));
+        test_almost(3.0, 10.0, -3.395732273553990993435, 1e-14, ln_pdf(-1.0));
+        test_almost(3.0, 10.0, -3.295732273553990993435, 1e-14, ln_pdf(0.0));
+        test_almost(3.0, 10.0, -3.195732273553990993435, 1e-14, ln_pdf(1.0));
+        test_almost(3.0, 10.0, -3.195732273553990993435, 1e-14, ln_pdf(5.0));
+        test_almost(3.0, 10.0, -5.695732273553990993435, 1e-14, ln_pdf(30.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Laplace| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.003368973499542733548318, 1e-17, cdf(-5.0));
+        test_almost(0.0, 1.0, 0.1839397205857211607978, 1e-15, cdf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, cdf(0.0));
+        test_almost(0.0, 1.0, 0.8160602794142788392022, 1e-15, cdf(1.0));
+        test_almost(0.0, 1.0, 0.9966310265004572664517, 1e-15, cdf(5.0));
+        test_almost(0.0, 1.0, 0.9999999999999532118852, 1e-15, cdf(30.0));
+        test_almost(-2.0, 0.5, 0.001239376088333179211523, 1e-17, cdf(-5.0));
+        test_almost(-2.0, 0.5, 0.932332358381693654053, 1e-15, cdf(-1.0));
+        test_almost(-2.0, 0.5, 0.9908421805556329098531, 1e-15, cdf(0.0));
+        test_almost(-2.0, 0.5, 0.9987606239116668207885, 1e-15, cdf(1.0));
+        test_almost(-2.0, 0.5, 0.9999995842356404482161, 1e-15, cdf(5.0));
+        test_almost(-2.0, 0.5, 1.0, 1e-14, cdf(30.0));
+        test_almost(3.0, 10.0, 0.2246644820586107957151, 1e-15, cdf(-5.0));
+        test_almost(3.0, 10.0, 0.3351600230178196503722, 1e-15, cdf(-1.0));
+        test_almost(3.0, 10.0, 0.3704091103408589330334, 1e-15, cdf(0.0));
+        test_almost(3.0, 10.0, 0.409365376538990929335, 1e-15, cdf(1.0));
+        test_almost(3.0, 10.0, 0.590634623461009070665, 1e-15, cdf(5.0));
+        test_almost(3.0, 10.0, 0.9663972436301251174367, 1e-15, cdf(30.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Laplace| x.sf(arg);
+        test_almost(0.0, 1.0, 0.9966310265004572664517, 1e-15, sf(-5.0));
+        test_almost(0.0, 1.0, 0.8160602794142788392022, 1e-15, sf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, sf(0.0));
+        test_almost(0.0, 1.0, 0.1839397205857211607978, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 0.003368973499542733548318, 1e-17, sf(5.0));
+        test_almost(0.0, 1.0, 4.678811484420087302458e-14, 1e-28, sf(30.0));
+        test_almost(-2.0, 0.5, 0.9987606239116668207885, 1e-15, sf(-5.0));
+        test_almost(-2.0, 0.5, 0.067667641618306345947, 1e-16, sf(-1.0));
+        test_almost(-2.0, 0.5, 0.009157819444367090146859, 1e-17, sf(0.0));
+        test_almost(-2.0, 0.5, 0.001239376088333179211523, 1e-17, sf(1.0));
+        test_almost(-2.0, 0.5, 4.15764359551783942032e-7, 1e-21, sf(5.0));
+        test_almost(-2.0, 0.5, 8.01905445274318926488e-29, 1e-43, sf(30.0));
+        test_almost(3.0, 10.0, 0.7753355179413892042849, 1e-15, sf(-5.0));
+        test_almost(3.0, 10.0, 0.6648399769821803496278, 1e-15, sf(-1.0));
+        test_almost(3.0, 10.0, 0.6295908896591410669666, 1e-15, sf(0.0));
+        test_almost(3.0, 10.0, 0.590634623461009070665, 1e-15, sf(1.0));
+        test_almost(3.0, 10.0, 0.409365376538990929335, 1e-15, sf(5.0));
+        test_almost(3.0, 10.0, 0.03360275636987488256328, 1e-16, sf(30.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Laplace| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, -22.33270374938051149433, 1e-13, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, -1.60943791243410031909, 1e-14, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 0.0, 1e-14, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 0.6931471805599453094172, 1e-14, inverse_cdf(0.75));
+        test_almost(0.0, 1.0, 6.214608098422190854458, 1e-14, inverse_cdf(0.999));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        test_case(-2.0, 0.5, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(-2.0, 0.5, -13.16635187469025574717, 1e-13, inverse_cdf(1e-10));
+        test_almost(-2.0, 0.5, -2.804718956217050159545, 1e-14, inverse_cdf(0.1));
+        test_almost(-2.0, 0.5, -2.0, 1e-14, inverse_cdf(0.5));
+        test_almost(-2.0, 0.5, -1.653426409720027345291, 1e-14, inverse_cdf(0.75));
+        test_almost(-2.0, 0.5, 1.107304049211095427229, 1e-14, inverse_cdf(0.999));
+        test_case(-2.0, 0.5, f64::INFINITY, inverse_cdf(1.0));
+        test_case(3.0, 10.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(3.0, 10.0, -220.3270374938051149433, 1e-12, inverse_cdf(1e-10));
+        test_almost(3.0, 10.0, -13.0943791243410031909, 1e-13, inverse_cdf(0.1));
+        test_almost(3.0, 10.0, 3.0, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 10.0, 9.931471805599453094172, 1e-14, inverse_cdf(0.75));
+        test_almost(3.0, 10.0, 65.14608098422190854458, 1e-13, inverse_cdf(0.999));
+        test_case(3.0, 10.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(-2.0, 0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(3.0, 10.0), 1e-12);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -40.0, 40.0);
+        tests::check_continuous_distribution(&try_create(-2.0, 0.5), -25.0, 20.0);
+    }
+}
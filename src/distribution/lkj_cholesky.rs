@@ -0,0 +1,230 @@
+use crate::distribution::lkj_corr::{ln_normalizer, sample_cholesky, UNIT_ACC};
+use crate::distribution::Continuous;
+use crate::prec;
+use crate::{Result, StatsError};
+use nalgebra::DMatrix;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [LKJ](https://en.wikipedia.org/wiki/Lewandowski-Kurowicka-Joe_distribution)
+/// distribution over the lower triangular Cholesky factors `L` of `d`
+/// dimensional correlation matrices `L * transpose(L)`
+///
+/// # Remarks
+///
+/// This is the distribution of the Cholesky factor of an `LKJCorr` sample,
+/// with the density taken with respect to the entries of `L` below the
+/// diagonal. Scaling the rows of a sample by standard deviations gives the
+/// Cholesky factor of a covariance matrix, the form in which
+/// `MultivariateNormal` stores its covariance.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{LKJCholesky, MultivariateNormal};
+/// use nalgebra::DMatrix;
+/// use rand::distributions::Distribution;
+///
+/// let lkj = LKJCholesky::new(3, 2.).unwrap();
+/// let mut rng = rand::thread_rng();
+/// let l = lkj.sample(&mut rng);
+/// let sd = DMatrix::from_diagonal(&nalgebra::DVector::from_vec(vec![1., 2., 0.5]));
+/// let l_cov = sd * l;
+/// let cov = &l_cov * l_cov.transpose();
+/// assert!(MultivariateNormal::new(vec![0., 0., 0.], cov.as_slice().to_vec()).is_ok());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LKJCholesky {
+    dim: usize,
+    eta: f64,
+    ln_norm: f64,
+}
+
+impl LKJCholesky {
+    /// Constructs a new LKJ distribution over the Cholesky factors of `dim`
+    /// dimensional correlation matrices with shape `eta`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dim < 2`, or if `eta` is not finite or not
+    /// greater than `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LKJCholesky;
+    ///
+    /// let mut result = LKJCholesky::new(3, 2.);
+    /// assert!(result.is_ok());
+    ///
+    /// result = LKJCholesky::new(1, 2.);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dim: usize, eta: f64) -> Result<LKJCholesky> {
+        if dim < 2 || !eta.is_finite() || eta <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(LKJCholesky {
+                dim,
+                eta,
+                ln_norm: ln_normalizer(dim, eta),
+            })
+        }
+    }
+
+    /// Returns the dimension of the correlation matrices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LKJCholesky;
+    ///
+    /// let lkj = LKJCholesky::new(3, 2.).unwrap();
+    /// assert_eq!(lkj.dim(), 3);
+    /// ```
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the shape `η` of the LKJ distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LKJCholesky;
+    ///
+    /// let lkj = LKJCholesky::new(3, 2.).unwrap();
+    /// assert_eq!(lkj.eta(), 2.);
+    /// ```
+    pub fn eta(&self) -> f64 {
+        self.eta
+    }
+}
+
+impl ::rand::distributions::Distribution<DMatrix<f64>> for LKJCholesky {
+    /// Samples from the LKJ distribution over Cholesky factors with the
+    /// onion method
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMatrix<f64> {
+        sample_cholesky(rng, self.dim, self.eta)
+    }
+}
+
+impl<'a> Continuous<&'a DMatrix<f64>, f64> for LKJCholesky {
+    /// Calculates the probability density function for the LKJ
+    /// distribution over Cholesky factors at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` unless `x` is a lower triangular matrix of the right
+    /// dimension with a positive diagonal and rows of unit length
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Π_k x_kk^(d - k + 2η - 2) / c
+    /// ```
+    ///
+    /// where `d` is the dimension, `Π` is the product from `k = 2` to `d` and
+    /// `c` is the integral of `det(R)^(η - 1)` over all correlation matrices
+    /// `R`
+    fn pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the LKJ
+    /// distribution over Cholesky factors at `x`. Equivalent to
+    /// pdf(x).ln().
+    fn ln_pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        let d = self.dim;
+        if x.nrows() != d
+            || x.ncols() != d
+            || x.iter().any(|v| !v.is_finite())
+            || x.upper_triangle() != DMatrix::from_diagonal(&x.diagonal())
+            || x.diagonal().iter().any(|&v| v <= 0.0)
+            || x.row_iter()
+                .any(|row| !prec::almost_eq(row.norm_squared(), 1.0, UNIT_ACC))
+        {
+            return f64::NEG_INFINITY;
+        }
+        (1..d)
+            .map(|k| (d as f64 - k as f64 - 1.0 + 2.0 * self.eta - 2.0) * x[(k, k)].ln())
+            .sum::<f64>()
+            - self.ln_norm
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::{Continuous, LKJCholesky, LKJCorr};
+    use nalgebra::DMatrix;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn try_create(dim: usize, eta: f64) -> LKJCholesky {
+        let lkj = LKJCholesky::new(dim, eta);
+        assert!(lkj.is_ok());
+        lkj.unwrap()
+    }
+
+    fn bad_create_case(dim: usize, eta: f64) {
+        let lkj = LKJCholesky::new(dim, eta);
+        assert!(lkj.is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let lkj = try_create(3, 2.);
+        assert_eq!(lkj.dim(), 3);
+        assert_eq!(lkj.eta(), 2.);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(1, 1.);
+        bad_create_case(3, 0.);
+        bad_create_case(3, f64::NAN);
+        bad_create_case(3, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        // in two dimensions the density of L_10 = r is that of the correlation
+        let lkj = try_create(2, 2.);
+        let r: f64 = 0.5;
+        let l = DMatrix::from_vec(2, 2, vec![1., r, 0., (1. - r * r).sqrt()]);
+        assert_almost_eq!(lkj.pdf(&l), 0.5625, 1e-14);
+        // otherwise it is the correlation density times the Jacobian
+        // Π_k L_kk^(d - k)
+        for &eta in &[0.5, 1., 2.5] {
+            let lkj = try_create(3, eta);
+            let corr = LKJCorr::new(3, eta).unwrap();
+            let r = DMatrix::from_vec(3, 3, vec![1., 0.5, -0.2, 0.5, 1., 0.1, -0.2, 0.1, 1.]);
+            let l = r.clone().cholesky().unwrap().unpack();
+            assert_almost_eq!(lkj.ln_pdf(&l), corr.ln_pdf(&r) + l[(1, 1)].ln(), 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf_outside_support() {
+        let lkj = try_create(2, 2.);
+        // not lower triangular
+        assert_eq!(lkj.ln_pdf(&DMatrix::from_vec(2, 2, vec![1., 0., 0.1, 1.])), f64::NEG_INFINITY);
+        // negative diagonal
+        assert_eq!(lkj.ln_pdf(&DMatrix::from_vec(2, 2, vec![1., 0.6, 0., -0.8])), f64::NEG_INFINITY);
+        // rows not of unit length
+        assert_eq!(lkj.ln_pdf(&DMatrix::from_vec(2, 2, vec![1., 0.6, 0., 0.7])), f64::NEG_INFINITY);
+        assert_eq!(lkj.pdf(&DMatrix::identity(3, 3)), 0.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let lkj = try_create(5, 0.8);
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        for _ in 0..1000 {
+            let l = lkj.sample(&mut rng);
+            assert!(lkj.ln_pdf(&l).is_finite());
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::distribution::{beta, normal, Continuous};
+use crate::function::beta as beta_fn;
+use crate::prec;
+use crate::statistics::{MeanN, Mode, VarianceN};
+use crate::{Result, StatsError};
+use nalgebra::{Cholesky, DMatrix, DVector};
+use rand::Rng;
+use std::f64;
+use std::f64::consts::LN_2;
+
+/// Tolerance on the unit diagonal of a correlation matrix and the unit
+/// rows of its Cholesky factor
+pub const UNIT_ACC: f64 = 1e-12;
+
+/// Implements the [LKJ](https://en.wikipedia.org/wiki/Lewandowski-Kurowicka-Joe_distribution)
+/// distribution over `d` dimensional correlation matrices using the
+/// "nalgebra" crate for matrix operations
+///
+/// # Remarks
+///
+/// The density is proportional to `det(R)^(η - 1)`, so `η = 1` is uniform
+/// over correlation matrices and larger `η` concentrates the mass around
+/// the identity. `LKJCholesky` is the same distribution over the Cholesky
+/// factors of the correlation matrices.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{LKJCorr, Continuous};
+/// use nalgebra::DMatrix;
+///
+/// let lkj = LKJCorr::new(3, 1.).unwrap();
+/// // uniform over a set of volume π^2 / 2
+/// let r = DMatrix::from_vec(3, 3, vec![1., 0.5, 0., 0.5, 1., 0.5, 0., 0.5, 1.]);
+/// assert!((lkj.pdf(&r) - 2. / (std::f64::consts::PI * std::f64::consts::PI)).abs() < 1e-15);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LKJCorr {
+    dim: usize,
+    eta: f64,
+    ln_norm: f64,
+}
+
+impl LKJCorr {
+    /// Constructs a new LKJ distribution over `dim` dimensional correlation
+    /// matrices with shape `eta`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dim < 2`, or if `eta` is not finite or not
+    /// greater than `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LKJCorr;
+    ///
+    /// let mut result = LKJCorr::new(3, 2.);
+    /// assert!(result.is_ok());
+    ///
+    /// result = LKJCorr::new(3, 0.);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dim: usize, eta: f64) -> Result<LKJCorr> {
+        if dim < 2 || !eta.is_finite() || eta <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(LKJCorr {
+                dim,
+                eta,
+                ln_norm: ln_normalizer(dim, eta),
+            })
+        }
+    }
+
+    /// Returns the dimension of the correlation matrices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LKJCorr;
+    ///
+    /// let lkj = LKJCorr::new(3, 2.).unwrap();
+    /// assert_eq!(lkj.dim(), 3);
+    /// ```
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the shape `η` of the LKJ distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::LKJCorr;
+    ///
+    /// let lkj = LKJCorr::new(3, 2.).unwrap();
+    /// assert_eq!(lkj.eta(), 2.);
+    /// ```
+    pub fn eta(&self) -> f64 {
+        self.eta
+    }
+}
+
+/// Returns the logarithm of the integral of `det(R)^(eta - 1)` over the
+/// `d` dimensional correlation matrices `R`
+///
+/// # Formula
+///
+/// ```ignore
+/// Σ_k (d - k) * ((2η - 2 + d - k) * ln(2) + ln(B(b_k, b_k)))
+/// ```
+///
+/// where `b_k = η + (d - k - 1) / 2`, `B` is the beta function and `Σ` is
+/// the sum from `k = 1` to `d - 1`, following Lewandowski, Kurowicka and
+/// Joe (2009)
+pub fn ln_normalizer(d: usize, eta: f64) -> f64 {
+    (1..d)
+        .map(|k| {
+            let m = (d - k) as f64;
+            let b = eta + 0.5 * (m - 1.0);
+            m * ((2.0 * eta - 2.0 + m) * LN_2 + beta_fn::ln_beta(b, b))
+        })
+        .sum()
+}
+
+/// draws the Cholesky factor of a sample from the LKJ distribution over
+/// `d` dimensional correlation matrices with shape `eta` using the onion
+/// method
+pub fn sample_cholesky<R: Rng + ?Sized>(rng: &mut R, d: usize, eta: f64) -> DMatrix<f64> {
+    let mut l = DMatrix::zeros(d, d);
+    l[(0, 0)] = 1.0;
+    for i in 1..d {
+        // squared norm of the off-diagonal part of the row
+        let y = beta::sample_unchecked(rng, 0.5 * i as f64, eta + 0.5 * (d - 1 - i) as f64);
+        let u = DVector::<f64>::from_fn(i, |_, _| normal::sample_unchecked(rng, 0.0, 1.0));
+        let w = u.normalize() * y.sqrt();
+        for j in 0..i {
+            l[(i, j)] = w[j];
+        }
+        l[(i, i)] = (1.0 - y).sqrt();
+    }
+    l
+}
+
+impl ::rand::distributions::Distribution<DMatrix<f64>> for LKJCorr {
+    /// Samples from the LKJ distribution by forming `L * transpose(L)` from
+    /// a Cholesky factor `L` drawn with the onion method
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMatrix<f64> {
+        let l = sample_cholesky(rng, self.dim, self.eta);
+        let mut r = &l * l.transpose();
+        r.fill_diagonal(1.0);
+        r
+    }
+}
+
+impl MeanN<DMatrix<f64>> for LKJCorr {
+    /// Returns the mean of the LKJ distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I
+    /// ```
+    ///
+    /// where `I` is the identity matrix
+    fn mean(&self) -> Option<DMatrix<f64>> {
+        Some(DMatrix::identity(self.dim, self.dim))
+    }
+}
+
+impl VarianceN<DMatrix<f64>> for LKJCorr {
+    /// Returns the variance of each entry of the LKJ distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Var(R_ij) = 1 / (2η + d - 1)
+    /// ```
+    ///
+    /// for `i != j`, where `d` is the dimension, and `0` on the diagonal
+    fn variance(&self) -> Option<DMatrix<f64>> {
+        let v = 1.0 / (2.0 * self.eta + self.dim as f64 - 1.0);
+        let mut var = DMatrix::from_element(self.dim, self.dim, v);
+        var.fill_diagonal(0.0);
+        Some(var)
+    }
+}
+
+impl Mode<Option<DMatrix<f64>>> for LKJCorr {
+    /// Returns the mode of the LKJ distribution
+    ///
+    /// # None
+    ///
+    /// If `η <= 1.0`, where the density is flat or grows towards singular
+    /// matrices
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I
+    /// ```
+    ///
+    /// where `I` is the identity matrix
+    fn mode(&self) -> Option<DMatrix<f64>> {
+        if self.eta > 1.0 {
+            Some(DMatrix::identity(self.dim, self.dim))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Continuous<&'a DMatrix<f64>, f64> for LKJCorr {
+    /// Calculates the probability density function for the LKJ
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` unless `x` is a symmetric positive-definite matrix of
+    /// the right dimension with a unit diagonal
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// det(x)^(η - 1) / c
+    /// ```
+    ///
+    /// where `c` is the integral of `det(R)^(η - 1)` over all correlation
+    /// matrices `R`
+    fn pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the LKJ
+    /// distribution at `x`. Equivalent to pdf(x).ln().
+    fn ln_pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        let d = self.dim;
+        if x.nrows() != d
+            || x.ncols() != d
+            || x.iter().any(|v| !v.is_finite())
+            || x.lower_triangle() != x.upper_triangle().transpose()
+            || x.diagonal()
+                .iter()
+                .any(|&v| !prec::almost_eq(v, 1.0, UNIT_ACC))
+        {
+            return f64::NEG_INFINITY;
+        }
+        match Cholesky::new(x.clone()) {
+            None => f64::NEG_INFINITY,
+            Some(c) => {
+                let ln_det = 2.0 * c.l_dirty().diagonal().iter().map(|v| v.ln()).sum::<f64>();
+                (self.eta - 1.0) * ln_det - self.ln_norm
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::{Beta, Continuous, LKJCorr};
+    use crate::distribution::internal::*;
+    use crate::statistics::*;
+    use nalgebra::DMatrix;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::f64::consts::PI;
+
+    fn try_create(dim: usize, eta: f64) -> LKJCorr {
+        let lkj = LKJCorr::new(dim, eta);
+        assert!(lkj.is_ok());
+        lkj.unwrap()
+    }
+
+    fn bad_create_case(dim: usize, eta: f64) {
+        let lkj = LKJCorr::new(dim, eta);
+        assert!(lkj.is_err());
+    }
+
+    fn corr3(a: f64, b: f64, c: f64) -> DMatrix<f64> {
+        DMatrix::from_vec(3, 3, vec![1., a, b, a, 1., c, b, c, 1.])
+    }
+
+    #[test]
+    fn test_create() {
+        let lkj = try_create(3, 2.);
+        assert_eq!(lkj.dim(), 3);
+        assert_eq!(lkj.eta(), 2.);
+        try_create(2, 0.1);
+        try_create(10, 100.);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0, 1.);
+        bad_create_case(1, 1.);
+        bad_create_case(3, 0.);
+        bad_create_case(3, -1.);
+        bad_create_case(3, f64::NAN);
+        bad_create_case(3, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let lkj = try_create(3, 2.);
+        assert_eq!(lkj.mean().unwrap(), DMatrix::identity(3, 3));
+        assert_eq!(lkj.variance().unwrap(), corr3(1. / 6., 1. / 6., 1. / 6.) - DMatrix::identity(3, 3));
+        assert_eq!(lkj.mode().unwrap(), DMatrix::identity(3, 3));
+        assert_eq!(try_create(3, 1.).mode(), None);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        // uniform on (-1, 1)
+        let lkj = try_create(2, 1.);
+        assert_almost_eq!(lkj.ln_pdf(&DMatrix::from_vec(2, 2, vec![1., 0.3, 0.3, 1.])), -(2f64.ln()), 1e-15);
+        // (1 - r^2) / (4 / 3)
+        let lkj = try_create(2, 2.);
+        assert_almost_eq!(lkj.pdf(&DMatrix::from_vec(2, 2, vec![1., 0.5, 0.5, 1.])), 0.5625, 1e-14);
+        // the 3 x 3 correlation matrices have volume π^2 / 2
+        let lkj = try_create(3, 1.);
+        assert_almost_eq!(lkj.ln_pdf(&corr3(0.5, -0.2, 0.1)), -(PI * PI / 2.).ln(), 1e-14);
+        let lkj = try_create(3, 2.5);
+        assert_almost_eq!(lkj.ln_pdf(&corr3(0.5, -0.2, 0.1)), -0.87147139633079330120, 1e-13);
+        let lkj = try_create(4, 0.5);
+        let x = DMatrix::from_vec(4, 4, vec![1., 0.3, -0.1, 0.2, 0.3, 1., 0.4, 0., -0.1, 0.4, 1., -0.3, 0.2, 0., -0.3, 1.]);
+        assert_almost_eq!(lkj.ln_pdf(&x), -3.6522882424865482708, 1e-13);
+    }
+
+    #[test]
+    fn test_ln_pdf_outside_support() {
+        let lkj = try_create(3, 2.);
+        // not positive-definite
+        assert_eq!(lkj.ln_pdf(&corr3(0.9, -0.9, 0.9)), f64::NEG_INFINITY);
+        // not a unit diagonal
+        assert_eq!(lkj.ln_pdf(&(corr3(0.5, 0., 0.) * 2.)), f64::NEG_INFINITY);
+        // not symmetric
+        assert_eq!(lkj.ln_pdf(&DMatrix::from_vec(3, 3, vec![1., 0.5, 0., 0.4, 1., 0., 0., 0., 1.])), f64::NEG_INFINITY);
+        // wrong dimension
+        assert_eq!(lkj.pdf(&DMatrix::identity(2, 2)), 0.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        // each correlation is 2 * X - 1 with X ~ Beta(b, b), b = η - 1 + d / 2
+        let lkj = try_create(4, 1.5);
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let samples: Vec<DMatrix<f64>> = (0..10_000).map(|_| lkj.sample(&mut rng)).collect();
+        for x in &samples {
+            assert!(lkj.ln_pdf(x).is_finite());
+        }
+        let beta = Beta::new(2.5, 2.5).unwrap();
+        for &(i, j) in &[(0, 1), (0, 3), (2, 3)] {
+            let r = samples.iter().map(|x| 0.5 * (x[(i, j)] + 1.)).collect();
+            tests::check_kolmogorov_smirnov(&beta, r);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::erf;
+use crate::function::{erf, factorial};
 use crate::statistics::*;
 use crate::{consts, Result, StatsError};
 use rand::Rng;
@@ -83,6 +83,49 @@ impl ContinuousCDF<f64, f64> for LogNormal {
             0.5 * erf::erfc((self.location - x.ln()) / (self.scale * f64::consts::SQRT_2))
         }
     }
+
+    /// Calculates the survival function for the log-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * erfc((ln(x) - μ) / sqrt(2) * σ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc` is the
+    /// complementary error function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            0.5 * erf::erfc((x.ln() - self.location) / (self.scale * f64::consts::SQRT_2))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// log-normal distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μ - sqrt(2) * σ * erfc_inv(2p))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `erfc_inv` is
+    /// the inverse of the complementary error function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        (self.location - self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * p)).exp()
+    }
 }
 
 impl Min<f64> for LogNormal {
@@ -164,6 +207,75 @@ impl Distribution<f64> for LogNormal {
         let expsigma2 = (self.scale * self.scale).exp();
         Some((expsigma2 + 2.0) * (expsigma2 - 1.0).sqrt())
     }
+    /// Returns the excess kurtosis of the log-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(4σ^2) + 2e^(3σ^2) + 3e^(2σ^2) - 6
+    /// ```
+    ///
+    /// where `σ` is the scale. It is evaluated as a polynomial in
+    /// `e^(σ^2) - 1` so that it stays accurate for small `σ`
+    fn kurtosis(&self) -> Option<f64> {
+        let x = (self.scale * self.scale).exp_m1();
+        Some(x * (16.0 + x * (15.0 + x * (6.0 + x))))
+    }
+    /// Returns the `k`th raw moment of the log-normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(kμ + k^2 σ^2 / 2)
+    /// ```
+    ///
+    /// where `μ` is the location and `σ` is the scale
+    fn moment(&self, k: u32) -> Option<f64> {
+        let k = k as f64;
+        Some((k * self.location + k * k * self.scale * self.scale / 2.0).exp())
+    }
+    /// Returns the `k`th central moment of the log-normal distribution
+    ///
+    /// # Remarks
+    ///
+    /// When `k(k - 1)σ^2 / 2 < 1` the alternating sum cancels badly, so
+    /// each exponential is expanded in powers of `σ^2 / 2` instead. Every
+    /// term of the resulting series is non-negative.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m^k * sum((k choose j) * (-1)^(k - j) * e^(j(j - 1)σ^2 / 2)) for j in 0..=k
+    /// ```
+    ///
+    /// where `m` is the mean and `σ` is the scale
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        if k == 0 {
+            return Some(1.0);
+        } else if k == 1 {
+            return Some(0.0);
+        }
+        let mean = self.mean()?;
+        let a = self.scale * self.scale / 2.0;
+        let scaled = if a * f64::from(k * (k - 1)) < 1.0 {
+            let mut sum = 0.0;
+            let mut coeff = 1.0;
+            let mut n = 0;
+            loop {
+                let term = coeff * forward_difference(k, |j| (j * (j - 1.0)).powi(n));
+                sum += term;
+                // the differences vanish until the polynomial reaches degree k
+                if 2 * n as u32 >= k && term <= f64::EPSILON * sum {
+                    break sum;
+                }
+                n += 1;
+                coeff *= a / n as f64;
+            }
+        } else {
+            forward_difference(k, |j| (a * j * (j - 1.0)).exp())
+        };
+        Some(mean.powi(k as i32) * scaled)
+    }
 }
 
 impl Median<f64> for LogNormal {
@@ -236,6 +348,15 @@ impl Continuous<f64, f64> for LogNormal {
     }
 }
 
+/// Returns the `k`th forward difference of `f` at zero,
+/// `sum((k choose j) * (-1)^(k - j) * f(j)) for j in 0..=k`
+fn forward_difference<F: Fn(f64) -> f64>(k: u32, f: F) -> f64 {
+    (0..=k).fold(0.0, |acc, j| {
+        let sign = (-1.0f64).powi((k - j) as i32);
+        acc + sign * factorial::binomial(k as u64, j as u64) * f(j as f64)
+    })
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -410,6 +531,40 @@ mod tests {
         test_almost(5.5, 5.5, 50829064464591483629.132631635472412625371367420496, 1e4, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: LogNormal| x.kurtosis().unwrap();
+        test_almost(0.5, 0.25, 1.0959312747301818559, 1e-15, kurtosis);
+        test_almost(0.0, 1.0, 110.93639217631152524, 1e-12, kurtosis);
+        test_almost(0.0, 1e-4, 1.6000000230000002367e-7, 1e-21, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: LogNormal| x.moment(k).unwrap();
+        test_case(0.5, 0.25, 1.0, moment(0));
+        test_almost(0.5, 0.25, 1.7010573018484006794, 1e-15, moment(1));
+        test_almost(0.5, 0.25, 12.182493960703473438, 1e-14, moment(4));
+        test_almost(0.5, 0.25, 61.86780925036788651, 1e-13, moment(6));
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: LogNormal| x.central_moment(k).unwrap();
+        test_case(0.5, 0.25, 1.0, central_moment(0));
+        test_case(0.5, 0.25, 0.0, central_moment(1));
+        test_almost(0.5, 0.25, 0.18662090474627030353, 1e-15, central_moment(2));
+        test_almost(0.5, 0.25, 0.062742306224722061604, 1e-15, central_moment(3));
+        test_almost(0.5, 0.25, 0.14265048159388778527, 1e-15, central_moment(4));
+        test_almost(0.5, 0.25, 0.15163986180738796134, 1e-15, central_moment(5));
+        test_almost(0.5, 0.25, 0.28581098178568712989, 1e-15, central_moment(6));
+        // small scales where the direct sum cancels
+        test_almost(0.0, 1e-4, 1.0000000150000001167e-8, 1e-23, central_moment(2));
+        test_almost(0.0, 1e-4, 3.0000000850000012625e-16, 1e-30, central_moment(3));
+        test_almost(0.0, 1e-4, 3.0000002500000084748e-16, 1e-30, central_moment(4));
+        test_almost(0.0, 1e-4, 1.5000003975000338459e-23, 1e-37, central_moment(6));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: LogNormal| x.mode().unwrap();
@@ -596,6 +751,27 @@ mod tests {
         test_case(0.0, 1.0, 0.0, cdf(0.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: LogNormal| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(0.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 7.6198530241605260659733432516e-24, 1e-33, sf(22026.465794806716516957900645284));
+        test_case(0.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: LogNormal| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 0.5, 0.8494434259057596672111, 1e-14, inverse_cdf(0.01));
+        test_almost(0.0, 1.0, 1.0, 1e-15, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 3.602224479279157717298, 1e-14, inverse_cdf(0.9));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-10);
+        tests::check_inverse_cdf(&try_create(1.0, 0.5), 1e-10);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(0.0, 0.25), 0.0, 10.0);
@@ -0,0 +1,675 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::logistic;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use num_complex::Complex;
+use rand::distributions::Open01;
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Logistic](https://en.wikipedia.org/wiki/Logistic_distribution)
+/// distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Logistic, Continuous};
+/// use statrs::statistics::Mode;
+///
+/// let n = Logistic::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mode().unwrap(), 0.0);
+/// assert_eq!(n.pdf(0.0), 0.25);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Logistic {
+    location: f64,
+    scale: f64,
+}
+
+impl Logistic {
+    /// Constructs a new logistic distribution with the given
+    /// location and scale.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if location or scale are `NaN` or if
+    /// `scale <= 0.0` or `scale == INF`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Logistic;
+    ///
+    /// let mut result = Logistic::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Logistic::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Logistic> {
+        if location.is_nan() || scale.is_nan() || scale <= 0.0 || scale.is_infinite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Logistic { location, scale })
+        }
+    }
+
+    /// Returns the location of the logistic distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Logistic;
+    ///
+    /// let n = Logistic::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.location(), 0.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the logistic distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Logistic;
+    ///
+    /// let n = Logistic::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.scale(), 1.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Logistic {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let u: f64 = r.sample(Open01);
+        self.location + self.scale * (u.ln() - (-u).ln_1p())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Logistic {
+    /// Calculates the cumulative distribution function for the
+    /// logistic distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (1 + e^(-(x - μ) / s))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        logistic::logistic((x - self.location) / self.scale)
+    }
+
+    /// Calculates the survival function for the
+    /// logistic distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (1 + e^((x - μ) / s))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        logistic::logistic((self.location - x) / self.scale)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// logistic distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + s * ln(p / (1 - p))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.location + self.scale * logistic::logit(p)
+    }
+}
+
+impl Min<f64> for Logistic {
+    /// Returns the minimum value in the domain of the logistic
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Logistic {
+    /// Returns the maximum value in the domain of the logistic
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Logistic {
+    /// Returns the mean of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s^2 * π^2 / 3
+    /// ```
+    ///
+    /// where `s` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(self.scale * self.scale * f64::consts::PI * f64::consts::PI / 3.0)
+    }
+    /// Returns the entropy of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(s) + 2
+    /// ```
+    ///
+    /// where `s` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + 2.0)
+    }
+    /// Returns the skewness of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    /// Returns the excess kurtosis of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / 5
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(1.2)
+    }
+}
+
+impl GeneratingFunction<f64> for Logistic {
+    /// Returns the moment generating function of the logistic distribution
+    /// at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `|t| >= 1 / s` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μt) * B(1 - st, 1 + st) = e^(μt) * πst / sin(πst)
+    /// ```
+    ///
+    /// where `μ` is the location, `s` is the scale and `B` is the beta
+    /// function
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let x = self.scale * t;
+        if x.abs() >= 1.0 {
+            None
+        } else {
+            Some((self.location * t).exp() * beta_symmetric(x))
+        }
+    }
+    /// Returns the cumulant generating function of the logistic
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `|t| >= 1 / s` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μt + ln(πst / sin(πst))
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let x = self.scale * t;
+        if x.abs() >= 1.0 {
+            None
+        } else if x.abs() < 0.25 {
+            // ln(y / sin(y)) = -ln(1 - (y - sin(y)) / y) with y = πx
+            let y = f64::consts::PI * x;
+            let y2 = y * y;
+            let rel = (0..SIN_SERIES_TERMS).rev().fold(0.0, |acc, k| {
+                let k = 2.0 * k as f64;
+                (1.0 - acc * y2) / ((k + 2.0) * (k + 3.0))
+            }) * y2;
+            Some(self.location * t - (-rel).ln_1p())
+        } else {
+            Some(self.location * t + beta_symmetric(x).ln())
+        }
+    }
+    /// Returns the characteristic function of the logistic distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(iμt) * πst / sinh(πst)
+    /// ```
+    ///
+    /// where `μ` is the location and `s` is the scale
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let y = f64::consts::PI * self.scale * t;
+        let ratio = if y == 0.0 { 1.0 } else { y / y.sinh() };
+        Complex::from_polar(ratio, self.location * t)
+    }
+}
+
+/// Number of terms of the Taylor series of `(y - sin(y)) / y^3` summed by
+/// `Logistic::cgf`, enough for double precision for `|y| < π / 4`
+const SIN_SERIES_TERMS: i32 = 8;
+
+/// Computes `B(1 - x, 1 + x) = πx / sin(πx)` for `|x| < 1`, reflecting
+/// the sine about `π / 2` so that it keeps full relative accuracy as `|x|`
+/// approaches `1`
+fn beta_symmetric(x: f64) -> f64 {
+    let x = x.abs();
+    if x == 0.0 {
+        1.0
+    } else if x <= 0.5 {
+        f64::consts::PI * x / (f64::consts::PI * x).sin()
+    } else {
+        f64::consts::PI * x / (f64::consts::PI * (1.0 - x)).sin()
+    }
+}
+
+impl Median<f64> for Logistic {
+    /// Returns the median of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for Logistic {
+    /// Returns the mode of the logistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Logistic {
+    /// Calculates the probability density function for the logistic
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-z) / (s * (1 + e^(-z))^2)
+    /// ```
+    ///
+    /// where `z = (x - μ) / s`, `μ` is the location and `s` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        let e = (-((x - self.location) / self.scale).abs()).exp();
+        e / (self.scale * (1.0 + e) * (1.0 + e))
+    }
+
+    /// Calculates the log probability density function for the logistic
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -z - 2 * ln(1 + e^(-z)) - ln(s)
+    /// ```
+    ///
+    /// where `z = (x - μ) / s`, `μ` is the location and `s` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let z = ((x - self.location) / self.scale).abs();
+        -z - 2.0 * (-z).exp().ln_1p() - self.scale.ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Logistic};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Logistic {
+        let n = Logistic::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = Logistic::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+        where F: Fn(Logistic) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Logistic) -> f64
+    {
+        let n = try_create(location, scale);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(0.0, 1.0);
+        create_case(-5.0, 10.0);
+        create_case(10.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(f64::NAN, f64::NAN);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Logistic| x.mean().unwrap();
+        test_case(0.0, 1.0, 0.0, mean);
+        test_case(-2.0, 0.5, -2.0, mean);
+        test_case(3.0, 10.0, 3.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Logistic| x.variance().unwrap();
+        test_almost(0.0, 1.0, 3.289868133696452872945, 1e-14, variance);
+        test_almost(-2.0, 0.5, 0.8224670334241132182362, 1e-14, variance);
+        test_almost(3.0, 10.0, 328.9868133696452872945, 1e-13, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Logistic| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 2.0, 1e-14, entropy);
+        test_almost(-2.0, 0.5, 1.306852819440054690583, 1e-14, entropy);
+        test_almost(3.0, 10.0, 4.302585092994045684018, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Logistic| x.skewness().unwrap();
+        test_case(0.0, 1.0, 0.0, skewness);
+        test_case(-2.0, 0.5, 0.0, skewness);
+        test_case(3.0, 10.0, 0.0, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Logistic| x.kurtosis().unwrap();
+        test_case(0.0, 1.0, 1.2, kurtosis);
+        test_case(-2.0, 0.5, 1.2, kurtosis);
+        test_case(3.0, 10.0, 1.2, kurtosis);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: Logistic| x.median();
+        test_case(0.0, 1.0, 0.0, median);
+        test_case(-2.0, 0.5, -2.0, median);
+        test_case(3.0, 10.0, 3.0, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Logistic| x.mode().unwrap();
+        test_case(0.0, 1.0, 0.0, mode);
+        test_case(-2.0, 0.5, -2.0, mode);
+        test_case(3.0, 10.0, 3.0, mode);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Logistic| x.mgf(t).unwrap();
+        test_almost(0.0, 1.0, 99.016286722581869886, 1e-12, mgf(-0.99));
+        test_almost(0.0, 1.0, 1.016640738463051965, 1e-15, mgf(0.1));
+        test_almost(0.0, 1.0, 999.00164329102406676, 1e-10, mgf(0.999));
+        test_almost(2.0, 0.5, 0.21473635579452170208, 1e-15, mgf(-0.99));
+        test_almost(2.0, 0.5, 1.8913463080919263155, 1e-15, mgf(0.3));
+        test_almost(2.0, 0.5, 852.82528460758316581, 1e-11, mgf(1.9));
+        test_almost(-1.0, 3.0, 6.7783138464667102546, 1e-14, mgf(0.3));
+        assert_eq!(None, try_create(0.0, 1.0).mgf(1.0));
+        assert_eq!(None, try_create(0.0, 1.0).mgf(-1.5));
+        assert_eq!(None, try_create(-1.0, 3.0).mgf(-0.5));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Logistic| x.cgf(t).unwrap();
+        test_almost(0.0, 1.0, 4.5952843489532291607, 1e-14, cgf(-0.99));
+        test_almost(0.0, 1.0, 1.6449340668482265594e-16, 1e-31, cgf(1e-8));
+        test_almost(0.0, 1.0, 0.016503798474903248879, 1e-17, cgf(0.1));
+        test_almost(0.0, 1.0, 0.15269243702380603177, 1e-15, cgf(0.3));
+        test_almost(0.0, 1.0, 6.9067564235831606397, 1e-13, cgf(0.999));
+        test_almost(2.0, 0.5, 2.000000004112335209e-8, 1e-23, cgf(1e-8));
+        test_almost(2.0, 0.5, 6.7485547019021520837, 1e-14, cgf(1.9));
+        test_almost(-1.0, 3.0, 0.052692437023806055389, 1e-16, cgf(0.1));
+        assert_eq!(None, try_create(2.0, 0.5).cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Logistic| x.cf(t).re;
+        let im = |t| move |x: Logistic| x.cf(t).im;
+        test_almost(0.0, 1.0, 0.023467059305403782992, 1e-17, re(-2.0));
+        test_case(0.0, 1.0, 0.0, im(-2.0));
+        test_almost(0.0, 1.0, 1.4269748863613808561e-12, 1e-26, re(10.0));
+        test_almost(2.0, 0.5, -0.17781005647862808896, 1e-15, re(-2.0));
+        test_almost(2.0, 0.5, 0.20587226760673598937, 1e-15, im(-2.0));
+        test_almost(2.0, 0.5, 1.9320377519613115976e-6, 1e-20, re(10.0));
+        test_almost(2.0, 0.5, 4.3222794014556162461e-6, 1e-20, im(10.0));
+        test_almost(-1.0, 3.0, 0.074306983318659399076, 1e-16, re(0.5));
+        test_almost(-1.0, 3.0, -0.040594089999764637451, 1e-16, im(0.5));
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Logistic| x.min();
+        let max = |x: Logistic| x.max();
+        test_case(0.0, 1.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Logistic| x.pdf(arg);
+        test_almost(0.0, 1.0, 0.006648056670790154913999, 1e-17, pdf(-5.0));
+        test_almost(0.0, 1.0, 0.1966119332414818525374, 1e-15, pdf(-1.0));
+        test_almost(0.0, 1.0, 0.25, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.1966119332414818525374, 1e-15, pdf(1.0));
+        test_almost(0.0, 1.0, 0.006648056670790154913999, 1e-17, pdf(5.0));
+        test_almost(0.0, 1.0, 9.357622968838423302763e-14, 1e-28, pdf(30.0));
+        test_almost(-2.0, 0.5, 0.004933018582720095636578, 1e-17, pdf(-5.0));
+        test_almost(-2.0, 0.5, 0.2099871708070130346972, 1e-15, pdf(-1.0));
+        test_almost(-2.0, 0.5, 0.03532541242658223284312, 1e-16, pdf(0.0));
+        test_almost(-2.0, 0.5, 0.004933018582720095636578, 1e-17, pdf(1.0));
+        test_almost(-2.0, 0.5, 1.663054672450542701581e-6, 1e-20, pdf(5.0));
+        test_almost(-2.0, 0.5, 3.207621781097275705952e-28, 1e-42, pdf(30.0));
+        test_almost(3.0, 10.0, 0.02139096965202944238667, 1e-16, pdf(-5.0));
+        test_almost(3.0, 10.0, 0.02402607457415291504299, 1e-16, pdf(-1.0));
+        test_almost(3.0, 10.0, 0.0244458311690745869066, 1e-16, pdf(0.0));
+        test_almost(3.0, 10.0, 0.0247516572711859944587, 1e-16, pdf(1.0));
+        test_almost(3.0, 10.0, 0.0247516572711859944587, 1e-16, pdf(5.0));
+        test_almost(3.0, 10.0, 0.005900771248391523306106, 1e-17, pdf(30.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Logistic| x.ln_pdf(arg);
+        test_almost(0.0, 1.0, -5.013430696978236137233, 1e-14, ln_pdf(-5.0));
+        test_almost(0.0, 1.0, -1.626523375036445668098, 1e-14, ln_pdf(-1.0));
+        test_almost(0.0, 1.0, -1.386294361119890618834, 1e-14, ln_pdf(0.0));
+        test_almost(0.0, 1.0, -1.626523375036445668098, 1e-14, ln_pdf(1.0));
+        test_almost(0.0, 1.0, -5.013430696978236137233, 1e-14, ln_pdf(5.0));
+        test_almost(0.0, 1.0, -30.00000000000018715246, 1e-13, ln_pdf(30.0));
+        test_almost(-2.0, 0.5, -5.311804189715515589644, 1e-14, ln_pdf(-5.0));
+        test_almost(-2.0, 0.5, -1.56070884152599968347, 1e-14, ln_pdf(-1.0));
+        test_almost(-2.0, 0.5, -3.343152675275674171293, 1e-14, ln_pdf(0.0));
+        test_almost(-2.0, 0.5, -5.311804189715515589644, 1e-14, ln_pdf(1.0));
+        test_almost(-2.0, 0.5, -13.30685448249680145809, 1e-13, ln_pdf(5.0));
+        test_almost(-2.0, 0.5, -63.30685281944005469058, 1e-13, ln_pdf(30.0));
+        test_almost(3.0, 10.0, -3.84478642488960113603, 1e-14, ln_pdf(-5.0));
+        test_almost(3.0, 10.0, -3.728615597793950931355, 1e-14, ln_pdf(-1.0));
+        test_almost(3.0, 10.0, -3.711295581931099921647, 1e-14, ln_pdf(0.0));
+        test_almost(3.0, 10.0, -3.698862831757229363388, 1e-14, ln_pdf(1.0));
+        test_almost(3.0, 10.0, -3.698862831757229363388, 1e-14, ln_pdf(5.0));
+        test_almost(3.0, 10.0, -5.132672216547226780637, 1e-14, ln_pdf(30.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Logistic| x.cdf(arg);
+        test_almost(0.0, 1.0, 0.006692850924284855559362, 1e-17, cdf(-5.0));
+        test_almost(0.0, 1.0, 0.2689414213699951207488, 1e-15, cdf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, cdf(0.0));
+        test_almost(0.0, 1.0, 0.7310585786300048792512, 1e-15, cdf(1.0));
+        test_almost(0.0, 1.0, 0.9933071490757151444406, 1e-15, cdf(5.0));
+        test_almost(0.0, 1.0, 0.9999999999999064237703, 1e-15, cdf(30.0));
+        test_almost(-2.0, 0.5, 0.00247262315663477433406, 1e-17, cdf(-5.0));
+        test_almost(-2.0, 0.5, 0.8807970779778824440597, 1e-15, cdf(-1.0));
+        test_almost(-2.0, 0.5, 0.9820137900379084419732, 1e-15, cdf(0.0));
+        test_almost(-2.0, 0.5, 0.9975273768433652256659, 1e-15, cdf(1.0));
+        test_almost(-2.0, 0.5, 0.9999991684719723358679, 1e-15, cdf(5.0));
+        test_almost(-2.0, 0.5, 1.0, 1e-14, cdf(30.0));
+        test_almost(3.0, 10.0, 0.3100255188723875573661, 1e-15, cdf(-5.0));
+        test_almost(3.0, 10.0, 0.4013123398875479996309, 1e-15, cdf(-1.0));
+        test_almost(3.0, 10.0, 0.4255574831883410128479, 1e-15, cdf(0.0));
+        test_almost(3.0, 10.0, 0.4501660026875220914408, 1e-15, cdf(1.0));
+        test_almost(3.0, 10.0, 0.5498339973124779085592, 1e-15, cdf(5.0));
+        test_almost(3.0, 10.0, 0.9370266439430035104858, 1e-15, cdf(30.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Logistic| x.sf(arg);
+        test_almost(0.0, 1.0, 0.9933071490757151444406, 1e-15, sf(-5.0));
+        test_almost(0.0, 1.0, 0.7310585786300048792512, 1e-15, sf(-1.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, sf(0.0));
+        test_almost(0.0, 1.0, 0.2689414213699951207488, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 0.006692850924284855559362, 1e-17, sf(5.0));
+        test_almost(0.0, 1.0, 9.35762296883929895384e-14, 1e-28, sf(30.0));
+        test_almost(-2.0, 0.5, 0.9975273768433652256659, 1e-15, sf(-5.0));
+        test_almost(-2.0, 0.5, 0.1192029220221175559403, 1e-15, sf(-1.0));
+        test_almost(-2.0, 0.5, 0.01798620996209155802679, 1e-16, sf(0.0));
+        test_almost(-2.0, 0.5, 0.00247262315663477433406, 1e-17, sf(1.0));
+        test_almost(-2.0, 0.5, 8.31528027664132141792e-7, 1e-21, sf(5.0));
+        test_almost(-2.0, 0.5, 1.603810890548637852976e-28, 1e-42, sf(30.0));
+        test_almost(3.0, 10.0, 0.6899744811276124426339, 1e-15, sf(-5.0));
+        test_almost(3.0, 10.0, 0.5986876601124520003691, 1e-15, sf(-1.0));
+        test_almost(3.0, 10.0, 0.5744425168116589871521, 1e-15, sf(0.0));
+        test_almost(3.0, 10.0, 0.5498339973124779085592, 1e-15, sf(1.0));
+        test_almost(3.0, 10.0, 0.4501660026875220914408, 1e-15, sf(5.0));
+        test_almost(3.0, 10.0, 0.06297335605699648951416, 1e-16, sf(30.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Logistic| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, -23.02585092984045680374, 1e-13, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, -2.197224577336219321111, 1e-14, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 0.0, 1e-14, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 1.098612288668109691395, 1e-14, inverse_cdf(0.75));
+        test_almost(0.0, 1.0, 6.906754778648552629486, 1e-14, inverse_cdf(0.999));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+        test_case(-2.0, 0.5, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(-2.0, 0.5, -13.51292546492022840187, 1e-13, inverse_cdf(1e-10));
+        test_almost(-2.0, 0.5, -3.098612288668109660556, 1e-14, inverse_cdf(0.1));
+        test_almost(-2.0, 0.5, -2.0, 1e-14, inverse_cdf(0.5));
+        test_almost(-2.0, 0.5, -1.450693855665945154302, 1e-14, inverse_cdf(0.75));
+        test_almost(-2.0, 0.5, 1.453377389324276314743, 1e-14, inverse_cdf(0.999));
+        test_case(-2.0, 0.5, f64::INFINITY, inverse_cdf(1.0));
+        test_case(3.0, 10.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(3.0, 10.0, -227.2585092984045680374, 1e-12, inverse_cdf(1e-10));
+        test_almost(3.0, 10.0, -18.97224577336219321111, 1e-13, inverse_cdf(0.1));
+        test_almost(3.0, 10.0, 3.0, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 10.0, 13.98612288668109691395, 1e-13, inverse_cdf(0.75));
+        test_almost(3.0, 10.0, 72.06754778648552629486, 1e-13, inverse_cdf(0.999));
+        test_case(3.0, 10.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(-2.0, 0.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(3.0, 10.0), 1e-12);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -50.0, 50.0);
+        tests::check_continuous_distribution(&try_create(-2.0, 0.5), -30.0, 30.0);
+    }
+}
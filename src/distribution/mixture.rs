@@ -0,0 +1,766 @@
+use crate::distribution::{Categorical, Continuous, ContinuousCDF, Discrete, DiscreteCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::{Rng, RngCore};
+use std::f64;
+use std::fmt::Debug;
+
+/// Implements a finite [Mixture](https://en.wikipedia.org/wiki/Mixture_distribution)
+/// distribution, which draws from one of several component distributions
+/// chosen at random according to a set of weights
+///
+/// # Remarks
+///
+/// Continuous mixtures implement `Continuous` and `ContinuousCDF` and
+/// discrete mixtures implement `Discrete` and `DiscreteCDF`, in both cases
+/// through the weighted sums of the component functions. Components of
+/// different types can be combined as `Mixture<Box<dyn MixtureComponent>>`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, Mixture, Normal};
+/// use statrs::statistics::Distribution;
+///
+/// let components = vec![Normal::new(-1.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()];
+/// let n = Mixture::new(components, &[1.0, 1.0]).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.0);
+/// assert_eq!(n.variance().unwrap(), 2.0);
+/// assert!((n.pdf(1.0) - 0.2264666234573104).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mixture<D> {
+    components: Vec<D>,
+    weights: Vec<f64>,
+    categorical: Categorical,
+}
+
+impl<D> Mixture<D> {
+    /// Constructs a new mixture of `components`, where the `i`th component
+    /// is chosen with probability proportional to `weights[i]`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `components` is empty, if `components` and
+    /// `weights` differ in length, if any weight is negative or `NaN`, or
+    /// if the weights sum to zero
+    ///
+    /// # Note
+    ///
+    /// The weights do not need to be normalized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Mixture, Poisson};
+    ///
+    /// let components = vec![Poisson::new(1.0).unwrap(), Poisson::new(5.0).unwrap()];
+    /// let mut result = Mixture::new(components.clone(), &[0.3, 0.7]);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Mixture::new(components, &[0.3, -0.7]);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(components: Vec<D>, weights: &[f64]) -> Result<Mixture<D>> {
+        if components.is_empty() || components.len() != weights.len() {
+            return Err(StatsError::BadParams);
+        }
+        let categorical = Categorical::new(weights)?;
+        let sum: f64 = weights.iter().sum();
+        let weights = weights.iter().map(|w| w / sum).collect();
+        Ok(Mixture {
+            components,
+            weights,
+            categorical,
+        })
+    }
+
+    /// Returns the component distributions of the mixture
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Mixture, Poisson};
+    ///
+    /// let components = vec![Poisson::new(1.0).unwrap(), Poisson::new(5.0).unwrap()];
+    /// let n = Mixture::new(components.clone(), &[0.3, 0.7]).unwrap();
+    /// assert_eq!(n.components(), &components[..]);
+    /// ```
+    pub fn components(&self) -> &[D] {
+        &self.components
+    }
+
+    /// Returns the normalized weights of the components
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Mixture, Poisson};
+    ///
+    /// let components = vec![Poisson::new(1.0).unwrap(), Poisson::new(5.0).unwrap()];
+    /// let n = Mixture::new(components, &[1.0, 3.0]).unwrap();
+    /// assert_eq!(n.weights(), &[0.25, 0.75]);
+    /// ```
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Returns the weighted sum of `f` over the components
+    fn weighted_sum<F>(&self, f: F) -> f64
+    where
+        F: Fn(&D) -> f64,
+    {
+        self.components
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(c, w)| w * f(c))
+            .sum()
+    }
+
+    /// Returns `ln(Σ w_i * exp(f(c_i)))` over the components `c_i`, shifting
+    /// by the largest term so that it neither overflows nor underflows
+    fn ln_weighted_sum<F>(&self, f: F) -> f64
+    where
+        F: Fn(&D) -> f64,
+    {
+        let terms: Vec<f64> = self
+            .components
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(c, w)| w.ln() + f(c))
+            .collect();
+        let max = terms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        if max.is_infinite() {
+            return max;
+        }
+        max + terms.iter().map(|t| (t - max).exp()).sum::<f64>().ln()
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<f64> for Mixture<D>
+where
+    D: ::rand::distributions::Distribution<f64>,
+{
+    /// Generates one sample from the mixture by choosing a component through
+    /// a categorical draw on the weights and sampling from it
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let i: u64 = self.categorical.sample(rng);
+        self.components[i as usize].sample(rng)
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<u64> for Mixture<D>
+where
+    D: ::rand::distributions::Distribution<u64>,
+{
+    /// Generates one sample from the mixture by choosing a component through
+    /// a categorical draw on the weights and sampling from it
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let i: u64 = self.categorical.sample(rng);
+        self.components[i as usize].sample(rng)
+    }
+}
+
+impl<D> ContinuousCDF<f64, f64> for Mixture<D>
+where
+    D: ContinuousCDF<f64, f64>,
+{
+    /// Calculates the cumulative distribution function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * F_i(x)
+    /// ```
+    ///
+    /// where `w_i` and `F_i` are the weight and cdf of the `i`th component
+    fn cdf(&self, x: f64) -> f64 {
+        self.weighted_sum(|c| c.cdf(x))
+    }
+
+    /// Calculates the survival function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * S_i(x)
+    /// ```
+    ///
+    /// where `w_i` and `S_i` are the weight and survival function of the
+    /// `i`th component
+    fn sf(&self, x: f64) -> f64 {
+        self.weighted_sum(|c| c.sf(x))
+    }
+}
+
+impl<D> Min<f64> for Mixture<D>
+where
+    D: Min<f64>,
+{
+    /// Returns the smallest minimum of the components
+    fn min(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.min())
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl<D> Max<f64> for Mixture<D>
+where
+    D: Max<f64>,
+{
+    /// Returns the largest maximum of the components
+    fn max(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.max())
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+}
+
+impl<D> Distribution<f64> for Mixture<D>
+where
+    D: Distribution<f64>,
+{
+    /// Returns the mean of the mixture distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * μ_i
+    /// ```
+    ///
+    /// where `w_i` and `μ_i` are the weight and mean of the `i`th component
+    fn mean(&self) -> Option<f64> {
+        self.components
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(c, w)| c.mean().map(|m| w * m))
+            .sum()
+    }
+    /// Returns the variance of the mixture distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * σ_i^2 + Σ w_i * (μ_i - μ)^2
+    /// ```
+    ///
+    /// by the law of total variance, where `w_i`, `μ_i` and `σ_i^2` are the
+    /// weight, mean and variance of the `i`th component and `μ` is the mean
+    /// of the mixture
+    fn variance(&self) -> Option<f64> {
+        self.central_moment(2)
+    }
+    /// Returns the skewness of the mixture distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_3 / m_2^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the mixture
+    fn skewness(&self) -> Option<f64> {
+        let variance = self.central_moment(2)?;
+        Some(self.central_moment(3)? / (variance * variance.sqrt()))
+    }
+    /// Returns the excess kurtosis of the mixture distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_4 / m_2^2 - 3
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the mixture
+    fn kurtosis(&self) -> Option<f64> {
+        let variance = self.central_moment(2)?;
+        Some(self.central_moment(4)? / (variance * variance) - 3.0)
+    }
+    /// Returns the `k`th central moment of the mixture distribution, if the
+    /// components' central moments up to order `k` are known
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * Σ_j C(k, j) * c_(i, j) * (μ_i - μ)^(k - j)
+    /// ```
+    ///
+    /// where `w_i`, `μ_i` and `c_(i, j)` are the weight, mean and `j`th
+    /// central moment of the `i`th component and `μ` is the mean of the
+    /// mixture
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let mean = self.mean()?;
+        let mut sum = 0.0;
+        for (c, w) in self.components.iter().zip(self.weights.iter()) {
+            let shift = c.mean()? - mean;
+            let mut binomial = 1.0;
+            for j in 0..=k {
+                if j != 1 {
+                    sum += w * binomial * c.central_moment(j)? * shift.powi((k - j) as i32);
+                }
+                binomial *= (k - j) as f64 / (j + 1) as f64;
+            }
+        }
+        Some(sum)
+    }
+}
+
+impl<D> Continuous<f64, f64> for Mixture<D>
+where
+    D: Continuous<f64, f64>,
+{
+    /// Calculates the probability density function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * f_i(x)
+    /// ```
+    ///
+    /// where `w_i` and `f_i` are the weight and pdf of the `i`th component
+    fn pdf(&self, x: f64) -> f64 {
+        self.weighted_sum(|c| c.pdf(x))
+    }
+
+    /// Calculates the log probability density function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Σ w_i * exp(ln(f_i(x))))
+    /// ```
+    ///
+    /// where `w_i` and `f_i` are the weight and pdf of the `i`th component
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.ln_weighted_sum(|c| c.ln_pdf(x))
+    }
+}
+
+impl<D> DiscreteCDF<u64, f64> for Mixture<D>
+where
+    D: DiscreteCDF<u64, f64>,
+{
+    /// Calculates the cumulative distribution function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * F_i(x)
+    /// ```
+    ///
+    /// where `w_i` and `F_i` are the weight and cdf of the `i`th component
+    fn cdf(&self, x: u64) -> f64 {
+        self.weighted_sum(|c| c.cdf(x))
+    }
+
+    /// Calculates the survival function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * S_i(x)
+    /// ```
+    ///
+    /// where `w_i` and `S_i` are the weight and survival function of the
+    /// `i`th component
+    fn sf(&self, x: u64) -> f64 {
+        self.weighted_sum(|c| c.sf(x))
+    }
+}
+
+impl<D> Min<u64> for Mixture<D>
+where
+    D: Min<u64>,
+{
+    /// Returns the smallest minimum of the components
+    fn min(&self) -> u64 {
+        self.components.iter().map(|c| c.min()).min().unwrap()
+    }
+}
+
+impl<D> Max<u64> for Mixture<D>
+where
+    D: Max<u64>,
+{
+    /// Returns the largest maximum of the components
+    fn max(&self) -> u64 {
+        self.components.iter().map(|c| c.max()).max().unwrap()
+    }
+}
+
+impl<D> Discrete<u64, f64> for Mixture<D>
+where
+    D: Discrete<u64, f64>,
+{
+    /// Calculates the probability mass function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ w_i * p_i(x)
+    /// ```
+    ///
+    /// where `w_i` and `p_i` are the weight and pmf of the `i`th component
+    fn pmf(&self, x: u64) -> f64 {
+        self.weighted_sum(|c| c.pmf(x))
+    }
+
+    /// Calculates the log probability mass function for the mixture
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Σ w_i * exp(ln(p_i(x))))
+    /// ```
+    ///
+    /// where `w_i` and `p_i` are the weight and pmf of the `i`th component
+    fn ln_pmf(&self, x: u64) -> f64 {
+        self.ln_weighted_sum(|c| c.ln_pmf(x))
+    }
+}
+
+/// The `MixtureComponent` trait is an object safe view of a univariate
+/// continuous distribution, so that components of different types can be
+/// mixed as `Mixture<Box<dyn MixtureComponent>>`. It is implemented for
+/// every distribution providing `Continuous`, `ContinuousCDF` and
+/// `Distribution`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, Gamma, Mixture, MixtureComponent, Normal};
+/// use statrs::statistics::Distribution;
+///
+/// let components: Vec<Box<dyn MixtureComponent>> = vec![
+///     Box::new(Normal::new(0.0, 1.0).unwrap()),
+///     Box::new(Gamma::new(2.0, 1.0).unwrap()),
+/// ];
+/// let n = Mixture::new(components, &[0.5, 0.5]).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.0);
+/// assert!((n.pdf(-1.0) - 0.1209853622595717).abs() < 1e-15);
+/// ```
+pub trait MixtureComponent: ContinuousCDF<f64, f64> + Continuous<f64, f64> + Debug {
+    /// Returns the mean of the component, if it exists
+    fn component_mean(&self) -> Option<f64>;
+    /// Returns the `k`th central moment of the component, if it exists
+    fn component_central_moment(&self, k: u32) -> Option<f64>;
+    /// Draws a sample from the component with a type erased generator
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> f64;
+}
+
+impl<D> MixtureComponent for D
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64> + Distribution<f64> + Debug,
+{
+    fn component_mean(&self) -> Option<f64> {
+        self.mean()
+    }
+
+    fn component_central_moment(&self, k: u32) -> Option<f64> {
+        self.central_moment(k)
+    }
+
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> f64 {
+        self.sample(rng)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Box<dyn MixtureComponent> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let mut rng = rng;
+        (**self).sample_dyn(&mut rng)
+    }
+}
+
+impl Min<f64> for Box<dyn MixtureComponent> {
+    fn min(&self) -> f64 {
+        (**self).min()
+    }
+}
+
+impl Max<f64> for Box<dyn MixtureComponent> {
+    fn max(&self) -> f64 {
+        (**self).max()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Box<dyn MixtureComponent> {
+    fn cdf(&self, x: f64) -> f64 {
+        (**self).cdf(x)
+    }
+
+    fn sf(&self, x: f64) -> f64 {
+        (**self).sf(x)
+    }
+
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        (**self).inverse_cdf(p)
+    }
+
+    fn ln_cdf(&self, x: f64) -> f64 {
+        (**self).ln_cdf(x)
+    }
+
+    fn ln_sf(&self, x: f64) -> f64 {
+        (**self).ln_sf(x)
+    }
+}
+
+impl Continuous<f64, f64> for Box<dyn MixtureComponent> {
+    fn pdf(&self, x: f64) -> f64 {
+        (**self).pdf(x)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        (**self).ln_pdf(x)
+    }
+}
+
+impl Distribution<f64> for Box<dyn MixtureComponent> {
+    fn mean(&self) -> Option<f64> {
+        (**self).component_mean()
+    }
+    fn variance(&self) -> Option<f64> {
+        (**self).component_central_moment(2)
+    }
+    fn skewness(&self) -> Option<f64> {
+        let variance = (**self).component_central_moment(2)?;
+        Some((**self).component_central_moment(3)? / (variance * variance.sqrt()))
+    }
+    fn kurtosis(&self) -> Option<f64> {
+        let variance = (**self).component_central_moment(2)?;
+        Some((**self).component_central_moment(4)? / (variance * variance) - 3.0)
+    }
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        (**self).component_central_moment(k)
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Discrete, DiscreteCDF, Gamma, Mixture, MixtureComponent, Normal, Poisson, StudentsT};
+    use crate::distribution::internal::*;
+
+    fn try_create<D>(components: Vec<D>, weights: &[f64]) -> Mixture<D> {
+        let n = Mixture::new(components, weights);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case<D>(components: Vec<D>, weights: &[f64]) {
+        let n = Mixture::new(components, weights);
+        assert!(n.is_err());
+    }
+
+    fn gaussian() -> Mixture<Normal> {
+        try_create(vec![Normal::new(-1.0, 0.5).unwrap(), Normal::new(2.0, 1.5).unwrap()], &[0.3, 0.7])
+    }
+
+    fn poisson() -> Mixture<Poisson> {
+        try_create(vec![Poisson::new(1.0).unwrap(), Poisson::new(6.0).unwrap()], &[2.0, 3.0])
+    }
+
+    fn boxed() -> Mixture<Box<dyn MixtureComponent>> {
+        let components: Vec<Box<dyn MixtureComponent>> = vec![
+            Box::new(Normal::new(0.0, 1.0).unwrap()),
+            Box::new(Gamma::new(2.0, 1.0).unwrap()),
+        ];
+        try_create(components, &[0.5, 0.5])
+    }
+
+    #[test]
+    fn test_create() {
+        let n = gaussian();
+        assert_eq!(n.components().len(), 2);
+        assert_eq!(n.weights(), &[0.3, 0.7]);
+        assert_eq!(poisson().weights(), &[0.4, 0.6]);
+        try_create(vec![Normal::new(0.0, 1.0).unwrap()], &[2.0]);
+        try_create(vec![Normal::new(0.0, 1.0).unwrap(), Normal::new(1.0, 1.0).unwrap()], &[0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        bad_create_case::<Normal>(vec![], &[]);
+        bad_create_case(vec![normal, normal], &[1.0]);
+        bad_create_case(vec![normal], &[1.0, 1.0]);
+        bad_create_case(vec![normal, normal], &[1.0, -1.0]);
+        bad_create_case(vec![normal, normal], &[1.0, f64::NAN]);
+        bad_create_case(vec![normal, normal], &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = gaussian();
+        assert_almost_eq!(n.mean().unwrap(), 1.1, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 3.54, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), 0.2270109980848965029545, 1e-14);
+        assert_almost_eq!(n.kurtosis().unwrap(), -0.8757540936512496409078, 1e-14);
+        let n = poisson();
+        assert_almost_eq!(n.mean().unwrap(), 4.0, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 10.0, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), 0.5059644256269406931198, 1e-14);
+        assert_almost_eq!(n.kurtosis().unwrap(), -0.56, 1e-14);
+        let n = boxed();
+        assert_almost_eq!(n.mean().unwrap(), 1.0, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 2.5, 1e-15);
+    }
+
+    #[test]
+    fn test_undefined_moments() {
+        let components: Vec<Box<dyn MixtureComponent>> = vec![
+            Box::new(Normal::new(0.0, 1.0).unwrap()),
+            Box::new(StudentsT::new(0.0, 1.0, 1.0).unwrap()),
+        ];
+        let n = try_create(components, &[0.5, 0.5]);
+        assert!(n.mean().is_none());
+        assert!(n.variance().is_none());
+        let n = try_create(vec![StudentsT::new(0.0, 1.0, 2.0).unwrap()], &[1.0]);
+        assert_eq!(n.mean().unwrap(), 0.0);
+        assert!(n.variance().is_none());
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = gaussian();
+        assert_almost_eq!(n.pdf(-3.0), 0.0008000283337281141022031, 1e-18);
+        assert_almost_eq!(n.pdf(-1.0), 0.2645611526136806976742, 1e-15);
+        assert_almost_eq!(n.pdf(0.0), 0.108932614756709853738, 1e-15);
+        assert_almost_eq!(n.pdf(0.5), 0.1155787804894297008777, 1e-15);
+        assert_almost_eq!(n.pdf(2.0), 0.1861730678328649595993, 1e-15);
+        assert_almost_eq!(n.pdf(5.0), 0.02519578437282109091026, 1e-16);
+        let n = boxed();
+        assert_almost_eq!(n.pdf(-1.0), 0.1209853622595716748989, 1e-15);
+        assert_almost_eq!(n.pdf(0.5), 0.3276653283103080947883, 1e-15);
+        assert_almost_eq!(n.pdf(2.0), 0.1623307664932067178693, 1e-15);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let n = gaussian();
+        assert_almost_eq!(n.ln_pdf(-3.0), -7.130863413763376987941, 1e-14);
+        assert_almost_eq!(n.ln_pdf(-1.0), -1.329682853598704419646, 1e-14);
+        assert_almost_eq!(n.ln_pdf(0.5), -2.157802899373038182556, 1e-14);
+        assert_almost_eq!(n.ln_pdf(5.0), -3.681078585251569502671, 1e-14);
+        assert_almost_eq!(n.ln_pdf(40.0), -322.5699674741404583916, 1e-12);
+        assert_almost_eq!(n.ln_pdf(-40.0), -393.6810785852515695027, 1e-12);
+        assert_eq!(n.ln_pdf(f64::INFINITY), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = gaussian();
+        assert_almost_eq!(n.cdf(-3.0), 0.0003098436057877222145206, 1e-12);
+        assert_almost_eq!(n.cdf(-1.0), 0.1659250923637254450402, 1e-10);
+        assert_almost_eq!(n.cdf(0.5), 0.4106537083425309076323, 1e-10);
+        assert_almost_eq!(n.cdf(2.0), 0.6499999997040237064887, 1e-10);
+        assert_almost_eq!(n.cdf(5.0), 0.9840749076362745549598, 1e-10);
+        let n = boxed();
+        assert_almost_eq!(n.cdf(-1.0), 0.07932762696572852570738, 1e-10);
+        assert_almost_eq!(n.cdf(0.5), 0.390833235852531484116, 1e-10);
+        assert_almost_eq!(n.cdf(2.0), 0.7856220091709913585589, 1e-10);
+    }
+
+    #[test]
+    fn test_sf() {
+        let n = gaussian();
+        assert_almost_eq!(n.sf(-3.0), 0.9996901563942122777855, 1e-12);
+        assert_almost_eq!(n.sf(0.5), 0.5893462916574690923677, 1e-10);
+        assert_almost_eq!(n.sf(5.0), 0.0159250923637254450402, 1e-10);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = gaussian();
+        assert_almost_eq!(n.inverse_cdf(0.01), -1.98721416095646909561, 1e-9);
+        assert_almost_eq!(n.inverse_cdf(0.1), -1.263863710799190418482, 1e-9);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.151092759884040756284, 1e-9);
+        assert_almost_eq!(n.inverse_cdf(0.9), 3.601355785817212774939, 1e-9);
+        assert_almost_eq!(n.inverse_cdf(0.99), 5.28402463328312605078, 1e-9);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let n = gaussian();
+        assert_eq!(Min::<f64>::min(&n), f64::NEG_INFINITY);
+        assert_eq!(Max::<f64>::max(&n), f64::INFINITY);
+        let n = boxed();
+        assert_eq!(Min::<f64>::min(&n), f64::NEG_INFINITY);
+        assert_eq!(Max::<f64>::max(&n), f64::INFINITY);
+        let n = poisson();
+        assert_eq!(Min::<u64>::min(&n), 0);
+        assert_eq!(Max::<u64>::max(&n), u64::MAX);
+    }
+
+    #[test]
+    fn test_pmf() {
+        let n = poisson();
+        assert_almost_eq!(n.pmf(0), 0.148639027774576743692, 1e-15);
+        assert_almost_eq!(n.pmf(1), 0.1560752843045758189612, 1e-15);
+        assert_almost_eq!(n.pmf(3), 0.07806634309408949671081, 1e-15);
+        assert_almost_eq!(n.pmf(6), 0.09657826209610548344444, 1e-15);
+        assert_almost_eq!(n.pmf(10), 0.02478189659848741776595, 1e-15);
+        assert_almost_eq!(n.pmf(20), 0.000002235037168225830890973, 1e-18);
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let n = poisson();
+        assert_almost_eq!(n.ln_pmf(0), -1.906234544744130457226, 1e-14);
+        assert_almost_eq!(n.ln_pmf(3), -2.550196261319009120366, 1e-14);
+        assert_almost_eq!(n.ln_pmf(20), -13.01125269995834863485, 1e-13);
+    }
+
+    #[test]
+    fn test_discrete_cdf() {
+        let n = poisson();
+        assert_almost_eq!(n.cdf(0), 0.148639027774576743692, 1e-13);
+        assert_almost_eq!(n.cdf(3), 0.483127066915527194652, 1e-13);
+        assert_almost_eq!(n.cdf(10), 0.9744274418313705885992, 1e-13);
+        assert_almost_eq!(n.sf(6), 0.236251627012160447077, 1e-13);
+        assert_almost_eq!(n.sf(20), 8.730641939814100909012e-7, 1e-17);
+        assert_eq!(n.inverse_cdf(0.5), 4);
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut r = StdRng::seed_from_u64(1);
+        let n = gaussian();
+        let mean = (0..10000).map(|_| n.sample(&mut r)).sum::<f64>() / 10000.0;
+        assert!((mean - 1.1).abs() < 0.1);
+        let n = boxed();
+        let mean = (0..10000).map(|_| n.sample(&mut r)).sum::<f64>() / 10000.0;
+        assert!((mean - 1.0).abs() < 0.1);
+        let n = poisson();
+        let mean = (0..10000).map(|_| Distribution::<u64>::sample(&n, &mut r) as f64).sum::<f64>() / 10000.0;
+        assert!((mean - 4.0).abs() < 0.2);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&gaussian(), -5.0, 10.0);
+        tests::check_continuous_distribution(&boxed(), -10.0, 30.0);
+    }
+
+    #[test]
+    fn test_discrete() {
+        tests::check_discrete_distribution(&poisson(), 30);
+    }
+}
@@ -6,6 +6,7 @@ use ::num_traits::{float::Float, Bounded, Num};
 
 pub use self::bernoulli::Bernoulli;
 pub use self::beta::Beta;
+pub use self::beta_binomial::BetaBinomial;
 pub use self::binomial::Binomial;
 pub use self::categorical::Categorical;
 pub use self::cauchy::Cauchy;
@@ -13,29 +14,59 @@ pub use self::chi::Chi;
 pub use self::chi_squared::ChiSquared;
 pub use self::dirac::Dirac;
 pub use self::dirichlet::Dirichlet;
+pub use self::dirichlet_multinomial::DirichletMultinomial;
 pub use self::discrete_uniform::DiscreteUniform;
 pub use self::empirical::Empirical;
 pub use self::erlang::Erlang;
 pub use self::exponential::Exp;
 pub use self::fisher_snedecor::FisherSnedecor;
 pub use self::gamma::Gamma;
+pub use self::generalized_extreme_value::GeneralizedExtremeValue;
+pub use self::generalized_pareto::GeneralizedPareto;
 pub use self::geometric::Geometric;
+pub use self::gumbel::Gumbel;
+pub use self::hurdle::Hurdle;
 pub use self::hypergeometric::Hypergeometric;
 pub use self::inverse_gamma::InverseGamma;
+pub use self::inverse_gaussian::InverseGaussian;
+pub use self::inverse_wishart::InverseWishart;
+pub use self::laplace::Laplace;
+pub use self::lkj_cholesky::LKJCholesky;
+pub use self::lkj_corr::LKJCorr;
 pub use self::log_normal::LogNormal;
+pub use self::logistic::Logistic;
+pub use self::mixture::{Mixture, MixtureComponent};
 pub use self::multinomial::Multinomial;
 pub use self::multivariate_normal::MultivariateNormal;
+pub use self::multivariate_students_t::MultivariateStudent;
+pub use self::nakagami::Nakagami;
 pub use self::negative_binomial::NegativeBinomial;
+pub use self::noncentral_chi_squared::NoncentralChiSquared;
+pub use self::noncentral_f::NoncentralF;
+pub use self::noncentral_t::NoncentralT;
 pub use self::normal::Normal;
+pub use self::normal_inverse_gaussian::NormalInverseGaussian;
 pub use self::pareto::Pareto;
 pub use self::poisson::Poisson;
+pub use self::rayleigh::Rayleigh;
+pub use self::rice::Rice;
+pub use self::skew_normal::SkewNormal;
+pub use self::skew_students_t::SkewStudentsT;
 pub use self::students_t::StudentsT;
 pub use self::triangular::Triangular;
+pub use self::truncated::Truncated;
+pub use self::truncated_normal::TruncatedNormal;
 pub use self::uniform::Uniform;
+pub use self::von_mises::VonMises;
 pub use self::weibull::Weibull;
+pub use self::wishart::Wishart;
+pub use self::wrapped_cauchy::WrappedCauchy;
+pub use self::wrapped_normal::WrappedNormal;
+pub use self::zero_inflated::ZeroInflated;
 
 mod bernoulli;
 mod beta;
+mod beta_binomial;
 mod binomial;
 mod categorical;
 mod cauchy;
@@ -43,27 +74,56 @@ mod chi;
 mod chi_squared;
 mod dirac;
 mod dirichlet;
+mod dirichlet_multinomial;
 mod discrete_uniform;
 mod empirical;
 mod erlang;
 mod exponential;
 mod fisher_snedecor;
 mod gamma;
+mod generalized_extreme_value;
+mod generalized_pareto;
 mod geometric;
+mod gumbel;
+mod hurdle;
 mod hypergeometric;
 mod internal;
 mod inverse_gamma;
+mod inverse_gaussian;
+mod inverse_wishart;
+mod laplace;
+mod lkj_cholesky;
+mod lkj_corr;
 mod log_normal;
+mod logistic;
+mod mixture;
 mod multinomial;
 mod multivariate_normal;
+mod multivariate_students_t;
+mod nakagami;
 mod negative_binomial;
+mod noncentral_chi_squared;
+mod noncentral_f;
+mod noncentral_t;
 mod normal;
+mod normal_inverse_gaussian;
 mod pareto;
 mod poisson;
+mod rayleigh;
+mod rice;
+mod skew_normal;
+mod skew_students_t;
 mod students_t;
 mod triangular;
+mod truncated;
+mod truncated_normal;
 mod uniform;
+mod von_mises;
 mod weibull;
+mod wishart;
+mod wrapped_cauchy;
+mod wrapped_normal;
+mod zero_inflated;
 mod ziggurat;
 mod ziggurat_tables;
 
@@ -85,39 +145,111 @@ pub trait ContinuousCDF<K: Float, T: Float>: Min<K> + Max<K> {
     /// assert_eq!(0.5, n.cdf(0.5));
     /// ```
     fn cdf(&self, x: K) -> T;
-    /// Due to issues with rounding and floating-point accuracy the default
-    /// implementation may be ill-behaved.
-    /// Specialized inverse cdfs should be used whenever possible.
-    /// Performs a binary search on the domain of `cdf` to obtain an approximation
-    /// of `F^-1(p) := inf { x | F(x) >= p }`. Needless to say, performance may
-    /// may be lacking.
+    /// Returns the inverse cumulative distribution function, or quantile,
+    /// calculated at `p` for a given distribution, i.e.
+    /// `F^-1(p) := inf { x | F(x) >= p }`. May panic depending on the
+    /// implementor.
+    ///
+    /// The default implementation numerically inverts `cdf` through
+    /// `inverse_cdf_with_tolerance` to within a relative tolerance of a few
+    /// machine epsilons. Specialized inverse cdfs should be used whenever
+    /// possible.
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0`, `p > 1.0`, or if the default implementation fails to
+    /// converge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5, n.inverse_cdf(0.5));
+    /// ```
     fn inverse_cdf(&self, p: T) -> K {
-        if p == T::zero() {
-            return self.min();
-        };
-        if p == T::one() {
-            return self.max();
-        };
-        let two = K::one() + K::one();
-        let mut high = two;
-        let mut low = -high;
-        while self.cdf(low) > p {
-            low = low + low;
-        }
-        while self.cdf(high) < p {
-            high = high + high;
-        }
-        let mut i = 16;
-        while i != 0 {
-            let mid = (high + low) / two;
-            if self.cdf(mid) >= p {
-                high = mid;
-            } else {
-                low = mid;
-            }
-            i -= 1;
-        }
-        (high + low) / two
+        let four = K::from(4.0).unwrap();
+        self.inverse_cdf_with_tolerance(p, four * K::epsilon())
+            .unwrap()
+    }
+
+    /// Numerically inverts `cdf` at `p` using Brent's method, stopping once
+    /// the root is known to within a relative tolerance of `tol`.
+    ///
+    /// The search is bracketed by `min()` and `max()`. Infinite bounds are
+    /// approached by expanding the bracket geometrically until it encloses
+    /// `p`.
+    ///
+    /// # Errors
+    ///
+    /// If `p < 0.0` or `p > 1.0`, or if the root could not be bracketed or
+    /// refined to the requested tolerance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// let x = n.inverse_cdf_with_tolerance(0.975, 1e-10).unwrap();
+    /// assert!((x - 1.959963984540054).abs() < 1e-9);
+    /// ```
+    fn inverse_cdf_with_tolerance(&self, p: T, tol: K) -> Result<K> {
+        internal::inverse_cdf_brent(self, p, tol)
+    }
+
+    /// Returns the survival function calculated at `x` for a given
+    /// distribution, i.e. `1 - cdf(x)`. May panic depending on the
+    /// implementor.
+    ///
+    /// The default implementation subtracts the cdf from one and therefore
+    /// loses all precision once the upper tail drops below machine epsilon.
+    /// Implementors should override it with a direct computation whenever
+    /// possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.25, n.sf(0.75));
+    /// ```
+    fn sf(&self, x: K) -> T {
+        T::one() - self.cdf(x)
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution function
+    /// calculated at `x` for a given distribution. May panic depending on the
+    /// implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5f64.ln(), n.ln_cdf(0.5));
+    /// ```
+    fn ln_cdf(&self, x: K) -> T {
+        self.cdf(x).ln()
+    }
+
+    /// Returns the natural logarithm of the survival function calculated
+    /// at `x` for a given distribution. May panic depending on the
+    /// implementor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.25f64.ln(), n.ln_sf(0.75));
+    /// ```
+    fn ln_sf(&self, x: K) -> T {
+        self.sf(x).ln()
     }
 }
 
@@ -137,31 +269,71 @@ pub trait DiscreteCDF<K: Bounded + Clone + Num, T: Float>: Min<K> + Max<K> {
     /// assert_eq!(0.5, n.cdf(0.5));
     /// ```
     fn cdf(&self, x: K) -> T;
-    /// Due to issues with rounding and floating-point accuracy the default implementation may be ill-behaved
-    /// Specialized inverse cdfs should be used whenever possible.
-    fn inverse_cdf(&self, p: T) -> K {
-        // TODO: fix integer implementation
-        if p == T::zero() {
-            return self.min();
-        };
-        if p == T::one() {
-            return self.max();
-        };
-        let two = K::one() + K::one();
-        let mut high = two.clone();
-        let mut low = K::min_value();
-        while self.cdf(high.clone()) < p {
-            high = high.clone() + high.clone();
-        }
-        while high != low {
-            let mid = (high.clone() + low.clone()) / two.clone();
-            if self.cdf(mid.clone()) >= p {
-                high = mid;
-            } else {
-                low = mid;
-            }
-        }
-        high
+    /// Returns the inverse cumulative distribution function, or quantile,
+    /// calculated at `p` for a given distribution, i.e. the smallest `k` in
+    /// the support such that `F(k) >= p`. May panic depending on the
+    /// implementor.
+    ///
+    /// The default implementation searches upwards from `min()` with
+    /// exponentially growing steps until `F(k) >= p`, never stepping past
+    /// `max()`, and then bisects the last step. It needs a number of cdf
+    /// evaluations logarithmic in the distance between `min()` and the
+    /// result.
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{DiscreteCDF, Geometric};
+    ///
+    /// let n = Geometric::new(0.5).unwrap();
+    /// assert_eq!(n.inverse_cdf(0.5), 1);
+    /// assert_eq!(n.inverse_cdf(0.9), 4);
+    /// ```
+    fn inverse_cdf(&self, p: T) -> K
+    where
+        K: PartialOrd,
+    {
+        internal::discrete_inverse_cdf(self, p, self.min())
+    }
+
+    /// Returns the survival function calculated at `x` for a given
+    /// distribution, i.e. `1 - cdf(x)` or equivalently `P(X > x)`. May panic
+    /// depending on the implementor.
+    ///
+    /// The default implementation subtracts the cdf from one and therefore
+    /// loses all precision once the upper tail drops below machine epsilon.
+    /// Implementors should override it with a direct computation whenever
+    /// possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{DiscreteCDF, Binomial};
+    /// use statrs::prec;
+    ///
+    /// let n = Binomial::new(0.5, 10).unwrap();
+    /// assert!(prec::almost_eq(n.sf(9), 0.0009765625, 1e-15));
+    /// ```
+    fn sf(&self, x: K) -> T {
+        T::one() - self.cdf(x)
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution function
+    /// calculated at `x` for a given distribution. May panic depending on the
+    /// implementor.
+    fn ln_cdf(&self, x: K) -> T {
+        self.cdf(x).ln()
+    }
+
+    /// Returns the natural logarithm of the survival function calculated
+    /// at `x` for a given distribution. May panic depending on the
+    /// implementor.
+    fn ln_sf(&self, x: K) -> T {
+        self.sf(x).ln()
     }
 }
 
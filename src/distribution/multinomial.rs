@@ -1,3 +1,4 @@
+use crate::distribution::categorical::AliasTable;
 use crate::distribution::Discrete;
 use crate::function::factorial;
 use crate::statistics::*;
@@ -25,6 +26,7 @@ use rand::Rng;
 pub struct Multinomial {
     p: Vec<f64>,
     n: u64,
+    alias: AliasTable,
 }
 
 impl Multinomial {
@@ -55,7 +57,11 @@ impl Multinomial {
         if !super::internal::is_valid_multinomial(p, true) {
             Err(StatsError::BadParams)
         } else {
-            Ok(Multinomial { p: p.to_vec(), n })
+            Ok(Multinomial {
+                p: p.to_vec(),
+                n,
+                alias: AliasTable::new(p),
+            })
         }
     }
 
@@ -92,12 +98,9 @@ impl Multinomial {
 
 impl ::rand::distributions::Distribution<Vec<f64>> for Multinomial {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
-        let p_cdf = super::categorical::prob_mass_to_cdf(self.p());
         let mut res = vec![0.0; self.p.len()];
         for _ in 0..self.n {
-            let i = super::categorical::sample_unchecked(rng, &p_cdf);
-            let el = res.get_mut(i as usize).unwrap();
-            *el += 1.0;
+            res[self.alias.sample(rng)] += 1.0;
         }
         res
     }
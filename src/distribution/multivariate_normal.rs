@@ -1,5 +1,5 @@
-use crate::distribution::Continuous;
 use crate::distribution::Normal;
+use crate::distribution::{internal, Continuous};
 use crate::statistics::{Max, MeanN, Min, Mode, VarianceN};
 use crate::{Result, StatsError};
 use nalgebra::{
@@ -33,8 +33,7 @@ pub struct MultivariateNormal {
     cov_chol_decomp: DMatrix<f64>,
     mu: DVector<f64>,
     cov: DMatrix<f64>,
-    precision: DMatrix<f64>,
-    pdf_const: f64,
+    ln_pdf_const: f64,
 }
 
 impl MultivariateNormal {
@@ -46,39 +45,26 @@ impl MultivariateNormal {
     /// Returns an error if the given covariance matrix is not
     /// symmetric or positive-definite
     pub fn new(mean: Vec<f64>, cov: Vec<f64>) -> Result<Self> {
-        let mean = DVector::from_vec(mean);
-        let cov = DMatrix::from_vec(mean.len(), mean.len(), cov);
-        let dim = mean.len();
-        // Check that the provided covariance matrix is symmetric
-        if cov.lower_triangle() != cov.upper_triangle().transpose()
-        // Check that mean and covariance do not contain NaN
-            || mean.iter().any(|f| f.is_nan())
-            || cov.iter().any(|f| f.is_nan())
-        // Check that the dimensions match
-            || mean.nrows() != cov.nrows() || cov.nrows() != cov.ncols()
-        {
-            return Err(StatsError::BadParams);
-        }
-        let cov_det = cov.determinant();
-        let pdf_const = ((2. * PI).powi(mean.nrows() as i32) * cov_det.abs())
-            .recip()
-            .sqrt();
+        let (mu, cov) = internal::parse_mean_cov(mean, cov)?;
         // Store the Cholesky decomposition of the covariance matrix
-        // for sampling
-        match Cholesky::new(cov.clone()) {
-            None => Err(StatsError::BadParams),
-            Some(cholesky_decomp) => {
-                let precision = cholesky_decomp.inverse();
-                Ok(MultivariateNormal {
-                    dim,
-                    cov_chol_decomp: cholesky_decomp.unpack(),
-                    mu: mean,
-                    cov,
-                    precision,
-                    pdf_const,
-                })
-            }
-        }
+        // for sampling and evaluating the density
+        let cov_chol_decomp = Cholesky::new(cov.clone())
+            .ok_or(StatsError::BadParams)?
+            .unpack();
+        // ln(det(Σ)) / 2 is the sum of the logs of the diagonal of the factor
+        let half_ln_det = cov_chol_decomp
+            .diagonal()
+            .iter()
+            .map(|x| x.ln())
+            .sum::<f64>();
+        let ln_pdf_const = -0.5 * mu.len() as f64 * (2. * PI).ln() - half_ln_det;
+        Ok(MultivariateNormal {
+            dim: mu.len(),
+            cov_chol_decomp,
+            mu,
+            cov,
+            ln_pdf_const,
+        })
     }
     /// Returns the entropy of the multivariate normal distribution
     ///
@@ -88,16 +74,119 @@ impl MultivariateNormal {
     /// (1 / 2) * ln(det(2 * π * e * Σ))
     /// ```
     ///
-    /// where `Σ` is the covariance matrix and `det` is the determinant
+    /// where `Σ` is the covariance matrix and `det` is the determinant.
+    /// The log determinant is summed from the diagonal of the Cholesky
+    /// factor, so it neither overflows nor underflows in high dimensions
     pub fn entropy(&self) -> Option<f64> {
-        Some(
-            0.5 * self
-                .variance()
-                .unwrap()
-                .scale(2. * PI * E)
-                .determinant()
-                .ln(),
-        )
+        let half_ln_det = self
+            .cov_chol_decomp
+            .diagonal()
+            .iter()
+            .map(|x| x.ln())
+            .sum::<f64>();
+        Some(0.5 * self.dim as f64 * (2. * PI * E).ln() + half_ln_det)
+    }
+
+    /// Returns the marginal distribution of the components `indices`, in
+    /// the given order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is empty, repeats an index or holds
+    /// one outside the dimension of the distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let mvn = MultivariateNormal::new(vec![1., 2., 3.], vec![2., 1., 0., 1., 2., 1., 0., 1., 2.]).unwrap();
+    /// let marginal = mvn.marginal(&[2, 0]).unwrap();
+    /// assert_eq!(marginal, MultivariateNormal::new(vec![3., 1.], vec![2., 0., 0., 2.]).unwrap());
+    /// ```
+    pub fn marginal(&self, indices: &[usize]) -> Result<Self> {
+        internal::complement_indices(self.dim, indices)?;
+        let mu = self.mu.select_rows(indices);
+        let cov = self.cov.select_rows(indices).select_columns(indices);
+        MultivariateNormal::new(mu.as_slice().to_vec(), cov.as_slice().to_vec())
+    }
+
+    /// Returns the distribution of the remaining components, in increasing
+    /// order, given that the components `indices` take `values`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is empty, repeats an index, holds one
+    /// outside the dimension of the distribution or covers all of it, or if
+    /// `values` does not match `indices` in length or contains `NaN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ' = μ_a + Σ_ab * inv(Σ_bb) * (x_b - μ_b)
+    /// Σ' = Σ_aa - Σ_ab * inv(Σ_bb) * Σ_ba
+    /// ```
+    ///
+    /// where `b` indexes the observed components and `a` the remaining ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    ///
+    /// let mvn = MultivariateNormal::new(vec![0., 0.], vec![1., 0.5, 0.5, 1.]).unwrap();
+    /// let conditional = mvn.conditional(&[1], &[2.]).unwrap();
+    /// assert_eq!(conditional, MultivariateNormal::new(vec![1.], vec![0.75]).unwrap());
+    /// ```
+    pub fn conditional(&self, indices: &[usize], values: &[f64]) -> Result<Self> {
+        let rest = internal::complement_indices(self.dim, indices)?;
+        if rest.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let (mu, cov, _) =
+            internal::condition_mean_cov(&self.mu, &self.cov, indices, &rest, values)?;
+        MultivariateNormal::new(mu.as_slice().to_vec(), cov.as_slice().to_vec())
+    }
+
+    /// Returns the distribution of `A * X + b`, where `X` follows this
+    /// distribution
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimensions of `a` and `b` do not match, or if
+    /// `A * Σ * transpose(A)` is not positive-definite. Linearly dependent
+    /// rows of `a` make it singular, though rounding may hide this
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ' = A * μ + b
+    /// Σ' = (A * L) * transpose(A * L)
+    /// ```
+    ///
+    /// where `L` is the Cholesky decomposition of the covariance matrix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateNormal;
+    /// use nalgebra::{DMatrix, DVector};
+    ///
+    /// let mvn = MultivariateNormal::new(vec![1., 2.], vec![1., 0., 0., 1.]).unwrap();
+    /// // the sum of the components
+    /// let a = DMatrix::from_vec(1, 2, vec![1., 1.]);
+    /// let sum = mvn.affine(&a, &DVector::from_vec(vec![0.])).unwrap();
+    /// assert_eq!(sum, MultivariateNormal::new(vec![3.], vec![2.]).unwrap());
+    /// ```
+    pub fn affine(&self, a: &DMatrix<f64>, b: &DVector<f64>) -> Result<Self> {
+        if a.ncols() != self.dim || a.nrows() != b.len() || b.iter().any(|x| x.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        let mu = a * &self.mu + b;
+        let factor = a * &self.cov_chol_decomp;
+        // exactly symmetric, unlike A * Σ * transpose(A)
+        let cov = &factor * factor.transpose();
+        MultivariateNormal::new(mu.as_slice().to_vec(), cov.as_slice().to_vec())
     }
 }
 
@@ -178,28 +267,29 @@ impl<'a> Continuous<&'a DVector<f64>, f64> for MultivariateNormal {
     /// # Formula
     ///
     /// ```ignore
-    /// (2 * π) ^ (-k / 2) * det(Σ) ^ (1 / 2) * e ^ ( -(1 / 2) * transpose(x - μ) * inv(Σ) * (x - μ))
+    /// (2 * π) ^ (-k / 2) * det(Σ) ^ (-1 / 2) * e ^ ( -(1 / 2) * transpose(x - μ) * inv(Σ) * (x - μ))
     /// ```
     ///
     /// where `μ` is the mean, `inv(Σ)` is the precision matrix, `det(Σ)` is the determinant
     /// of the covariance matrix, and `k` is the dimension of the distribution
     fn pdf(&self, x: &'a DVector<f64>) -> f64 {
-        let dv = x - &self.mu;
-        let exp_term = -0.5
-            * *(&dv.transpose() * &self.precision * &dv)
-                .get((0, 0))
-                .unwrap();
-        self.pdf_const * exp_term.exp()
+        self.ln_pdf(x).exp()
     }
     /// Calculates the log probability density function for the multivariate
     /// normal distribution at `x`. Equivalent to pdf(x).ln().
+    ///
+    /// # Remarks
+    ///
+    /// Both the quadratic form and the determinant are taken from the
+    /// Cholesky decomposition `L` of the covariance matrix, as
+    /// `|inv(L) * (x - μ)|^2` and the squared product of its diagonal,
+    /// without forming the precision matrix
     fn ln_pdf(&self, x: &'a DVector<f64>) -> f64 {
         let dv = x - &self.mu;
-        let exp_term = -0.5
-            * *(&dv.transpose() * &self.precision * &dv)
-                .get((0, 0))
-                .unwrap();
-        self.pdf_const.ln() + exp_term
+        match self.cov_chol_decomp.solve_lower_triangular(&dv) {
+            Some(z) => self.ln_pdf_const - 0.5 * z.norm_squared(),
+            None => f64::NAN,
+        }
     }
 }
 
@@ -308,6 +398,12 @@ mod tests  {
         test_case(vec![0., 0.], vec![1., 0., 0., 1.], 2.8378770664093453, entropy);
         test_case(vec![0., 0.], vec![1., 0.5, 0.5, 1.], 2.694036030183455, entropy);
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::INFINITY, entropy);
+        // det(2πeΣ) underflows here, while the entropy itself is moderate
+        let mut cov = vec![0.; 200 * 200];
+        for i in 0..200 {
+            cov[i * 201] = 1e-3;
+        }
+        test_almost(vec![0.; 200], cov, -406.98782125727915685, 1e-12, entropy);
     }
 
     #[test]
@@ -354,4 +450,88 @@ mod tests  {
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::NEG_INFINITY, ln_pdf(dvec![10., 10.]));
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::NEG_INFINITY, ln_pdf(dvec![100., 100.]));
     }
+
+    #[test]
+    fn test_ln_pdf_ill_conditioned() {
+        let ln_pdf = |arg: DVector<_>| move |x: MultivariateNormal| x.ln_pdf(&arg);
+        // a near-singular correlation, where rounding in the covariance
+        // itself limits the accuracy to about 1e-9 relative
+        let c = 1. - 1e-9;
+        test_almost(vec![0., 0.], vec![1., c, c, 1.], -1000000020.1047499871180970855, 1., ln_pdf(dvec![1., -1.]));
+        test_almost(vec![0., 0.], vec![1., c, c, 1.], 8.0521822761123533662, 1e-9, ln_pdf(dvec![0.5, 0.5]));
+        // Hilbert matrices, with condition numbers of about 1.6e4 and 1.5e10
+        let hilbert = |n| DMatrix::from_fn(n, n, |i, j| 1. / (i + j + 1) as f64).as_slice().to_vec();
+        test_almost(vec![0.; 4], hilbert(4), -14323.868135032437773, 1e-9, ln_pdf(dvec![1., -1., 1., -1.]));
+        test_almost(vec![0.; 4], hilbert(4), -3.1481350343972900384, 1e-13, ln_pdf(dvec![0.1, 0.2, 0.3, 0.4]));
+        test_almost(vec![0.; 8], hilbert(8), 29.817705398611403353, 5e-9, ln_pdf(DVector::from_element(8, 0.1)));
+    }
+
+    #[test]
+    fn test_marginal() {
+        let mvn = try_create(vec![1., 2., 3.], vec![2., 1., 0., 1., 3., 1., 0., 1., 4.]);
+        assert_eq!(mvn.marginal(&[1]).unwrap(), try_create(vec![2.], vec![3.]));
+        assert_eq!(mvn.marginal(&[2, 0]).unwrap(), try_create(vec![3., 1.], vec![4., 0., 0., 2.]));
+        assert_eq!(mvn.marginal(&[0, 1, 2]).unwrap(), mvn);
+        assert!(mvn.marginal(&[]).is_err());
+        assert!(mvn.marginal(&[0, 0]).is_err());
+        assert!(mvn.marginal(&[3]).is_err());
+    }
+
+    #[test]
+    fn test_conditional() {
+        let mvn = try_create(vec![1., 2., 3.], vec![2., 1., 0., 1., 3., 1., 0., 1., 4.]);
+        let c = mvn.conditional(&[1], &[4.]).unwrap();
+        let mean = c.mean().unwrap();
+        assert_almost_eq!(mean[0], 5. / 3., 1e-15);
+        assert_almost_eq!(mean[1], 11. / 3., 1e-15);
+        let cov = c.variance().unwrap();
+        assert_almost_eq!(cov[(0, 0)], 5. / 3., 1e-15);
+        assert_almost_eq!(cov[(0, 1)], -1. / 3., 1e-15);
+        assert_almost_eq!(cov[(1, 1)], 11. / 3., 1e-15);
+
+        let c = mvn.conditional(&[2, 0], &[5., 0.]).unwrap();
+        // 2 + (1 / 2) * (0 - 1) + (1 / 4) * (5 - 3)
+        assert_almost_eq!(c.mean().unwrap()[0], 2., 1e-15);
+        assert_almost_eq!(c.variance().unwrap()[(0, 0)], 9. / 4., 1e-15);
+
+        assert!(mvn.conditional(&[0, 1, 2], &[1., 2., 3.]).is_err());
+        assert!(mvn.conditional(&[0], &[1., 2.]).is_err());
+        assert!(mvn.conditional(&[0], &[f64::NAN]).is_err());
+        assert!(mvn.conditional(&[4], &[1.]).is_err());
+    }
+
+    #[test]
+    fn test_conditional_density() {
+        // the joint density factors into the marginal and conditional ones
+        let mvn = try_create(vec![0.5, -0.2, 1.], vec![2., 0.3, 0.1, 0.3, 0.5, -0.2, 0.1, -0.2, 1.5]);
+        let x = dvec![1.2, 0.4, -0.7];
+        let marginal = mvn.marginal(&[0, 2]).unwrap();
+        let conditional = mvn.conditional(&[0, 2], &[1.2, -0.7]).unwrap();
+        assert_almost_eq!(
+            mvn.ln_pdf(&x),
+            marginal.ln_pdf(&dvec![1.2, -0.7]) + conditional.ln_pdf(&dvec![0.4]),
+            1e-14
+        );
+    }
+
+    #[test]
+    fn test_affine() {
+        let mvn = try_create(vec![1., 2.], vec![2., 1., 1., 3.]);
+        let a = DMatrix::from_vec(1, 2, vec![2., -1.]);
+        let t = mvn.affine(&a, &dvec![1.]).unwrap();
+        assert_eq!(t.mean().unwrap(), dvec![1.]);
+        // 4 * 2 - 4 * 1 + 3
+        assert_almost_eq!(t.variance().unwrap()[(0, 0)], 7., 1e-14);
+        let a = DMatrix::from_vec(2, 2, vec![1., 1., 1., -1.]);
+        let t = mvn.affine(&a, &dvec![0.5, 0.]).unwrap();
+        assert_eq!(t.mean().unwrap(), dvec![3.5, -1.]);
+        let cov = t.variance().unwrap();
+        assert_almost_eq!(cov[(0, 0)], 7., 1e-14);
+        assert_almost_eq!(cov[(0, 1)], -1., 1e-14);
+        assert_almost_eq!(cov[(1, 1)], 3., 1e-14);
+        // dimension mismatches and singular results
+        assert!(mvn.affine(&DMatrix::identity(3, 3), &dvec![0., 0., 0.]).is_err());
+        assert!(mvn.affine(&DMatrix::identity(2, 2), &dvec![0.]).is_err());
+        assert!(mvn.affine(&DMatrix::from_vec(2, 2, vec![1., 2., 1., 2.]), &dvec![0., 0.]).is_err());
+    }
 }
@@ -0,0 +1,614 @@
+use crate::distribution::{gamma as gamma_dist, internal, normal, Continuous};
+use crate::function::gamma;
+use crate::statistics::{Max, MeanN, Min, Mode, VarianceN};
+use crate::{Result, StatsError};
+use nalgebra::{Cholesky, DMatrix, DVector};
+use rand::Rng;
+use std::f64;
+use std::f64::consts::PI;
+
+/// Implements the [Multivariate Student's
+/// t](https://en.wikipedia.org/wiki/Multivariate_t-distribution)
+/// distribution using the "nalgebra" crate for matrix operations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{MultivariateStudent, Continuous};
+/// use nalgebra::{DVector, DMatrix};
+/// use statrs::statistics::{MeanN, VarianceN};
+///
+/// let mvs = MultivariateStudent::new(vec![0., 0.], vec![1., 0., 0., 1.], 4.).unwrap();
+/// assert_eq!(mvs.mean().unwrap(), DVector::from_vec(vec![0., 0.]));
+/// assert_eq!(mvs.variance().unwrap(), DMatrix::from_vec(2, 2, vec![2., 0., 0., 2.]));
+/// assert!((mvs.pdf(&DVector::from_vec(vec![1., 1.])) - 0.0471570201753764).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultivariateStudent {
+    dim: usize,
+    scale_chol_decomp: DMatrix<f64>,
+    location: DVector<f64>,
+    scale: DMatrix<f64>,
+    freedom: f64,
+    ln_pdf_const: f64,
+}
+
+impl MultivariateStudent {
+    /// Constructs a new multivariate student's t-distribution with a
+    /// location of `location`, scale matrix `scale` and `freedom` degrees
+    /// of freedom
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given scale matrix is not symmetric or
+    /// positive-definite, or if `freedom` is `NaN` or not greater than `0.0`
+    pub fn new(location: Vec<f64>, scale: Vec<f64>, freedom: f64) -> Result<Self> {
+        if freedom.is_nan() || freedom <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let (location, scale) = internal::parse_mean_cov(location, scale)?;
+        let dim = location.len();
+        let scale_chol_decomp = Cholesky::new(scale.clone())
+            .ok_or(StatsError::BadParams)?
+            .unpack();
+        // ln(det(Σ)) / 2
+        let half_ln_det = scale_chol_decomp
+            .diagonal()
+            .iter()
+            .map(|x| x.ln())
+            .sum::<f64>();
+        let p = dim as f64;
+        // ln(Γ((ν + p) / 2) / Γ(ν / 2)) - (p / 2) * ln(ν * π) - ln(det(Σ)) / 2
+        let ln_pdf_const = internal::ln_scaled_gamma_ratio(0.5 * freedom, 0.5 * p)
+            - 0.5 * p * (2.0 * PI).ln()
+            - half_ln_det;
+        Ok(MultivariateStudent {
+            dim,
+            scale_chol_decomp,
+            location,
+            scale,
+            freedom,
+            ln_pdf_const,
+        })
+    }
+
+    /// Returns the location of the multivariate student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateStudent;
+    /// use nalgebra::DVector;
+    ///
+    /// let mvs = MultivariateStudent::new(vec![1., 2.], vec![1., 0., 0., 1.], 3.).unwrap();
+    /// assert_eq!(*mvs.location(), DVector::from_vec(vec![1., 2.]));
+    /// ```
+    pub fn location(&self) -> &DVector<f64> {
+        &self.location
+    }
+
+    /// Returns the scale matrix of the multivariate student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateStudent;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let mvs = MultivariateStudent::new(vec![1., 2.], vec![1., 0., 0., 1.], 3.).unwrap();
+    /// assert_eq!(*mvs.scale(), DMatrix::from_vec(2, 2, vec![1., 0., 0., 1.]));
+    /// ```
+    pub fn scale(&self) -> &DMatrix<f64> {
+        &self.scale
+    }
+
+    /// Returns the degrees of freedom of the multivariate student's
+    /// t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateStudent;
+    ///
+    /// let mvs = MultivariateStudent::new(vec![1., 2.], vec![1., 0., 0., 1.], 3.).unwrap();
+    /// assert_eq!(mvs.freedom(), 3.);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the entropy of the multivariate student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Γ(ν / 2) / Γ((ν + p) / 2)) + (p / 2) * ln(ν * π) + (1 / 2) * ln(det(Σ))
+    ///     + ((ν + p) / 2) * (ψ((ν + p) / 2) - ψ(ν / 2))
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension, `Σ` the scale
+    /// matrix, `Γ` the gamma function and `ψ` the digamma function
+    pub fn entropy(&self) -> Option<f64> {
+        let p = self.dim as f64;
+        let half_ln_det = self
+            .scale_chol_decomp
+            .diagonal()
+            .iter()
+            .map(|x| x.ln())
+            .sum::<f64>();
+        if self.freedom.is_infinite() {
+            return Some(0.5 * p * (2.0 * PI * f64::consts::E).ln() + half_ln_det);
+        }
+        let z = 0.5 * self.freedom;
+        let a = 0.5 * p;
+        Some(
+            0.5 * p * (2.0 * PI).ln() + half_ln_det - internal::ln_scaled_gamma_ratio(z, a)
+                + (z + a) * internal::digamma_difference(z, a),
+        )
+    }
+
+    /// Returns the marginal distribution of the components `indices`, in
+    /// the given order
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is empty, repeats an index or holds
+    /// one outside the dimension of the distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateStudent;
+    ///
+    /// let mvs = MultivariateStudent::new(vec![1., 2., 3.], vec![2., 1., 0., 1., 2., 1., 0., 1., 2.], 3.).unwrap();
+    /// let marginal = mvs.marginal(&[2, 0]).unwrap();
+    /// assert_eq!(marginal, MultivariateStudent::new(vec![3., 1.], vec![2., 0., 0., 2.], 3.).unwrap());
+    /// ```
+    pub fn marginal(&self, indices: &[usize]) -> Result<Self> {
+        internal::complement_indices(self.dim, indices)?;
+        let location = self.location.select_rows(indices);
+        let scale = self.scale.select_rows(indices).select_columns(indices);
+        MultivariateStudent::new(
+            location.as_slice().to_vec(),
+            scale.as_slice().to_vec(),
+            self.freedom,
+        )
+    }
+
+    /// Returns the distribution of the remaining components, in increasing
+    /// order, given that the components `indices` take `values`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `indices` is empty, repeats an index, holds one
+    /// outside the dimension of the distribution or covers all of it, or if
+    /// `values` does not match `indices` in length or contains `NaN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ' = μ_a + Σ_ab * inv(Σ_bb) * (x_b - μ_b)
+    /// Σ' = (ν + d) / (ν + p_b) * (Σ_aa - Σ_ab * inv(Σ_bb) * Σ_ba)
+    /// ν' = ν + p_b
+    /// ```
+    ///
+    /// where `b` indexes the `p_b` observed components, `a` the remaining
+    /// ones and `d = transpose(x_b - μ_b) * inv(Σ_bb) * (x_b - μ_b)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::MultivariateStudent;
+    ///
+    /// let mvs = MultivariateStudent::new(vec![0., 0.], vec![1., 0., 0., 1.], 3.).unwrap();
+    /// let conditional = mvs.conditional(&[1], &[2.]).unwrap();
+    /// assert_eq!(conditional, MultivariateStudent::new(vec![0.], vec![7. / 4.], 4.).unwrap());
+    /// ```
+    pub fn conditional(&self, indices: &[usize], values: &[f64]) -> Result<Self> {
+        let rest = internal::complement_indices(self.dim, indices)?;
+        if rest.is_empty() {
+            return Err(StatsError::BadParams);
+        }
+        let (location, scale, d) =
+            internal::condition_mean_cov(&self.location, &self.scale, indices, &rest, values)?;
+        let p_b = indices.len() as f64;
+        let factor = if self.freedom.is_infinite() {
+            1.0
+        } else {
+            (self.freedom + d) / (self.freedom + p_b)
+        };
+        MultivariateStudent::new(
+            location.as_slice().to_vec(),
+            (scale * factor).as_slice().to_vec(),
+            self.freedom + p_b,
+        )
+    }
+}
+
+impl ::rand::distributions::Distribution<DVector<f64>> for MultivariateStudent {
+    /// Samples from the multivariate student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// L * Z * sqrt(ν / W) + μ
+    /// ```
+    ///
+    /// where `L` is the Cholesky decomposition of the scale matrix, `Z` is
+    /// a vector of normally distributed random variables, `W` is a
+    /// chi-squared random variable with `ν` degrees of freedom and `μ` is
+    /// the location
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DVector<f64> {
+        let z = DVector::<f64>::from_fn(self.dim, |_, _| normal::sample_unchecked(rng, 0.0, 1.0));
+        let factor = if self.freedom.is_infinite() {
+            1.0
+        } else {
+            let w = gamma_dist::sample_unchecked(rng, 0.5 * self.freedom, 0.5);
+            (self.freedom / w).sqrt()
+        };
+        (&self.scale_chol_decomp * z) * factor + &self.location
+    }
+}
+
+impl Min<DVector<f64>> for MultivariateStudent {
+    /// Returns the minimum value in the domain of the
+    /// multivariate student's t-distribution represented by a real vector
+    fn min(&self) -> DVector<f64> {
+        DVector::from_vec(vec![f64::NEG_INFINITY; self.dim])
+    }
+}
+
+impl Max<DVector<f64>> for MultivariateStudent {
+    /// Returns the maximum value in the domain of the
+    /// multivariate student's t-distribution represented by a real vector
+    fn max(&self) -> DVector<f64> {
+        DVector::from_vec(vec![f64::INFINITY; self.dim])
+    }
+}
+
+impl MeanN<DVector<f64>> for MultivariateStudent {
+    /// Returns the mean of the multivariate student's t-distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<DVector<f64>> {
+        if self.freedom > 1.0 {
+            Some(self.location.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl VarianceN<DMatrix<f64>> for MultivariateStudent {
+    /// Returns the covariance matrix of the multivariate student's
+    /// t-distribution
+    ///
+    /// # None
+    ///
+    /// If `freedom <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ * ν / (ν - 2)
+    /// ```
+    ///
+    /// where `Σ` is the scale matrix and `ν` the degrees of freedom
+    fn variance(&self) -> Option<DMatrix<f64>> {
+        if self.freedom.is_infinite() {
+            Some(self.scale.clone())
+        } else if self.freedom > 2.0 {
+            Some(&self.scale * (self.freedom / (self.freedom - 2.0)))
+        } else {
+            None
+        }
+    }
+}
+
+impl Mode<DVector<f64>> for MultivariateStudent {
+    /// Returns the mode of the multivariate student's t-distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> DVector<f64> {
+        self.location.clone()
+    }
+}
+
+impl<'a> Continuous<&'a DVector<f64>, f64> for MultivariateStudent {
+    /// Calculates the probability density function for the multivariate
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Γ((ν + p) / 2) / (Γ(ν / 2) * (ν * π) ^ (p / 2) * det(Σ) ^ (1 / 2))
+    ///     * (1 + transpose(x - μ) * inv(Σ) * (x - μ) / ν) ^ (-(ν + p) / 2)
+    /// ```
+    ///
+    /// where `μ` is the location, `Σ` the scale matrix, `ν` the degrees of
+    /// freedom, `p` the dimension and `Γ` the gamma function
+    fn pdf(&self, x: &'a DVector<f64>) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the multivariate
+    /// student's t-distribution at `x`. Equivalent to pdf(x).ln().
+    ///
+    /// # Remarks
+    ///
+    /// The quadratic form is evaluated through the Cholesky factor of the
+    /// scale matrix, and the multivariate normal density is used for
+    /// infinite `freedom`
+    fn ln_pdf(&self, x: &'a DVector<f64>) -> f64 {
+        if x.iter().any(|v| v.is_infinite()) {
+            return f64::NEG_INFINITY;
+        }
+        let dv = x - &self.location;
+        let d = match self.scale_chol_decomp.solve_lower_triangular(&dv) {
+            Some(y) => y.norm_squared(),
+            None => return f64::NAN,
+        };
+        if self.freedom.is_infinite() {
+            self.ln_pdf_const - 0.5 * d
+        } else {
+            self.ln_pdf_const - 0.5 * (self.freedom + self.dim as f64) * (d / self.freedom).ln_1p()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::{Continuous, FisherSnedecor, MultivariateNormal, MultivariateStudent};
+    use crate::distribution::internal::*;
+    use crate::statistics::*;
+    use core::fmt::Debug;
+    use nalgebra::{DMatrix, DVector};
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn try_create(location: Vec<f64>, scale: Vec<f64>, freedom: f64) -> MultivariateStudent {
+        let mvs = MultivariateStudent::new(location, scale, freedom);
+        assert!(mvs.is_ok());
+        mvs.unwrap()
+    }
+
+    fn create_case(location: Vec<f64>, scale: Vec<f64>, freedom: f64) {
+        let mvs = try_create(location.clone(), scale.clone(), freedom);
+        assert_eq!(DVector::from_vec(location.clone()), *mvs.location());
+        assert_eq!(DMatrix::from_vec(location.len(), location.len(), scale), *mvs.scale());
+        assert_eq!(freedom, mvs.freedom());
+    }
+
+    fn bad_create_case(location: Vec<f64>, scale: Vec<f64>, freedom: f64) {
+        let mvs = MultivariateStudent::new(location, scale, freedom);
+        assert!(mvs.is_err());
+    }
+
+    fn test_case<T, F>(location: Vec<f64>, scale: Vec<f64>, freedom: f64, expected: T, eval: F)
+    where
+        T: Debug + PartialEq,
+        F: FnOnce(MultivariateStudent) -> T,
+    {
+        let mvs = try_create(location, scale, freedom);
+        let x = eval(mvs);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: Vec<f64>, scale: Vec<f64>, freedom: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: FnOnce(MultivariateStudent) -> f64,
+    {
+        let mvs = try_create(location, scale, freedom);
+        let x = eval(mvs);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    macro_rules! dvec {
+        ($($x:expr),*) => (DVector::from_vec(vec![$($x),*]));
+    }
+
+    macro_rules! mat2 {
+        ($x11:expr, $x12:expr, $x21:expr, $x22:expr) => (DMatrix::from_vec(2,2,vec![$x11, $x12, $x21, $x22]));
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(vec![0., 0.], vec![1., 0., 0., 1.], 1.);
+        create_case(vec![10., 5.], vec![2., 1., 1., 2.], 0.5);
+        create_case(vec![4., 5., 6.], vec![2., 1., 0., 1., 2., 1., 0., 1., 2.], 3.);
+        create_case(vec![0., 0.], vec![1., 0., 0., 1.], f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        // Scale not symmetric
+        bad_create_case(vec![0., 0.], vec![1., 1., 0., 1.], 1.);
+        // Scale not positive-definite
+        bad_create_case(vec![0., 0.], vec![1., 2., 2., 1.], 1.);
+        // Scale of the wrong size
+        bad_create_case(vec![0., 0.], vec![1., 0., 0.], 1.);
+        // NaN in location or scale
+        bad_create_case(vec![0., f64::NAN], vec![1., 0., 0., 1.], 1.);
+        bad_create_case(vec![0., 0.], vec![1., 0., 0., f64::NAN], 1.);
+        // Bad freedom
+        bad_create_case(vec![0., 0.], vec![1., 0., 0., 1.], 0.);
+        bad_create_case(vec![0., 0.], vec![1., 0., 0., 1.], -1.);
+        bad_create_case(vec![0., 0.], vec![1., 0., 0., 1.], f64::NAN);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: MultivariateStudent| x.mean();
+        test_case(vec![1., 2.], vec![1., 0., 0., 1.], 1.5, Some(dvec![1., 2.]), mean);
+        test_case(vec![1., 2.], vec![1., 0., 0., 1.], 1., None, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: MultivariateStudent| x.variance();
+        test_case(vec![0., 0.], vec![2., 1., 1., 2.], 4., Some(mat2![4., 2., 2., 4.]), variance);
+        test_case(vec![0., 0.], vec![2., 1., 1., 2.], f64::INFINITY, Some(mat2![2., 1., 1., 2.]), variance);
+        test_case(vec![0., 0.], vec![2., 1., 1., 2.], 2., None, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: MultivariateStudent| x.entropy().unwrap();
+        test_almost(vec![0., 0.], vec![1., 0., 0., 1.], 1., 4.8378770664093454836, 1e-14, entropy);
+        test_almost(vec![0., 0.], vec![2., 1., 1., 2.], 5., 3.7871832107434003293, 1e-14, entropy);
+        test_almost(vec![0., 0.], vec![1., 0.5, 0.5, 1.], f64::INFINITY, 2.694036030183455, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: MultivariateStudent| x.mode();
+        test_case(vec![1., -1.], vec![1., 0., 0., 1.], 3., dvec![1., -1.], mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: MultivariateStudent| x.min();
+        let max = |x: MultivariateStudent| x.max();
+        test_case(vec![0., 0.], vec![1., 0., 0., 1.], 3., dvec![f64::NEG_INFINITY, f64::NEG_INFINITY], min);
+        test_case(vec![0., 0.], vec![1., 0., 0., 1.], 3., dvec![f64::INFINITY, f64::INFINITY], max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: DVector<f64>| move |x: MultivariateStudent| x.pdf(&arg);
+        test_almost(vec![0., 0.], vec![1., 0., 0., 1.], 4., 0.047157020175376395783, 1e-15, pdf(dvec![1., 1.]));
+        test_almost(vec![0., 0.], vec![1., 0., 0., 1.], 1., 0.00015449693711277704993, 1e-17, pdf(dvec![1., 10.]));
+        test_almost(vec![0.5, -0.2], vec![2.0, 0.3, 0.3, 0.5], 3., 0.0045266944266359852133, 1e-16, pdf(dvec![2., 2.]));
+        test_almost(vec![1., 2., 3.], vec![2., 1., 0., 1., 2., 1., 0., 1., 2.], 5., 0.0022656105418500699576, 1e-16, pdf(dvec![0., 0., 0.]));
+        test_case(vec![0., 0.], vec![1., 0., 0., 1.], 4., 0.0, pdf(dvec![f64::INFINITY, 0.]));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: DVector<f64>| move |x: MultivariateStudent| x.ln_pdf(&arg);
+        test_almost(vec![0., 0.], vec![1., 0., 0., 1.], 4., -3.0542723907338386295, 1e-14, ln_pdf(dvec![1., 1.]));
+        test_almost(vec![0.5, -0.2], vec![2.0, 0.3, 0.3, 0.5], 3., -5.3977633129974140566, 1e-14, ln_pdf(dvec![2., 2.]));
+        test_almost(vec![0., 0.], vec![1., 0., 0., 1.], 0.5, -175.39819301666270342, 1e-12, ln_pdf(dvec![1e30, 0.]));
+        test_case(vec![0., 0.], vec![1., 0., 0., 1.], 4., f64::NEG_INFINITY, ln_pdf(dvec![f64::INFINITY, 0.]));
+    }
+
+    #[test]
+    fn test_ln_pdf_matches_normal_for_infinite_freedom() {
+        let mvs = try_create(vec![0.5, -0.2], vec![2.0, 0.3, 0.3, 0.5], f64::INFINITY);
+        let mvn = MultivariateNormal::new(vec![0.5, -0.2], vec![2.0, 0.3, 0.3, 0.5]).unwrap();
+        for x in &[dvec![2., 2.], dvec![0., 0.], dvec![-3., 1.]] {
+            assert_almost_eq!(mvs.ln_pdf(x), mvn.ln_pdf(x), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_large_freedom() {
+        // both sides of the switch to the Stirling series at ν = 20 and far
+        // beyond it, approaching the normal limit continuously
+        let location = vec![1., -1., 0.5];
+        let scale = vec![2., 1., 0., 1., 3., 1., 0., 1., 4.];
+        let x = dvec![0.5, 0., 1.];
+        let cases = [
+            (15., -4.593228820913868460643, 5.899073286774689309258),
+            (25., -4.586601335219128185441, 5.820892249239849697159),
+            (1e8, -4.577001480905850586289, 5.702001508562100496445),
+            (1e12, -4.577001478562334946445, 5.702001478565100571445),
+            (f64::INFINITY, -4.577001478562100571445, 5.702001478562100571445),
+        ];
+        for &(freedom, ln_pdf, entropy) in cases.iter() {
+            let mvs = try_create(location.clone(), scale.clone(), freedom);
+            assert_almost_eq!(mvs.ln_pdf(&x), ln_pdf, 1e-13);
+            assert_almost_eq!(mvs.entropy().unwrap(), entropy, 1e-13);
+        }
+        let below = try_create(location.clone(), scale.clone(), 20. * (1. - f64::EPSILON));
+        let above = try_create(location, scale, 20.);
+        assert_almost_eq!(below.ln_pdf(&x), above.ln_pdf(&x), 1e-13);
+        assert_almost_eq!(below.entropy().unwrap(), above.entropy().unwrap(), 1e-13);
+    }
+
+    #[test]
+    fn test_marginal() {
+        let mvs = try_create(vec![1., 2., 3.], vec![2., 1., 0., 1., 3., 1., 0., 1., 4.], 5.);
+        assert_eq!(mvs.marginal(&[1]).unwrap(), try_create(vec![2.], vec![3.], 5.));
+        assert_eq!(mvs.marginal(&[2, 0]).unwrap(), try_create(vec![3., 1.], vec![4., 0., 0., 2.], 5.));
+        assert!(mvs.marginal(&[]).is_err());
+        assert!(mvs.marginal(&[0, 0]).is_err());
+        assert!(mvs.marginal(&[3]).is_err());
+    }
+
+    #[test]
+    fn test_conditional() {
+        let mvs = try_create(vec![1., 2., 3.], vec![2., 1., 0., 1., 3., 1., 0., 1., 4.], 5.);
+        let c = mvs.conditional(&[1], &[4.]).unwrap();
+        assert_eq!(c.freedom(), 6.);
+        let location = c.location();
+        assert_almost_eq!(location[0], 5. / 3., 1e-15);
+        assert_almost_eq!(location[1], 11. / 3., 1e-15);
+        // (5 + 4 / 3) / 6 * [[5 / 3, -1 / 3], [-1 / 3, 11 / 3]]
+        let scale = c.scale();
+        assert_almost_eq!(scale[(0, 0)], 95. / 54., 1e-15);
+        assert_almost_eq!(scale[(0, 1)], -19. / 54., 1e-15);
+        assert_eq!(scale[(0, 1)], scale[(1, 0)]);
+        assert_almost_eq!(scale[(1, 1)], 209. / 54., 1e-15);
+
+        let c = mvs.conditional(&[2, 0], &[3., 1.]).unwrap();
+        assert_eq!(c.freedom(), 7.);
+        assert_almost_eq!(c.location()[0], 2., 1e-15);
+        // (5 + 0) / 7 * (3 - 1 / 2 - 1 / 4)
+        assert_almost_eq!(c.scale()[(0, 0)], 45. / 28., 1e-15);
+
+        assert!(mvs.conditional(&[0, 1, 2], &[1., 2., 3.]).is_err());
+        assert!(mvs.conditional(&[0], &[1., 2.]).is_err());
+        assert!(mvs.conditional(&[0], &[f64::NAN]).is_err());
+        assert!(mvs.conditional(&[4], &[1.]).is_err());
+    }
+
+    #[test]
+    fn test_conditional_density() {
+        // the joint density factors into the marginal and conditional ones
+        let mvs = try_create(vec![0.5, -0.2, 1.], vec![2., 0.3, 0.1, 0.3, 0.5, -0.2, 0.1, -0.2, 1.5], 3.5);
+        let x = dvec![1.2, 0.4, -0.7];
+        let marginal = mvs.marginal(&[0]).unwrap();
+        let conditional = mvs.conditional(&[0], &[1.2]).unwrap();
+        assert_almost_eq!(
+            mvs.ln_pdf(&x),
+            marginal.ln_pdf(&dvec![1.2]) + conditional.ln_pdf(&dvec![0.4, -0.7]),
+            1e-13
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        // the scaled Mahalanobis distance of a sample follows F(p, ν)
+        let mvs = try_create(vec![1., -1., 0.5], vec![2., 1., 0., 1., 3., 1., 0., 1., 4.], 4.);
+        let f = FisherSnedecor::new(3., 4.).unwrap();
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let stats = (0..10_000)
+            .map(|_| {
+                let x: DVector<f64> = mvs.sample(&mut rng);
+                let dv = x - mvs.location();
+                let y = mvs.scale().clone().cholesky().unwrap().l().solve_lower_triangular(&dv).unwrap();
+                y.norm_squared() / 3.
+            })
+            .collect();
+        tests::check_kolmogorov_smirnov(&f, stats);
+    }
+}
@@ -0,0 +1,564 @@
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Nakagami](https://en.wikipedia.org/wiki/Nakagami_distribution)
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Nakagami, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = Nakagami::new(1.0, 2.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.25331413731550025121, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.60653065971263342360, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Nakagami {
+    shape: f64,
+    spread: f64,
+}
+
+impl Nakagami {
+    /// Constructs a new nakagami distribution with a shape (m) of `shape`
+    /// and a spread (Ω) of `spread`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `shape` or `spread` are `NaN`, infinite or
+    /// less than or equal to `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Nakagami;
+    ///
+    /// let mut result = Nakagami::new(1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Nakagami::new(0.0, 2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(shape: f64, spread: f64) -> Result<Nakagami> {
+        if !shape.is_finite() || !spread.is_finite() || shape <= 0.0 || spread <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Nakagami { shape, spread })
+        }
+    }
+
+    /// Returns the shape of the nakagami distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Nakagami;
+    ///
+    /// let n = Nakagami::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.shape(), 1.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the spread of the nakagami distribution, which is the
+    /// second moment `E[X^2]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Nakagami;
+    ///
+    /// let n = Nakagami::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.spread(), 2.0);
+    /// ```
+    pub fn spread(&self) -> f64 {
+        self.spread
+    }
+
+    /// Returns the mean of the distribution with a spread of one, which
+    /// is `Γ(m + 1/2) / (Γ(m) * sqrt(m))`
+    fn unit_mean(&self) -> f64 {
+        let m = self.shape;
+        ((0.5 / m).ln_1p() / 2.0 - internal::ln_scaled_gamma_ratio(m + 0.5, -0.5)).exp()
+    }
+
+    /// Returns the variance of the distribution with a spread of one,
+    /// which is `1 - Γ(m + 1/2)^2 / (Γ(m)^2 * m)`, without cancellation
+    /// for large shapes
+    fn unit_variance(&self) -> f64 {
+        let m = self.shape;
+        -((0.5 / m).ln_1p() - 2.0 * internal::ln_scaled_gamma_ratio(m + 0.5, -0.5)).exp_m1()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Nakagami {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        super::gamma::sample_unchecked(rng, self.shape, self.shape / self.spread).sqrt()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Nakagami {
+    /// Calculates the cumulative distribution function for the
+    /// nakagami distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P(m, m * x^2 / Ω)
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `P` is the
+    /// regularized lower incomplete gamma function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            gamma::gamma_lr(self.shape, self.shape * x * x / self.spread)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// nakagami distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q(m, m * x^2 / Ω)
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `Q` is the
+    /// regularized upper incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            gamma::gamma_ur(self.shape, self.shape * x * x / self.spread)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// nakagami distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(Ω / m * P^-1(m, p))
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `P^-1` is the inverse
+    /// of the regularized lower incomplete gamma function
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        (self.spread / self.shape * gamma::inv_gamma_lr(self.shape, p)).sqrt()
+    }
+}
+
+impl Min<f64> for Nakagami {
+    /// Returns the minimum value in the domain of the
+    /// nakagami distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Nakagami {
+    /// Returns the maximum value in the domain of the
+    /// nakagami distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Nakagami {
+    /// Returns the mean of the nakagami distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Γ(m + 1/2) / Γ(m) * sqrt(Ω / m)
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `Γ` is the gamma
+    /// function
+    fn mean(&self) -> Option<f64> {
+        Some(self.spread.sqrt() * self.unit_mean())
+    }
+    /// Returns the variance of the nakagami distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Ω * (1 - 1 / m * (Γ(m + 1/2) / Γ(m))^2)
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `Γ` is the gamma
+    /// function
+    fn variance(&self) -> Option<f64> {
+        Some(self.spread * self.unit_variance())
+    }
+    /// Returns the entropy of the nakagami distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Γ(m)) - (m - 1/2) * ψ(m) + m + ln(Ω / (4m)) / 2
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread, `Γ` is the gamma
+    /// function and `ψ` is the digamma function
+    fn entropy(&self) -> Option<f64> {
+        let m = self.shape;
+        Some(
+            gamma::ln_gamma(m) - (m - 0.5) * gamma::digamma(m)
+                + m
+                + (self.spread / (4.0 * m)).ln() / 2.0,
+        )
+    }
+    /// Returns the skewness of the nakagami distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ * (1 / (2m) - 2σ^2) / σ^3
+    /// ```
+    ///
+    /// where `m` is the shape and `μ` and `σ` are the mean and standard
+    /// deviation of the distribution with a spread of one
+    fn skewness(&self) -> Option<f64> {
+        let mean = self.unit_mean();
+        let var = self.unit_variance();
+        Some(mean * (0.5 / self.shape - 2.0 * var) / (var * var.sqrt()))
+    }
+    /// Returns the excess kurtosis of the nakagami distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (4 + 2 / m - 3σ^2) / σ^2 - 1 / (m * σ^4) - 3
+    /// ```
+    ///
+    /// where `m` is the shape and `σ` is the standard deviation of the
+    /// distribution with a spread of one
+    fn kurtosis(&self) -> Option<f64> {
+        let m = self.shape;
+        let var = self.unit_variance();
+        Some((4.0 + 2.0 / m - 3.0 * var) / var - 1.0 / (m * var * var) - 3.0)
+    }
+}
+
+impl Mode<Option<f64>> for Nakagami {
+    /// Returns the mode of the nakagami distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `shape < 0.5`, where the density is unbounded
+    /// at zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(Ω * (2m - 1) / (2m))
+    /// ```
+    ///
+    /// where `m` is the shape and `Ω` is the spread
+    fn mode(&self) -> Option<f64> {
+        if self.shape < 0.5 {
+            return None;
+        }
+        Some((self.spread * (1.0 - 0.5 / self.shape)).sqrt())
+    }
+}
+
+impl Continuous<f64, f64> for Nakagami {
+    /// Calculates the probability density function for the nakagami
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * m^m / (Γ(m) * Ω^m) * x^(2m - 1) * e^(-m * x^2 / Ω)
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `Γ` is the gamma
+    /// function
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            0.0
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the nakagami
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 * m^m / (Γ(m) * Ω^m) * x^(2m - 1) * e^(-m * x^2 / Ω))
+    /// ```
+    ///
+    /// where `m` is the shape, `Ω` is the spread and `Γ` is the gamma
+    /// function
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            let m = self.shape;
+            f64::consts::LN_2 + m * (m / self.spread).ln() - gamma::ln_gamma(m)
+                + (2.0 * m - 1.0) * x.ln()
+                - m * x * x / self.spread
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::distribution::internal::*;
+    use crate::distribution::{Chi, Continuous, ContinuousCDF, Nakagami};
+    use crate::statistics::*;
+
+    fn try_create(shape: f64, spread: f64) -> Nakagami {
+        let n = Nakagami::new(shape, spread);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(shape: f64, spread: f64) {
+        let n = try_create(shape, spread);
+        assert_eq!(shape, n.shape());
+        assert_eq!(spread, n.spread());
+    }
+
+    fn bad_create_case(shape: f64, spread: f64) {
+        let n = Nakagami::new(shape, spread);
+        assert!(n.is_err());
+    }
+
+    fn get_value<F>(shape: f64, spread: f64, eval: F) -> f64
+    where
+        F: Fn(Nakagami) -> f64,
+    {
+        let n = try_create(shape, spread);
+        eval(n)
+    }
+
+    fn test_case<F>(shape: f64, spread: f64, expected: f64, eval: F)
+    where
+        F: Fn(Nakagami) -> f64,
+    {
+        let x = get_value(shape, spread, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(shape: f64, spread: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: Fn(Nakagami) -> f64,
+    {
+        let x = get_value(shape, spread, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.5, 1.0);
+        create_case(1.0, 2.0);
+        create_case(10.0, 0.1);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Nakagami| x.mean().unwrap();
+        test_almost(0.5, 1.0, 0.79788456080286535588, 1e-15, mean);
+        test_almost(1.0, 2.0, 1.25331413731550025121, 1e-15, mean);
+        test_almost(2.5, 3.0, 1.6481032619656088761, 1e-15, mean);
+        test_almost(100.0, 1.0, 0.99875078612625182106, 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Nakagami| x.variance().unwrap();
+        test_almost(0.5, 1.0, 0.36338022763241865692, 1e-15, variance);
+        test_almost(1.0, 2.0, 0.42920367320510338077, 1e-15, variance);
+        test_almost(2.5, 3.0, 0.28375563789831960288, 1e-15, variance);
+        test_almost(100.0, 1.0, 0.0024968672121939929466, 1e-17, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Nakagami| x.entropy().unwrap();
+        test_almost(0.5, 1.0, 0.72579135264472743236, 1e-15, entropy);
+        test_almost(1.0, 2.0, 0.94203424217079377559, 1e-15, entropy);
+        test_almost(2.5, 3.0, 0.77638318701946478887, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Nakagami| x.skewness().unwrap();
+        test_almost(0.5, 1.0, 0.99527174643115604244, 1e-14, skewness);
+        test_almost(1.0, 2.0, 0.63111065781893713819, 1e-14, skewness);
+        test_almost(2.5, 3.0, 0.35424222542003260765, 1e-14, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Nakagami| x.kurtosis().unwrap();
+        test_almost(0.5, 1.0, 0.86917730360597411666, 1e-13, kurtosis);
+        test_almost(1.0, 2.0, 0.24508930068763806285, 1e-13, kurtosis);
+        test_almost(2.5, 3.0, 0.03698105755090560722, 1e-13, kurtosis);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Nakagami| x.mode().unwrap();
+        test_case(0.5, 1.0, 0.0, mode);
+        test_case(1.0, 2.0, 1.0, mode);
+        test_almost(2.5, 3.0, 1.5491933384829668687, 1e-15, mode);
+        assert!(try_create(0.25, 1.0).mode().is_none());
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Nakagami| x.min();
+        let max = |x: Nakagami| x.max();
+        test_case(1.0, 2.0, 0.0, min);
+        test_case(1.0, 2.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Nakagami| x.pdf(arg);
+        test_case(1.0, 2.0, 0.0, pdf(-1.0));
+        test_case(1.0, 2.0, 0.0, pdf(0.0));
+        test_almost(1.0, 2.0, 0.60653065971263342360, 1e-15, pdf(1.0));
+        test_almost(0.5, 1.0, 0.70413065352859895555, 1e-15, pdf(0.5));
+        test_almost(2.5, 3.0, 0.4145038918315540792, 1e-14, pdf(1.0));
+        test_almost(2.5, 3.0, 0.042728444746070566756, 1e-15, pdf(3.0));
+        test_almost(0.25, 1.0, 3.9005249965502297231, 1e-14, pdf(0.01));
+        test_case(1.0, 2.0, 0.0, pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Nakagami| x.ln_pdf(arg);
+        test_case(1.0, 2.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_almost(1.0, 2.0, -0.5, 1e-15, ln_pdf(1.0));
+        test_almost(2.5, 3.0, -0.88067291523119374908, 1e-14, ln_pdf(1.0));
+        test_almost(2.5, 3.0, -74.170332543255011013, 1e-13, ln_pdf(10.0));
+        test_case(1.0, 2.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Nakagami| x.cdf(arg);
+        test_case(1.0, 2.0, 0.0, cdf(-1.0));
+        test_case(1.0, 2.0, 0.0, cdf(0.0));
+        test_almost(1.0, 2.0, 0.39346934028736657640, 1e-15, cdf(1.0));
+        test_almost(0.5, 1.0, 0.38292492254802620728, 1e-15, cdf(0.5));
+        test_almost(2.5, 3.0, 0.10692785926404225799, 1e-15, cdf(1.0));
+        test_almost(2.5, 3.0, 0.98963766208421356342, 1e-15, cdf(3.0));
+        test_case(1.0, 2.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Nakagami| x.sf(arg);
+        test_case(1.0, 2.0, 1.0, sf(0.0));
+        test_almost(1.0, 2.0, 0.60653065971263342360, 1e-15, sf(1.0));
+        test_almost(2.5, 3.0, 2.0732219212614097507e-21, 1e-33, sf(8.0));
+        test_case(1.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Nakagami| x.inverse_cdf(arg);
+        test_case(1.0, 2.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 2.0, 1.1774100225154746910, 1e-14, inverse_cdf(0.5));
+        test_case(1.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.5, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.5, 3.0), 1e-12);
+    }
+
+    #[test]
+    fn test_consistent_with_chi() {
+        // a nakagami distribution with shape m and spread Ω is a chi
+        // distribution with 2m degrees of freedom scaled by sqrt(Ω / (2m))
+        for &(shape, spread) in &[(0.5, 1.0), (1.5, 0.3), (2.5, 3.0), (7.0, 20.0)] {
+            let n = try_create(shape, spread);
+            let chi = Chi::new(2.0 * shape).unwrap();
+            let c = (spread / (2.0 * shape)).sqrt();
+            for &x in &[0.1, 0.5, 1.0, 2.0, 4.0] {
+                assert_almost_eq!(n.pdf(c * x), chi.pdf(x) / c, 1e-13);
+                assert_almost_eq!(n.cdf(c * x), chi.cdf(x), 1e-14);
+            }
+            assert_almost_eq!(n.mean().unwrap(), c * chi.mean().unwrap(), 1e-13);
+            assert_almost_eq!(n.variance().unwrap(), c * c * chi.variance().unwrap(), 1e-13);
+            assert_almost_eq!(n.entropy().unwrap(), chi.entropy().unwrap() + c.ln(), 1e-13);
+            assert_almost_eq!(n.skewness().unwrap(), chi.skewness().unwrap(), 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(2.5, 3.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean - n.mean().unwrap()).abs() < 5.0 * (n.variance().unwrap() / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 1.5).count() as f64 / count as f64;
+        assert!((below - n.cdf(1.5)).abs() < 0.015);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0, 2.0), 0.0, 10.0);
+        tests::check_continuous_distribution(&try_create(2.5, 3.0), 0.0, 10.0);
+    }
+}
@@ -2,6 +2,7 @@ use crate::distribution::{self, poisson, Discrete, DiscreteCDF};
 use crate::function::{beta, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -88,7 +89,7 @@ impl NegativeBinomial {
 
 impl ::rand::distributions::Distribution<u64> for NegativeBinomial {
     fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> u64 {
-        let lambda = distribution::gamma::sample_unchecked(r, self.r, (1.0 - self.p) / self.p);
+        let lambda = distribution::gamma::sample_unchecked(r, self.r, self.p / (1.0 - self.p));
         poisson::sample_unchecked(r, lambda).floor() as u64
     }
 }
@@ -113,6 +114,20 @@ impl DiscreteCDF<u64, f64> for NegativeBinomial {
     fn cdf(&self, x: u64) -> f64 {
         1.0 - beta::beta_reg(x as f64 + 1.0, self.r, 1.0 - self.p)
     }
+
+    /// Calculates the survival function for the
+    /// negative binomial distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(1 - p)(x + 1, r)
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function
+    fn sf(&self, x: u64) -> f64 {
+        beta::beta_reg(x as f64 + 1.0, self.r, 1.0 - self.p)
+    }
 }
 
 impl Min<u64> for NegativeBinomial {
@@ -176,6 +191,73 @@ impl DiscreteDistribution<f64> for NegativeBinomial {
     fn skewness(&self) -> Option<f64> {
         Some((2.0 - self.p) / f64::sqrt(self.r * (1.0 - self.p)))
     }
+    /// Returns the excess kurtosis of the negative binomial distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `p == 1.0` since the distribution is then degenerate
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / r + p^2 / (r * (1-p))
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        if self.p == 1.0 {
+            None
+        } else {
+            Some(6.0 / self.r + self.p * self.p / (self.r * (1.0 - self.p)))
+        }
+    }
+}
+
+impl GeneratingFunction<f64> for NegativeBinomial {
+    /// Returns the moment generating function of the negative binomial
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= -ln(1 - p)` since the series diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (p / (1 - (1 - p) * e^t))^r
+    /// ```
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the negative binomial
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= -ln(1 - p)` since the series diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// r * (ln(p) - ln(1 - (1 - p) * e^t))
+    /// ```
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let x = t + (-self.p).ln_1p();
+        if x >= 0.0 {
+            None
+        } else {
+            Some(self.r * (self.p.ln() - (-x.exp_m1()).ln()))
+        }
+    }
+    /// Returns the characteristic function of the negative binomial
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (p / (1 - (1 - p) * e^(it)))^r
+    /// ```
+    fn cf(&self, t: f64) -> Complex<f64> {
+        (self.p / (1.0 - Complex::from_polar(1.0 - self.p, t))).powf(self.r)
+    }
 }
 
 impl Mode<Option<f64>> for NegativeBinomial {
@@ -234,6 +316,7 @@ mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
     use crate::distribution::{DiscreteCDF, Discrete, NegativeBinomial};
+    use crate::distribution::internal::*;
     use crate::consts::ACC;
 
     fn try_create(r: f64, p: f64) -> NegativeBinomial {
@@ -327,6 +410,54 @@ mod tests {
         test_case(1.0, 1.0, f64::INFINITY, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: NegativeBinomial| x.kurtosis().unwrap();
+        test_almost(4.0, 0.3, 1.5321428571428571429, 1e-15, kurtosis);
+        test_case(1.0, 0.5, 6.5, kurtosis);
+        test_case(3.0, 1.0, None, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: NegativeBinomial| x.mgf(t).unwrap();
+        test_almost(2.5, 0.3, 0.063221304195715560074, 1e-16, mgf(-2.0));
+        test_almost(2.5, 0.3, 69.174670957827405156, 1e-13, mgf(0.3));
+        test_almost(4.0, 0.8, 0.45711798940663035919, 1e-15, mgf(-2.0));
+        test_almost(4.0, 0.8, 1.4421197007854838015, 1e-14, mgf(0.3));
+        assert_eq!(None, try_create(2.5, 0.3).mgf(1.7));
+        assert_eq!(None, try_create(4.0, 0.8).mgf(1.7));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: NegativeBinomial| x.cgf(t).unwrap();
+        test_almost(2.5, 0.3, -2.7611139429276089853, 1e-14, cgf(-2.0));
+        test_almost(2.5, 0.3, 4.2366347689985383027, 1e-14, cgf(0.3));
+        test_almost(4.0, 0.8, -0.7828137389022628038, 1e-15, cgf(-2.0));
+        test_almost(4.0, 0.8, 0.36611404567105307131, 1e-15, cgf(0.3));
+        assert_eq!(None, try_create(2.5, 0.3).cgf(1.7));
+        assert_eq!(None, try_create(4.0, 0.8).cgf(1.7));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: NegativeBinomial| x.cf(t).re;
+        let im = |t| move |x: NegativeBinomial| x.cf(t).im;
+        test_almost(2.5, 0.3, 0.0081890705796737257025, 1e-15, re(-2.0));
+        test_almost(2.5, 0.3, -0.018051831194120454366, 1e-15, im(-2.0));
+        test_almost(2.5, 0.3, 0.090168708302599103908, 1e-15, re(0.3));
+        test_almost(2.5, 0.3, 0.50922418349808186549, 1e-15, im(0.3));
+        test_almost(2.5, 0.3, 0.0039641212602002244759, 1e-15, re(1.7));
+        test_almost(2.5, 0.3, 0.025654257673201487469, 1e-15, im(1.7));
+        test_almost(4.0, 0.8, 0.22138474480017445396, 1e-15, re(-2.0));
+        test_almost(4.0, 0.8, -0.17372027421190266891, 1e-15, im(-2.0));
+        test_almost(4.0, 0.8, 0.90643335880838929621, 1e-15, re(0.3));
+        test_almost(4.0, 0.8, 0.27220836139696420925, 1e-15, im(0.3));
+        test_almost(4.0, 0.8, 0.24824175596340750514, 1e-15, re(1.7));
+        test_almost(4.0, 0.8, 0.23782701534350728489, 1e-15, im(1.7));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: NegativeBinomial| x.mode().unwrap();
@@ -433,4 +564,30 @@ mod tests {
     //     tests::check_discrete_distribution(&try_create(5.0, 0.3), 35);
     //     tests::check_discrete_distribution(&try_create(10.0, 0.7), 21);
     // }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: NegativeBinomial| x.inverse_cdf(arg);
+        test_case(3.0, 0.01, 0, inverse_cdf(0.0));
+        test_case(3.0, 0.5, 3, inverse_cdf(0.6));
+        test_case(3.0, 0.01, 265, inverse_cdf(0.5));
+        test_case(3.0, 0.5, u64::MAX, inverse_cdf(1.0));
+        tests::check_discrete_inverse_cdf(&try_create(3.0, 0.5));
+        tests::check_discrete_inverse_cdf(&try_create(3.0, 0.01));
+        tests::check_discrete_inverse_cdf(&try_create(0.5, 0.9));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: NegativeBinomial| x.sf(arg);
+        test_almost(4.0, 0.5, 0.5, 1e-12, sf(3));
+        test_almost(4.0, 0.5, 9.2476684804861924551281792717e-27, 1e-37, sf(100));
+    }
+
+    #[test]
+    fn test_sample_goodness_of_fit() {
+        tests::check_goodness_of_fit(&try_create(3.0, 0.4), 0, 30, 10_000);
+        tests::check_goodness_of_fit(&try_create(0.5, 0.9), 0, 5, 10_000);
+        tests::check_goodness_of_fit(&try_create(10.0, 0.1), 20, 200, 10_000);
+    }
 }
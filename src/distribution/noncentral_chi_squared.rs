@@ -0,0 +1,601 @@
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use num_complex::Complex;
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Noncentral chi-squared](https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution)
+/// distribution, the distribution of the sum of squares of `k` independent
+/// normal variables with unit variance whose means have squares summing to
+/// the noncentrality `λ`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralChiSquared, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralChiSquared::new(4.0, 3.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 7.0);
+/// assert!(prec::almost_eq(n.pdf(5.0), 0.10282294376467914, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralChiSquared {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralChiSquared {
+    /// Constructs a new noncentral chi-squared distribution with `freedom`
+    /// degrees of freedom and noncentrality `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` or `noncentrality` are `NaN` or
+    /// infinite. Also returns an error if `freedom <= 0.0` or
+    /// `noncentrality < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let mut result = NoncentralChiSquared::new(2.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralChiSquared::new(2.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, noncentrality: f64) -> Result<NoncentralChiSquared> {
+        if !freedom.is_finite()
+            || freedom <= 0.0
+            || !noncentrality.is_finite()
+            || noncentrality < 0.0
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralChiSquared {
+                freedom,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the degrees of freedom of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(2.0, 1.0).unwrap();
+    /// assert_eq!(n.freedom(), 2.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality of the noncentral chi-squared distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(2.0, 1.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 1.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralChiSquared {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.freedom, self.noncentrality)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralChiSquared {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The series is summed outwards from its largest term and all of its
+    /// terms are positive, so the lower tail keeps full relative accuracy
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(e^(-λ / 2) * (λ / 2)^j / j! * P(k / 2 + j, x / 2)) for j = 0..INF
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and `P`
+    /// is the regularized lower incomplete gamma function
+    fn cdf(&self, x: f64) -> f64 {
+        cdf_unchecked(x, self.freedom, self.noncentrality)
+    }
+
+    /// Calculates the survival function for the noncentral chi-squared
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The series is summed outwards from its largest term and all of its
+    /// terms are positive, so the upper tail keeps full relative accuracy
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(e^(-λ / 2) * (λ / 2)^j / j! * Q(k / 2 + j, x / 2)) for j = 0..INF
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and `Q`
+    /// is the regularized upper incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        sf_unchecked(x, self.freedom, self.noncentrality)
+    }
+}
+
+impl Min<f64> for NoncentralChiSquared {
+    /// Returns the minimum value in the domain of the noncentral
+    /// chi-squared distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralChiSquared {
+    /// Returns the maximum value in the domain of the noncentral
+    /// chi-squared distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralChiSquared {
+    /// Returns the mean of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k + λ
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        Some(self.freedom + self.noncentrality)
+    }
+    /// Returns the variance of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * (k + 2λ)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * (self.freedom + 2.0 * self.noncentrality))
+    }
+    /// Returns the skewness of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2^(3 / 2) * (k + 3λ) / (k + 2λ)^(3 / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn skewness(&self) -> Option<f64> {
+        let k = self.freedom;
+        let l = self.noncentrality;
+        Some((8.0f64).sqrt() * (k + 3.0 * l) / (k + 2.0 * l).powf(1.5))
+    }
+    /// Returns the excess kurtosis of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 * (k + 4λ) / (k + 2λ)^2
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn kurtosis(&self) -> Option<f64> {
+        let k = self.freedom;
+        let l = self.noncentrality;
+        Some(12.0 * (k + 4.0 * l) / ((k + 2.0 * l) * (k + 2.0 * l)))
+    }
+}
+
+impl GeneratingFunction<f64> for NoncentralChiSquared {
+    /// Returns the moment generating function of the noncentral chi-squared
+    /// distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= 1 / 2` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(λt / (1 - 2t)) * (1 - 2t)^(-k / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the noncentral
+    /// chi-squared distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `t >= 1 / 2` since the integral diverges there
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λt / (1 - 2t) - k / 2 * ln(1 - 2t)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn cgf(&self, t: f64) -> Option<f64> {
+        if t >= 0.5 {
+            None
+        } else {
+            Some(self.noncentrality * t / (1.0 - 2.0 * t) - self.freedom / 2.0 * (-2.0 * t).ln_1p())
+        }
+    }
+    /// Returns the characteristic function of the noncentral chi-squared
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(iλt / (1 - 2it)) * (1 - 2it)^(-k / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn cf(&self, t: f64) -> Complex<f64> {
+        // iλt / (1 - 2it) = λt * (i - 2t) / (1 + 4t^2)
+        let x = 2.0 * t;
+        let d = 1.0 + x * x;
+        let l = self.noncentrality * t / d;
+        Complex::from_polar(
+            (-l * x - self.freedom / 4.0 * (x * x).ln_1p()).exp(),
+            l + self.freedom / 2.0 * x.atan(),
+        )
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralChiSquared {
+    /// Calculates the probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `INF` at `x = 0` if `k < 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(e^(-λ / 2) * (λ / 2)^j / j! * f(x; k + 2j)) for j = 0..INF
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f(x; ν)` is the probability density function of the chi-squared
+    /// distribution with `ν` degrees of freedom
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `INF` at `x = 0` if `k < 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sum(e^(-λ / 2) * (λ / 2)^j / j! * f(x; k + 2j)) for j = 0..INF)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f(x; ν)` is the probability density function of the chi-squared
+    /// distribution with `ν` degrees of freedom
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let k = self.freedom;
+        let mean = self.noncentrality / 2.0;
+        if x < 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if k < 2.0 {
+                f64::INFINITY
+            } else if k == 2.0 {
+                -mean - f64::consts::LN_2
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            // the ratio of consecutive terms is λx / (4 * (j + 1) * (j + k / 2))
+            let peak = (-(1.0 + k / 2.0)
+                + ((1.0 - k / 2.0) * (1.0 - k / 2.0) + self.noncentrality * x).sqrt())
+                / 2.0;
+            let half = x / 2.0;
+            internal::ln_sum_terms(peak.max(0.0).round() as u64, |j| {
+                let a = k / 2.0 + j as f64;
+                internal::ln_poisson_weight(j, mean) + (a - 1.0) * half.ln()
+                    - half
+                    - gamma::ln_gamma(a)
+                    - f64::consts::LN_2
+            })
+        }
+    }
+}
+
+/// performs an unchecked cdf calculation for a noncentral chi-squared
+/// distribution with the given freedom and noncentrality at x
+pub fn cdf_unchecked(x: f64, freedom: f64, noncentrality: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x == f64::INFINITY {
+        1.0
+    } else {
+        let mean = noncentrality / 2.0;
+        let a = freedom / 2.0;
+        internal::ln_sum_terms(mean.floor() as u64, |j| {
+            internal::ln_poisson_weight(j, mean) + gamma::gamma_lr(a + j as f64, x / 2.0).ln()
+        })
+        .exp()
+    }
+}
+
+/// performs an unchecked survival function calculation for a noncentral
+/// chi-squared distribution with the given freedom and noncentrality at x
+pub fn sf_unchecked(x: f64, freedom: f64, noncentrality: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x == f64::INFINITY {
+        0.0
+    } else {
+        let mean = noncentrality / 2.0;
+        let a = freedom / 2.0;
+        internal::ln_sum_terms(mean.floor() as u64, |j| {
+            internal::ln_poisson_weight(j, mean) + gamma::gamma_ur(a + j as f64, x / 2.0).ln()
+        })
+        .exp()
+    }
+}
+
+/// Computes the generalized Marcum Q function
+///
+/// ```ignore
+/// Q_M(a, b) = int(x * (x / a)^(M - 1) * e^(-(x^2 + a^2) / 2) * I_(M - 1)(a * x), x=b..INF)
+/// ```
+///
+/// for `a, b >= 0` as the survival function of the noncentral chi-squared
+/// distribution with `2M` degrees of freedom and noncentrality `a^2`
+/// at `b^2`
+pub fn marcum_q(m: f64, a: f64, b: f64) -> f64 {
+    sf_unchecked(b * b, 2.0 * m, a * a)
+}
+
+/// draws a sample from a noncentral chi-squared distribution as a chi-squared
+/// variate whose degrees of freedom are raised by twice a Poisson variate
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, freedom: f64, noncentrality: f64) -> f64 {
+    let j = if noncentrality > 0.0 {
+        super::poisson::sample_unchecked(rng, noncentrality / 2.0)
+    } else {
+        0.0
+    };
+    super::gamma::sample_unchecked(rng, freedom / 2.0 + j, 0.5)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ChiSquared, ContinuousCDF, Continuous, NoncentralChiSquared};
+    use crate::distribution::internal::*;
+
+    fn try_create(freedom: f64, noncentrality: f64) -> NoncentralChiSquared {
+        let n = NoncentralChiSquared::new(freedom, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(freedom: f64, noncentrality: f64) {
+        let n = try_create(freedom, noncentrality);
+        assert_eq!(freedom, n.freedom());
+        assert_eq!(noncentrality, n.noncentrality());
+    }
+
+    fn bad_create_case(freedom: f64, noncentrality: f64) {
+        let n = NoncentralChiSquared::new(freedom, noncentrality);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(freedom: f64, noncentrality: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(NoncentralChiSquared) -> f64
+    {
+        let n = try_create(freedom, noncentrality);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.5, 0.0);
+        create_case(1.0, 1.0);
+        create_case(10.0, 0.5);
+        create_case(3.0, 1000.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(0.0, 1.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(4.0, 3.0);
+        assert_eq!(n.mean().unwrap(), 7.0);
+        assert_eq!(n.variance().unwrap(), 20.0);
+        assert_almost_eq!(n.skewness().unwrap(), 1.162755348299891, 1e-15);
+        assert_almost_eq!(n.kurtosis().unwrap(), 1.92, 1e-15);
+        assert_almost_eq!(n.central_moment(3).unwrap(), 8.0 * 13.0, 1e-12);
+        let n = try_create(3.0, 0.0);
+        let c = ChiSquared::new(3.0).unwrap();
+        assert_almost_eq!(n.skewness().unwrap(), c.skewness().unwrap(), 1e-15);
+        assert_almost_eq!(n.kurtosis().unwrap(), c.kurtosis().unwrap(), 1e-15);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: NoncentralChiSquared| x.mgf(t).unwrap();
+        test_almost(4.0, 3.0, 0.040875493463493591288, 1e-16, mgf(-1.0));
+        test_almost(4.0, 3.0, 7.5507828568306824668, 1e-14, mgf(0.2));
+        test_almost(0.5, 10.0, 0.0039937071413130728414, 1e-17, mgf(-10.0));
+        test_almost(0.5, 10.0, 62122894926245104279.0, 1e7, mgf(0.45));
+        assert_eq!(None, try_create(4.0, 3.0).mgf(0.5));
+        assert_eq!(None, try_create(0.5, 10.0).mgf(1.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: NoncentralChiSquared| x.cgf(t).unwrap();
+        test_almost(4.0, 3.0, -3.1972245773362193828, 1e-15, cgf(-1.0));
+        test_almost(4.0, 3.0, 7.0000001000000018798e-8, 1e-22, cgf(1e-8));
+        test_almost(4.0, 3.0, 2.0216512475319815329, 1e-15, cgf(0.2));
+        test_almost(0.5, 10.0, -5.5230353713356176539, 1e-15, cgf(-10.0));
+        test_almost(0.5, 10.0, 45.575646273248522579, 1e-13, cgf(0.45));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: NoncentralChiSquared| x.cf(t).re;
+        let im = |t| move |x: NoncentralChiSquared| x.cf(t).im;
+        test_almost(4.0, 3.0, -0.014201690380332400297, 1e-16, re(-2.0));
+        test_almost(4.0, 3.0, -0.0019581299017493499933, 1e-17, im(-2.0));
+        test_almost(4.0, 3.0, -0.16099167564344107301, 1e-15, re(0.5));
+        test_almost(4.0, 3.0, 0.17281267433444955401, 1e-15, im(0.5));
+        test_almost(0.5, 10.0, 0.0019512377880978934218, 1e-17, re(3.0));
+        test_almost(0.5, 10.0, 0.0045069847758821441944, 1e-17, im(3.0));
+    }
+
+    // Reference values are computed with mpmath at 40 digits, the density from
+    // its Bessel function form and the cdf and sf from the Poisson series
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: NoncentralChiSquared| x.pdf(arg);
+        test_almost(2.0, 1.0, 0.23287980379682022, 1e-15, pdf(1.0));
+        test_almost(4.0, 3.0, 0.10282294376467914, 1e-15, pdf(5.0));
+        test_almost(1.0, 10.0, 0.014008569862618516, 1e-16, pdf(0.5));
+        test_almost(10.0, 0.5, 0.00051046301395797377, 1e-18, pdf(30.0));
+        test_almost(3.0, 50.0, 3.1745586614233865e-9, 1e-22, pdf(2.0));
+        test_almost(5.0, 100.0, 5.2681191035697147e-6, 1e-18, pdf(200.0));
+        test_almost(0.5, 2.0, 15.195594362887411, 1e-12, pdf(1e-3));
+        test_almost(2.0, 1.0, 0.5 * (-0.5f64).exp(), 1e-16, pdf(0.0));
+        assert_eq!(try_create(2.0, 1.0).pdf(-1.0), 0.0);
+        assert_eq!(try_create(0.5, 2.0).pdf(0.0), f64::INFINITY);
+        assert_eq!(try_create(3.0, 2.0).pdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: NoncentralChiSquared| x.ln_pdf(arg);
+        test_almost(4.0, 3.0, 0.10282294376467914f64.ln(), 1e-14, ln_pdf(5.0));
+        test_almost(3.0, 50.0, 3.1745586614233865e-9f64.ln(), 1e-13, ln_pdf(2.0));
+        let n = try_create(2.0, 1.0);
+        assert_eq!(n.ln_pdf(-1.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_central() {
+        let n = try_create(20.0, 0.0);
+        let c = ChiSquared::new(20.0).unwrap();
+        for &x in &[0.5, 5.0, 15.0, 40.0] {
+            assert_almost_eq!(n.pdf(x), c.pdf(x), 1e-15);
+            assert_almost_eq!(n.cdf(x), c.cdf(x), 1e-14);
+            assert_almost_eq!(n.sf(x), c.sf(x), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: NoncentralChiSquared| x.cdf(arg);
+        test_almost(2.0, 1.0, 0.26712019620317978, 1e-15, cdf(1.0));
+        test_almost(4.0, 3.0, 0.3884149155488384, 1e-15, cdf(5.0));
+        test_almost(1.0, 10.0, 0.0069863264069654995, 1e-16, cdf(0.5));
+        test_almost(10.0, 0.5, 0.99856168586355656, 1e-15, cdf(30.0));
+        test_almost(3.0, 50.0, 1.3595116165333681e-9, 1e-22, cdf(2.0));
+        test_almost(0.5, 2.0, 0.060709573430433592, 1e-15, cdf(1e-3));
+        test_almost(5.0, 100.0, 1.7542838953352233e-16, 1e-29, cdf(5.0));
+        assert_eq!(try_create(2.0, 1.0).cdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: NoncentralChiSquared| x.sf(arg);
+        test_almost(2.0, 1.0, 0.73287980379682022, 1e-15, sf(1.0));
+        test_almost(4.0, 3.0, 0.6115850844511616, 1e-15, sf(5.0));
+        test_almost(10.0, 0.5, 0.0014383141364434381, 1e-17, sf(30.0));
+        test_almost(5.0, 100.0, 3.5243878099594201e-5, 1e-17, sf(200.0));
+        test_almost(5.0, 100.0, 3.062670291801272e-23, 1e-36, sf(400.0));
+        assert_eq!(try_create(2.0, 1.0).sf(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(4.0, 3.0);
+        assert_almost_eq!(n.inverse_cdf(0.3884149155488384), 5.0, 1e-8);
+        tests::check_inverse_cdf(&n, 1e-8);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(4.0, 3.0), 0.0, 60.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(3.0, 5.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean - 8.0).abs() < 5.0 * (26.0f64 / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 6.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(6.0)).abs() < 0.015);
+    }
+}
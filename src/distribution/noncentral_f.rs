@@ -0,0 +1,620 @@
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::beta;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Noncentral F](https://en.wikipedia.org/wiki/Noncentral_F-distribution)
+/// distribution, the distribution of the ratio of a noncentral chi-squared
+/// variate with noncentrality `λ` to an independent central chi-squared
+/// variate, each divided by its degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralF, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralF::new(3.0, 12.0, 2.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 2.0, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(0.5), 0.39540042869196841, 1e-14));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralF {
+    freedom_1: f64,
+    freedom_2: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralF {
+    /// Constructs a new noncentral fisher-snedecor distribution with
+    /// degrees of freedom `freedom_1` and `freedom_2` and noncentrality
+    /// `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite. Also returns
+    /// an error if `freedom_1 <= 0.0`, `freedom_2 <= 0.0` or
+    /// `noncentrality < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralF;
+    ///
+    /// let mut result = NoncentralF::new(3.0, 5.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralF::new(3.0, 5.0, -2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom_1: f64, freedom_2: f64, noncentrality: f64) -> Result<NoncentralF> {
+        if !freedom_1.is_finite()
+            || freedom_1 <= 0.0
+            || !freedom_2.is_finite()
+            || freedom_2 <= 0.0
+            || !noncentrality.is_finite()
+            || noncentrality < 0.0
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralF {
+                freedom_1,
+                freedom_2,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the first degree of freedom for the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralF;
+    ///
+    /// let n = NoncentralF::new(3.0, 5.0, 2.0).unwrap();
+    /// assert_eq!(n.freedom_1(), 3.0);
+    /// ```
+    pub fn freedom_1(&self) -> f64 {
+        self.freedom_1
+    }
+
+    /// Returns the second degree of freedom for the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralF;
+    ///
+    /// let n = NoncentralF::new(3.0, 5.0, 2.0).unwrap();
+    /// assert_eq!(n.freedom_2(), 5.0);
+    /// ```
+    pub fn freedom_2(&self) -> f64 {
+        self.freedom_2
+    }
+
+    /// Returns the noncentrality of the noncentral fisher-snedecor
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralF;
+    ///
+    /// let n = NoncentralF::new(3.0, 5.0, 2.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 2.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Returns `(y, 1 - y)` for the beta variate `y = d1 * x / (d1 * x + d2)`
+    /// corresponding to `x`, computing both without cancellation
+    fn beta_variate(&self, x: f64) -> (f64, f64) {
+        let denom = self.freedom_1 * x + self.freedom_2;
+        (self.freedom_1 * x / denom, self.freedom_2 / denom)
+    }
+
+    /// Returns the mean and the second to fourth central moments of the
+    /// noncentral chi-squared numerator, followed by those of the reciprocal
+    /// of the central chi-squared denominator. The latter are only finite
+    /// for `d2 > 2k` where `k` is the order of the moment
+    fn component_moments(&self) -> ([f64; 4], [f64; 4]) {
+        let k = self.freedom_1;
+        let l = self.noncentrality;
+        let u2 = 2.0 * (k + 2.0 * l);
+        let numer = [
+            k + l,
+            u2,
+            8.0 * (k + 3.0 * l),
+            48.0 * (k + 4.0 * l) + 3.0 * u2 * u2,
+        ];
+
+        let n = self.freedom_2;
+        let v2 = 2.0 / ((n - 2.0) * (n - 2.0) * (n - 4.0));
+        let denom = [
+            1.0 / (n - 2.0),
+            v2,
+            16.0 / ((n - 2.0) * (n - 2.0) * (n - 2.0) * (n - 4.0) * (n - 6.0)),
+            (3.0 + 12.0 * (5.0 * n - 22.0) / ((n - 6.0) * (n - 8.0))) * v2 * v2,
+        ];
+        (numer, denom)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralF {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        (super::noncentral_chi_squared::sample_unchecked(rng, self.freedom_1, self.noncentrality)
+            * self.freedom_2)
+            / (super::gamma::sample_unchecked(rng, self.freedom_2 / 2.0, 0.5) * self.freedom_1)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralF {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The series is summed outwards from its largest term and all of its
+    /// terms are positive, so the lower tail keeps full relative accuracy
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(e^(-λ / 2) * (λ / 2)^j / j! * I_y(d1 / 2 + j, d2 / 2)) for j = 0..INF
+    /// ```
+    ///
+    /// where `y = d1 * x / (d1 * x + d2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, `λ` is the
+    /// noncentrality and `I` is the regularized incomplete beta function
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            let mean = self.noncentrality / 2.0;
+            let a = self.freedom_1 / 2.0;
+            let b = self.freedom_2 / 2.0;
+            let (y, _) = self.beta_variate(x);
+            internal::ln_sum_terms(mean.floor() as u64, |j| {
+                internal::ln_poisson_weight(j, mean) + beta::beta_reg(a + j as f64, b, y).ln()
+            })
+            .exp()
+        }
+    }
+
+    /// Calculates the survival function for the noncentral fisher-snedecor
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The series is summed outwards from its largest term and all of its
+    /// terms are positive, so the upper tail keeps full relative accuracy
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(e^(-λ / 2) * (λ / 2)^j / j! * I_(1 - y)(d2 / 2, d1 / 2 + j)) for j = 0..INF
+    /// ```
+    ///
+    /// where `y = d1 * x / (d1 * x + d2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, `λ` is the
+    /// noncentrality and `I` is the regularized incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            let mean = self.noncentrality / 2.0;
+            let a = self.freedom_1 / 2.0;
+            let b = self.freedom_2 / 2.0;
+            let (_, y_c) = self.beta_variate(x);
+            internal::ln_sum_terms(mean.floor() as u64, |j| {
+                internal::ln_poisson_weight(j, mean) + beta::beta_reg(b, a + j as f64, y_c).ln()
+            })
+            .exp()
+        }
+    }
+}
+
+impl Min<f64> for NoncentralF {
+    /// Returns the minimum value in the domain of the noncentral
+    /// fisher-snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralF {
+    /// Returns the maximum value in the domain of the noncentral
+    /// fisher-snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralF {
+    /// Returns the mean of the noncentral fisher-snedecor distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom_2 <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d2 * (d1 + λ) / (d1 * (d2 - 2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        if self.freedom_2 <= 2.0 {
+            None
+        } else {
+            let d1 = self.freedom_1;
+            let d2 = self.freedom_2;
+            Some(d2 * (d1 + self.noncentrality) / (d1 * (d2 - 2.0)))
+        }
+    }
+    /// Returns the variance of the noncentral fisher-snedecor distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom_2 <= 4.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * (d2 / d1)^2 * ((d1 + λ)^2 + (d1 + 2λ) * (d2 - 2)) / ((d2 - 2)^2 * (d2 - 4))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        if self.freedom_2 <= 4.0 {
+            None
+        } else {
+            let d1 = self.freedom_1;
+            let d2 = self.freedom_2;
+            let l = self.noncentrality;
+            let ratio = d2 / d1;
+            Some(
+                2.0 * ratio * ratio * ((d1 + l) * (d1 + l) + (d1 + 2.0 * l) * (d2 - 2.0))
+                    / ((d2 - 2.0) * (d2 - 2.0) * (d2 - 4.0)),
+            )
+        }
+    }
+    /// Returns the skewness of the noncentral fisher-snedecor distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom_2 <= 6.0`. The central moments are
+    /// assembled from those of the independent numerator and reciprocal
+    /// denominator rather than from the raw moments, which would cancel
+    /// catastrophically for large noncentralities
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (m^3 * v3 + 3 * m * u2 * (2 * w * v2 + v3) + u3 * (w^3 + 3 * w * v2 + v3)) / σ^3
+    /// ```
+    ///
+    /// where `m` and `u_k` are the mean and central moments of the
+    /// noncentral chi-squared numerator, `w` and `v_k` those of the
+    /// reciprocal of the chi-squared denominator and `σ^2 = w^2 * u2 +
+    /// m^2 * v2 + u2 * v2`
+    fn skewness(&self) -> Option<f64> {
+        if self.freedom_2 <= 6.0 {
+            None
+        } else {
+            let ([m, u2, u3, _], [w, v2, v3, _]) = self.component_moments();
+            let var = w * w * u2 + m * m * v2 + u2 * v2;
+            let c3 = m * m * m * v3
+                + 3.0 * m * u2 * (2.0 * w * v2 + v3)
+                + u3 * (w * w * w + 3.0 * w * v2 + v3);
+            Some(c3 / var.powf(1.5))
+        }
+    }
+    /// Returns the excess kurtosis of the noncentral fisher-snedecor
+    /// distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom_2 <= 8.0`. The central moments are
+    /// assembled from those of the independent numerator and reciprocal
+    /// denominator as for the skewness
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (m^4 * v4 + 6 * m^2 * u2 * (w^2 * v2 + 2 * w * v3 + v4)
+    ///     + 4 * m * u3 * (3 * w^2 * v2 + 3 * w * v3 + v4)
+    ///     + u4 * (w^4 + 6 * w^2 * v2 + 4 * w * v3 + v4)) / σ^4 - 3
+    /// ```
+    ///
+    /// where `m` and `u_k` are the mean and central moments of the
+    /// noncentral chi-squared numerator, `w` and `v_k` those of the
+    /// reciprocal of the chi-squared denominator and `σ^2 = w^2 * u2 +
+    /// m^2 * v2 + u2 * v2`
+    fn kurtosis(&self) -> Option<f64> {
+        if self.freedom_2 <= 8.0 {
+            None
+        } else {
+            let ([m, u2, u3, u4], [w, v2, v3, v4]) = self.component_moments();
+            let var = w * w * u2 + m * m * v2 + u2 * v2;
+            let c4 = m * m * m * m * v4
+                + 6.0 * m * m * u2 * (w * w * v2 + 2.0 * w * v3 + v4)
+                + 4.0 * m * u3 * (3.0 * w * w * v2 + 3.0 * w * v3 + v4)
+                + u4 * (w * w * w * w + 6.0 * w * w * v2 + 4.0 * w * v3 + v4);
+            Some(c4 / (var * var) - 3.0)
+        }
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralF {
+    /// Calculates the probability density function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `INF` at `x = 0` if `d1 < 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d1 * d2 / (d1 * x + d2)^2 * sum(e^(-λ / 2) * (λ / 2)^j / j!
+    ///     * y^(d1 / 2 + j - 1) * (1 - y)^(d2 / 2 - 1) / β(d1 / 2 + j, d2 / 2)) for j = 0..INF
+    /// ```
+    ///
+    /// where `y = d1 * x / (d1 * x + d2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, `λ` is the
+    /// noncentrality and `β` is the beta function
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `INF` at `x = 0` if `d1 < 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(d1 * d2 / (d1 * x + d2)^2 * sum(e^(-λ / 2) * (λ / 2)^j / j!
+    ///     * y^(d1 / 2 + j - 1) * (1 - y)^(d2 / 2 - 1) / β(d1 / 2 + j, d2 / 2)) for j = 0..INF)
+    /// ```
+    ///
+    /// where `y = d1 * x / (d1 * x + d2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, `λ` is the
+    /// noncentrality and `β` is the beta function
+    fn ln_pdf(&self, x: f64) -> f64 {
+        let d1 = self.freedom_1;
+        let d2 = self.freedom_2;
+        let mean = self.noncentrality / 2.0;
+        if x < 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else if x == 0.0 {
+            if d1 < 2.0 {
+                f64::INFINITY
+            } else if d1 == 2.0 {
+                -mean
+            } else {
+                f64::NEG_INFINITY
+            }
+        } else {
+            let a = d1 / 2.0;
+            let b = d2 / 2.0;
+            let (y, y_c) = self.beta_variate(x);
+            let (ln_y, ln_y_c) = (y.ln(), y_c.ln());
+            let ln_jacobian = (d1 * d2).ln() - 2.0 * (d1 * x + d2).ln();
+            // the ratio of consecutive terms is
+            // (λ / 2) * y * (a + b + j) / ((j + 1) * (a + j))
+            let c = mean * y;
+            let peak = (c - a - 1.0 + ((a - 1.0 + c) * (a - 1.0 + c) + 4.0 * b * c).sqrt()) / 2.0;
+            internal::ln_sum_terms(peak.max(0.0).round() as u64, |j| {
+                let aj = a + j as f64;
+                internal::ln_poisson_weight(j, mean) + (aj - 1.0) * ln_y + (b - 1.0) * ln_y_c
+                    - beta::ln_beta(aj, b)
+            }) + ln_jacobian
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, FisherSnedecor, NoncentralF};
+    use crate::distribution::internal::*;
+
+    fn try_create(freedom_1: f64, freedom_2: f64, noncentrality: f64) -> NoncentralF {
+        let n = NoncentralF::new(freedom_1, freedom_2, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(freedom_1: f64, freedom_2: f64, noncentrality: f64) {
+        let n = try_create(freedom_1, freedom_2, noncentrality);
+        assert_eq!(freedom_1, n.freedom_1());
+        assert_eq!(freedom_2, n.freedom_2());
+        assert_eq!(noncentrality, n.noncentrality());
+    }
+
+    fn bad_create_case(freedom_1: f64, freedom_2: f64, noncentrality: f64) {
+        let n = NoncentralF::new(freedom_1, freedom_2, noncentrality);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(freedom_1: f64, freedom_2: f64, noncentrality: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(NoncentralF) -> f64
+    {
+        let n = try_create(freedom_1, freedom_2, noncentrality);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    fn test_none<F>(freedom_1: f64, freedom_2: f64, noncentrality: f64, eval: F)
+        where F: Fn(NoncentralF) -> Option<f64>
+    {
+        let n = try_create(freedom_1, freedom_2, noncentrality);
+        assert!(eval(n).is_none());
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.5, 0.5, 0.0);
+        create_case(3.0, 5.0, 2.0);
+        create_case(10.0, 1.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 1.0);
+        bad_create_case(1.0, f64::NAN, 1.0);
+        bad_create_case(1.0, 1.0, f64::NAN);
+        bad_create_case(0.0, 1.0, 1.0);
+        bad_create_case(1.0, 0.0, 1.0);
+        bad_create_case(1.0, 1.0, -1.0);
+        bad_create_case(f64::INFINITY, 1.0, 1.0);
+        bad_create_case(1.0, f64::INFINITY, 1.0);
+        bad_create_case(1.0, 1.0, f64::INFINITY);
+    }
+
+    // Moment references are computed with mpmath at 40 digits from the raw
+    // moments of the numerator and the reciprocal of the denominator
+    #[test]
+    fn test_moments() {
+        let mean = |x: NoncentralF| x.mean().unwrap();
+        let variance = |x: NoncentralF| x.variance().unwrap();
+        let skewness = |x: NoncentralF| x.skewness().unwrap();
+        let kurtosis = |x: NoncentralF| x.kurtosis().unwrap();
+        test_almost(3.0, 12.0, 2.0, 2.0, 1e-15, mean);
+        test_almost(3.0, 12.0, 2.0, 3.8, 1e-14, variance);
+        test_almost(3.0, 12.0, 2.0, 3.1679331573155635, 1e-14, skewness);
+        test_almost(3.0, 12.0, 2.0, 25.337950138504155, 1e-12, kurtosis);
+        test_almost(10.0, 20.0, 30.0, 4.4135802469135802, 1e-14, variance);
+        test_almost(10.0, 20.0, 30.0, 1.6463620566875094, 1e-14, skewness);
+        test_almost(10.0, 20.0, 30.0, 5.7362092453001544, 1e-13, kurtosis);
+        test_almost(4.0, 9.0, 0.5, 4.7446018805827406, 1e-13, skewness);
+        test_almost(4.0, 9.0, 0.5, 113.18875534898958, 1e-11, kurtosis);
+        test_almost(5.0, 100.0, 1000.0, 205.10204081632653, 1e-12, mean);
+        test_almost(5.0, 100.0, 1000.0, 1046.8858461752048, 1e-10, variance);
+        test_almost(5.0, 100.0, 1000.0, 0.58983894482312105, 1e-14, skewness);
+        test_almost(5.0, 100.0, 1000.0, 0.66363729171066934, 1e-14, kurtosis);
+        test_none(3.0, 2.0, 1.0, |x| x.mean());
+        test_none(3.0, 4.0, 1.0, |x| x.variance());
+        test_none(3.0, 6.0, 1.0, |x| x.skewness());
+        test_none(3.0, 8.0, 1.0, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_central() {
+        let n = try_create(3.0, 9.0, 0.0);
+        let f = FisherSnedecor::new(3.0, 9.0).unwrap();
+        assert_almost_eq!(n.skewness().unwrap(), f.skewness().unwrap(), 1e-14);
+        assert_almost_eq!(n.kurtosis().unwrap(), f.kurtosis().unwrap(), 1e-13);
+        for &x in &[0.1, 1.0, 2.5, 20.0] {
+            assert_almost_eq!(n.pdf(x), f.pdf(x), 1e-14);
+            assert_almost_eq!(n.cdf(x), f.cdf(x), 1e-14);
+            assert_almost_eq!(n.sf(x), f.sf(x), 1e-14);
+        }
+    }
+
+    // Reference values are computed with mpmath at 40 digits, the density by
+    // quadrature over the denominator and the cdf and sf from the Poisson series
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: NoncentralF| x.pdf(arg);
+        test_almost(3.0, 5.0, 2.0, 0.25023937160753767, 1e-14, pdf(1.5));
+        test_almost(1.0, 10.0, 4.0, 0.15517954207532137, 1e-14, pdf(0.2));
+        test_almost(10.0, 20.0, 30.0, 0.23990920540764695, 1e-14, pdf(3.0));
+        test_almost(5.0, 2.0, 1.0, 0.00046433470038810155, 1e-18, pdf(50.0));
+        test_almost(4.0, 30.0, 100.0, 5.9510837889533446e-15, 1e-27, pdf(1.0));
+        test_almost(2.0, 7.0, 3.0, (-1.5f64).exp(), 1e-14, pdf(0.0));
+        assert_eq!(try_create(1.0, 7.0, 3.0).pdf(0.0), f64::INFINITY);
+        assert_eq!(try_create(3.0, 7.0, 3.0).pdf(0.0), 0.0);
+        assert_eq!(try_create(3.0, 7.0, 3.0).pdf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: NoncentralF| x.ln_pdf(arg);
+        test_almost(3.0, 5.0, 2.0, 0.25023937160753767f64.ln(), 1e-14, ln_pdf(1.5));
+        test_almost(4.0, 30.0, 100.0, 5.9510837889533446e-15f64.ln(), 1e-13, ln_pdf(1.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: NoncentralF| x.cdf(arg);
+        test_almost(3.0, 5.0, 2.0, 0.48196716603366046, 1e-14, cdf(1.5));
+        test_almost(1.0, 10.0, 4.0, 0.052167848862616206, 1e-14, cdf(0.2));
+        test_almost(10.0, 20.0, 30.0, 0.25237358046871392, 1e-14, cdf(3.0));
+        test_almost(5.0, 2.0, 1.0, 0.97639439779622518, 1e-14, cdf(50.0));
+        test_almost(4.0, 30.0, 100.0, 5.6553819261057121e-16, 1e-28, cdf(1.0));
+        assert_eq!(try_create(3.0, 5.0, 2.0).cdf(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: NoncentralF| x.sf(arg);
+        test_almost(3.0, 5.0, 2.0, 0.51803283396633954, 1e-14, sf(1.5));
+        test_almost(1.0, 10.0, 4.0, 0.94783215113738379, 1e-14, sf(0.2));
+        test_almost(5.0, 2.0, 1.0, 0.023605602203774815, 1e-16, sf(50.0));
+        test_almost(4.0, 30.0, 100.0, 0.99999999999999943, 1e-14, sf(1.0));
+        assert_eq!(try_create(3.0, 5.0, 2.0).sf(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(3.0, 5.0, 2.0);
+        assert_almost_eq!(n.inverse_cdf(0.48196716603366046), 1.5, 1e-8);
+        tests::check_inverse_cdf(&n, 1e-8);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(3.0, 12.0, 2.0), 0.0, 100.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(3.0, 12.0, 2.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean - 2.0).abs() < 5.0 * (3.8f64 / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 1.5).count() as f64 / count as f64;
+        assert!((below - n.cdf(1.5)).abs() < 0.015);
+    }
+}
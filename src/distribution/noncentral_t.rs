@@ -0,0 +1,642 @@
+use crate::consts;
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::{beta, gamma};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Noncentral t](https://en.wikipedia.org/wiki/Noncentral_t-distribution)
+/// distribution, the distribution of `(Z + δ) / sqrt(V / ν)` where `Z` is a
+/// standard normal variate and `V` an independent chi-squared variate with
+/// `ν` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralT, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralT::new(5.0, 1.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.1894160774351807, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(2.0), 0.21140643469129709, 1e-14));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralT {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralT {
+    /// Constructs a new noncentral student's t-distribution with `freedom`
+    /// degrees of freedom and noncentrality `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` or `noncentrality` are `NaN` or
+    /// infinite. Also returns an error if `freedom <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralT;
+    ///
+    /// let mut result = NoncentralT::new(5.0, -1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralT::new(0.0, 1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, noncentrality: f64) -> Result<NoncentralT> {
+        if !freedom.is_finite() || freedom <= 0.0 || !noncentrality.is_finite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NoncentralT {
+                freedom,
+                noncentrality,
+            })
+        }
+    }
+
+    /// Returns the degrees of freedom of the noncentral student's
+    /// t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralT;
+    ///
+    /// let n = NoncentralT::new(5.0, 1.0).unwrap();
+    /// assert_eq!(n.freedom(), 5.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality of the noncentral student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralT;
+    ///
+    /// let n = NoncentralT::new(5.0, 1.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 1.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Returns `g^2 - 1` where `g = sqrt(ν / 2) * Γ((ν - 1) / 2) / Γ(ν / 2)`
+    /// is the ratio of the mean to the noncentrality. The central moments
+    /// are expressed in terms of this small quantity so that they do not
+    /// cancel for large degrees of freedom
+    fn mean_ratio_sq_m1(&self) -> f64 {
+        (2.0 * internal::ln_scaled_gamma_ratio(self.freedom / 2.0, -0.5)).exp_m1()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralT {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = super::normal::sample_unchecked(rng, self.noncentrality, 1.0);
+        let v = super::gamma::sample_unchecked(rng, self.freedom / 2.0, 0.5);
+        z / (v / self.freedom).sqrt()
+    }
+}
+
+/// Returns `(x, 1 - x)` for the beta variate `x = t^2 / (ν + t^2)`,
+/// computing both without cancellation or overflow
+fn beta_variate(t: f64, freedom: f64) -> (f64, f64) {
+    (
+        1.0 / (1.0 + freedom / (t * t)),
+        1.0 / (1.0 + t * t / freedom),
+    )
+}
+
+/// Sums the series for the noncentral t-distribution at `t >= 0` with
+/// `δ >= 0`, for which every term is positive. With `lower` set this gives
+/// `2 * (P(T <= t) - Φ(-δ))`, otherwise `2 * P(T > t)`
+fn incomplete_beta_series(t: f64, freedom: f64, noncentrality: f64, lower: bool) -> f64 {
+    let (x, x_c) = beta_variate(t, freedom);
+    let b = freedom / 2.0;
+    let mean = noncentrality * noncentrality / 2.0;
+    let ln_scale = noncentrality.ln() - 0.5 * f64::consts::LN_2;
+    let ln_beta = |a: f64| {
+        if lower {
+            beta::beta_reg(a, b, x).ln()
+        } else {
+            beta::beta_reg(b, a, x_c).ln()
+        }
+    };
+    internal::ln_sum_terms(mean.floor() as u64, |j| {
+        let a = j as f64 + 0.5;
+        let ln_p = internal::ln_poisson_weight(j, mean);
+        // q_j = p_j * δ / sqrt(2) * Γ(j + 1) / Γ(j + 3/2)
+        let ln_q =
+            ln_p + ln_scale + internal::ln_scaled_gamma_ratio(a + 1.0, -0.5) - 0.5 * (a + 1.0).ln();
+        internal::ln_add_exp(ln_p + ln_beta(a), ln_q + ln_beta(a + 0.5))
+    })
+    .exp()
+}
+
+/// Computes `P(T > t)` for `t >= 0` and any `δ` as
+/// `E[Φ(δ - t * W / sqrt(ν))]` over the chi distributed `W = sqrt(V)`,
+/// integrated in `s = ln(W)`. This avoids the cancellation of the series
+/// when `δ < 0`, where the probability lies in the opposite tail
+fn upper_by_quadrature(t: f64, freedom: f64, noncentrality: f64) -> f64 {
+    let c = t / freedom.sqrt();
+    let ln_norm = (1.0 - freedom / 2.0) * f64::consts::LN_2 - gamma::ln_gamma(freedom / 2.0);
+    // the peak of the integrand lies close to the positive root of
+    // (1 + c^2) * w^2 - c * δ * w - ν = 0
+    let w = internal::quadratic_root(1.0 + c * c, c * noncentrality, freedom);
+    let step = 0.25 / ((1.0 + c * c) * w * w + freedom).sqrt();
+    internal::ln_integrate_trapezoid(
+        |s| {
+            let w = s.exp();
+            ln_norm + freedom * s - 0.5 * w * w + super::normal::ln_std_cdf(noncentrality - c * w)
+        },
+        w.ln(),
+        step,
+    )
+    .exp()
+}
+
+/// Computes `P(T <= t)` for `t >= 0`
+fn lower_nonnegative(t: f64, freedom: f64, noncentrality: f64) -> f64 {
+    if noncentrality >= 0.0 && t < noncentrality {
+        super::normal::sf_unchecked(noncentrality, 0.0, 1.0)
+            + 0.5 * incomplete_beta_series(t, freedom, noncentrality, true)
+    } else {
+        1.0 - upper_nonnegative(t, freedom, noncentrality)
+    }
+}
+
+/// Computes `P(T > t)` for `t >= 0`
+fn upper_nonnegative(t: f64, freedom: f64, noncentrality: f64) -> f64 {
+    if noncentrality >= 0.0 {
+        0.5 * incomplete_beta_series(t, freedom, noncentrality, false)
+    } else {
+        upper_by_quadrature(t, freedom, noncentrality)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralT {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// student's t-distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Negative `t` are reflected using `F(t; ν, δ) = 1 - F(-t; ν, -δ)`.
+    /// When the series below would alternate, i.e. when `t` and `δ` have
+    /// opposite signs, the tail probability is instead integrated
+    /// numerically against the chi distribution of `sqrt(V)`. The accuracy
+    /// is then limited by that of the normal cdf
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(-δ) + 1 / 2 * sum(p_j * I_x(j + 1/2, ν / 2) + q_j * I_x(j + 1, ν / 2)) for j = 0..INF
+    /// ```
+    ///
+    /// where `x = t^2 / (ν + t^2)`, `p_j = e^(-δ^2 / 2) * (δ^2 / 2)^j / j!`,
+    /// `q_j = δ * e^(-δ^2 / 2) * (δ^2 / 2)^j / (sqrt(2) * Γ(j + 3/2))`,
+    /// `ν` is the degrees of freedom, `δ` is the noncentrality, `Φ` is the
+    /// standard normal cdf and `I` is the regularized incomplete beta
+    /// function
+    fn cdf(&self, t: f64) -> f64 {
+        if t == f64::NEG_INFINITY {
+            0.0
+        } else if t == f64::INFINITY {
+            1.0
+        } else if t >= 0.0 {
+            lower_nonnegative(t, self.freedom, self.noncentrality)
+        } else {
+            upper_nonnegative(-t, self.freedom, -self.noncentrality)
+        }
+    }
+
+    /// Calculates the survival function for the noncentral student's
+    /// t-distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// Negative `t` are reflected using `S(t; ν, δ) = 1 - S(-t; ν, -δ)`.
+    /// When the series below would alternate, i.e. when `t` and `δ` have
+    /// opposite signs, the tail probability is instead integrated
+    /// numerically against the chi distribution of `sqrt(V)`. The accuracy
+    /// is then limited by that of the normal cdf
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2 * sum(p_j * I_(1 - x)(ν / 2, j + 1/2) + q_j * I_(1 - x)(ν / 2, j + 1)) for j = 0..INF
+    /// ```
+    ///
+    /// where `x = t^2 / (ν + t^2)`, `p_j = e^(-δ^2 / 2) * (δ^2 / 2)^j / j!`,
+    /// `q_j = δ * e^(-δ^2 / 2) * (δ^2 / 2)^j / (sqrt(2) * Γ(j + 3/2))`,
+    /// `ν` is the degrees of freedom, `δ` is the noncentrality and `I` is
+    /// the regularized incomplete beta function
+    fn sf(&self, t: f64) -> f64 {
+        if t == f64::NEG_INFINITY {
+            1.0
+        } else if t == f64::INFINITY {
+            0.0
+        } else if t >= 0.0 {
+            upper_nonnegative(t, self.freedom, self.noncentrality)
+        } else {
+            lower_nonnegative(-t, self.freedom, -self.noncentrality)
+        }
+    }
+}
+
+impl Min<f64> for NoncentralT {
+    /// Returns the minimum value in the domain of the noncentral student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for NoncentralT {
+    /// Returns the maximum value in the domain of the noncentral student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralT {
+    /// Returns the mean of the noncentral student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// δ * g
+    /// ```
+    ///
+    /// where `g = sqrt(ν / 2) * Γ((ν - 1) / 2) / Γ(ν / 2)`, `ν` is the
+    /// degrees of freedom and `δ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        if self.freedom <= 1.0 {
+            None
+        } else {
+            Some(
+                self.noncentrality
+                    * internal::ln_scaled_gamma_ratio(self.freedom / 2.0, -0.5).exp(),
+            )
+        }
+    }
+    /// Returns the variance of the noncentral student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ν * (1 + δ^2) / (ν - 2) - δ^2 * g^2
+    /// ```
+    ///
+    /// where `g = sqrt(ν / 2) * Γ((ν - 1) / 2) / Γ(ν / 2)`, `ν` is the
+    /// degrees of freedom and `δ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        if self.freedom <= 2.0 {
+            None
+        } else {
+            let nu = self.freedom;
+            let d2 = self.noncentrality * self.noncentrality;
+            let e = self.mean_ratio_sq_m1();
+            Some(nu / (nu - 2.0) + d2 * (2.0 / (nu - 2.0) - e))
+        }
+    }
+    /// Returns the skewness of the noncentral student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 3.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (μ'_3 - 3 * μ * μ'_2 + 2 * μ^3) / σ^3
+    /// ```
+    ///
+    /// where `μ'_k = (ν / 2)^(k / 2) * Γ((ν - k) / 2) / Γ(ν / 2) * E[(Z + δ)^k]`
+    /// are the raw moments, `μ` is the mean, `σ^2` is the variance, `ν` is
+    /// the degrees of freedom and `δ` is the noncentrality. The terms are
+    /// rearranged in powers of `δ^2` so that they do not cancel
+    fn skewness(&self) -> Option<f64> {
+        if self.freedom <= 3.0 {
+            None
+        } else {
+            let nu = self.freedom;
+            let d = self.noncentrality;
+            let e = self.mean_ratio_sq_m1();
+            let a2 = 2.0 / (nu - 2.0);
+            let a3 = 3.0 / (nu - 3.0);
+            let g = (1.0 + e).sqrt();
+            let c3 =
+                d * g * (3.0 * nu / ((nu - 2.0) * (nu - 3.0)) + d * d * (a3 - 3.0 * a2 + 2.0 * e));
+            Some(c3 / self.variance().unwrap().powf(1.5))
+        }
+    }
+    /// Returns the excess kurtosis of the noncentral student's
+    /// t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 4.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (μ'_4 - 4 * μ * μ'_3 + 6 * μ^2 * μ'_2 - 3 * μ^4) / σ^4 - 3
+    /// ```
+    ///
+    /// where `μ'_k = (ν / 2)^(k / 2) * Γ((ν - k) / 2) / Γ(ν / 2) * E[(Z + δ)^k]`
+    /// are the raw moments, `μ` is the mean, `σ^2` is the variance, `ν` is
+    /// the degrees of freedom and `δ` is the noncentrality. The terms are
+    /// rearranged in powers of `δ^2` so that they do not cancel
+    fn kurtosis(&self) -> Option<f64> {
+        if self.freedom <= 4.0 {
+            None
+        } else {
+            let nu = self.freedom;
+            let d2 = self.noncentrality * self.noncentrality;
+            let e = self.mean_ratio_sq_m1();
+            let a2 = 2.0 / (nu - 2.0);
+            let a3 = 3.0 / (nu - 3.0);
+            let a4 = (6.0 * nu - 8.0) / ((nu - 2.0) * (nu - 4.0));
+            let c4 = 3.0 * (1.0 + a4)
+                + d2 * (6.0 * a4 - 12.0 * a3 + 6.0 * a2 - 6.0 * e - 12.0 * e * a3 + 6.0 * e * a2)
+                + d2 * d2
+                    * (a4 - 4.0 * a3 + 6.0 * a2 - 4.0 * e - 4.0 * e * a3 + 6.0 * e * a2
+                        - 3.0 * e * e);
+            let var = self.variance().unwrap();
+            Some(c4 / (var * var) - 3.0)
+        }
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralT {
+    /// Calculates the probability density function for the noncentral
+    /// student's t-distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// The density is integrated numerically over the chi distributed
+    /// `W = sqrt(V)` rather than summed as a series, since the series
+    /// alternates and cancels when `t` and `δ` have opposite signs
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// int(f_W(w) * w / sqrt(ν) * φ(t * w / sqrt(ν) - δ), w=0..INF)
+    /// ```
+    ///
+    /// where `f_W` is the pdf of the chi distribution with `ν` degrees of
+    /// freedom, `ν` is the degrees of freedom, `δ` is the noncentrality
+    /// and `φ` is the standard normal pdf
+    fn pdf(&self, t: f64) -> f64 {
+        self.ln_pdf(t).exp()
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// student's t-distribution at `t`
+    ///
+    /// # Remarks
+    ///
+    /// The density is integrated numerically over the chi distributed
+    /// `W = sqrt(V)` rather than summed as a series, since the series
+    /// alternates and cancels when `t` and `δ` have opposite signs
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(int(f_W(w) * w / sqrt(ν) * φ(t * w / sqrt(ν) - δ), w=0..INF))
+    /// ```
+    ///
+    /// where `f_W` is the pdf of the chi distribution with `ν` degrees of
+    /// freedom, `ν` is the degrees of freedom, `δ` is the noncentrality
+    /// and `φ` is the standard normal pdf
+    fn ln_pdf(&self, t: f64) -> f64 {
+        if t.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let nu = self.freedom;
+        let d = self.noncentrality;
+        let c = t / nu.sqrt();
+        let ln_norm = (1.0 - nu / 2.0) * f64::consts::LN_2
+            - gamma::ln_gamma(nu / 2.0)
+            - 0.5 * nu.ln()
+            - consts::LN_SQRT_2PI;
+        // the integrand peaks exactly at the positive root of
+        // (1 + c^2) * w^2 - c * δ * w - (ν + 1) = 0
+        let w = internal::quadratic_root(1.0 + c * c, c * d, nu + 1.0);
+        let step = 0.25 / ((1.0 + c * c) * w * w + nu + 1.0).sqrt();
+        internal::ln_integrate_trapezoid(
+            |s| {
+                let w = s.exp();
+                let z = c * w - d;
+                ln_norm + (nu + 1.0) * s - 0.5 * w * w - 0.5 * z * z
+            },
+            w.ln(),
+            step,
+        )
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, NoncentralT, StudentsT};
+    use crate::distribution::internal::*;
+
+    fn try_create(freedom: f64, noncentrality: f64) -> NoncentralT {
+        let n = NoncentralT::new(freedom, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(freedom: f64, noncentrality: f64) {
+        let n = try_create(freedom, noncentrality);
+        assert_eq!(freedom, n.freedom());
+        assert_eq!(noncentrality, n.noncentrality());
+    }
+
+    fn bad_create_case(freedom: f64, noncentrality: f64) {
+        let n = NoncentralT::new(freedom, noncentrality);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(freedom: f64, noncentrality: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(NoncentralT) -> f64
+    {
+        let n = try_create(freedom, noncentrality);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    fn test_none<F>(freedom: f64, noncentrality: f64, eval: F)
+        where F: Fn(NoncentralT) -> Option<f64>
+    {
+        let n = try_create(freedom, noncentrality);
+        assert!(eval(n).is_none());
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.5, 0.0);
+        create_case(5.0, 1.0);
+        create_case(30.0, -10.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+        bad_create_case(0.0, 1.0);
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+    }
+
+    // Moment references are computed with mpmath at 50 digits from the raw
+    // moments
+    #[test]
+    fn test_moments() {
+        let mean = |x: NoncentralT| x.mean().unwrap();
+        let variance = |x: NoncentralT| x.variance().unwrap();
+        let skewness = |x: NoncentralT| x.skewness().unwrap();
+        let kurtosis = |x: NoncentralT| x.kurtosis().unwrap();
+        test_almost(5.0, 1.0, 1.1894160774351807, 1e-15, mean);
+        test_almost(5.0, 1.0, 1.9186227280720415, 1e-15, variance);
+        test_almost(5.0, 1.0, 1.2663303981719546, 1e-14, skewness);
+        test_almost(5.0, 1.0, 10.320672480863629, 1e-13, kurtosis);
+        test_almost(10.0, -2.0, -2.1674446158782873, 1e-15, mean);
+        test_almost(10.0, -2.0, 1.5521838371002237, 1e-15, variance);
+        test_almost(10.0, -2.0, -0.72363329408286697, 1e-15, skewness);
+        test_almost(10.0, -2.0, 1.8273295684844696, 1e-14, kurtosis);
+        test_almost(4.5, 0.5, 15.128091006556802, 1e-12, kurtosis);
+        test_almost(1000.0, 3.0, 1.006520929816636, 1e-15, variance);
+        test_almost(1000.0, 3.0, 0.0089978174835638677, 1e-15, skewness);
+        test_almost(1000.0, 3.0, 0.0061321927873601152, 1e-14, kurtosis);
+        test_almost(1e5, 20.0, 20.000150001562516, 1e-13, mean);
+        test_almost(1e5, 20.0, 1.0020200754020831, 1e-14, variance);
+        test_almost(1e5, 20.0, 0.00059921796306088627, 1e-15, skewness);
+        test_almost(1e5, 20.0, 6.0481234074238687e-5, 1e-15, kurtosis);
+        test_none(1.0, 1.0, |x| x.mean());
+        test_none(2.0, 1.0, |x| x.variance());
+        test_none(3.0, 1.0, |x| x.skewness());
+        test_none(4.0, 1.0, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_central() {
+        let n = try_create(8.0, 0.0);
+        let s = StudentsT::new(0.0, 1.0, 8.0).unwrap();
+        assert_almost_eq!(n.variance().unwrap(), s.variance().unwrap(), 1e-15);
+        assert_almost_eq!(n.kurtosis().unwrap(), s.kurtosis().unwrap(), 1e-14);
+        for &t in &[-5.0, -1.2, 0.0, 0.7, 3.0] {
+            assert_almost_eq!(n.pdf(t), s.pdf(t), 1e-14);
+            assert_almost_eq!(n.cdf(t), s.cdf(t), 1e-14);
+            assert_almost_eq!(n.sf(t), s.sf(t), 1e-14);
+        }
+    }
+
+    // Reference values are computed with mpmath at 40 digits by quadrature
+    // over the chi distributed denominator. The cdf and sf go through the
+    // normal cdf and are only checked to its relative accuracy of about 1e-10
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: NoncentralT| x.pdf(arg);
+        test_almost(5.0, 1.0, 0.21140643469129709, 1e-14, pdf(2.0));
+        test_almost(10.0, -2.0, 0.0048090241205533645, 1e-16, pdf(1.0));
+        test_almost(3.0, 4.0, 3.4966272775479188e-6, 1e-19, pdf(-1.0));
+        test_almost(1.0, 0.5, 0.3007251386156554, 1e-14, pdf(0.5));
+        test_almost(20.0, -8.0, 1.0723741669374629e-23, 1e-36, pdf(3.0));
+        test_almost(4.0, 2.0, 1.3794983398188687e-5, 1e-18, pdf(30.0));
+        test_almost(30.0, 10.0, 0.10990766823315095, 1e-14, pdf(8.0));
+        test_almost(2.5, -1.0, 0.086420051400396527, 1e-14, pdf(-3.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: NoncentralT| x.ln_pdf(arg);
+        test_almost(5.0, 1.0, 0.21140643469129709f64.ln(), 1e-13, ln_pdf(2.0));
+        test_almost(20.0, -8.0, 1.0723741669374629e-23f64.ln(), 1e-12, ln_pdf(3.0));
+        assert_eq!(try_create(5.0, 1.0).ln_pdf(f64::INFINITY), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: NoncentralT| x.cdf(arg);
+        test_almost(5.0, 1.0, 0.77807466261621487, 1e-10, cdf(2.0));
+        test_almost(10.0, -2.0, 0.99818356658957035, 1e-10, cdf(1.0));
+        test_almost(3.0, 4.0, 1.5878344612792132e-6, 1e-15, cdf(-1.0));
+        test_almost(1.0, 0.5, 0.45986019407653986, 1e-10, cdf(0.5));
+        test_almost(4.0, 2.0, 0.99989564658158761, 1e-10, cdf(30.0));
+        test_almost(30.0, 10.0, 0.076082257361034326, 1e-10, cdf(8.0));
+        test_almost(2.5, -1.0, 0.14077821949967685, 1e-10, cdf(-3.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: NoncentralT| x.sf(arg);
+        test_almost(5.0, 1.0, 0.22192533738378513, 1e-10, sf(2.0));
+        test_almost(10.0, -2.0, 0.0018164334104296491, 1e-12, sf(1.0));
+        test_almost(3.0, 4.0, 0.99999841216553872, 1e-10, sf(-1.0));
+        test_almost(20.0, -8.0, 2.1898544934037567e-24, 1e-33, sf(3.0));
+        test_almost(4.0, 2.0, 0.00010435341841239011, 1e-14, sf(30.0));
+        test_almost(30.0, 10.0, 0.92391774263896567, 1e-10, sf(8.0));
+        test_almost(2.5, -1.0, 0.85922178050032315, 1e-10, sf(-3.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(5.0, 1.0);
+        assert_almost_eq!(n.inverse_cdf(0.77807466261621487), 2.0, 1e-8);
+        tests::check_inverse_cdf(&n, 1e-8);
+        tests::check_inverse_cdf(&try_create(10.0, -2.0), 1e-8);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(5.0, -1.5), -20.0, 10.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(10.0, -2.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean + 2.1674446158782873).abs() < 5.0 * (1.5521838371002237f64 / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= -2.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(-2.0)).abs() < 0.015);
+    }
+}
@@ -2,6 +2,7 @@ use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
 use crate::function::erf;
 use crate::statistics::*;
 use crate::{consts, Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -74,6 +75,44 @@ impl ContinuousCDF<f64, f64> for Normal {
     fn cdf(&self, x: f64) -> f64 {
         cdf_unchecked(x, self.mean, self.std_dev)
     }
+
+    /// Calculates the survival function for the
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * erfc((x - μ) / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, and
+    /// `erfc` is the complementary error function
+    fn sf(&self, x: f64) -> f64 {
+        sf_unchecked(x, self.mean, self.std_dev)
+    }
+
+    /// Calculates the log of the cumulative distribution function for the
+    /// normal distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Remains finite far into the lower tail where the cdf itself
+    /// underflows to `0.0`
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln_std_cdf((x - self.mean) / self.std_dev)
+    }
+
+    /// Calculates the log of the survival function for the
+    /// normal distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Remains finite far into the upper tail where the survival
+    /// function itself underflows to `0.0`
+    fn ln_sf(&self, x: f64) -> f64 {
+        ln_std_cdf((self.mean - x) / self.std_dev)
+    }
+
     /// Calculates the inverse cumulative distribution function for the
     /// normal distribution at `x`
     ///
@@ -93,7 +132,7 @@ impl ContinuousCDF<f64, f64> for Normal {
         if x < 0.0 || x > 1.0 {
             panic!("x must be in [0, 1]");
         } else {
-            self.mean - (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x))
+            inverse_cdf_unchecked(x, self.mean, self.std_dev)
         }
     }
 }
@@ -169,6 +208,79 @@ impl Distribution<f64> for Normal {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(0.0)
+    }
+    /// Returns the `k`th central moment of the normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^k (k - 1)!!
+    /// ```
+    ///
+    /// for even `k` and `0` for odd `k`, where `σ` is the standard
+    /// deviation and `!!` is the double factorial
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        if k % 2 == 1 {
+            return Some(0.0);
+        }
+        let moment = (1..k)
+            .step_by(2)
+            .fold(1.0, |acc, i| acc * i as f64 * self.std_dev * self.std_dev);
+        Some(moment)
+    }
+}
+
+impl GeneratingFunction<f64> for Normal {
+    /// Returns the moment generating function of the normal distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μt + σ^2 t^2 / 2)
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` the standard deviation
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the normal distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μt + σ^2 t^2 / 2
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` the standard deviation
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let s = self.std_dev * t;
+        Some(self.mean * t + s * s / 2.0)
+    }
+    /// Returns the characteristic function of the normal distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(iμt - σ^2 t^2 / 2)
+    /// ```
+    ///
+    /// where `μ` is the mean and `σ` the standard deviation
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let s = self.std_dev * t;
+        Complex::from_polar((-s * s / 2.0).exp(), self.mean * t)
+    }
 }
 
 impl Median<f64> for Normal {
@@ -238,6 +350,34 @@ pub fn cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     0.5 * erf::erfc((mean - x) / (std_dev * f64::consts::SQRT_2))
 }
 
+/// performs an unchecked survival function calculation for a normal
+/// distribution with the given mean and standard deviation at x
+pub fn sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    0.5 * erf::erfc((x - mean) / (std_dev * f64::consts::SQRT_2))
+}
+
+/// performs an unchecked inverse cdf calculation for a normal distribution
+/// with the given mean and standard deviation at p
+pub fn inverse_cdf_unchecked(p: f64, mean: f64, std_dev: f64) -> f64 {
+    mean - (std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * p))
+}
+
+/// performs an unchecked log(cdf) calculation for the standard normal
+/// distribution at z. Below `z = -30` the cdf is approaching the underflow
+/// limit so the asymptotic expansion of the Mills ratio is used instead
+pub fn ln_std_cdf(z: f64) -> f64 {
+    if z > 0.0 {
+        (-0.5 * erf::erfc(z / f64::consts::SQRT_2)).ln_1p()
+    } else if z > -30.0 {
+        (0.5 * erf::erfc(-z / f64::consts::SQRT_2)).ln()
+    } else {
+        // φ(z) / -z * (1 - 1/z^2 + 3/z^4 - 15/z^6 + 105/z^8)
+        let z2 = z * z;
+        let series = 1.0 - (1.0 - (3.0 - (15.0 - 105.0 / z2) / z2) / z2) / z2;
+        -0.5 * z2 - consts::LN_SQRT_2PI - (-z).ln() + series.ln()
+    }
+}
+
 /// performs an unchecked pdf calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -343,6 +483,76 @@ mod tests {
         test_case(0.0, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Normal| x.kurtosis().unwrap();
+        test_case(1.5, 2.0, 0.0, kurtosis);
+        test_case(-5.0, 0.1, 0.0, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Normal| x.moment(k).unwrap();
+        test_case(1.5, 2.0, 1.0, moment(0));
+        test_case(1.5, 2.0, 1.5, moment(1));
+        test_case(1.5, 2.0, 6.25, moment(2));
+        test_case(1.5, 2.0, 21.375, moment(3));
+        test_case(1.5, 2.0, 107.0625, moment(4));
+        test_case(1.5, 2.0, 2895.140625, moment(6));
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Normal| x.central_moment(k).unwrap();
+        test_case(1.5, 2.0, 1.0, central_moment(0));
+        test_case(1.5, 2.0, 0.0, central_moment(1));
+        test_case(1.5, 2.0, 4.0, central_moment(2));
+        test_case(1.5, 2.0, 0.0, central_moment(3));
+        test_case(1.5, 2.0, 48.0, central_moment(4));
+        test_case(1.5, 2.0, 960.0, central_moment(6));
+        test_case(0.0, 1.0, 654729075.0, central_moment(20));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Normal| x.mgf(t).unwrap();
+        test_almost(1.0, 2.0, 20.085536923187667741, 1e-13, mgf(-1.5));
+        test_almost(1.0, 2.0, 2.7182818284590452354, 1e-14, mgf(0.5));
+        test_almost(1.0, 2.0, 22026.465794806716517, 1e-10, mgf(2.0));
+        test_almost(-0.5, 0.1, 2.1409507369970078791, 1e-14, mgf(-1.5));
+        test_almost(-0.5, 0.1, 0.77977489274195102316, 1e-15, mgf(0.5));
+        test_almost(-0.5, 0.1, 0.37531109885139954538, 1e-15, mgf(2.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Normal| x.cgf(t).unwrap();
+        test_almost(1.0, 2.0, 3.0, 1e-14, cgf(-1.5));
+        test_almost(1.0, 2.0, 1.0, 1e-14, cgf(0.5));
+        test_almost(1.0, 2.0, 10.0, 1e-13, cgf(2.0));
+        test_almost(-0.5, 0.1, 0.76124999999999998224, 1e-15, cgf(-1.5));
+        test_almost(-0.5, 0.1, -0.24874999999999999889, 1e-15, cgf(0.5));
+        test_almost(-0.5, 0.1, -0.97999999999999998224, 1e-15, cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Normal| x.cf(t).re;
+        let im = |t| move |x: Normal| x.cf(t).im;
+        test_almost(1.0, 2.0, 0.00078581932845145953807, 1e-15, re(-1.5));
+        test_almost(1.0, 2.0, -0.011081168353098496565, 1e-15, im(-1.5));
+        test_almost(1.0, 2.0, 0.53228073021567071484, 1e-15, re(0.5));
+        test_almost(1.0, 2.0, 0.29078628821269184886, 1e-15, im(0.5));
+        test_almost(1.0, 2.0, -0.00013960171138142144116, 1e-15, re(2.0));
+        test_almost(1.0, 2.0, 0.00030503530434793514532, 1e-15, im(2.0));
+        test_almost(-0.5, 0.1, 0.72350349813927210002, 1e-15, re(-1.5));
+        test_almost(-0.5, 0.1, 0.67401329762369870536, 1e-15, im(-1.5));
+        test_almost(-0.5, 0.1, 0.96770203783103329866, 1e-15, re(0.5));
+        test_almost(-0.5, 0.1, -0.24709489750928796143, 1e-15, im(0.5));
+        test_almost(-0.5, 0.1, 0.52960360339653125904, 1e-15, re(2.0));
+        test_almost(-0.5, 0.1, -0.82480874293482899879, 1e-15, im(2.0));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Normal| x.mode().unwrap();
@@ -444,6 +654,41 @@ mod tests {
         test_almost(5.0, 2.0, 0.993790334674, 1e-12, cdf(10.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Normal| x.sf(arg);
+        test_case(5.0, 2.0, 1.0, sf(f64::NEG_INFINITY));
+        test_almost(5.0, 2.0, 0.9937903346742238648330218954258077788721022530769078, 1e-12, sf(0.0));
+        test_case(5.0, 2.0, 0.5, sf(5.0));
+        test_almost(5.0, 2.0, 0.30853753872598689636229538939166226011639782444542207, 1e-12, sf(6.0));
+        test_almost(5.0, 2.0, 0.0062096653257761351669781045741922211278977469230927036, 1e-12, sf(10.0));
+        test_almost(5.0, 2.0, 0.00000028665157187919391167375233287464535385442301361187883, 1e-16, sf(15.0));
+        test_almost(0.0, 1.0, 7.619853024160526065973343251599308363504e-24, 1e-33, sf(10.0));
+        test_case(5.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let ln_cdf = |arg: f64| move |x: Normal| x.ln_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_cdf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, -804.6084420137537881666068329186099362002, 1e-10, ln_cdf(-40.0));
+        test_almost(0.0, 1.0, -484.8539636271792885789665079297746374861, 1e-10, ln_cdf(-31.0));
+        test_almost(0.0, 1.0, -451.3229124585286344679221156057908547764, 1e-10, ln_cdf(-29.9));
+        test_almost(0.0, 1.0, -35.01343715991454989550412815253531055057, 1e-9, ln_cdf(-8.0));
+        test_almost(0.0, 1.0, -6.220960574271786058533518453054125914127e-16, 1e-25, ln_cdf(8.0));
+        test_case(0.0, 1.0, 0.0, ln_cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let ln_sf = |arg: f64| move |x: Normal| x.ln_sf(arg);
+        test_case(0.0, 1.0, 0.0, ln_sf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, -6.220960574271786058533518453054125914127e-16, 1e-25, ln_sf(-8.0));
+        test_almost(0.0, 1.0, -616.9751012619225134732442056835419883835, 1e-10, ln_sf(35.0));
+        test_almost(5.0, 2.0, -804.6084420137537881666068329186099362002, 1e-10, ln_sf(85.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_sf(f64::INFINITY));
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(0.0, 1.0), -10.0, 10.0);
@@ -463,5 +708,7 @@ mod tests {
         test_almost(5.0, 2.0, 6.0, 1e-14, inverse_cdf(0.69146246127401310363770461060833773988360217555457859));
         test_almost(5.0, 2.0, 10.0, 1e-14, inverse_cdf(0.9937903346742238648330218954258077788721022530769078));
         test_case(5.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-10);
+        tests::check_inverse_cdf(&try_create(20.0, 0.5), 1e-10);
     }
 }
@@ -0,0 +1,574 @@
+use crate::consts;
+use crate::distribution::{
+    internal, inverse_gaussian, normal, ziggurat, Continuous, ContinuousCDF,
+};
+use crate::function::bessel;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Normal-Inverse
+/// Gaussian](https://en.wikipedia.org/wiki/Normal-inverse_Gaussian_distribution)
+/// distribution, the normal variance-mean mixture `μ + βW + sqrt(W) * Z`
+/// where `Z` is standard normal and `W` is inverse gaussian with a mean of
+/// `δ / γ` and a shape of `δ^2`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NormalInverseGaussian, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NormalInverseGaussian::new(2.0, 0.0, 1.0, 0.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.65793179512803420, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NormalInverseGaussian {
+    alpha: f64,
+    beta: f64,
+    delta: f64,
+    location: f64,
+}
+
+impl NormalInverseGaussian {
+    /// Constructs a new normal-inverse gaussian distribution with a tail
+    /// heaviness (α) of `alpha`, an asymmetry (β) of `beta`, a scale (δ) of
+    /// `delta` and a location (μ) of `location`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the parameters are `NaN` or infinite.
+    /// Also returns an error if `delta <= 0.0` or `|beta| >= alpha`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NormalInverseGaussian;
+    ///
+    /// let mut result = NormalInverseGaussian::new(2.0, 1.0, 1.0, 0.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NormalInverseGaussian::new(1.0, 2.0, 1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(alpha: f64, beta: f64, delta: f64, location: f64) -> Result<NormalInverseGaussian> {
+        if !alpha.is_finite()
+            || !beta.is_finite()
+            || !delta.is_finite()
+            || !location.is_finite()
+            || delta <= 0.0
+            || beta.abs() >= alpha
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(NormalInverseGaussian {
+                alpha,
+                beta,
+                delta,
+                location,
+            })
+        }
+    }
+
+    /// Returns the tail heaviness (α) of the normal-inverse gaussian
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NormalInverseGaussian;
+    ///
+    /// let n = NormalInverseGaussian::new(2.0, 1.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.alpha(), 2.0);
+    /// ```
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the asymmetry (β) of the normal-inverse gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NormalInverseGaussian;
+    ///
+    /// let n = NormalInverseGaussian::new(2.0, 1.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.beta(), 1.0);
+    /// ```
+    pub fn beta(&self) -> f64 {
+        self.beta
+    }
+
+    /// Returns the scale (δ) of the normal-inverse gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NormalInverseGaussian;
+    ///
+    /// let n = NormalInverseGaussian::new(2.0, 1.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.delta(), 3.0);
+    /// ```
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    /// Returns the location (μ) of the normal-inverse gaussian distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NormalInverseGaussian;
+    ///
+    /// let n = NormalInverseGaussian::new(2.0, 1.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.location(), 4.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns `γ = sqrt(α^2 - β^2)`
+    fn gamma(&self) -> f64 {
+        ((self.alpha - self.beta) * (self.alpha + self.beta)).sqrt()
+    }
+
+    /// Computes `P(X <= x)` if `lower` and `P(X > x)` otherwise as the
+    /// expectation of the conditional normal cdf over the mixing inverse
+    /// gaussian variable `W`, integrated in `s = ln(W)`. Both tails are
+    /// integrals of positive terms so neither loses relative accuracy
+    fn tail_by_quadrature(&self, x: f64, lower: bool) -> f64 {
+        let d = x - self.location;
+        let gamma = self.gamma();
+        let q2 = self.delta * self.delta + d * d;
+        let ln_norm = self.delta.ln() - consts::LN_SQRT_2PI + self.delta * gamma;
+        // the peak of the integrand lies close to the positive root of
+        // α^2 * w^2 + w - (δ^2 + (x - μ)^2) = 0, the saddle point of the
+        // integral defining the density
+        let w = internal::quadratic_root(self.alpha * self.alpha, -1.0, q2);
+        let step = 0.25 * (2.0 / (self.alpha * self.alpha * w + q2 / w)).sqrt();
+        internal::ln_integrate_trapezoid(
+            |s| {
+                let w = s.exp();
+                let z = (d - self.beta * w) / w.sqrt();
+                let z = if lower { z } else { -z };
+                ln_norm - 0.5 * s - 0.5 * (gamma * gamma * w + self.delta * self.delta / w)
+                    + normal::ln_std_cdf(z)
+            },
+            w.ln(),
+            step,
+        )
+        .exp()
+        .min(1.0)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NormalInverseGaussian {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let w = inverse_gaussian::sample_unchecked(
+            r,
+            self.delta / self.gamma(),
+            self.delta * self.delta,
+        );
+        self.location + self.beta * w + w.sqrt() * ziggurat::sample_std_normal(r)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NormalInverseGaussian {
+    /// Calculates the cumulative distribution function for the
+    /// normal-inverse gaussian distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form so the cdf is integrated numerically over the
+    /// inverse gaussian mixing distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// E[Φ((x - μ - βW) / sqrt(W))]
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the asymmetry, `Φ` is the cdf of the
+    /// standard normal distribution and `W` is inverse gaussian with a mean
+    /// of `δ / γ` and a shape of `δ^2`, with `δ` the scale and
+    /// `γ = sqrt(α^2 - β^2)`
+    fn cdf(&self, x: f64) -> f64 {
+        if x == f64::NEG_INFINITY {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            self.tail_by_quadrature(x, true)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// normal-inverse gaussian distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// There is no closed form so the survival function is integrated
+    /// numerically over the inverse gaussian mixing distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// E[Φ(-(x - μ - βW) / sqrt(W))]
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the asymmetry, `Φ` is the cdf of the
+    /// standard normal distribution and `W` is inverse gaussian with a mean
+    /// of `δ / γ` and a shape of `δ^2`, with `δ` the scale and
+    /// `γ = sqrt(α^2 - β^2)`
+    fn sf(&self, x: f64) -> f64 {
+        if x == f64::NEG_INFINITY {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            self.tail_by_quadrature(x, false)
+        }
+    }
+}
+
+impl Min<f64> for NormalInverseGaussian {
+    /// Returns the minimum value in the domain of the normal-inverse
+    /// gaussian distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// NEG_INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for NormalInverseGaussian {
+    /// Returns the maximum value in the domain of the normal-inverse
+    /// gaussian distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NormalInverseGaussian {
+    /// Returns the mean of the normal-inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + δβ / γ
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the asymmetry, `δ` is the scale and
+    /// `γ = sqrt(α^2 - β^2)`
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.delta * self.beta / self.gamma())
+    }
+    /// Returns the variance of the normal-inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// δα^2 / γ^3
+    /// ```
+    ///
+    /// where `α` is the tail heaviness, `δ` is the scale and
+    /// `γ = sqrt(α^2 - β^2)`
+    fn variance(&self) -> Option<f64> {
+        let gamma = self.gamma();
+        Some(self.delta * self.alpha * self.alpha / (gamma * gamma * gamma))
+    }
+    /// Returns the skewness of the normal-inverse gaussian distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3β / (α * sqrt(δγ))
+    /// ```
+    ///
+    /// where `α` is the tail heaviness, `β` is the asymmetry, `δ` is the
+    /// scale and `γ = sqrt(α^2 - β^2)`
+    fn skewness(&self) -> Option<f64> {
+        Some(3.0 * self.beta / (self.alpha * (self.delta * self.gamma()).sqrt()))
+    }
+    /// Returns the excess kurtosis of the normal-inverse gaussian
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 3 * (1 + 4β^2 / α^2) / (δγ)
+    /// ```
+    ///
+    /// where `α` is the tail heaviness, `β` is the asymmetry, `δ` is the
+    /// scale and `γ = sqrt(α^2 - β^2)`
+    fn kurtosis(&self) -> Option<f64> {
+        let r = self.beta / self.alpha;
+        Some(3.0 * (1.0 + 4.0 * r * r) / (self.delta * self.gamma()))
+    }
+}
+
+impl Continuous<f64, f64> for NormalInverseGaussian {
+    /// Calculates the probability density function for the normal-inverse
+    /// gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// αδ * K1(α * sqrt(δ^2 + (x - μ)^2)) / (π * sqrt(δ^2 + (x - μ)^2))
+    ///     * e^(δγ + β(x - μ))
+    /// ```
+    ///
+    /// where `α` is the tail heaviness, `β` is the asymmetry, `δ` is the
+    /// scale, `μ` is the location, `γ = sqrt(α^2 - β^2)` and `K1` is the
+    /// modified Bessel function of the second kind of order one
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the
+    /// normal-inverse gaussian distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(αδ * K1(α * sqrt(δ^2 + (x - μ)^2)) / (π * sqrt(δ^2 + (x - μ)^2))
+    ///     * e^(δγ + β(x - μ)))
+    /// ```
+    ///
+    /// where `α` is the tail heaviness, `β` is the asymmetry, `δ` is the
+    /// scale, `μ` is the location, `γ = sqrt(α^2 - β^2)` and `K1` is the
+    /// modified Bessel function of the second kind of order one
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let d = x - self.location;
+        let q = self.delta.hypot(d);
+        let z = self.alpha * q;
+        // K1 is evaluated exponentially scaled so that its decay can be
+        // combined with the growth of e^(β(x - μ)) without underflow
+        (self.alpha * self.delta / (f64::consts::PI * q)).ln()
+            + bessel::bessel_k1e(z).ln()
+            + self.delta * self.gamma()
+            + self.beta * d
+            - z
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, NormalInverseGaussian};
+    use crate::distribution::internal::*;
+
+    fn try_create(alpha: f64, beta: f64, delta: f64, location: f64) -> NormalInverseGaussian {
+        let n = NormalInverseGaussian::new(alpha, beta, delta, location);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(alpha: f64, beta: f64, delta: f64, location: f64) {
+        let n = try_create(alpha, beta, delta, location);
+        assert_eq!(alpha, n.alpha());
+        assert_eq!(beta, n.beta());
+        assert_eq!(delta, n.delta());
+        assert_eq!(location, n.location());
+    }
+
+    fn bad_create_case(alpha: f64, beta: f64, delta: f64, location: f64) {
+        let n = NormalInverseGaussian::new(alpha, beta, delta, location);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(alpha: f64, beta: f64, delta: f64, location: f64, expected: f64, eval: F)
+        where F: Fn(NormalInverseGaussian) -> f64
+    {
+        let n = try_create(alpha, beta, delta, location);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(alpha: f64, beta: f64, delta: f64, location: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(NormalInverseGaussian) -> f64
+    {
+        let n = try_create(alpha, beta, delta, location);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(1.0, 0.0, 1.0, 0.0);
+        create_case(2.0, 1.0, 3.0, 4.0);
+        create_case(2.0, -1.9, 0.5, -1.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 0.0, 1.0, 0.0);
+        bad_create_case(1.0, f64::NAN, 1.0, 0.0);
+        bad_create_case(1.0, 0.0, f64::NAN, 0.0);
+        bad_create_case(1.0, 0.0, 1.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 0.0, 1.0, 0.0);
+        bad_create_case(1.0, 0.0, f64::INFINITY, 0.0);
+        bad_create_case(1.0, 0.0, 1.0, f64::INFINITY);
+        bad_create_case(1.0, 0.0, 0.0, 0.0);
+        bad_create_case(1.0, 0.0, -1.0, 0.0);
+        bad_create_case(1.0, 1.0, 1.0, 0.0);
+        bad_create_case(1.0, -1.0, 1.0, 0.0);
+        bad_create_case(0.0, 0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: NormalInverseGaussian| x.mean().unwrap();
+        test_case(1.0, 0.0, 1.0, 0.0, 0.0, mean);
+        test_almost(2.0, 1.0, 3.0, 4.0, 4.0 + 3.0f64.sqrt(), 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: NormalInverseGaussian| x.variance().unwrap();
+        test_case(1.0, 0.0, 1.0, 0.0, 1.0, variance);
+        test_almost(2.0, 1.0, 3.0, 4.0, 4.0 / 3.0f64.sqrt(), 1e-15, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: NormalInverseGaussian| x.skewness().unwrap();
+        test_case(1.0, 0.0, 1.0, 0.0, 0.0, skewness);
+        test_almost(2.0, 1.0, 3.0, 4.0, 1.5 / 27.0f64.sqrt().sqrt(), 1e-15, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: NormalInverseGaussian| x.kurtosis().unwrap();
+        test_case(1.0, 0.0, 1.0, 0.0, 3.0, kurtosis);
+        test_almost(2.0, 1.0, 3.0, 4.0, 2.0 / 3.0f64.sqrt(), 1e-15, kurtosis);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: NormalInverseGaussian| x.min();
+        let max = |x: NormalInverseGaussian| x.max();
+        test_case(1.0, 0.0, 1.0, 0.0, f64::NEG_INFINITY, min);
+        test_case(1.0, 0.0, 1.0, 0.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: NormalInverseGaussian| x.pdf(arg);
+        test_almost(1.0, 0.0, 1.0, 0.0, 2.4776741393529707602e-11, 1e-25, pdf(-20.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.039868429121751136558, 1e-16, pdf(-2.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.19223501274440739582, 1e-15, pdf(1.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 1.5234193810621896174e-6, 1e-20, pdf(10.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 9.5882733326998529953e-32, 1e-44, pdf(-20.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 0.27972589005900367875, 1e-15, pdf(5.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 2.5571155735928422751e-16, 1e-29, pdf(40.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 0.000031981238435937245168, 1e-18, pdf(-40.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 0.26799862263893379037, 1e-15, pdf(-2.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 8.6356873471152877518, 1e-13, pdf(0.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 4.4611891436301779696e-87, 1e-99, pdf(5.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, 0.05427032335020777292, 1e-16, pdf(10.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: NormalInverseGaussian| x.ln_pdf(arg);
+        test_case(1.0, 0.0, 1.0, 0.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+        test_almost(1.0, 0.0, 1.0, 0.0, -24.421115749846391647, 1e-13, ln_pdf(-20.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, -1.6490366307514140199, 1e-15, ln_pdf(1.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, -71.42218215185268991, 1e-12, ln_pdf(-20.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, -1.2739451193861709773, 1e-15, ln_pdf(5.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, -47.457712863096917935, 1e-12, ln_pdf(10.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 2.1559033085598787285, 1e-14, ln_pdf(0.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, -198.82948773585557218, 1e-11, ln_pdf(5.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, -9.1495756790583450812, 1e-14, ln_pdf(40.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: NormalInverseGaussian| x.cdf(arg);
+        test_case(1.0, 0.0, 1.0, 0.0, 0.0, cdf(f64::NEG_INFINITY));
+        test_case(1.0, 0.0, 1.0, 0.0, 1.0, cdf(f64::INFINITY));
+        // the normal cdf is only accurate to about 1e-10 relative
+        test_almost(1.0, 0.0, 1.0, 0.0, 2.3127977234278048786e-11, 1e-20, cdf(-20.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.027222857445034395584, 1e-11, cdf(-2.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.87596522110053150851, 1e-10, cdf(1.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.99999865901525108151, 1e-10, cdf(10.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 3.147820238992161e-32, 1e-41, cdf(-20.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 8.1765731836588476237e-6, 1e-15, cdf(0.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 0.33228518847022059374, 1e-10, cdf(5.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 0.9893132885246647242, 1e-10, cdf(10.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 0.00024087965836460383073, 1e-13, cdf(-40.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 0.37691007544357873028, 1e-10, cdf(-2.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 1.494860335419610758e-53, 1e-62, cdf(-2.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 0.33023519601626672918, 1e-10, cdf(0.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, 1.7588848795042995164e-11, 1e-20, cdf(-40.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, 0.1008847571104864412, 1e-10, cdf(0.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, 0.59645543890423037963, 1e-10, cdf(10.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: NormalInverseGaussian| x.sf(arg);
+        test_case(1.0, 0.0, 1.0, 0.0, 1.0, sf(f64::NEG_INFINITY));
+        test_case(1.0, 0.0, 1.0, 0.0, 0.0, sf(f64::INFINITY));
+        // the normal cdf is only accurate to about 1e-10 relative
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.99999999997687202277, 1e-10, sf(-20.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 0.12403477889946849149, 1e-10, sf(1.0));
+        test_almost(1.0, 0.0, 1.0, 0.0, 1.3409847489184869343e-6, 1e-15, sf(10.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 0.66771481152977940626, 1e-10, sf(5.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 0.010686711475335275797, 1e-11, sf(10.0));
+        test_almost(2.0, 1.0, 3.0, 4.0, 2.4732611052138034921e-16, 1e-25, sf(40.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 0.62308992455642126972, 1e-10, sf(-2.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 0.000011310769030786297106, 1e-14, sf(1.0));
+        test_almost(2.0, -1.9, 0.5, -1.0, 6.0784452800540781761e-22, 1e-31, sf(10.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 0.66976480398373327082, 1e-10, sf(0.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 3.0391937210700895427e-18, 1e-27, sf(1.0));
+        test_almost(50.0, 10.0, 0.1, 0.0, 1.1073024634865639726e-88, 1e-97, sf(5.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, 0.8991152428895135588, 1e-10, sf(0.0));
+        test_almost(0.5, 0.2, 20.0, 0.0, 0.0003761232389627114859, 1e-13, sf(40.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_inverse_cdf(&try_create(1.0, 0.0, 1.0, 0.0), 1e-9);
+        tests::check_inverse_cdf(&try_create(2.0, 1.0, 3.0, 4.0), 1e-9);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(2.0, 1.0, 3.0, 4.0), -5.0, 30.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(2.0, 1.0, 3.0, 4.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let expected = n.mean().unwrap();
+        assert!((mean - expected).abs() < 5.0 * (n.variance().unwrap() / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 5.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(5.0)).abs() < 0.015);
+    }
+}
@@ -113,6 +113,71 @@ impl ContinuousCDF<f64, f64> for Pareto {
             1.0 - (self.scale / x).powf(self.shape)
         }
     }
+
+    /// Calculates the survival function for the Pareto
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < x_m {
+    ///     1
+    /// } else {
+    ///     (x_m/x)^α
+    /// }
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn sf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            1.0
+        } else {
+            (self.scale / x).powf(self.shape)
+        }
+    }
+
+    /// Calculates the log of the survival function for the Pareto
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < x_m {
+    ///     0
+    /// } else {
+    ///     α * ln(x_m/x)
+    /// }
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            0.0
+        } else {
+            self.shape * (self.scale / x).ln()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// Pareto distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_m * (1 - p)^(-1 / α)
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.scale * (-(-p).ln_1p() / self.shape).exp()
+    }
 }
 
 impl Min<f64> for Pareto {
@@ -224,6 +289,27 @@ impl Distribution<f64> for Pareto {
             )
         }
     }
+    /// Returns the excess kurtosis of the Pareto distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `α <= 4.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6(α^3 + α^2 - 6α - 2) / (α(α - 3)(α - 4))
+    /// ```
+    ///
+    /// where `α` is the shape
+    fn kurtosis(&self) -> Option<f64> {
+        let a = self.shape;
+        if a <= 4.0 {
+            None
+        } else {
+            Some(6.0 * (a * a * a + a * a - 6.0 * a - 2.0) / (a * (a - 3.0) * (a - 4.0)))
+        }
+    }
 }
 
 impl Median<f64> for Pareto {
@@ -401,6 +487,14 @@ mod tests {
         test_case(1.0, 100.0, (707.0/485.0)*2f64.sqrt(), skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Pareto| x.kurtosis().unwrap();
+        test_almost(1.0, 6.0, 35.666666666666666667, 1e-13, kurtosis);
+        test_almost(10.0, 10.0, 14.828571428571428571, 1e-13, kurtosis);
+        assert!(try_create(1.0, 4.0).kurtosis().is_none());
+    }
+
     #[test]
     #[should_panic]
     fn test_skewness_invalid_shape() {
@@ -487,6 +581,28 @@ mod tests {
         test_case(1.0, 1.0, 1.0, cdf(f64::INFINITY));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Pareto| x.sf(arg);
+        test_case(1.0, 2.0, 1.0, sf(0.5));
+        test_case(1.0, 2.0, 1.0, sf(1.0));
+        test_almost(1.0, 2.0, 0.01, 1e-16, sf(10.0));
+        test_almost(1.0, 2.0, -4.605170185988091368, 1e-14, |x: Pareto| x.ln_sf(10.0));
+        test_almost(1.0, 10.0, 1e-100, 1e-114, sf(1e10));
+        test_case(1.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Pareto| x.inverse_cdf(arg);
+        test_case(1.0, 2.0, 1.0, inverse_cdf(0.0));
+        test_almost(1.0, 2.0, 1.414213562373095048802, 1e-15, inverse_cdf(0.5));
+        test_almost(1.0, 2.0, 10.0, 1e-13, inverse_cdf(0.99));
+        test_case(1.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(1.0, 2.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(5.0, 0.5), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0, 10.0), 1.0, 10.0);
@@ -1,7 +1,8 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{internal, Discrete, DiscreteCDF};
 use crate::function::{factorial, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 use std::u64;
@@ -67,14 +68,20 @@ impl Poisson {
     }
 }
 
-impl ::rand::distributions::Distribution<f64> for Poisson {
+impl ::rand::distributions::Distribution<u64> for Poisson {
     /// Generates one sample from the Poisson distribution either by
     /// Knuth's method if lambda < 30.0 or Rejection method PA by
     /// A. C. Atkinson from the Journal of the Royal Statistical Society
     /// Series C (Applied Statistics) Vol. 28 No. 1. (1979) pp. 29 - 35
     /// otherwise
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        sample_unchecked(rng, self.lambda) as u64
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Poisson {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
-        sample_unchecked(rng, self.lambda)
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
     }
 }
 
@@ -85,12 +92,54 @@ impl DiscreteCDF<u64, f64> for Poisson {
     /// # Formula
     ///
     /// ```ignore
-    /// 1 - P(x + 1, λ)
+    /// Q(x + 1, λ)
     /// ```
     ///
-    /// where `λ` is the rate and `P` is the lower regularized gamma function
+    /// where `λ` is the rate and `Q` is the upper regularized gamma function
     fn cdf(&self, x: u64) -> f64 {
-        1.0 - gamma::gamma_lr(x as f64 + 1.0, self.lambda)
+        gamma::gamma_ur(x as f64 + 1.0, self.lambda)
+    }
+
+    /// Calculates the survival function for the poisson
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P(x + 1, λ)
+    /// ```
+    ///
+    /// where `λ` is the rate and `P` is the lower regularized gamma function
+    fn sf(&self, x: u64) -> f64 {
+        gamma::gamma_lr(x as f64 + 1.0, self.lambda)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// poisson distribution at `x`, i.e. the smallest `k` such that
+    /// `cdf(k) >= x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the Cornish-Fisher approximation
+    ///
+    /// ```ignore
+    /// λ + sqrt(λ) * z + (z^2 - 1) / 6
+    /// ```
+    ///
+    /// where `z` is the standard normal quantile of `x`, so that only a
+    /// handful of cdf evaluations are needed even for very large `λ`
+    fn inverse_cdf(&self, x: f64) -> u64 {
+        let guess = if x > 0.0 && x < 1.0 {
+            let z = super::normal::inverse_cdf_unchecked(x, 0.0, 1.0);
+            self.lambda + self.lambda.sqrt() * z + (z * z - 1.0) / 6.0
+        } else {
+            self.lambda
+        };
+        internal::discrete_inverse_cdf(self, x, guess.max(0.0).floor() as u64)
     }
 }
 
@@ -176,6 +225,70 @@ impl Distribution<f64> for Poisson {
     fn skewness(&self) -> Option<f64> {
         Some(1.0 / self.lambda.sqrt())
     }
+    /// Returns the excess kurtosis of the poisson distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ^-1
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn kurtosis(&self) -> Option<f64> {
+        Some(1.0 / self.lambda)
+    }
+    /// Returns the `k`th central moment of the poisson distribution
+    ///
+    /// # Remarks
+    ///
+    /// Computed from the cumulants, all of which equal `λ`
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let cumulants = vec![self.lambda; k as usize + 1];
+        Some(internal::central_moments_from_cumulants(&cumulants)[k as usize])
+    }
+}
+
+impl GeneratingFunction<f64> for Poisson {
+    /// Returns the moment generating function of the poisson distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(λ(e^t - 1))
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn mgf(&self, t: f64) -> Option<f64> {
+        self.cgf(t).map(f64::exp)
+    }
+    /// Returns the cumulant generating function of the poisson distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ(e^t - 1)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn cgf(&self, t: f64) -> Option<f64> {
+        Some(self.lambda * t.exp_m1())
+    }
+    /// Returns the characteristic function of the poisson distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(λ(e^(it) - 1))
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let s = (t / 2.0).sin();
+        Complex::from_polar((-2.0 * self.lambda * s * s).exp(), self.lambda * t.sin())
+    }
 }
 
 impl Median<f64> for Poisson {
@@ -373,6 +486,76 @@ mod tests {
         test_almost(10.8, 0.3042903097250922852539, 1e-16, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Poisson| x.kurtosis().unwrap();
+        test_almost(3.5, 0.28571428571428571429, 1e-16, kurtosis);
+        test_case(0.5, 2.0, kurtosis);
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Poisson| x.central_moment(k).unwrap();
+        test_case(3.5, 1.0, central_moment(0));
+        test_case(3.5, 0.0, central_moment(1));
+        test_case(3.5, 3.5, central_moment(2));
+        test_case(3.5, 3.5, central_moment(3));
+        test_case(3.5, 40.25, central_moment(4));
+        test_case(3.5, 126.0, central_moment(5));
+        test_case(3.5, 952.875, central_moment(6));
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Poisson| x.moment(k).unwrap();
+        test_case(3.5, 1.0, moment(0));
+        test_case(3.5, 3.5, moment(1));
+        test_case(3.5, 15.75, moment(2));
+        test_case(3.5, 83.125, moment(3));
+        test_case(3.5, 496.5625, moment(4));
+        test_almost(3.5, 23712.609375, 1e-11, moment(6));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Poisson| x.mgf(t).unwrap();
+        test_almost(0.5, 0.64899364235987346041, 1e-15, mgf(-2.0));
+        test_almost(0.5, 1.1911621221103193711, 1e-14, mgf(0.3));
+        test_almost(0.5, 9.3649471812450361842, 1e-14, mgf(1.7));
+        test_almost(20.0, 3.0875762829728395511e-8, 1e-16, mgf(-2.0));
+        test_almost(20.0, 1093.5408007806045749, 1e-11, mgf(0.3));
+        test_almost(20.0, 7.2479183302104952254e+38, 1e25, mgf(1.7));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Poisson| x.cgf(t).unwrap();
+        test_almost(0.5, -0.43233235838169365405, 1e-15, cgf(-2.0));
+        test_almost(0.5, 0.1749294037880015445, 1e-15, cgf(0.3));
+        test_almost(0.5, 2.2369736958635997588, 1e-14, cgf(1.7));
+        test_almost(20.0, -17.293294335267746162, 1e-13, cgf(-2.0));
+        test_almost(20.0, 6.9971761515200617799, 1e-14, cgf(0.3));
+        test_almost(20.0, 89.478947834543990354, 1e-13, cgf(1.7));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Poisson| x.cf(t).re;
+        let im = |t| move |x: Poisson| x.cf(t).im;
+        test_almost(0.5, 0.44255248646743816694, 1e-15, re(-2.0));
+        test_almost(0.5, -0.21632030392327772035, 1e-15, im(-2.0));
+        test_almost(0.5, 0.96725972050230122317, 1e-15, re(0.3));
+        test_almost(0.5, 0.14397170489752249817, 1e-15, im(0.3));
+        test_almost(0.5, 0.50020314183840396477, 1e-15, re(1.7));
+        test_almost(0.5, 0.27056155644659282472, 1e-15, im(1.7));
+        test_almost(20.0, 3.9437307111358296351e-13, 1e-15, re(-2.0));
+        test_almost(20.0, 3.0836086417240835878e-13, 1e-15, im(-2.0));
+        test_almost(20.0, 0.38120244942161510677, 1e-15, re(0.3));
+        test_almost(20.0, -0.14907539058321232974, 1e-15, im(0.3));
+        test_almost(20.0, 8.6780542179563983113e-11, 1e-15, re(1.7));
+        test_almost(20.0, 1.3043815885878375334e-10, 1e-15, im(1.7));
+    }
+
     #[test]
     fn test_median() {
         let median = |x: Poisson| x.median();
@@ -443,9 +626,39 @@ mod tests {
         test_almost(10.8, 0.9961800769608090000000, 1e-15, cdf(20));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Poisson| x.sf(arg);
+        test_almost(5.0, 0.734974084702638294198982095356, 1e-14, sf(3));
+        test_almost(5.0, 2.13386175799709978778727504624e-33, 1e-45, sf(50));
+        test_almost(1.0, 0.632120558828557678404476229839, 1e-15, sf(0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Poisson| x.inverse_cdf(arg);
+        test_case(1000.0, 0, inverse_cdf(0.0));
+        test_case(1000.0, 806, inverse_cdf(1e-10));
+        test_case(1000.0, 904, inverse_cdf(0.001));
+        test_case(1000.0, 960, inverse_cdf(0.1));
+        test_case(1000.0, 1000, inverse_cdf(0.5));
+        test_case(1000.0, 1041, inverse_cdf(0.9));
+        test_case(1000.0, 1154, inverse_cdf(0.999999));
+        test_case(1000.0, u64::MAX, inverse_cdf(1.0));
+        tests::check_discrete_inverse_cdf(&try_create(0.5));
+        tests::check_discrete_inverse_cdf(&try_create(12.5));
+        tests::check_discrete_inverse_cdf(&try_create(1e6));
+    }
+
     #[test]
     fn test_discrete() {
         tests::check_discrete_distribution(&try_create(0.3), 10);
         tests::check_discrete_distribution(&try_create(4.5), 30);
     }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&try_create(0.5));
+        tests::check_discrete_samples(&try_create(1e6));
+    }
 }
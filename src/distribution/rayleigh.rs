@@ -0,0 +1,511 @@
+use crate::consts;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Rayleigh](https://en.wikipedia.org/wiki/Rayleigh_distribution)
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Rayleigh, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = Rayleigh::new(1.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.25331413731550025121, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.60653065971263342360, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rayleigh {
+    scale: f64,
+}
+
+impl Rayleigh {
+    /// Constructs a new rayleigh distribution with a scale (σ) of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is `NaN`, infinite or less than or
+    /// equal to `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Rayleigh;
+    ///
+    /// let mut result = Rayleigh::new(1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Rayleigh::new(0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(scale: f64) -> Result<Rayleigh> {
+        if !scale.is_finite() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Rayleigh { scale })
+        }
+    }
+
+    /// Returns the scale of the rayleigh distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Rayleigh;
+    ///
+    /// let n = Rayleigh::new(2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Rayleigh {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.scale)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Rayleigh {
+    /// Calculates the cumulative distribution function for the
+    /// rayleigh distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-x^2 / (2σ^2))
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            let z = x / self.scale;
+            -(-0.5 * z * z).exp_m1()
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// rayleigh distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-x^2 / (2σ^2))
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            let z = x / self.scale;
+            (-0.5 * z * z).exp()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// rayleigh distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(-2 * ln(1 - p))
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.scale * (-2.0 * (-p).ln_1p()).sqrt()
+    }
+}
+
+impl Min<f64> for Rayleigh {
+    /// Returns the minimum value in the domain of the
+    /// rayleigh distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Rayleigh {
+    /// Returns the maximum value in the domain of the
+    /// rayleigh distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Rayleigh {
+    /// Returns the mean of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(π / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn mean(&self) -> Option<f64> {
+        Some(self.scale * (f64::consts::PI / 2.0).sqrt())
+    }
+    /// Returns the variance of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (4 - π) / 2 * σ^2
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some((4.0 - f64::consts::PI) / 2.0 * self.scale * self.scale)
+    }
+    /// Returns the entropy of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 + ln(σ / sqrt(2)) + γ / 2
+    /// ```
+    ///
+    /// where `σ` is the scale and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(1.0 + (self.scale / f64::consts::SQRT_2).ln() + consts::EULER_MASCHERONI / 2.0)
+    }
+    /// Returns the skewness of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * sqrt(π) * (π - 3) / (4 - π)^(3 / 2)
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        let pi = f64::consts::PI;
+        Some(2.0 * pi.sqrt() * (pi - 3.0) / (4.0 - pi).powf(1.5))
+    }
+    /// Returns the excess kurtosis of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(6π^2 - 24π + 16) / (4 - π)^2
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        let pi = f64::consts::PI;
+        Some(-(6.0 * pi * pi - 24.0 * pi + 16.0) / ((4.0 - pi) * (4.0 - pi)))
+    }
+}
+
+impl Median<f64> for Rayleigh {
+    /// Returns the median of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(2 * ln(2))
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn median(&self) -> f64 {
+        self.scale * (2.0 * f64::consts::LN_2).sqrt()
+    }
+}
+
+impl Mode<Option<f64>> for Rayleigh {
+    /// Returns the mode of the rayleigh distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn mode(&self) -> Option<f64> {
+        Some(self.scale)
+    }
+}
+
+impl Continuous<f64, f64> for Rayleigh {
+    /// Calculates the probability density function for the rayleigh
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x / σ^2 * e^(-x^2 / (2σ^2))
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            0.0
+        } else {
+            let z = x / self.scale;
+            z / self.scale * (-0.5 * z * z).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the rayleigh
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(x / σ^2) - x^2 / (2σ^2)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            let z = x / self.scale;
+            z.ln() - self.scale.ln() - 0.5 * z * z
+        }
+    }
+}
+
+/// draws a sample from a rayleigh distribution with the given scale
+/// by inversion
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, scale: f64) -> f64 {
+    let u: f64 = rng.gen();
+    scale * (-2.0 * (-u).ln_1p()).sqrt()
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::distribution::internal::*;
+    use crate::distribution::{Chi, Continuous, ContinuousCDF, Rayleigh};
+    use crate::statistics::*;
+
+    fn try_create(scale: f64) -> Rayleigh {
+        let n = Rayleigh::new(scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(scale: f64) {
+        let n = try_create(scale);
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(scale: f64) {
+        let n = Rayleigh::new(scale);
+        assert!(n.is_err());
+    }
+
+    fn get_value<F>(scale: f64, eval: F) -> f64
+    where
+        F: Fn(Rayleigh) -> f64,
+    {
+        let n = try_create(scale);
+        eval(n)
+    }
+
+    fn test_case<F>(scale: f64, expected: f64, eval: F)
+    where
+        F: Fn(Rayleigh) -> f64,
+    {
+        let x = get_value(scale, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(scale: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: Fn(Rayleigh) -> f64,
+    {
+        let x = get_value(scale, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.1);
+        create_case(1.0);
+        create_case(25.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0);
+        bad_create_case(-1.0);
+        bad_create_case(f64::INFINITY);
+        bad_create_case(f64::NAN);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Rayleigh| x.mean().unwrap();
+        test_almost(1.0, 1.2533141373155002512, 1e-15, mean);
+        test_almost(2.5, 3.1332853432887506280, 1e-15, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Rayleigh| x.variance().unwrap();
+        test_almost(1.0, 0.42920367320510338077, 1e-15, variance);
+        test_almost(2.5, 2.6825229575318961298, 1e-15, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: Rayleigh| x.entropy().unwrap();
+        test_almost(1.0, 0.94203424217079377559, 1e-15, entropy);
+        test_almost(2.5, 1.8583249740449488408, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Rayleigh| x.skewness().unwrap();
+        test_almost(1.0, 0.63111065781893713819, 1e-15, skewness);
+        test_almost(2.5, 0.63111065781893713819, 1e-15, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Rayleigh| x.kurtosis().unwrap();
+        test_almost(1.0, 0.24508930068763806285, 1e-14, kurtosis);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: Rayleigh| x.median();
+        test_almost(1.0, 1.1774100225154746910, 1e-15, median);
+        test_almost(2.5, 2.9435250562886867276, 1e-15, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: Rayleigh| x.mode().unwrap();
+        test_case(1.0, 1.0, mode);
+        test_case(2.5, 2.5, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Rayleigh| x.min();
+        let max = |x: Rayleigh| x.max();
+        test_case(1.0, 0.0, min);
+        test_case(1.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Rayleigh| x.pdf(arg);
+        test_case(1.0, 0.0, pdf(-1.0));
+        test_case(1.0, 0.0, pdf(0.0));
+        test_almost(1.0, 0.099501247919268236762, 1e-16, pdf(0.1));
+        test_almost(1.0, 0.60653065971263342360, 1e-15, pdf(1.0));
+        test_almost(1.0, 1.4847681768496578863e-6, 1e-20, pdf(5.5));
+        test_almost(2.5, 0.14769861542186172809, 1e-15, pdf(1.0));
+        test_case(1.0, 0.0, pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Rayleigh| x.ln_pdf(arg);
+        test_case(1.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_almost(1.0, -2.3075850929940456295, 1e-15, ln_pdf(0.1));
+        test_almost(1.0, -0.5, 1e-15, ln_pdf(1.0));
+        test_almost(1.0, -13.420251907761574765, 1e-14, ln_pdf(5.5));
+        test_almost(2.5, -1.9125814637483101112, 1e-15, ln_pdf(1.0));
+        test_case(1.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Rayleigh| x.cdf(arg);
+        test_case(1.0, 0.0, cdf(-1.0));
+        test_case(1.0, 0.0, cdf(0.0));
+        test_almost(1.0, 0.0049875208073176876140, 1e-18, cdf(0.1));
+        test_almost(1.0, 0.39346934028736657640, 1e-15, cdf(1.0));
+        test_almost(2.5, 0.076883653613364218627, 1e-16, cdf(1.0));
+        test_case(1.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Rayleigh| x.sf(arg);
+        test_case(1.0, 1.0, sf(0.0));
+        test_almost(1.0, 0.60653065971263342360, 1e-15, sf(1.0));
+        test_almost(1.0, 1.9287498479639177830e-22, 1e-36, sf(10.0));
+        test_case(1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Rayleigh| x.inverse_cdf(arg);
+        test_case(1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 1.1774100225154746910, 1e-15, inverse_cdf(0.5));
+        test_case(1.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.5), 1e-12);
+    }
+
+    #[test]
+    fn test_consistent_with_chi() {
+        // a rayleigh distribution with scale σ is a chi distribution with
+        // two degrees of freedom scaled by σ
+        let chi = Chi::new(2.0).unwrap();
+        for &scale in &[0.5, 1.0, 3.0] {
+            let n = try_create(scale);
+            for &x in &[0.1, 0.5, 1.0, 2.0, 4.0] {
+                assert_almost_eq!(n.pdf(scale * x), chi.pdf(x) / scale, 1e-14);
+                assert_almost_eq!(n.cdf(scale * x), chi.cdf(x), 1e-14);
+            }
+            assert_almost_eq!(n.mean().unwrap(), scale * chi.mean().unwrap(), 1e-14);
+            assert_almost_eq!(n.variance().unwrap(), scale * scale * chi.variance().unwrap(), 1e-13);
+            assert_almost_eq!(n.entropy().unwrap(), chi.entropy().unwrap() + scale.ln(), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(2.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean - n.mean().unwrap()).abs() < 5.0 * (n.variance().unwrap() / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 2.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(2.0)).abs() < 0.015);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0), 0.0, 10.0);
+        tests::check_continuous_distribution(&try_create(2.5), 0.0, 25.0);
+    }
+}
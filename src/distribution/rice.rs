@@ -0,0 +1,631 @@
+use crate::distribution::{noncentral_chi_squared, Continuous, ContinuousCDF};
+use crate::function::bessel;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Value of `ν^2 / (2σ^2)` above which the Laguerre polynomial `L_(1/2)` in
+/// the variance is summed from its asymptotic expansion. The closed form
+/// loses about `log10(ν^2 / σ^2)` digits to cancellation, while the error of
+/// the expansion is of order `e^(-ν^2 / (2σ^2))`
+const LAGUERRE_ASYMPTOTIC_BOUND: f64 = 50.0;
+
+/// Value of `ν^2 / (2σ^2)` above which the skewness and kurtosis are summed
+/// from the asymptotic expansions. Their closed forms lose about three times
+/// as many digits as the variance, so both sides stay within a relative
+/// error of about `1e-9` at this crossover
+const SHAPE_ASYMPTOTIC_BOUND: f64 = 28.0;
+
+/// Number of terms kept from the expansions in `σ^2 / ν^2` of the
+/// skewness and kurtosis, close to the optimal truncation at
+/// `SHAPE_ASYMPTOTIC_BOUND` and enough for double precision above
+/// `LAGUERRE_ASYMPTOTIC_BOUND`
+const LAGUERRE_ASYMPTOTIC_TERMS: usize = 30;
+
+/// Implements the [Rice](https://en.wikipedia.org/wiki/Rice_distribution)
+/// distribution
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Rice, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = Rice::new(0.0, 1.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.25331413731550025121, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(1.0), 0.60653065971263342360, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rice {
+    noncentrality: f64,
+    scale: f64,
+}
+
+impl Rice {
+    /// Constructs a new rice distribution with a noncentrality (ν) of
+    /// `noncentrality` and a scale (σ) of `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `noncentrality` or `scale` are `NaN` or infinite,
+    /// if `noncentrality < 0.0` or if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Rice;
+    ///
+    /// let mut result = Rice::new(1.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Rice::new(1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(noncentrality: f64, scale: f64) -> Result<Rice> {
+        if !noncentrality.is_finite() || !scale.is_finite() || noncentrality < 0.0 || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Rice {
+                noncentrality,
+                scale,
+            })
+        }
+    }
+
+    /// Returns the noncentrality of the rice distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Rice;
+    ///
+    /// let n = Rice::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 1.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Returns the scale of the rice distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Rice;
+    ///
+    /// let n = Rice::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns `ν^2 / (2σ^2)`, the argument of the Laguerre polynomial in
+    /// the moments
+    fn laguerre_arg(&self) -> f64 {
+        let r = self.noncentrality / self.scale;
+        r * r / 2.0
+    }
+
+    /// Returns the skewness and excess kurtosis. For large `ν / σ` the raw
+    /// moments cancel to about `(σ / ν)^3` in the central moments, so there
+    /// each central moment is expanded in `1 / x` with `x = ν^2 / (2σ^2)`
+    /// and the cancelling coefficients are subtracted exactly
+    fn shape_moments(&self) -> (f64, f64) {
+        let x = self.laguerre_arg();
+        let (mu2, mu3, kappa4) = if x <= SHAPE_ASYMPTOTIC_BOUND {
+            // raw moments of the distribution with unit scale
+            let c = (f64::consts::PI / 2.0).sqrt();
+            let i0 = bessel::bessel_i0e(x / 2.0);
+            let i1 = bessel::bessel_i1e(x / 2.0);
+            let m1 = c * ((1.0 + x) * i0 + x * i1);
+            let m2 = 2.0 + 2.0 * x;
+            let m3 = c * ((3.0 + 6.0 * x + 2.0 * x * x) * i0 + (4.0 + 2.0 * x) * x * i1);
+            let m4 = 4.0 * (x * x + 4.0 * x + 2.0);
+            let mu2 = m2 - m1 * m1;
+            let mu3 = m3 - 3.0 * m1 * m2 + 2.0 * m1 * m1 * m1;
+            let mu4 = m4 - 4.0 * m1 * m3 + 6.0 * m1 * m1 * m2 - 3.0 * m1 * m1 * m1 * m1;
+            (mu2, mu3, mu4 - 3.0 * mu2 * mu2)
+        } else {
+            // the raw moments divided by (ν / σ)^k are series in 1 / x:
+            // m_1 = l, m_2 = 1 + 1 / x, m_3 = L and m_4 = 1 + 4 / x + 2 / x^2,
+            // where l and L are the expansions of L_(1/2) and L_(3/2)
+            let l = laguerre_expansion(0.5);
+            let big_l = laguerre_expansion(1.5);
+            let m2 = [1.0, 1.0];
+            let m4 = [1.0, 4.0, 2.0];
+            let l2 = series_mul(&l, &l);
+            let l3 = series_mul(&l2, &l);
+            let l4 = series_mul(&l3, &l);
+            let mu2 = series_sum(&[(1.0, &m2), (-1.0, &l2)]);
+            let mu3 = series_sum(&[(1.0, &big_l), (-3.0, &series_mul(&l, &m2)), (2.0, &l3)]);
+            let mu4 = series_sum(&[
+                (1.0, &m4),
+                (-4.0, &series_mul(&l, &big_l)),
+                (6.0, &series_mul(&l2, &m2)),
+                (-3.0, &l4),
+            ]);
+            let kappa4 = series_sum(&[(1.0, &mu4), (-3.0, &series_mul(&mu2, &mu2))]);
+            let inv = 1.0 / x;
+            (
+                series_eval(&mu2, inv),
+                series_eval(&mu3, inv),
+                series_eval(&kappa4, inv),
+            )
+        };
+        (mu3 / (mu2 * mu2.sqrt()), kappa4 / (mu2 * mu2))
+    }
+}
+
+/// Returns the coefficients `((-α)_k)^2 / k!` of the asymptotic expansion
+/// `L_α(-x) ~ x^α / Γ(α + 1) * sum(((-α)_k)^2 / k! * x^-k, k=0..)`
+fn laguerre_expansion(alpha: f64) -> Vec<f64> {
+    let mut coeff = 1.0;
+    let mut coeffs = vec![coeff];
+    for k in 1..=LAGUERRE_ASYMPTOTIC_TERMS {
+        let rising = k as f64 - 1.0 - alpha;
+        coeff *= rising * rising / k as f64;
+        coeffs.push(coeff);
+    }
+    coeffs
+}
+
+/// Multiplies two power series, truncated after `LAGUERRE_ASYMPTOTIC_TERMS`
+fn series_mul(a: &[f64], b: &[f64]) -> Vec<f64> {
+    (0..=LAGUERRE_ASYMPTOTIC_TERMS)
+        .map(|n| {
+            (0..=n)
+                .map(|k| a.get(k).unwrap_or(&0.0) * b.get(n - k).unwrap_or(&0.0))
+                .sum()
+        })
+        .collect()
+}
+
+/// Returns the linear combination `sum(c * s)` of power series `s`,
+/// truncated after `LAGUERRE_ASYMPTOTIC_TERMS`
+fn series_sum(terms: &[(f64, &[f64])]) -> Vec<f64> {
+    (0..=LAGUERRE_ASYMPTOTIC_TERMS)
+        .map(|n| {
+            terms
+                .iter()
+                .map(|&(c, s)| c * s.get(n).unwrap_or(&0.0))
+                .sum()
+        })
+        .collect()
+}
+
+/// Evaluates a power series at `y`
+fn series_eval(coeffs: &[f64], y: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, &c| acc * y + c)
+}
+
+impl ::rand::distributions::Distribution<f64> for Rice {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = super::normal::sample_unchecked(rng, self.noncentrality, self.scale);
+        let y = super::normal::sample_unchecked(rng, 0.0, self.scale);
+        x.hypot(y)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Rice {
+    /// Calculates the cumulative distribution function for the
+    /// rice distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - Q_1(ν / σ, x / σ)
+    /// ```
+    ///
+    /// where `ν` is the noncentrality, `σ` is the scale and `Q_1` is the
+    /// Marcum Q function, evaluated as the cdf of the noncentral
+    /// chi-squared distribution with two degrees of freedom
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else {
+            let r = self.noncentrality / self.scale;
+            let z = x / self.scale;
+            noncentral_chi_squared::cdf_unchecked(z * z, 2.0, r * r)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// rice distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q_1(ν / σ, x / σ)
+    /// ```
+    ///
+    /// where `ν` is the noncentrality, `σ` is the scale and `Q_1` is the
+    /// Marcum Q function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else {
+            noncentral_chi_squared::marcum_q(1.0, self.noncentrality / self.scale, x / self.scale)
+        }
+    }
+}
+
+impl Min<f64> for Rice {
+    /// Returns the minimum value in the domain of the
+    /// rice distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for Rice {
+    /// Returns the maximum value in the domain of the
+    /// rice distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Rice {
+    /// Returns the mean of the rice distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ * sqrt(π / 2) * L_(1/2)(-ν^2 / (2σ^2))
+    /// ```
+    ///
+    /// where `ν` is the noncentrality, `σ` is the scale and `L_(1/2)` is
+    /// the Laguerre polynomial of order one half, evaluated as
+    ///
+    /// ```ignore
+    /// L_(1/2)(-x) = e^(x / 2) * ((1 + x) * I0(x / 2) + x * I1(x / 2))
+    /// ```
+    ///
+    /// with `I0` and `I1` the modified Bessel functions of the first kind
+    fn mean(&self) -> Option<f64> {
+        let x = self.laguerre_arg();
+        let laguerre = (1.0 + x) * bessel::bessel_i0e(x / 2.0) + x * bessel::bessel_i1e(x / 2.0);
+        Some(self.scale * (f64::consts::PI / 2.0).sqrt() * laguerre)
+    }
+    /// Returns the variance of the rice distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2σ^2 + ν^2 - π * σ^2 / 2 * L_(1/2)(-ν^2 / (2σ^2))^2
+    /// ```
+    ///
+    /// where `ν` is the noncentrality, `σ` is the scale and `L_(1/2)` is
+    /// the Laguerre polynomial of order one half. For large `ν / σ` this
+    /// is evaluated from the asymptotic expansion
+    ///
+    /// ```ignore
+    /// L_(1/2)(-x) ~ 2 * sqrt(x / π) * sum(((-1/2)_k)^2 / k! * x^-k, k=0..)
+    /// ```
+    ///
+    /// with the leading terms cancelled analytically
+    fn variance(&self) -> Option<f64> {
+        let x = self.laguerre_arg();
+        let s2 = self.scale * self.scale;
+        if x <= LAGUERRE_ASYMPTOTIC_BOUND {
+            let mean = self.mean()?;
+            Some(2.0 * s2 + self.noncentrality * self.noncentrality - mean * mean)
+        } else {
+            // with L = 2 * sqrt(x / π) * (1 + t) the variance is
+            // σ^2 * (2 - 2x * t * (2 + t)), where x * t is summed directly
+            let mut term = 0.25;
+            let mut xt = 0.0;
+            let mut k = 1.0;
+            while term > f64::EPSILON * xt {
+                xt += term;
+                term *= (k - 0.5) * (k - 0.5) / ((k + 1.0) * x);
+                k += 1.0;
+            }
+            let t = xt / x;
+            Some(s2 * (2.0 - 2.0 * xt * (2.0 + t)))
+        }
+    }
+    /// Returns the skewness of the rice distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (μ'_3 - 3μ * μ'_2 + 2μ^3) / σ_R^3
+    /// ```
+    ///
+    /// where `μ` and `σ_R^2` are the mean and variance and the raw moments
+    /// are
+    ///
+    /// ```ignore
+    /// μ'_2 = 2σ^2 + ν^2
+    /// μ'_3 = 3σ^3 * sqrt(π / 2) * L_(3/2)(-ν^2 / (2σ^2))
+    /// L_(3/2)(-x) = e^(x / 2) * ((3 + 6x + 2x^2) * I0(x / 2) + (4x + 2x^2) * I1(x / 2)) / 3
+    /// ```
+    ///
+    /// with `ν` the noncentrality, `σ` the scale and `I0` and `I1` the
+    /// modified Bessel functions of the first kind. For large `ν / σ` the
+    /// central moment is evaluated from its asymptotic expansion instead
+    fn skewness(&self) -> Option<f64> {
+        Some(self.shape_moments().0)
+    }
+    /// Returns the excess kurtosis of the rice distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (μ'_4 - 4μ * μ'_3 + 6μ^2 * μ'_2 - 3μ^4) / σ_R^4 - 3
+    /// ```
+    ///
+    /// where `μ` and `σ_R^2` are the mean and variance, `μ'_2` and `μ'_3`
+    /// are as for the skewness and `μ'_4 = ν^4 + 8σ^2 * ν^2 + 8σ^4`. For
+    /// large `ν / σ` the fourth cumulant is evaluated from its asymptotic
+    /// expansion instead
+    fn kurtosis(&self) -> Option<f64> {
+        Some(self.shape_moments().1)
+    }
+}
+
+impl Continuous<f64, f64> for Rice {
+    /// Calculates the probability density function for the rice
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x / σ^2 * e^(-(x^2 + ν^2) / (2σ^2)) * I0(x * ν / σ^2)
+    /// ```
+    ///
+    /// where `ν` is the noncentrality, `σ` is the scale and `I0` is the
+    /// modified Bessel function of the first kind of order zero
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            0.0
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the rice
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(x / σ^2) - (x - ν)^2 / (2σ^2) + ln(e^(-x * ν / σ^2) * I0(x * ν / σ^2))
+    /// ```
+    ///
+    /// where `ν` is the noncentrality, `σ` is the scale and `I0` is the
+    /// modified Bessel function of the first kind of order zero
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            let z = x / self.scale;
+            let r = self.noncentrality / self.scale;
+            z.ln() - self.scale.ln() - 0.5 * (z - r) * (z - r) + bessel::bessel_i0e(z * r).ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, Rayleigh, Rice};
+    use crate::statistics::*;
+
+    fn try_create(noncentrality: f64, scale: f64) -> Rice {
+        let n = Rice::new(noncentrality, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(noncentrality: f64, scale: f64) {
+        let n = try_create(noncentrality, scale);
+        assert_eq!(noncentrality, n.noncentrality());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(noncentrality: f64, scale: f64) {
+        let n = Rice::new(noncentrality, scale);
+        assert!(n.is_err());
+    }
+
+    fn get_value<F>(noncentrality: f64, scale: f64, eval: F) -> f64
+    where
+        F: Fn(Rice) -> f64,
+    {
+        let n = try_create(noncentrality, scale);
+        eval(n)
+    }
+
+    fn test_case<F>(noncentrality: f64, scale: f64, expected: f64, eval: F)
+    where
+        F: Fn(Rice) -> f64,
+    {
+        let x = get_value(noncentrality, scale, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(noncentrality: f64, scale: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: Fn(Rice) -> f64,
+    {
+        let x = get_value(noncentrality, scale, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0);
+        create_case(1.0, 2.0);
+        create_case(50.0, 0.1);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(-1.0, 1.0);
+        bad_create_case(1.0, 0.0);
+        bad_create_case(1.0, -1.0);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(1.0, f64::INFINITY);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(1.0, f64::NAN);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: Rice| x.mean().unwrap();
+        test_almost(0.0, 1.0, 1.2533141373155002512, 1e-15, mean);
+        test_almost(1.0, 2.0, 2.6608946812214063416, 1e-15, mean);
+        test_almost(3.0, 1.0, 3.1725772879007178273, 1e-14, mean);
+        test_almost(20.0, 1.0, 20.025015684057218006, 1e-13, mean);
+        test_almost(100.0, 0.5, 100.00125000781279299, 1e-12, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: Rice| x.variance().unwrap();
+        test_almost(0.0, 1.0, 0.42920367320510338077, 1e-15, variance);
+        test_almost(1.0, 2.0, 1.9196394954476303255, 1e-14, variance);
+        test_almost(3.0, 1.0, 0.9347533522965257885, 1e-14, variance);
+        test_almost(9.0, 1.0, 0.99374820375624945678, 1e-13, variance);
+        test_almost(11.0, 1.0, 0.99583281029459186845, 1e-14, variance);
+        test_almost(100.0, 0.5, 0.24999687492186962828, 1e-15, variance);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: Rice| x.skewness().unwrap();
+        test_almost(0.0, 1.0, 0.63111065781893713819, 1e-14, skewness);
+        test_almost(1.0, 2.0, 0.61774424626606353459, 1e-13, skewness);
+        test_almost(3.0, 1.0, 0.059483147708301110334, 1e-13, skewness);
+        test_almost(9.0, 1.0, 0.0014254110796029047114, 1e-17, skewness);
+        test_almost(11.0, 1.0, 0.00077062634217845123489, 1e-18, skewness);
+        test_almost(100.0, 0.5, 1.250093759889174795e-7, 1e-22, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Rice| x.kurtosis().unwrap();
+        test_almost(0.0, 1.0, 0.24508930068763806285, 1e-13, kurtosis);
+        test_almost(1.0, 2.0, 0.2104694648825942845, 1e-13, kurtosis);
+        test_almost(3.0, 1.0, -0.073040108277216081268, 1e-12, kurtosis);
+        test_almost(9.0, 1.0, -0.00048785970562746700323, 1e-16, kurtosis);
+        test_almost(11.0, 1.0, -0.00021383275944853198231, 1e-18, kurtosis);
+        test_almost(100.0, 0.5, -1.8752344110424017339e-9, 1e-24, kurtosis);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: Rice| x.min();
+        let max = |x: Rice| x.max();
+        test_case(1.0, 2.0, 0.0, min);
+        test_case(1.0, 2.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: Rice| x.pdf(arg);
+        test_case(1.0, 2.0, 0.0, pdf(-1.0));
+        test_case(1.0, 2.0, 0.0, pdf(0.0));
+        test_almost(1.0, 2.0, 0.19775429053492984097, 1e-15, pdf(1.0));
+        test_almost(3.0, 1.0, 0.008071953046010792791, 1e-17, pdf(0.5));
+        test_almost(3.0, 1.0, 0.40487857374516903867, 1e-15, pdf(3.0));
+        test_almost(50.0, 2.0, 0.17781916578483663071, 1e-14, pdf(51.0));
+        test_case(1.0, 2.0, 0.0, pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: Rice| x.ln_pdf(arg);
+        test_case(1.0, 2.0, f64::NEG_INFINITY, ln_pdf(0.0));
+        test_almost(1.0, 2.0, -1.6207299758065095547, 1e-15, ln_pdf(1.0));
+        test_almost(3.0, 1.0, -24.812713473834018466, 1e-14, ln_pdf(10.0));
+        test_almost(50.0, 2.0, -51.867164746630103457, 1e-13, ln_pdf(30.0));
+        test_case(1.0, 2.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: Rice| x.cdf(arg);
+        test_case(1.0, 2.0, 0.0, cdf(-1.0));
+        test_case(1.0, 2.0, 0.0, cdf(0.0));
+        test_almost(1.0, 2.0, 0.10449141893014031806, 1e-15, cdf(1.0));
+        test_almost(3.0, 1.0, 0.010829449821547850976, 1e-16, cdf(1.0));
+        test_almost(3.0, 1.0, 0.43252023770913849356, 1e-15, cdf(3.0));
+        test_almost(50.0, 2.0, 0.15376543831747774545, 1e-14, cdf(48.0));
+        test_case(1.0, 2.0, 1.0, cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Rice| x.sf(arg);
+        test_case(1.0, 2.0, 1.0, sf(0.0));
+        test_almost(1.0, 2.0, 0.89550858106985968194, 1e-15, sf(1.0));
+        test_almost(3.0, 1.0, 2.362425924427319028e-12, 1e-24, sf(10.0));
+        test_case(1.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_inverse_cdf(&try_create(1.0, 2.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(3.0, 1.0), 1e-12);
+    }
+
+    #[test]
+    fn test_consistent_with_rayleigh() {
+        // without noncentrality the rice distribution is the rayleigh
+        // distribution with the same scale
+        for &scale in &[0.5, 1.0, 3.0] {
+            let n = try_create(0.0, scale);
+            let r = Rayleigh::new(scale).unwrap();
+            for &x in &[0.1, 0.5, 1.0, 2.0, 4.0] {
+                assert_almost_eq!(n.pdf(x), r.pdf(x), 1e-15);
+                assert_almost_eq!(n.cdf(x), r.cdf(x), 1e-15);
+                assert_almost_eq!(n.sf(x), r.sf(x), 1e-15);
+            }
+            assert_almost_eq!(n.mean().unwrap(), r.mean().unwrap(), 1e-14);
+            assert_almost_eq!(n.variance().unwrap(), r.variance().unwrap(), 1e-14);
+            assert_almost_eq!(n.skewness().unwrap(), r.skewness().unwrap(), 1e-14);
+            assert_almost_eq!(n.kurtosis().unwrap(), r.kurtosis().unwrap(), 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(3.0, 1.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean - n.mean().unwrap()).abs() < 5.0 * (n.variance().unwrap() / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 3.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(3.0)).abs() < 0.015);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(1.0, 2.0), 0.0, 20.0);
+        tests::check_continuous_distribution(&try_create(3.0, 1.0), 0.0, 10.0);
+    }
+}
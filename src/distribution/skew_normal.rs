@@ -0,0 +1,530 @@
+use crate::consts;
+use crate::distribution::{normal, ziggurat, Continuous, ContinuousCDF};
+use crate::function::owens_t;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Skew
+/// normal](https://en.wikipedia.org/wiki/Skew_normal_distribution)
+/// distribution of Azzalini, a normal distribution with an additional
+/// shape parameter that controls its skewness
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{SkewNormal, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = SkewNormal::new(0.0, 1.0, 3.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 0.7569397566060480, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.3989422804014327, 1e-16));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkewNormal {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl SkewNormal {
+    /// Constructs a new skew normal distribution with a location (ξ) of
+    /// `location`, a scale (ω) of `scale` and a shape (α) of `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `location`, `scale` or `shape` are `NaN`
+    /// or infinite. Also returns an error if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let mut result = SkewNormal::new(0.0, 1.0, -2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = SkewNormal::new(0.0, 0.0, -2.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<SkewNormal> {
+        if !location.is_finite() || !scale.is_finite() || scale <= 0.0 || !shape.is_finite() {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(SkewNormal {
+                location,
+                scale,
+                shape,
+            })
+        }
+    }
+
+    /// Returns the location (ξ) of the skew normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale (ω) of the skew normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape (α) of the skew normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `δ * sqrt(2 / π)`, the mean of the standardized
+    /// distribution, where `δ = α / sqrt(1 + α^2)`
+    fn std_mean(&self) -> f64 {
+        self.shape / (1.0 + self.shape * self.shape).sqrt() * f64::consts::FRAC_2_PI.sqrt()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for SkewNormal {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        sample_unchecked(r, self.location, self.scale, self.shape)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for SkewNormal {
+    /// Calculates the cumulative distribution function for the skew normal
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The difference below is rewritten as a single integral of Owen's
+    /// `T` function type whenever it would cancel, so both tails keep their
+    /// relative accuracy. That accuracy is limited by the normal cdf when
+    /// `α <= 0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ((x - ξ) / ω) - 2 * T((x - ξ) / ω, α)
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `α` is the shape, `Φ`
+    /// is the standard normal cdf and `T` is Owen's T function
+    fn cdf(&self, x: f64) -> f64 {
+        cdf_unchecked(x, self.location, self.scale, self.shape)
+    }
+
+    /// Calculates the survival function for the skew normal distribution
+    /// at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The difference below is rewritten as a single integral of Owen's
+    /// `T` function type whenever it would cancel, so both tails keep their
+    /// relative accuracy. That accuracy is limited by the normal cdf when
+    /// `α >= 0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(-(x - ξ) / ω) + 2 * T((x - ξ) / ω, α)
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `α` is the shape, `Φ`
+    /// is the standard normal cdf and `T` is Owen's T function
+    fn sf(&self, x: f64) -> f64 {
+        sf_unchecked(x, self.location, self.scale, self.shape)
+    }
+}
+
+impl Min<f64> for SkewNormal {
+    /// Returns the minimum value in the domain of the skew normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for SkewNormal {
+    /// Returns the maximum value in the domain of the skew normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for SkewNormal {
+    /// Returns the mean of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ξ + ω * δ * sqrt(2 / π)
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale and
+    /// `δ = α / sqrt(1 + α^2)` with `α` the shape
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * self.std_mean())
+    }
+    /// Returns the variance of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ω^2 * (1 - 2δ^2 / π)
+    /// ```
+    ///
+    /// where `ω` is the scale and `δ = α / sqrt(1 + α^2)` with `α` the
+    /// shape
+    fn variance(&self) -> Option<f64> {
+        let m = self.std_mean();
+        Some(self.scale * self.scale * (1.0 - m * m))
+    }
+    /// Returns the skewness of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (4 - π) / 2 * (δ * sqrt(2 / π))^3 / (1 - 2δ^2 / π)^(3 / 2)
+    /// ```
+    ///
+    /// where `δ = α / sqrt(1 + α^2)` with `α` the shape
+    fn skewness(&self) -> Option<f64> {
+        let m = self.std_mean();
+        Some((4.0 - f64::consts::PI) / 2.0 * (m / (1.0 - m * m).sqrt()).powi(3))
+    }
+    /// Returns the excess kurtosis of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * (π - 3) * (δ * sqrt(2 / π))^4 / (1 - 2δ^2 / π)^2
+    /// ```
+    ///
+    /// where `δ = α / sqrt(1 + α^2)` with `α` the shape
+    fn kurtosis(&self) -> Option<f64> {
+        let m2 = self.std_mean().powi(2);
+        Some(2.0 * (f64::consts::PI - 3.0) * m2 * m2 / ((1.0 - m2) * (1.0 - m2)))
+    }
+}
+
+impl Continuous<f64, f64> for SkewNormal {
+    /// Calculates the probability density function for the skew normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 / ω * φ((x - ξ) / ω) * Φ(α * (x - ξ) / ω)
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `α` is the shape, `φ`
+    /// is the standard normal pdf and `Φ` is the standard normal cdf
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the skew normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 / ω * φ((x - ξ) / ω) * Φ(α * (x - ξ) / ω))
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `α` is the shape, `φ`
+    /// is the standard normal pdf and `Φ` is the standard normal cdf
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            f64::NEG_INFINITY
+        } else {
+            let z = (x - self.location) / self.scale;
+            f64::consts::LN_2 - self.scale.ln() - consts::LN_SQRT_2PI - 0.5 * z * z
+                + normal::ln_std_cdf(self.shape * z)
+        }
+    }
+}
+
+/// Computes `P(Z <= z)` for `z <= 0` and a standard skew normal `Z` with
+/// shape `shape`. Both branches add positive terms only
+fn lower_tail(z: f64, shape: f64) -> f64 {
+    let h = -z;
+    if shape > 0.0 {
+        // Φ(z) - 2 * T(h, α) = 2 * (T(h, INF) - T(h, α))
+        2.0 * owens_t::owens_t_upper(h, shape)
+    } else {
+        normal::cdf_unchecked(z, 0.0, 1.0) + 2.0 * owens_t::owens_t(h, -shape)
+    }
+}
+
+/// Computes the cdf of the skew normal distribution without checking the
+/// parameters
+pub fn cdf_unchecked(x: f64, location: f64, scale: f64, shape: f64) -> f64 {
+    let z = (x - location) / scale;
+    if z <= 0.0 {
+        lower_tail(z, shape)
+    } else {
+        1.0 - lower_tail(-z, -shape)
+    }
+}
+
+/// Computes the survival function of the skew normal distribution without
+/// checking the parameters
+pub fn sf_unchecked(x: f64, location: f64, scale: f64, shape: f64) -> f64 {
+    let z = (x - location) / scale;
+    if z >= 0.0 {
+        lower_tail(-z, -shape)
+    } else {
+        1.0 - lower_tail(z, shape)
+    }
+}
+
+/// Samples from a skew normal distribution with a location of `location`,
+/// a scale of `scale` and a shape of `shape` using `rng` as the source of
+/// randomness, from the representation `(α * |U| + V) / sqrt(1 + α^2)` in
+/// terms of two independent standard normal variates `U` and `V`
+pub fn sample_unchecked<R: Rng + ?Sized>(
+    rng: &mut R,
+    location: f64,
+    scale: f64,
+    shape: f64,
+) -> f64 {
+    let u = ziggurat::sample_std_normal(rng).abs();
+    let v = ziggurat::sample_std_normal(rng);
+    location + scale * (shape * u + v) / (1.0 + shape * shape).sqrt()
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Normal, SkewNormal};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> SkewNormal {
+        let n = SkewNormal::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, shape: f64) {
+        let n = try_create(location, scale, shape);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(shape, n.shape());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, shape: f64) {
+        let n = SkewNormal::new(location, scale, shape);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, shape: f64, expected: f64, eval: F)
+        where F: Fn(SkewNormal) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(SkewNormal) -> f64
+    {
+        let n = try_create(location, scale, shape);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0, 0.0);
+        create_case(1.0, 2.0, -2.0);
+        create_case(-1.0, 0.5, 10.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 1.0);
+        bad_create_case(0.0, f64::NAN, 1.0);
+        bad_create_case(0.0, 1.0, f64::NAN);
+        bad_create_case(0.0, 0.0, 1.0);
+        bad_create_case(0.0, -1.0, 1.0);
+        bad_create_case(f64::INFINITY, 1.0, 1.0);
+        bad_create_case(0.0, f64::INFINITY, 1.0);
+        bad_create_case(0.0, 1.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let mean = |x: SkewNormal| x.mean().unwrap();
+        let variance = |x: SkewNormal| x.variance().unwrap();
+        let skewness = |x: SkewNormal| x.skewness().unwrap();
+        let kurtosis = |x: SkewNormal| x.kurtosis().unwrap();
+        test_case(2.0, 3.0, 0.0, 2.0, mean);
+        test_case(2.0, 3.0, 0.0, 9.0, variance);
+        test_case(2.0, 3.0, 0.0, 0.0, skewness);
+        test_case(2.0, 3.0, 0.0, 0.0, kurtosis);
+        test_almost(0.0, 1.0, 3.0, 0.75693975660604801447, 1e-15, mean);
+        test_almost(0.0, 1.0, 3.0, 0.42704220486917679123, 1e-15, variance);
+        test_almost(0.0, 1.0, 3.0, 0.66702357015240795331, 1e-15, skewness);
+        test_almost(0.0, 1.0, 3.0, 0.50977012944941361572, 1e-15, kurtosis);
+        test_almost(1.0, 2.0, -2.0, -0.42729929292221689163, 1e-15, mean);
+        test_almost(1.0, 2.0, -2.0, 1.9628167284237397022, 1e-15, variance);
+        test_almost(1.0, 2.0, -2.0, -0.45382556395938175851, 1e-15, skewness);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: SkewNormal| x.min();
+        let max = |x: SkewNormal| x.max();
+        test_case(0.0, 1.0, 3.0, f64::NEG_INFINITY, min);
+        test_case(0.0, 1.0, 3.0, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_normal() {
+        let n = try_create(1.0, 2.0, 0.0);
+        let s = Normal::new(1.0, 2.0).unwrap();
+        for &x in &[-5.0, -1.2, 0.0, 0.7, 3.0] {
+            assert_almost_eq!(n.pdf(x), s.pdf(x), 1e-15);
+            assert_almost_eq!(n.cdf(x), s.cdf(x), 1e-15);
+            assert_almost_eq!(n.sf(x), s.sf(x), 1e-15);
+        }
+    }
+
+    // Reference values are computed with mpmath at 60 digits from Owen's
+    // T function. The density goes through the normal cdf as well, which is
+    // only accurate to about 1e-10 relative
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: SkewNormal| x.pdf(arg);
+        test_case(0.0, 1.0, 3.0, 0.0, pdf(f64::NEG_INFINITY));
+        test_case(0.0, 1.0, 3.0, 0.0, pdf(f64::INFINITY));
+        test_almost(0.0, 1.0, 3.0, 0.39894228040143267794, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 3.0, 1.0003465469316351274e-21, 1e-31, pdf(-3.0));
+        test_almost(0.0, 1.0, 3.0, 0.65708965523874128016, 1e-10, pdf(0.5));
+        test_almost(0.0, 1.0, 3.0, 2.9734390294685954158e-6, 1e-15, pdf(5.0));
+        test_almost(1.0, 2.0, -2.0, 0.23646585860873631684, 1e-10, pdf(-1.0));
+        test_almost(1.0, 2.0, -2.0, 1.1168702599516605699e-15, 1e-25, pdf(8.0));
+        test_almost(-1.0, 0.5, 10.0, 1.5285856843605711538, 1e-10, pdf(-0.9));
+        test_almost(-1.0, 0.5, 10.0, 5.946833103103045122e-90, 1e-99, pdf(-2.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: SkewNormal| x.ln_pdf(arg);
+        test_case(0.0, 1.0, 3.0, f64::NEG_INFINITY, ln_pdf(f64::INFINITY));
+        test_almost(0.0, 1.0, 3.0, -0.91893853320467274178, 1e-15, ln_pdf(0.0));
+        test_almost(0.0, 1.0, 3.0, -48.353940465976842929, 1e-10, ln_pdf(-3.0));
+        test_almost(0.0, 1.0, 3.0, -0.41993480825696141536, 1e-10, ln_pdf(0.5));
+        test_almost(1.0, 2.0, -2.0, -34.428246032015747984, 1e-10, ln_pdf(8.0));
+        test_almost(-1.0, 0.5, 10.0, -205.44979954318204606, 1e-10, ln_pdf(-2.0));
+        test_almost(-1.0, 0.5, 10.0, -7.5326441720847821229, 1e-10, ln_pdf(1.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: SkewNormal| x.cdf(arg);
+        test_case(0.0, 1.0, 3.0, 0.0, cdf(f64::NEG_INFINITY));
+        test_case(0.0, 1.0, 3.0, 1.0, cdf(f64::INFINITY));
+        test_almost(0.0, 1.0, 3.0, 3.2642743096552352041e-23, 1e-36, cdf(-3.0));
+        test_almost(0.0, 1.0, 3.0, 0.000056244433711877094156, 1e-18, cdf(-1.0));
+        test_almost(0.0, 1.0, 3.0, 0.10241638234956672582, 1e-15, cdf(0.0));
+        test_almost(0.0, 1.0, 3.0, 0.38929437512197628151, 1e-15, cdf(0.5));
+        test_almost(-1.0, 0.5, 10.0, 1.4647900657554495214e-92, 1e-105, cdf(-2.0));
+        test_almost(-1.0, 0.5, 10.0, 5.2153155120627067773e-7, 1e-20, cdf(-1.2));
+        test_almost(-1.0, 0.5, 10.0, 0.15917828540536970907, 1e-15, cdf(-0.9));
+        // the normal cdf is only accurate to about 1e-10 relative
+        test_almost(0.0, 1.0, 3.0, 0.95449973610873071157, 1e-10, cdf(2.0));
+        test_almost(1.0, 2.0, -2.0, 0.00046525815807092628093, 1e-13, cdf(-6.0));
+        test_almost(1.0, 2.0, -2.0, 0.3155916279176252441, 1e-10, cdf(-1.0));
+        test_almost(1.0, 2.0, -2.0, 0.73451935650493494457, 1e-10, cdf(0.5));
+        test_almost(1.0, 2.0, -2.0, 0.99828112005471114128, 1e-10, cdf(3.0));
+        test_almost(0.0, 1.0, -0.5, 0.040000287919222221463, 1e-11, cdf(-2.0));
+        test_almost(0.0, 1.0, -0.5, 0.64758361765043327418, 1e-10, cdf(0.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: SkewNormal| x.sf(arg);
+        test_case(0.0, 1.0, 3.0, 1.0, sf(f64::NEG_INFINITY));
+        test_case(0.0, 1.0, 3.0, 0.0, sf(f64::INFINITY));
+        test_almost(1.0, 2.0, -2.0, 1.2379177238775921054e-16, 1e-29, sf(8.0));
+        test_almost(1.0, 2.0, -2.0, 0.0017188799452888587249, 1e-17, sf(3.0));
+        test_almost(1.0, 2.0, -2.0, 0.14758361765043327418, 1e-15, sf(1.0));
+        // the normal cdf is only accurate to about 1e-10 relative
+        test_almost(0.0, 1.0, 3.0, 0.89758361765043327418, 1e-10, sf(0.0));
+        test_almost(0.0, 1.0, 3.0, 0.045500263891269288425, 1e-11, sf(2.0));
+        test_almost(0.0, 1.0, 3.0, 5.7330314375838782335e-7, 1e-16, sf(5.0));
+        test_almost(-1.0, 0.5, 10.0, 0.000063342483666239842508, 1e-14, sf(1.0));
+        test_almost(0.0, 1.0, -0.5, 0.022794288606872725943, 1e-12, sf(1.5));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(0.0, 1.0, 3.0);
+        assert_almost_eq!(n.inverse_cdf(0.38929437512197628151), 0.5, 1e-9);
+        tests::check_inverse_cdf(&n, 1e-9);
+        tests::check_inverse_cdf(&try_create(1.0, 2.0, -2.0), 1e-9);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, 3.0), -2.0, 8.0);
+        tests::check_continuous_distribution(&try_create(1.0, 2.0, -2.0), -12.0, 6.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0, 2.0, -2.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean + 0.42729929292221689).abs() < 5.0 * (1.9628167284237397f64 / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 0.5).count() as f64 / count as f64;
+        assert!((below - n.cdf(0.5)).abs() < 0.015);
+    }
+}
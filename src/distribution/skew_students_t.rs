@@ -0,0 +1,601 @@
+use crate::distribution::{internal, skew_normal, students_t, Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the skew student's t-distribution of Azzalini and
+/// Capitanio, the distribution of `ξ + ω * Z / sqrt(V / ν)` where `Z` is a
+/// standard [skew normal](https://en.wikipedia.org/wiki/Skew_normal_distribution)
+/// variate with shape `α` and `V` an independent chi-squared variate with
+/// `ν` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{SkewStudentsT, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = SkewStudentsT::new(0.0, 1.0, 0.0, 5.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.3796066898224944, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkewStudentsT {
+    location: f64,
+    scale: f64,
+    shape: f64,
+    freedom: f64,
+}
+
+impl SkewStudentsT {
+    /// Constructs a new skew student's t-distribution with a location (ξ)
+    /// of `location`, a scale (ω) of `scale`, a shape (α) of `shape` and
+    /// `freedom` (ν) degrees of freedom
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of `location`, `scale`, `shape` or
+    /// `freedom` are `NaN` or infinite. Also returns an error if
+    /// `scale <= 0.0` or `freedom <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let mut result = SkewStudentsT::new(0.0, 1.0, 2.0, 5.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = SkewStudentsT::new(0.0, 1.0, 2.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64, freedom: f64) -> Result<SkewStudentsT> {
+        if !location.is_finite()
+            || !scale.is_finite()
+            || scale <= 0.0
+            || !shape.is_finite()
+            || !freedom.is_finite()
+            || freedom <= 0.0
+        {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(SkewStudentsT {
+                location,
+                scale,
+                shape,
+                freedom,
+            })
+        }
+    }
+
+    /// Returns the location (ξ) of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 3.0, 5.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale (ω) of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 3.0, 5.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape (α) of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 3.0, 5.0).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the degrees of freedom (ν) of the skew student's
+    /// t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 3.0, 5.0).unwrap();
+    /// assert_eq!(n.freedom(), 5.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns `(δ^2, m)` where `δ = α / sqrt(1 + α^2)` and
+    /// `m = δ * sqrt(ν / π) * Γ((ν - 1) / 2) / Γ(ν / 2)` is the mean of the
+    /// standardized distribution
+    fn std_mean(&self) -> (f64, f64) {
+        let delta = self.shape / (1.0 + self.shape * self.shape).sqrt();
+        let g = internal::ln_scaled_gamma_ratio(self.freedom / 2.0, -0.5).exp();
+        (delta * delta, delta * f64::consts::FRAC_2_PI.sqrt() * g)
+    }
+
+    /// Returns the variance of the standardized distribution
+    fn std_variance(&self) -> f64 {
+        let (_, m) = self.std_mean();
+        self.freedom / (self.freedom - 2.0) - m * m
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for SkewStudentsT {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = skew_normal::sample_unchecked(rng, 0.0, 1.0, self.shape);
+        let v = super::gamma::sample_unchecked(rng, self.freedom / 2.0, 0.5);
+        self.location + self.scale * z / (v / self.freedom).sqrt()
+    }
+}
+
+/// Computes `P(T <= t)` for the standardized skew student's t-distribution
+/// as `E[F(t * W / sqrt(ν))]` over the chi distributed `W = sqrt(V)`,
+/// integrated in `s = ln(W)`, where `F` is the cdf of the standard skew
+/// normal distribution. The integrand is positive, so the tail
+/// probabilities do not cancel
+fn lower_by_quadrature(t: f64, shape: f64, freedom: f64) -> f64 {
+    let c = t / freedom.sqrt();
+    let ln_norm = (1.0 - freedom / 2.0) * f64::consts::LN_2 - gamma::ln_gamma(freedom / 2.0);
+    // in the lower tail F(y) decays like e^(-k * y^2 / 2), which moves the
+    // peak of the integrand to w^2 = ν / (1 + k * c^2)
+    let k = if c >= 0.0 {
+        0.0
+    } else if shape > 0.0 {
+        1.0 + shape * shape
+    } else {
+        1.0
+    };
+    let w = (freedom / (1.0 + k * c * c)).sqrt();
+    let step = 0.25 / (2.0 * freedom).sqrt();
+    internal::ln_integrate_trapezoid(
+        |s| {
+            let w = s.exp();
+            ln_norm + freedom * s - 0.5 * w * w
+                + skew_normal::cdf_unchecked(c * w, 0.0, 1.0, shape).ln()
+        },
+        w.ln(),
+        step,
+    )
+    .exp()
+    .min(1.0)
+}
+
+impl ContinuousCDF<f64, f64> for SkewStudentsT {
+    /// Calculates the cumulative distribution function for the skew
+    /// student's t-distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The expectation is integrated numerically against the chi
+    /// distribution of `sqrt(V)`. The accuracy is limited by that of the
+    /// normal cdf
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// E[F_SN((x - ξ) / ω * sqrt(V / ν))]
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `ν` is the degrees of
+    /// freedom, `V` is chi-squared distributed with `ν` degrees of freedom
+    /// and `F_SN` is the cdf of the standard skew normal distribution with
+    /// shape `α`
+    fn cdf(&self, x: f64) -> f64 {
+        if x == f64::NEG_INFINITY {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            let t = (x - self.location) / self.scale;
+            lower_by_quadrature(t, self.shape, self.freedom)
+        }
+    }
+
+    /// Calculates the survival function for the skew student's
+    /// t-distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// The expectation is integrated numerically against the chi
+    /// distribution of `sqrt(V)`. The accuracy is limited by that of the
+    /// normal cdf
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// E[F_SN(-(x - ξ) / ω * sqrt(V / ν))]
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `ν` is the degrees of
+    /// freedom, `V` is chi-squared distributed with `ν` degrees of freedom
+    /// and `F_SN` is the cdf of the standard skew normal distribution with
+    /// shape `-α`
+    fn sf(&self, x: f64) -> f64 {
+        if x == f64::NEG_INFINITY {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            let t = (x - self.location) / self.scale;
+            lower_by_quadrature(-t, -self.shape, self.freedom)
+        }
+    }
+}
+
+impl Min<f64> for SkewStudentsT {
+    /// Returns the minimum value in the domain of the skew student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for SkewStudentsT {
+    /// Returns the maximum value in the domain of the skew student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for SkewStudentsT {
+    /// Returns the mean of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ξ + ω * δ * b
+    /// ```
+    ///
+    /// where `b = sqrt(ν / π) * Γ((ν - 1) / 2) / Γ(ν / 2)`,
+    /// `δ = α / sqrt(1 + α^2)`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape and `ν` is the degrees of freedom
+    fn mean(&self) -> Option<f64> {
+        if self.freedom <= 1.0 {
+            None
+        } else {
+            Some(self.location + self.scale * self.std_mean().1)
+        }
+    }
+    /// Returns the variance of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ω^2 * (ν / (ν - 2) - δ^2 * b^2)
+    /// ```
+    ///
+    /// where `b = sqrt(ν / π) * Γ((ν - 1) / 2) / Γ(ν / 2)`,
+    /// `δ = α / sqrt(1 + α^2)`, `ω` is the scale, `α` is the shape and `ν`
+    /// is the degrees of freedom
+    fn variance(&self) -> Option<f64> {
+        if self.freedom <= 2.0 {
+            None
+        } else {
+            Some(self.scale * self.scale * self.std_variance())
+        }
+    }
+    /// Returns the skewness of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 3.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// δ * b * (ν * (3 - δ^2) / (ν - 3) - 3ν / (ν - 2) + 2δ^2 * b^2) / σ^3
+    /// ```
+    ///
+    /// where `b = sqrt(ν / π) * Γ((ν - 1) / 2) / Γ(ν / 2)`,
+    /// `δ = α / sqrt(1 + α^2)`, `σ^2 = ν / (ν - 2) - δ^2 * b^2`, `α` is the
+    /// shape and `ν` is the degrees of freedom
+    fn skewness(&self) -> Option<f64> {
+        if self.freedom <= 3.0 {
+            None
+        } else {
+            let nu = self.freedom;
+            let (d2, m) = self.std_mean();
+            let c3 = m * (nu * (3.0 - d2) / (nu - 3.0) - 3.0 * nu / (nu - 2.0) + 2.0 * m * m);
+            Some(c3 / self.std_variance().powf(1.5))
+        }
+    }
+    /// Returns the excess kurtosis of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 4.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (3ν^2 / ((ν - 2) * (ν - 4)) - 4δ^2 * b^2 * ν * (3 - δ^2) / (ν - 3)
+    ///     + 6δ^2 * b^2 * ν / (ν - 2) - 3δ^4 * b^4) / σ^4 - 3
+    /// ```
+    ///
+    /// where `b = sqrt(ν / π) * Γ((ν - 1) / 2) / Γ(ν / 2)`,
+    /// `δ = α / sqrt(1 + α^2)`, `σ^2 = ν / (ν - 2) - δ^2 * b^2`, `α` is the
+    /// shape and `ν` is the degrees of freedom
+    fn kurtosis(&self) -> Option<f64> {
+        if self.freedom <= 4.0 {
+            None
+        } else {
+            let nu = self.freedom;
+            let (d2, m) = self.std_mean();
+            let m2 = m * m;
+            let c4 = 3.0 * nu * nu / ((nu - 2.0) * (nu - 4.0))
+                - 4.0 * m2 * nu * (3.0 - d2) / (nu - 3.0)
+                + 6.0 * m2 * nu / (nu - 2.0)
+                - 3.0 * m2 * m2;
+            let var = self.std_variance();
+            Some(c4 / (var * var) - 3.0)
+        }
+    }
+}
+
+impl Continuous<f64, f64> for SkewStudentsT {
+    /// Calculates the probability density function for the skew student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 / ω * t_ν(k) * T_(ν + 1)(α * k * sqrt((ν + 1) / (ν + k^2)))
+    /// ```
+    ///
+    /// where `k = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape, `ν` is the degrees of freedom, `t_ν` is the pdf of the
+    /// standard student's t-distribution with `ν` degrees of freedom and
+    /// `T_(ν + 1)` is the cdf of that with `ν + 1` degrees of freedom
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the skew
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 / ω * t_ν(k) * T_(ν + 1)(α * k * sqrt((ν + 1) / (ν + k^2))))
+    /// ```
+    ///
+    /// where `k = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape, `ν` is the degrees of freedom, `t_ν` is the pdf of the
+    /// standard student's t-distribution with `ν` degrees of freedom and
+    /// `T_(ν + 1)` is the cdf of that with `ν + 1` degrees of freedom
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            f64::NEG_INFINITY
+        } else {
+            let nu = self.freedom;
+            let k = (x - self.location) / self.scale;
+            let y = self.shape * k * ((nu + 1.0) / (nu + k * k)).sqrt();
+            f64::consts::LN_2
+                + students_t::ln_pdf_unchecked(x, self.location, self.scale, nu)
+                + students_t::cdf_unchecked(y, 0.0, 1.0, nu + 1.0).ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, SkewStudentsT, StudentsT};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64, freedom: f64) -> SkewStudentsT {
+        let n = SkewStudentsT::new(location, scale, shape, freedom);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, shape: f64, freedom: f64) {
+        let n = try_create(location, scale, shape, freedom);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(shape, n.shape());
+        assert_eq!(freedom, n.freedom());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, shape: f64, freedom: f64) {
+        let n = SkewStudentsT::new(location, scale, shape, freedom);
+        assert!(n.is_err());
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, shape: f64, freedom: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(SkewStudentsT) -> f64
+    {
+        let n = try_create(location, scale, shape, freedom);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    fn test_none<F>(location: f64, scale: f64, shape: f64, freedom: f64, eval: F)
+        where F: Fn(SkewStudentsT) -> Option<f64>
+    {
+        let n = try_create(location, scale, shape, freedom);
+        assert!(eval(n).is_none());
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0, 0.0, 5.0);
+        create_case(1.0, 2.0, -2.0, 0.5);
+        create_case(-1.0, 0.5, 10.0, 100.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, 1.0, 1.0);
+        bad_create_case(0.0, f64::NAN, 1.0, 1.0);
+        bad_create_case(0.0, 1.0, f64::NAN, 1.0);
+        bad_create_case(0.0, 1.0, 1.0, f64::NAN);
+        bad_create_case(0.0, 0.0, 1.0, 1.0);
+        bad_create_case(0.0, 1.0, 1.0, 0.0);
+        bad_create_case(0.0, 1.0, 1.0, -1.0);
+        bad_create_case(f64::INFINITY, 1.0, 1.0, 1.0);
+        bad_create_case(0.0, 1.0, f64::INFINITY, 1.0);
+        bad_create_case(0.0, 1.0, 1.0, f64::INFINITY);
+    }
+
+    // Moment references are computed with mpmath at 40 digits and agree
+    // with quadrature of the density
+    #[test]
+    fn test_moments() {
+        let mean = |x: SkewStudentsT| x.mean().unwrap();
+        let variance = |x: SkewStudentsT| x.variance().unwrap();
+        let skewness = |x: SkewStudentsT| x.skewness().unwrap();
+        let kurtosis = |x: SkewStudentsT| x.kurtosis().unwrap();
+        test_almost(0.0, 1.0, 3.0, 10.0, 0.8203125, 1e-15, mean);
+        test_almost(0.0, 1.0, 3.0, 10.0, 0.57708740234375, 1e-15, variance);
+        test_almost(0.0, 1.0, 3.0, 10.0, 1.1148997103204657378, 1e-14, skewness);
+        test_almost(0.0, 1.0, 3.0, 10.0, 2.5954717173194521853, 1e-14, kurtosis);
+        test_almost(1.0, 2.0, -2.0, 6.0, -0.64316767251549834037, 1e-15, mean);
+        test_almost(1.0, 2.0, -2.0, 6.0, 3.3, 1e-14, variance);
+        test_almost(1.0, 2.0, -2.0, 6.0, -1.3705061117171073757, 1e-14, skewness);
+        test_almost(1.0, 2.0, -2.0, 6.0, 6.2975206611570247934, 1e-13, kurtosis);
+        test_none(0.0, 1.0, 1.0, 1.0, |x| x.mean());
+        test_none(0.0, 1.0, 1.0, 2.0, |x| x.variance());
+        test_none(0.0, 1.0, 1.0, 3.0, |x| x.skewness());
+        test_none(0.0, 1.0, 1.0, 4.0, |x| x.kurtosis());
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let n = try_create(1.0, 2.0, 0.0, 8.0);
+        let s = StudentsT::new(1.0, 2.0, 8.0).unwrap();
+        assert_eq!(n.mean().unwrap(), 1.0);
+        assert_almost_eq!(n.variance().unwrap(), s.variance().unwrap(), 1e-15);
+        assert_almost_eq!(n.kurtosis().unwrap(), s.kurtosis().unwrap(), 1e-14);
+        for &x in &[-5.0, -1.2, 0.0, 0.7, 3.0] {
+            assert_almost_eq!(n.pdf(x), s.pdf(x), 1e-15);
+            // the quadrature goes through the normal cdf
+            assert_almost_eq!(n.cdf(x), s.cdf(x), 1e-10);
+            assert_almost_eq!(n.sf(x), s.sf(x), 1e-10);
+        }
+    }
+
+    // Reference values are computed with mpmath at 40 digits by quadrature
+    // of the density. The cdf and sf go through the normal cdf and are only
+    // checked to its relative accuracy of about 1e-10
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: SkewStudentsT| x.pdf(arg);
+        test_almost(0.0, 1.0, 3.0, 5.0, 0.000018345123894647443160, 1e-19, pdf(-3.0));
+        test_almost(0.0, 1.0, 3.0, 5.0, 0.43408547890389970397, 1e-15, pdf(1.0));
+        test_almost(0.0, 1.0, 3.0, 5.0, 1.2801370798820052737e-7, 1e-21, pdf(30.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.00025574540297093705969, 1e-18, pdf(-20.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.24719489131677485206, 1e-15, pdf(0.5));
+        test_almost(-1.0, 0.5, 10.0, 1.5, 0.71812533002059465755, 1e-15, pdf(-0.5));
+        test_almost(-1.0, 0.5, 10.0, 1.5, 0.000050623836519663072233, 1e-18, pdf(-3.0));
+        test_almost(0.0, 1.0, 1.0, 30.0, 0.48135091414996697408, 1e-12, pdf(0.5));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: SkewStudentsT| x.ln_pdf(arg);
+        assert_eq!(try_create(0.0, 1.0, 3.0, 5.0).ln_pdf(f64::INFINITY), f64::NEG_INFINITY);
+        test_almost(0.0, 1.0, 3.0, 5.0, -10.90614674658771404, 1e-13, ln_pdf(-3.0));
+        test_almost(0.0, 1.0, 3.0, 5.0, -15.871128485103093754, 1e-13, ln_pdf(30.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, -1.3975782198064265692, 1e-14, ln_pdf(0.5));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: SkewStudentsT| x.cdf(arg);
+        assert_eq!(try_create(0.0, 1.0, 3.0, 5.0).cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(try_create(0.0, 1.0, 3.0, 5.0).cdf(f64::INFINITY), 1.0);
+        test_almost(0.0, 1.0, 3.0, 5.0, 0.000011462595741502484468, 1e-15, cdf(-3.0));
+        test_almost(0.0, 1.0, 3.0, 5.0, 0.10241638234956672582, 1e-14, cdf(0.0));
+        test_almost(0.0, 1.0, 3.0, 5.0, 0.63820484823704758984, 1e-10, cdf(1.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.0018293128625441645031, 1e-12, cdf(-20.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.74301546381300002272, 1e-10, cdf(0.5));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.99981049688515634508, 1e-10, cdf(10.0));
+        test_almost(-1.0, 0.5, 10.0, 1.5, 0.000067533272020171887792, 1e-15, cdf(-3.0));
+        test_almost(-1.0, 0.5, 10.0, 1.5, 0.54940179096654092422, 1e-10, cdf(-0.5));
+        test_almost(0.0, 1.0, 1.0, 30.0, 0.001003475652759930293, 1e-13, cdf(-2.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: SkewStudentsT| x.sf(arg);
+        assert_eq!(try_create(0.0, 1.0, 3.0, 5.0).sf(f64::NEG_INFINITY), 1.0);
+        assert_eq!(try_create(0.0, 1.0, 3.0, 5.0).sf(f64::INFINITY), 0.0);
+        test_almost(0.0, 1.0, 3.0, 5.0, 0.010320573179363233151, 1e-12, sf(4.0));
+        test_almost(0.0, 1.0, 3.0, 5.0, 7.7173807828716922332e-7, 1e-16, sf(30.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.14758361765043327418, 1e-14, sf(1.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.00018950311484365491964, 1e-17, sf(10.0));
+        test_almost(1.0, 2.0, -2.0, 3.0, 0.9981706871374558355, 1e-10, sf(-20.0));
+        test_almost(-1.0, 0.5, 10.0, 1.5, 0.050165688418631256199, 1e-11, sf(2.0));
+        test_almost(0.0, 1.0, 1.0, 30.0, 0.0053702783774289148197, 1e-12, sf(3.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(0.0, 1.0, 3.0, 5.0);
+        assert_almost_eq!(n.inverse_cdf(0.63820484823704758984), 1.0, 1e-8);
+        tests::check_inverse_cdf(&n, 1e-8);
+        tests::check_inverse_cdf(&try_create(1.0, 2.0, -2.0, 3.0), 1e-8);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0, 2.0, -2.0, 6.0);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        assert!((mean + 0.64316767251549834).abs() < 5.0 * (3.3 / count as f64).sqrt());
+        let below = samples.iter().filter(|&&x| x <= 0.0).count() as f64 / count as f64;
+        assert!((below - n.cdf(0.0)).abs() < 0.015);
+    }
+}
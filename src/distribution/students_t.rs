@@ -137,33 +137,60 @@ impl ContinuousCDF<f64, f64> for StudentsT {
     /// incomplete
     /// beta function
     fn cdf(&self, x: f64) -> f64 {
-        if self.freedom.is_infinite() {
-            super::normal::cdf_unchecked(x, self.location, self.scale)
-        } else {
-            let k = (x - self.location) / self.scale;
-            let h = self.freedom / (self.freedom + k * k);
-            let ib = 0.5 * beta::beta_reg(self.freedom / 2.0, 0.5, h);
-            if x <= self.location {
-                ib
-            } else {
-                1.0 - ib
-            }
-        }
+        cdf_unchecked(x, self.location, self.scale, self.freedom)
+    }
+
+    /// Calculates the survival function for the student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < μ {
+    ///     1 - (1 / 2) * I(t, v / 2, 1 / 2)
+    /// } else {
+    ///     (1 / 2) * I(t, v / 2, 1 / 2)
+    /// }
+    /// ```
+    ///
+    /// where `t = v / (v + k^2)`, `k = (x - μ) / σ`, `μ` is the location,
+    /// `σ` is the scale, `v` is the freedom, and `I` is the regularized
+    /// incomplete
+    /// beta function
+    fn sf(&self, x: f64) -> f64 {
+        sf_unchecked(x, self.location, self.scale, self.freedom)
     }
 
     /// Calculates the inverse cumulative distribution function for the
-    /// student's t-distribution at `x`
-    fn inverse_cdf(&self, x: f64) -> f64 {
-        assert!(x >= 0.0 && x <= 1.0);
-        let x = 2. * x.min(1. - x);
-        let a = 0.5 * self.freedom;
-        let b = 0.5;
-        let y = beta::inv_beta_reg(a, b, x);
-        let y = (self.freedom * (1. - y) / y).sqrt();
-        if x <= 0.5 {
-            y
+    /// student's t-distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + sign(p - 1 / 2) * σ * sqrt(v * (1 - y) / y)
+    /// ```
+    ///
+    /// where `y = I^-1_q(v / 2, 1 / 2)`, `q = 2 * min(p, 1 - p)`, `μ` is the
+    /// location, `σ` is the scale, `v` is the freedom, and `I^-1` is the
+    /// inverse of the regularized incomplete beta function. If the freedom
+    /// is infinite, the quantile of the normal distribution is returned
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if self.freedom.is_infinite() {
+            return super::normal::inverse_cdf_unchecked(p, self.location, self.scale);
+        }
+        let y = beta::inv_beta_reg(self.freedom / 2.0, 0.5, 2.0 * p.min(1.0 - p));
+        let t = (self.freedom * (1.0 - y) / y).sqrt();
+        if p < 0.5 {
+            self.location - self.scale * t
         } else {
-            -y
+            self.location + self.scale * t
         }
     }
 }
@@ -284,6 +311,29 @@ impl Distribution<f64> for StudentsT {
             Some(0.0)
         }
     }
+    /// Returns the excess kurtosis of the student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if freedom is less than or equal to `2.0` and
+    /// infinity if freedom is in `(2.0, 4.0]`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 6 / (v - 4)
+    /// ```
+    ///
+    /// where `v` is the freedom
+    fn kurtosis(&self) -> Option<f64> {
+        if self.freedom <= 2.0 {
+            None
+        } else if self.freedom <= 4.0 {
+            Some(f64::INFINITY)
+        } else {
+            Some(6.0 / (self.freedom - 4.0))
+        }
+    }
 }
 
 impl Median<f64> for StudentsT {
@@ -361,21 +411,61 @@ impl Continuous<f64, f64> for StudentsT {
     /// the freedom,
     /// and `Γ` is the gamma function
     fn ln_pdf(&self, x: f64) -> f64 {
-        if x.is_infinite() {
-            f64::NEG_INFINITY
-        } else if self.freedom >= 1e8 {
-            super::normal::ln_pdf_unchecked(x, self.location, self.scale)
+        ln_pdf_unchecked(x, self.location, self.scale, self.freedom)
+    }
+}
+
+/// performs an unchecked cdf calculation for a student's t-distribution
+/// with the given location, scale and freedom at x
+pub fn cdf_unchecked(x: f64, location: f64, scale: f64, freedom: f64) -> f64 {
+    if freedom.is_infinite() {
+        super::normal::cdf_unchecked(x, location, scale)
+    } else {
+        let k = (x - location) / scale;
+        let h = freedom / (freedom + k * k);
+        let ib = 0.5 * beta::beta_reg(freedom / 2.0, 0.5, h);
+        if x <= location {
+            ib
         } else {
-            let d = (x - self.location) / self.scale;
-            gamma::ln_gamma((self.freedom + 1.0) / 2.0)
-                - 0.5 * ((self.freedom + 1.0) * (1.0 + d * d / self.freedom).ln())
-                - gamma::ln_gamma(self.freedom / 2.0)
-                - 0.5 * (self.freedom * f64::consts::PI).ln()
-                - self.scale.ln()
+            1.0 - ib
         }
     }
 }
 
+/// performs an unchecked survival function calculation for a student's
+/// t-distribution with the given location, scale and freedom at x
+pub fn sf_unchecked(x: f64, location: f64, scale: f64, freedom: f64) -> f64 {
+    if freedom.is_infinite() {
+        super::normal::sf_unchecked(x, location, scale)
+    } else {
+        let k = (x - location) / scale;
+        let h = freedom / (freedom + k * k);
+        let ib = 0.5 * beta::beta_reg(freedom / 2.0, 0.5, h);
+        if x >= location {
+            ib
+        } else {
+            1.0 - ib
+        }
+    }
+}
+
+/// performs an unchecked log(pdf) calculation for a student's
+/// t-distribution with the given location, scale and freedom at x
+pub fn ln_pdf_unchecked(x: f64, location: f64, scale: f64, freedom: f64) -> f64 {
+    if x.is_infinite() {
+        f64::NEG_INFINITY
+    } else if freedom >= 1e8 {
+        super::normal::ln_pdf_unchecked(x, location, scale)
+    } else {
+        let d = (x - location) / scale;
+        gamma::ln_gamma((freedom + 1.0) / 2.0)
+            - 0.5 * ((freedom + 1.0) * (1.0 + d * d / freedom).ln())
+            - gamma::ln_gamma(freedom / 2.0)
+            - 0.5 * (freedom * f64::consts::PI).ln()
+            - scale.ln()
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -489,6 +579,16 @@ mod tests {
         test_case(0.0, f64::INFINITY, 1.0, None, variance);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: StudentsT| x.kurtosis();
+        test_case(0.0, 1.0, 7.0, Some(2.0), kurtosis);
+        test_case(5.0, 3.0, 10.0, Some(1.0), kurtosis);
+        test_case(0.0, 1.0, 4.0, Some(f64::INFINITY), kurtosis);
+        test_case(0.0, 1.0, 2.0, None, kurtosis);
+        test_case(0.0, 1.0, f64::INFINITY, Some(0.0), kurtosis);
+    }
+
     #[test]
     #[should_panic]
     fn test_variance_freedom_lte1() {
@@ -604,6 +704,16 @@ mod tests {
         test_almost(0.0, 1.0, f64::INFINITY, 0.977249868051821, 1e-11, cdf(2.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: StudentsT| x.sf(arg);
+        test_case(0.0, 1.0, 5.0, 0.5, sf(0.0));
+        test_almost(0.0, 1.0, 5.0, 0.949030260585070815373490938704, 1e-14, sf(-2.0));
+        test_almost(0.0, 1.0, 5.0, 0.0509697394149291846265090612957, 1e-14, sf(2.0));
+        test_almost(0.0, 1.0, 5.0, 0.000000385932431024802559096401241904, 1e-18, sf(30.0));
+        test_almost(0.0, 1.0, f64::INFINITY, 7.619853024160526065973343251599308363504e-24, 1e-33, sf(10.0));
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(0.0, 1.0, 3.0), -30.0, 30.0);
@@ -611,6 +721,35 @@ mod tests {
         tests::check_continuous_distribution(&try_create(20.0, 0.5, 10.0), 10.0, 30.0);
     }
 
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: StudentsT| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, 5.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, 5.0, -2.570581835636315514696, 1e-13, inverse_cdf(0.025));
+        test_case(0.0, 1.0, 5.0, 0.0, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 5.0, 1.475884048824481078546, 1e-13, inverse_cdf(0.9));
+        test_almost(1.0, 2.0, 5.0, -1.951768097648962157093, 1e-13, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 2.0, 1.060660171779821286601, 1e-13, inverse_cdf(0.8));
+        test_almost(5.0, 2.0, f64::INFINITY, 5.0, 1e-14, inverse_cdf(0.5));
+        test_case(0.0, 1.0, 5.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, 5.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(1.0, 2.0, 1.0), 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_cdf_sign() {
+        let inverse_cdf = |arg: f64| move |x: StudentsT| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 5.0, -0.5594296444693607501485, 1e-14, inverse_cdf(0.3));
+        test_almost(0.0, 1.0, 5.0, 0.5594296444693607501485, 1e-14, inverse_cdf(0.7));
+        // below the lower quartile and between the median and the upper
+        // quartile the sign used to be flipped
+        test_almost(0.0, 1.0, 5.0, -0.9195437802408260260701, 1e-14, inverse_cdf(0.2));
+        test_almost(0.0, 1.0, 5.0, 0.2671808657041451267291, 1e-14, inverse_cdf(0.6));
+        test_case(0.0, 1.0, 5.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        // location and scale used to be ignored
+        test_almost(1.0, 2.0, 5.0, 2.118859288938721500297, 1e-14, inverse_cdf(0.7));
+    }
+
     #[test]
     fn test_inv_cdf() {
         let test = |x: f64, freedom: f64, expected: f64| {
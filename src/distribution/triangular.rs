@@ -1,6 +1,7 @@
 use crate::distribution::{Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::Rng;
 use std::f64;
 
@@ -57,6 +58,23 @@ impl Triangular {
         }
         Ok(Triangular { min, max, mode })
     }
+
+    /// Returns the moment generating function at `t` divided by
+    /// `e^(t * mode)`
+    fn scaled_mgf(&self, t: f64) -> f64 {
+        let left = self.mode - self.min;
+        let right = self.max - self.mode;
+        2.0 * (left * exprel(2, -t * left) + right * exprel(2, t * right)) / (self.max - self.min)
+    }
+
+    /// Returns `scaled_mgf(t) - 1` without cancellation for small `t`,
+    /// using `exprel(2, x) - 1 / 2 = x * exprel(3, x)`
+    fn scaled_mgf_m1(&self, t: f64) -> f64 {
+        let left = self.mode - self.min;
+        let right = self.max - self.mode;
+        2.0 * t * (right * right * exprel(3, t * right) - left * left * exprel(3, -t * left))
+            / (self.max - self.min)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Triangular {
@@ -97,6 +115,67 @@ impl ContinuousCDF<f64, f64> for Triangular {
             1.0
         }
     }
+
+    /// Calculates the survival function for the triangular
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x <= min {
+    ///     1
+    /// } if min < x <= mode {
+    ///     1 - (x - min)^2 / ((max - min) * (mode - min))
+    /// } else if mode < x < max {
+    ///     (max - x)^2 / ((max - min) * (max - mode))
+    /// } else {
+    ///     0
+    /// }
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if x <= a {
+            1.0
+        } else if x <= c {
+            1.0 - (x - a) * (x - a) / ((b - a) * (c - a))
+        } else if x < b {
+            (b - x) * (b - x) / ((b - a) * (b - c))
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// triangular distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if p < (mode - min) / (max - min) {
+    ///     min + sqrt(p * (max - min) * (mode - min))
+    /// } else {
+    ///     max - sqrt((1 - p) * (max - min) * (max - mode))
+    /// }
+    /// ```
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if p < (c - a) / (b - a) {
+            a + (p * (b - a) * (c - a)).sqrt()
+        } else {
+            b - ((1.0 - p) * (b - a) * (b - c)).sqrt()
+        }
+    }
 }
 
 impl Min<f64> for Triangular {
@@ -175,6 +254,111 @@ impl Distribution<f64> for Triangular {
         let d = 5.0 * (a * a + b * b + c * c - a * b - a * c - b * c).powf(3.0 / 2.0);
         Some(q / d)
     }
+    /// Returns the excess kurtosis of the triangular distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -3 / 5
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(-0.6)
+    }
+}
+
+impl GeneratingFunction<f64> for Triangular {
+    /// Returns the moment generating function of the triangular
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * e^(t * mode) * ((mode - min) * φ(-t * (mode - min)) + (max - mode) * φ(t * (max - mode))) / (max - min)
+    /// ```
+    ///
+    /// where `φ(x) = (e^x - 1 - x) / x^2`, which splits the distribution at
+    /// its mode and avoids the cancellation of the usual closed form
+    fn mgf(&self, t: f64) -> Option<f64> {
+        Some((t * self.mode).exp() * self.scaled_mgf(t))
+    }
+    /// Returns the cumulant generating function of the triangular
+    /// distribution at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// t * mode + ln(2 * ((mode - min) * φ(-t * (mode - min)) + (max - mode) * φ(t * (max - mode))) / (max - min))
+    /// ```
+    ///
+    /// where `φ(x) = (e^x - 1 - x) / x^2`. The logarithm is taken with
+    /// `ln_1p` while its argument is close to one
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let m1 = self.scaled_mgf_m1(t);
+        if m1.abs() < 0.5 {
+            Some(t * self.mode + m1.ln_1p())
+        } else {
+            Some(t * self.mode + self.scaled_mgf(t).ln())
+        }
+    }
+    /// Returns the characteristic function of the triangular distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * e^(it * mode) * ((mode - min) * φ(-it * (mode - min)) + (max - mode) * φ(it * (max - mode))) / (max - min)
+    /// ```
+    ///
+    /// where `φ(x) = (e^x - 1 - x) / x^2`
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let left = self.mode - self.min;
+        let right = self.max - self.mode;
+        let phi = exprel2_imag(-t * left) * left + exprel2_imag(t * right) * right;
+        Complex::from_polar(2.0 / (self.max - self.min), t * self.mode) * phi
+    }
+}
+
+/// Magnitude of the argument below which `exprel` and `exprel2_imag` sum
+/// their Taylor series instead of the cancelling closed form
+const EXPREL_SERIES_BOUND: f64 = 2.0;
+
+/// Number of terms of the Taylor series used by `exprel` and
+/// `exprel2_imag`, enough for double precision below
+/// `EXPREL_SERIES_BOUND`
+const EXPREL_SERIES_TERMS: u32 = 24;
+
+/// Computes `(e^x - sum(x^k / k!, k=0..n-1)) / x^n`, summing its Taylor
+/// series `sum(x^k / (k + n)!, k=0..)` where the closed form cancels
+fn exprel(n: u32, x: f64) -> f64 {
+    if x.abs() < EXPREL_SERIES_BOUND {
+        let series = (0..EXPREL_SERIES_TERMS)
+            .rev()
+            .fold(0.0, |acc, k| (acc * x + 1.0) / (k + n) as f64);
+        (1..n).fold(series, |acc, k| acc / k as f64)
+    } else {
+        let (partial, _) = (1..n).fold((0.0, 1.0), |(sum, term), k| {
+            let term = term * x / k as f64;
+            (sum + term, term)
+        });
+        (x.exp_m1() - partial) / x.powi(n as i32)
+    }
+}
+
+/// Computes `(e^(iy) - 1 - iy) / (iy)^2`, whose real part is
+/// `(1 - cos(y)) / y^2` and imaginary part `(y - sin(y)) / y^2`
+fn exprel2_imag(y: f64) -> Complex<f64> {
+    let half = y / 2.0;
+    let sinc = if half == 0.0 { 1.0 } else { half.sin() / half };
+    let im = if y.abs() < EXPREL_SERIES_BOUND {
+        let y2 = y * y;
+        (0..EXPREL_SERIES_TERMS / 2).rev().fold(0.0, |acc, k| {
+            let k = 2.0 * k as f64;
+            (1.0 - acc * y2) / ((k + 2.0) * (k + 3.0))
+        }) * y
+    } else {
+        (y - y.sin()) / (y * y)
+    };
+    Complex::new(sinc * sinc / 2.0, im)
 }
 
 impl Median<f64> for Triangular {
@@ -384,6 +568,62 @@ mod tests {
         test_case(15.0, 134.0, 21.0, 0.5605920922751860613217, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Triangular| x.kurtosis().unwrap();
+        test_case(0.0, 3.0, 1.0, -0.6, kurtosis);
+        test_case(-5.0, 8.0, 5.0, -0.6, kurtosis);
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Triangular| x.mgf(t).unwrap();
+        test_almost(0.0, 1.0, 0.5, 0.26823411025377968227, 1e-16, mgf(-3.0));
+        test_almost(0.0, 1.0, 0.5, 1.2907269971943228592, 1e-15, mgf(0.5));
+        test_almost(0.0, 1.0, 0.5, 2.9524924420125597565, 1e-15, mgf(2.0));
+        test_almost(-5.0, 8.0, -3.5, 36785.926667820206732, 1e-10, mgf(-3.0));
+        test_almost(-5.0, 8.0, -3.5, 2.8747252180703824523, 1e-15, mgf(0.5));
+        test_almost(-5.0, 8.0, -3.5, 29719.433153688835218, 1e-10, mgf(2.0));
+        test_almost(0.0, 1.0, 0.0, 0.45550823741508087622, 1e-16, mgf(-3.0));
+        test_almost(0.0, 1.0, 0.0, 2.1945280494653251136, 1e-15, mgf(2.0));
+        test_almost(-5.0, 8.0, 8.0, 4298.5106804366983948, 1e-11, mgf(-3.0));
+        test_almost(-5.0, 8.0, 8.0, 657256.69530397105281, 1e-9, mgf(2.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Triangular| x.cgf(t).unwrap();
+        test_almost(0.0, 1.0, 0.5, -1.315895134067236756, 1e-15, cgf(-3.0));
+        test_almost(0.0, 1.0, 0.5, 5.0000000002083336447e-10, 1e-24, cgf(1e-9));
+        test_almost(0.0, 1.0, 0.5, 1.082649709225836218, 1e-15, cgf(2.0));
+        test_almost(-5.0, 8.0, -3.5, 10.512870623508089572, 1e-14, cgf(-3.0));
+        test_almost(-5.0, 8.0, -3.5, -1.6666666245138889708e-10, 1e-24, cgf(1e-9));
+        test_almost(-5.0, 8.0, -3.5, 1.055957093358260691, 1e-15, cgf(0.5));
+        test_almost(0.0, 1.0, 0.0, 3.3333333336111113187e-10, 1e-24, cgf(1e-9));
+        test_almost(-5.0, 8.0, 8.0, 3.6666666713611113368e-9, 1e-23, cgf(1e-9));
+        test_almost(-5.0, 8.0, 8.0, 13.395829929385386331, 1e-14, cgf(2.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Triangular| x.cf(t).re;
+        let im = |t| move |x: Triangular| x.cf(t).im;
+        test_almost(0.0, 1.0, 0.5, 0.058429733304822789977, 1e-15, re(-3.0));
+        test_almost(0.0, 1.0, 0.5, -0.8239422067325518399, 1e-15, im(-3.0));
+        test_almost(0.0, 1.0, 0.5, 5.0000000000000003111e-10, 1e-24, im(1e-9));
+        test_almost(0.0, 1.0, 0.5, 0.4967514482834218218, 1e-15, re(2.0));
+        test_almost(0.0, 1.0, 0.5, 0.77364454279011131791, 1e-15, im(2.0));
+        test_almost(-5.0, 8.0, -3.5, 0.00190081746356798833, 1e-15, re(-3.0));
+        test_almost(-5.0, 8.0, -3.5, -0.020089406747874139476, 1e-15, im(-3.0));
+        test_almost(-5.0, 8.0, -3.5, -1.6666666666666667854e-10, 1e-24, im(1e-9));
+        test_almost(-5.0, 8.0, -3.5, 0.28098705147439940843, 1e-15, re(0.5));
+        test_almost(-5.0, 8.0, -3.5, -0.17031648282555318171, 1e-15, im(0.5));
+        test_almost(0.0, 1.0, 0.0, 0.4422205548000989905, 1e-15, re(-3.0));
+        test_almost(0.0, 1.0, 0.0, -0.63530666487558506176, 1e-15, im(-3.0));
+        test_almost(-5.0, 8.0, 8.0, -0.022497260901001863473, 1e-15, re(2.0));
+        test_almost(-5.0, 8.0, 8.0, 0.071204798996608182193, 1e-15, im(2.0));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Triangular| x.mode().unwrap();
@@ -472,6 +712,29 @@ mod tests {
         test_case(0.0, 3.0, 1.5, 1.0, cdf(5.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Triangular| x.sf(arg);
+        test_case(0.0, 1.0, 0.5, 1.0, sf(0.0));
+        test_case(0.0, 1.0, 0.5, 0.875, sf(0.25));
+        test_case(0.0, 1.0, 0.5, 0.5, sf(0.5));
+        test_case(0.0, 1.0, 0.5, 0.125, sf(0.75));
+        test_case(0.0, 1.0, 0.5, 0.0, sf(1.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Triangular| x.inverse_cdf(arg);
+        test_case(0.0, 4.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_almost(0.0, 4.0, 1.0, 0.6324555320336758663998, 1e-15, inverse_cdf(0.1));
+        test_case(0.0, 4.0, 1.0, 1.0, inverse_cdf(0.25));
+        test_almost(0.0, 4.0, 1.0, 1.550510257216821901803, 1e-15, inverse_cdf(0.5));
+        test_case(0.0, 4.0, 1.0, 4.0, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 4.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(-1.0, 1.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(-1.0, 1.0, -1.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(-5.0, 5.0, 0.0), -5.0, 5.0);
@@ -0,0 +1,578 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements a [Truncated](https://en.wikipedia.org/wiki/Truncated_distribution)
+/// distribution, i.e. a continuous parent distribution conditioned on
+/// lying in the interval `[a, b]`
+///
+/// # Remarks
+///
+/// Probabilities are tracked through the logarithm of the parent's cdf or
+/// survival function, whichever is smaller over `[a, b]`, so truncations
+/// far into either tail keep their precision even when the retained mass
+/// underflows
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, ContinuousCDF, Normal, Truncated};
+///
+/// let n = Truncated::new(Normal::new(0.0, 1.0).unwrap(), 0.0, f64::INFINITY).unwrap();
+/// assert_eq!(n.cdf(0.0), 0.0);
+/// assert!((n.pdf(0.0) - 0.7978845608028654).abs() < 1e-15);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Truncated<D> {
+    dist: D,
+    min: f64,
+    max: f64,
+    // whether probabilities are tracked through the parent's survival
+    // function rather than its cdf
+    upper_tail: bool,
+    // the smallest and largest values of the log tracked probability over
+    // `[min, max]`
+    ln_tail_min: f64,
+    ln_tail_max: f64,
+    ln_mass: f64,
+}
+
+impl<D> Truncated<D>
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64>,
+{
+    /// Constructs a new distribution by truncating `dist` to the interval
+    /// `[lower, upper]`. Bounds outside the support of `dist` are clamped
+    /// to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower` or `upper` are `NaN`, if the clamped
+    /// bounds satisfy `lower >= upper`, or if `dist` has no mass in the
+    /// interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Gamma, Truncated};
+    ///
+    /// let mut result = Truncated::new(Gamma::new(2.0, 1.0).unwrap(), 1.0, 3.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Truncated::new(Gamma::new(2.0, 1.0).unwrap(), -3.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, lower: f64, upper: f64) -> Result<Truncated<D>> {
+        if lower.is_nan() || upper.is_nan() {
+            return Err(StatsError::BadParams);
+        }
+        let min = lower.max(dist.min());
+        let max = upper.min(dist.max());
+        if min >= max {
+            return Err(StatsError::BadParams);
+        }
+        let upper_tail = dist.ln_sf(min) < dist.ln_cdf(max);
+        let (ln_tail_min, ln_tail_max) = if upper_tail {
+            (dist.ln_sf(max), dist.ln_sf(min))
+        } else {
+            (dist.ln_cdf(min), dist.ln_cdf(max))
+        };
+        let ln_mass = ln_tail_max + (-(ln_tail_min - ln_tail_max).exp()).ln_1p();
+        if ln_mass.is_nan() || ln_mass == f64::NEG_INFINITY {
+            return Err(StatsError::BadParams);
+        }
+        Ok(Truncated {
+            dist,
+            min,
+            max,
+            upper_tail,
+            ln_tail_min,
+            ln_tail_max,
+            ln_mass,
+        })
+    }
+
+    /// Returns the parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Truncated};
+    ///
+    /// let d = Normal::new(0.0, 1.0).unwrap();
+    /// let n = Truncated::new(d, -1.0, 1.0).unwrap();
+    /// assert_eq!(*n.parent(), d);
+    /// ```
+    pub fn parent(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the natural logarithm of the probability mass that the
+    /// parent distribution assigns to `[min, max]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Truncated};
+    ///
+    /// let n = Truncated::new(Normal::new(0.0, 1.0).unwrap(), 0.0, f64::INFINITY).unwrap();
+    /// assert_eq!(n.ln_mass(), 0.5f64.ln());
+    /// ```
+    pub fn ln_mass(&self) -> f64 {
+        self.ln_mass
+    }
+
+    /// Returns the log of the tracked parent probability at `x`
+    fn ln_tail(&self, x: f64) -> f64 {
+        if self.upper_tail {
+            self.dist.ln_sf(x)
+        } else {
+            self.dist.ln_cdf(x)
+        }
+    }
+
+    /// Returns the fraction of the mass lying between `x` and the bound
+    /// where the tracked probability is smallest
+    fn inner_fraction(&self, x: f64) -> f64 {
+        let ln_tail = self.ln_tail(x);
+        let width = -(self.ln_tail_min - self.ln_tail_max).exp_m1();
+        if self.ln_tail_min == f64::NEG_INFINITY {
+            (ln_tail - self.ln_tail_max).exp() / width
+        } else {
+            (self.ln_tail_min - self.ln_tail_max).exp() * (ln_tail - self.ln_tail_min).exp_m1()
+                / width
+        }
+    }
+
+    /// Returns the fraction of the mass lying between `x` and the bound
+    /// where the tracked probability is largest
+    fn outer_fraction(&self, x: f64) -> f64 {
+        let ln_tail = self.ln_tail(x);
+        let width = -(self.ln_tail_min - self.ln_tail_max).exp_m1();
+        -(ln_tail - self.ln_tail_max).exp_m1() / width
+    }
+
+    /// Returns the parent probability whose quantile is the quantile of the
+    /// truncated distribution at `p`, together with whether it is a
+    /// survival probability rather than a cumulative one
+    pub(crate) fn parent_target(&self, p: f64) -> (f64, bool) {
+        let fraction = if self.upper_tail { 1.0 - p } else { p };
+        let width = -(self.ln_tail_min - self.ln_tail_max).exp_m1();
+        let tail = self.ln_tail_max.exp()
+            * ((self.ln_tail_min - self.ln_tail_max).exp() + fraction * width);
+        (tail, self.upper_tail)
+    }
+
+    /// Polishes an approximate quantile `x` at `p` with Newton steps on the
+    /// truncated cdf, or on the survival function above the median, so that
+    /// it is consistent with `cdf` even where the parent's quantile function
+    /// and cdf disagree slightly
+    pub(crate) fn refine_inverse(&self, x: f64, p: f64) -> f64 {
+        let mut x = x.max(self.min).min(self.max);
+        for _ in 0..2 {
+            let density = self.pdf(x);
+            if !(density > 0.0 && density.is_finite()) {
+                break;
+            }
+            let step = if p <= 0.5 {
+                (self.cdf(x) - p) / density
+            } else {
+                (1.0 - p - self.sf(x)) / density
+            };
+            x = (x - step).max(self.min).min(self.max);
+        }
+        x
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<f64> for Truncated<D>
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64> + ::rand::distributions::Distribution<f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        // rejection from the parent accepts at least a quarter of its draws,
+        // below that inversion is cheaper
+        if self.ln_mass >= -2.0 * f64::consts::LN_2 {
+            loop {
+                let x = self.dist.sample(r);
+                if x >= self.min && x <= self.max {
+                    return x;
+                }
+            }
+        }
+        self.inverse_cdf(r.gen::<f64>())
+    }
+}
+
+impl<D> ContinuousCDF<f64, f64> for Truncated<D>
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64>,
+{
+    /// Calculates the cumulative distribution function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (F(x) - F(a)) / (F(b) - F(a))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution and `[a, b]` is the
+    /// truncation interval
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            0.0
+        } else if x >= self.max {
+            1.0
+        } else if self.upper_tail {
+            self.outer_fraction(x)
+        } else {
+            self.inner_fraction(x)
+        }
+    }
+
+    /// Calculates the survival function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (F(b) - F(x)) / (F(b) - F(a))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution and `[a, b]` is the
+    /// truncation interval
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            1.0
+        } else if x >= self.max {
+            0.0
+        } else if self.upper_tail {
+            self.inner_fraction(x)
+        } else {
+            self.outer_fraction(x)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// truncated distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Uses the quantile function of the parent distribution, except where
+    /// the parent probability to invert is too close to one, or too close
+    /// to zero, to be represented accurately. There the truncated cdf is inverted
+    /// numerically instead. The result is polished with Newton steps so
+    /// that it round trips through `cdf`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(F(a) + p * (F(b) - F(a)))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution and `[a, b]` is the
+    /// truncation interval
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 {
+            return self.min;
+        }
+        if p == 1.0 {
+            return self.max;
+        }
+        let x = match self.parent_target(p) {
+            (q, false) if q >= f64::MIN_POSITIVE => self.dist.inverse_cdf(q),
+            (q, true) if q >= 0.5 => self.dist.inverse_cdf(1.0 - q),
+            _ => self
+                .inverse_cdf_with_tolerance(p, 4.0 * f64::EPSILON)
+                .unwrap(),
+        };
+        self.refine_inverse(x, p)
+    }
+}
+
+impl<D> Min<f64> for Truncated<D> {
+    /// Returns the lower bound of the truncation interval, clamped to the
+    /// support of the parent distribution
+    fn min(&self) -> f64 {
+        self.min
+    }
+}
+
+impl<D> Max<f64> for Truncated<D> {
+    /// Returns the upper bound of the truncation interval, clamped to the
+    /// support of the parent distribution
+    fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl<D> Median<f64> for Truncated<D>
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64>,
+{
+    /// Returns the median of the truncated distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1((F(a) + F(b)) / 2)
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution and `[a, b]` is the
+    /// truncation interval
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl<D> Continuous<f64, f64> for Truncated<D>
+where
+    D: ContinuousCDF<f64, f64> + Continuous<f64, f64>,
+{
+    /// Calculates the probability density function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// f(x) / (F(b) - F(a))
+    /// ```
+    ///
+    /// for `x` in `[a, b]` and zero otherwise, where `f` and `F` are the
+    /// pdf and cdf of the parent distribution
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x)) - ln(F(b) - F(a))
+    /// ```
+    ///
+    /// for `x` in `[a, b]` and `-INF` otherwise, where `f` and `F` are the
+    /// pdf and cdf of the parent distribution
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            f64::NEG_INFINITY
+        } else {
+            self.dist.ln_pdf(x) - self.ln_mass
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Gamma, LogNormal, Normal, Truncated};
+    use crate::distribution::internal::*;
+
+    fn try_create<D>(dist: D, lower: f64, upper: f64) -> Truncated<D>
+        where D: ContinuousCDF<f64, f64> + Continuous<f64, f64>
+    {
+        let n = Truncated::new(dist, lower, upper);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case<D>(dist: D, lower: f64, upper: f64)
+        where D: ContinuousCDF<f64, f64> + Continuous<f64, f64>
+    {
+        let n = Truncated::new(dist, lower, upper);
+        assert!(n.is_err());
+    }
+
+    fn normal() -> Normal {
+        Normal::new(0.0, 1.0).unwrap()
+    }
+
+    fn gamma() -> Truncated<Gamma> {
+        try_create(Gamma::new(2.0, 1.0).unwrap(), 1.0, 3.0)
+    }
+
+    fn log_normal() -> Truncated<LogNormal> {
+        try_create(LogNormal::new(0.0, 1.0).unwrap(), 0.5, 2.0)
+    }
+
+    #[test]
+    fn test_create() {
+        let n = try_create(normal(), -1.0, 2.0);
+        assert_eq!(*n.parent(), normal());
+        assert_eq!(n.min(), -1.0);
+        assert_eq!(n.max(), 2.0);
+        let n = try_create(Gamma::new(2.0, 1.0).unwrap(), -3.0, f64::INFINITY);
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), f64::INFINITY);
+        assert_eq!(n.ln_mass(), 0.0);
+        try_create(normal(), 40.0, f64::INFINITY);
+        try_create(normal(), f64::NEG_INFINITY, -40.0);
+        try_create(normal(), 1e3, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(normal(), f64::NAN, 1.0);
+        bad_create_case(normal(), -1.0, f64::NAN);
+        bad_create_case(normal(), 1.0, 1.0);
+        bad_create_case(normal(), 2.0, -1.0);
+        bad_create_case(normal(), f64::INFINITY, f64::INFINITY);
+        bad_create_case(Gamma::new(2.0, 1.0).unwrap(), -3.0, -1.0);
+        bad_create_case(Gamma::new(2.0, 1.0).unwrap(), -3.0, 0.0);
+    }
+
+    #[test]
+    fn test_ln_mass() {
+        assert_almost_eq!(gamma().ln_mass(), -0.6224825706466723666192, 1e-14);
+        assert_almost_eq!(log_normal().ln_mass(), -0.6698549462293049766548, 1e-10);
+        assert_almost_eq!(try_create(normal(), 40.0, f64::INFINITY).ln_mass(), -804.6084420137537, 1e-10);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = gamma();
+        assert_eq!(n.pdf(0.5), 0.0);
+        assert_almost_eq!(n.pdf(1.0), 0.6855612525908627826852, 1e-14);
+        assert_almost_eq!(n.pdf(1.5), 0.6237208782110329852269, 1e-14);
+        assert_almost_eq!(n.pdf(2.0), 0.5044077809838412298841, 1e-14);
+        assert_almost_eq!(n.pdf(3.0), 0.2783418788862941740279, 1e-14);
+        assert_eq!(n.pdf(4.0), 0.0);
+        let n = log_normal();
+        assert_eq!(n.pdf(0.25), 0.0);
+        assert_almost_eq!(n.pdf(0.5), 1.226098389358422990251, 1e-10);
+        assert_almost_eq!(n.pdf(1.0), 0.779514813375969846503, 1e-10);
+        assert_almost_eq!(n.pdf(1.5), 0.4786672152247069908176, 1e-10);
+        assert_almost_eq!(n.pdf(2.0), 0.3065245973396057475628, 1e-10);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let n = gamma();
+        assert_eq!(n.ln_pdf(0.5), f64::NEG_INFINITY);
+        assert_almost_eq!(n.ln_pdf(1.0), -0.3775174293533276333808, 1e-14);
+        assert_almost_eq!(n.ln_pdf(1.5), -0.4720523212451632514028, 1e-14);
+        assert_almost_eq!(n.ln_pdf(3.0), -1.278905140685217941986, 1e-14);
+        assert_eq!(n.ln_pdf(4.0), f64::NEG_INFINITY);
+        let n = log_normal();
+        assert_almost_eq!(n.ln_pdf(0.5), 0.2038370866254768319582, 1e-10);
+        assert_almost_eq!(n.ln_pdf(1.0), -0.2490835869753677651255, 1e-10);
+        assert_almost_eq!(n.ln_pdf(2.0), -1.182457274494413786876, 1e-10);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = gamma();
+        assert_eq!(n.cdf(0.5), 0.0);
+        assert_eq!(n.cdf(1.0), 0.0);
+        assert_almost_eq!(n.cdf(1.5), 0.331587708163337256659, 1e-14);
+        assert_almost_eq!(n.cdf(2.0), 0.6145108337059637205443, 1e-14);
+        assert_eq!(n.cdf(3.0), 1.0);
+        assert_eq!(n.cdf(4.0), 1.0);
+        let n = log_normal();
+        assert_almost_eq!(n.cdf(1.0), 0.5, 1e-10);
+        assert_almost_eq!(n.cdf(1.5), 0.8076151970959487391963, 1e-10);
+    }
+
+    #[test]
+    fn test_sf() {
+        let n = gamma();
+        assert_eq!(n.sf(0.5), 1.0);
+        assert_almost_eq!(n.sf(1.5), 0.668412291836662743341, 1e-14);
+        assert_almost_eq!(n.sf(2.0), 0.3854891662940362794557, 1e-14);
+        assert_eq!(n.sf(3.0), 0.0);
+        let n = log_normal();
+        assert_almost_eq!(n.sf(1.0), 0.5, 1e-10);
+        assert_almost_eq!(n.sf(1.5), 0.1923848029040512608037, 1e-10);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = gamma();
+        assert_almost_eq!(n.inverse_cdf(1e-10), 1.000000000145865886705, 1e-14);
+        assert_almost_eq!(n.inverse_cdf(0.1), 1.146351728619316240426, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.784536759049052854668, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.9), 2.677297913288992700595, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.999), 2.996411590728123286582, 1e-13);
+        let n = log_normal();
+        assert_almost_eq!(n.inverse_cdf(1e-10), 0.5000000000815595231756, 1e-10);
+        assert_almost_eq!(n.inverse_cdf(0.1), 0.5839121280011566875477, 1e-10);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.0, 1e-10);
+        assert_almost_eq!(n.inverse_cdf(0.9), 1.712586452730810745768, 1e-10);
+        assert_almost_eq!(n.inverse_cdf(0.999), 1.99674211688958254025, 1e-10);
+    }
+
+    #[test]
+    fn test_median() {
+        assert_almost_eq!(gamma().median(), 1.784536759049052854668, 1e-13);
+        assert_almost_eq!(log_normal().median(), 1.0, 1e-10);
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(gamma().min(), 1.0);
+        assert_eq!(gamma().max(), 3.0);
+        assert_eq!(log_normal().min(), 0.5);
+        assert_eq!(log_normal().max(), 2.0);
+    }
+
+    #[test]
+    fn test_far_tail() {
+        let n = try_create(normal(), 40.0, f64::INFINITY);
+        assert_almost_eq!(n.pdf(40.0), 40.02496884720726372324, 1e-11);
+        assert_almost_eq!(n.cdf(40.01), 0.3298807901962844752064, 1e-11);
+        assert_almost_eq!(n.sf(41.0), 2.513984854965318702462e-18, 1e-29);
+        assert_almost_eq!(n.inverse_cdf(0.5), 40.01731412676465110614, 1e-12);
+        let n = try_create(normal(), f64::NEG_INFINITY, -40.0);
+        assert_almost_eq!(n.pdf(-40.0), 40.02496884720726372324, 1e-9);
+        assert_almost_eq!(n.sf(-40.01), 0.3298807901962844752064, 1e-10);
+        assert_almost_eq!(n.inverse_cdf(0.5), -40.01731412676465110614, 1e-10);
+        let n = try_create(normal(), -40.0, -38.0);
+        for &x in [-39.9, -39.0, -38.48, -38.3, -38.01].iter() {
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut r = StdRng::seed_from_u64(1);
+        let n = gamma();
+        assert!((0..1000).map(|_| n.sample(&mut r)).all(|x| x >= 1.0 && x <= 3.0));
+        let n = try_create(normal(), 5.0, 5.5);
+        assert!((0..1000).map(|_| n.sample(&mut r)).all(|x| x >= 5.0 && x <= 5.5));
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(normal(), -1.0, 2.0), -1.0, 2.0);
+        tests::check_continuous_distribution(&gamma(), 1.0, 3.0);
+        tests::check_continuous_distribution(&log_normal(), 0.5, 2.0);
+        tests::check_continuous_distribution(&try_create(Gamma::new(2.0, 1.0).unwrap(), 5.0, f64::INFINITY), 5.0, 50.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        tests::check_inverse_cdf(&try_create(normal(), -1.0, 2.0), 1e-12);
+        tests::check_inverse_cdf(&gamma(), 1e-12);
+        tests::check_inverse_cdf(&log_normal(), 1e-12);
+        tests::check_inverse_cdf(&try_create(normal(), 40.0, f64::INFINITY), 1e-12);
+        tests::check_inverse_cdf(&try_create(normal(), f64::NEG_INFINITY, -40.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(normal(), -40.0, -38.0), 1e-12);
+    }
+}
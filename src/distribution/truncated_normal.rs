@@ -0,0 +1,854 @@
+use crate::consts;
+use crate::distribution::{ziggurat, Continuous, ContinuousCDF, Normal, Truncated};
+use crate::statistics::*;
+use crate::Result;
+use rand::Rng;
+use std::f64;
+
+/// Standardized distance into a tail beyond which the moments are computed
+/// from the continued fraction of the Mills ratio rather than from `φ / Z`,
+/// which inherits the rounding error of `Z` and amplifies it in a tail
+const TAIL_THRESHOLD: f64 = 1.0;
+
+/// Number of continued fraction terms, enough for full double precision
+/// from `TAIL_THRESHOLD` onwards
+const TAIL_TERMS: usize = 400;
+
+/// Bound on `(β - α) * max(|α|, |β|, 1)` below which the interval is narrow
+/// enough for the moments to be computed from a power series of the
+/// density, avoiding the cancellation in `Φ(β) - Φ(α)`
+const NARROW_THRESHOLD: f64 = 4.0;
+
+/// Number of power series terms, enough for full double precision up to
+/// `NARROW_THRESHOLD`
+const NARROW_TERMS: usize = 40;
+
+/// Implements the [Truncated
+/// normal](https://en.wikipedia.org/wiki/Truncated_normal_distribution)
+/// distribution, a normal distribution conditioned on lying in `[a, b]`
+///
+/// # Remarks
+///
+/// Unlike `Truncated<Normal>` it provides closed form moments and samples
+/// directly from the truncated density, using the exponential and uniform
+/// rejection samplers of Robert (1995) so that truncations far into a tail
+/// stay cheap
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{TruncatedNormal, Continuous};
+/// use statrs::statistics::Distribution;
+///
+/// let n = TruncatedNormal::new(0.0, 1.0, 0.0, f64::INFINITY).unwrap();
+/// assert!((n.mean().unwrap() - 0.7978845608028654).abs() < 1e-15);
+/// assert!((n.pdf(0.0) - 0.7978845608028654).abs() < 1e-15);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TruncatedNormal {
+    location: f64,
+    scale: f64,
+    truncated: Truncated<Normal>,
+}
+
+impl TruncatedNormal {
+    /// Constructs a new truncated normal distribution from a normal
+    /// distribution with mean `location` and standard deviation `scale`
+    /// restricted to `[lower, upper]`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN`, if `scale <= 0.0`, if
+    /// `lower >= upper` or if the interval carries no probability mass in
+    /// double precision
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let mut result = TruncatedNormal::new(0.0, 1.0, -1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = TruncatedNormal::new(0.0, 1.0, 2.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, lower: f64, upper: f64) -> Result<TruncatedNormal> {
+        let truncated = Truncated::new(Normal::new(location, scale)?, lower, upper)?;
+        Ok(TruncatedNormal {
+            location,
+            scale,
+            truncated,
+        })
+    }
+
+    /// Returns the mean of the underlying untruncated normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let n = TruncatedNormal::new(1.0, 2.0, 0.0, 3.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the standard deviation of the underlying untruncated normal
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::TruncatedNormal;
+    ///
+    /// let n = TruncatedNormal::new(1.0, 2.0, 0.0, 3.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the standardized truncation bounds `α` and `β`
+    fn std_bounds(&self) -> (f64, f64) {
+        (
+            (self.truncated.min() - self.location) / self.scale,
+            (self.truncated.max() - self.location) / self.scale,
+        )
+    }
+
+    /// Returns `(z - c)^k * φ(z) / Z` where `φ` is the standard normal pdf
+    /// and `Z` the retained mass, which vanishes at infinite bounds
+    fn bound_term(&self, z: f64, c: f64, k: i32) -> f64 {
+        if z.is_infinite() {
+            0.0
+        } else {
+            (z - c).powi(k) * (-0.5 * z * z - consts::LN_SQRT_2PI - self.truncated.ln_mass()).exp()
+        }
+    }
+
+    /// Returns whether the standardized bounds are close enough for
+    /// `std_narrow` to apply
+    fn is_narrow(a: f64, b: f64) -> bool {
+        (b - a) * a.abs().max(b.abs()).max(1.0) <= NARROW_THRESHOLD
+    }
+
+    /// Returns the mean and the first four central moments of the
+    /// standardized truncated normal distribution
+    fn std_moments(&self) -> (f64, [f64; 5]) {
+        let (a, b) = self.std_bounds();
+        if TruncatedNormal::is_narrow(a, b) {
+            let (e, _) = std_narrow(a, b);
+            (a + e[1], central_from_shifted(&e))
+        } else if a >= TAIL_THRESHOLD {
+            let (e, _) = std_tail(a, b);
+            (a + e[1], central_from_shifted(&e))
+        } else if b <= -TAIL_THRESHOLD {
+            let (e, _) = std_tail(-b, -a);
+            let c = central_from_shifted(&e);
+            (b - e[1], [c[0], c[1], c[2], -c[3], c[4]])
+        } else {
+            // integrating by parts gives
+            // c_k = (k - 1) * c_(k - 2) + ((α - m)^(k - 1) * φ(α) - (β - m)^(k - 1) * φ(β)) / Z - m * c_(k - 1)
+            let m = self.bound_term(a, 0.0, 0) - self.bound_term(b, 0.0, 0);
+            let mut c = [1.0, 0.0, 0.0, 0.0, 0.0];
+            for k in 2..5 {
+                c[k] = (k - 1) as f64 * c[k - 2] + self.bound_term(a, m, k as i32 - 1)
+                    - self.bound_term(b, m, k as i32 - 1)
+                    - m * c[k - 1];
+            }
+            (m, c)
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for TruncatedNormal {
+    fn sample<R: Rng + ?Sized>(&self, r: &mut R) -> f64 {
+        let (a, b) = self.std_bounds();
+        self.location + self.scale * sample_std_unchecked(r, a, b)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for TruncatedNormal {
+    /// Calculates the cumulative distribution function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (Φ((x - μ) / σ) - Φ(α)) / (Φ(β) - Φ(α))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds and `Φ` is the standard normal cdf
+    fn cdf(&self, x: f64) -> f64 {
+        self.truncated.cdf(x)
+    }
+
+    /// Calculates the survival function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (Φ(β) - Φ((x - μ) / σ)) / (Φ(β) - Φ(α))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds and `Φ` is the standard normal cdf
+    fn sf(&self, x: f64) -> f64 {
+        self.truncated.sf(x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// truncated normal distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// Upper tail truncations are inverted through the survival function
+    /// using the symmetry of the normal distribution, so no precision is
+    /// lost when `Φ(α)` is close to one
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * Φ^-1(Φ(α) + p * (Φ(β) - Φ(α)))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds and `Φ` is the standard normal cdf
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 {
+            return self.min();
+        }
+        if p == 1.0 {
+            return self.max();
+        }
+        let x = match self.truncated.parent_target(p) {
+            (q, false) if q >= f64::MIN_POSITIVE => {
+                super::normal::inverse_cdf_unchecked(q, self.location, self.scale)
+            }
+            (q, true) if q >= f64::MIN_POSITIVE => {
+                2.0 * self.location
+                    - super::normal::inverse_cdf_unchecked(q, self.location, self.scale)
+            }
+            _ => self
+                .inverse_cdf_with_tolerance(p, 4.0 * f64::EPSILON)
+                .unwrap(),
+        };
+        self.truncated.refine_inverse(x, p)
+    }
+}
+
+impl Min<f64> for TruncatedNormal {
+    /// Returns the lower truncation bound of the truncated normal
+    /// distribution
+    fn min(&self) -> f64 {
+        self.truncated.min()
+    }
+}
+
+impl Max<f64> for TruncatedNormal {
+    /// Returns the upper truncation bound of the truncated normal
+    /// distribution
+    fn max(&self) -> f64 {
+        self.truncated.max()
+    }
+}
+
+impl Distribution<f64> for TruncatedNormal {
+    /// Returns the mean of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * (φ(α) - φ(β)) / Z
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds, `φ` is the standard normal pdf and
+    /// `Z = Φ(β) - Φ(α)`
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * self.std_moments().0)
+    }
+    /// Returns the variance of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 * (1 + (αφ(α) - βφ(β)) / Z - ((φ(α) - φ(β)) / Z)^2)
+    /// ```
+    ///
+    /// where `σ` is the scale, `α` and `β` are the standardized bounds,
+    /// `φ` is the standard normal pdf and `Z = Φ(β) - Φ(α)`
+    fn variance(&self) -> Option<f64> {
+        let (_, c) = self.std_moments();
+        Some(self.scale * self.scale * c[2])
+    }
+    /// Returns the entropy of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sqrt(2πe) * σ * Z) + (αφ(α) - βφ(β)) / (2Z)
+    /// ```
+    ///
+    /// where `σ` is the scale, `α` and `β` are the standardized bounds,
+    /// `φ` is the standard normal pdf and `Z = Φ(β) - Φ(α)`
+    fn entropy(&self) -> Option<f64> {
+        let (a, b) = self.std_bounds();
+        let std_entropy = if TruncatedNormal::is_narrow(a, b) {
+            let (e, ln_integral) = std_narrow(a, b);
+            ln_integral + 0.5 * (e[2] + 2.0 * a * e[1])
+        } else if a >= TAIL_THRESHOLD {
+            std_tail(a, b).1
+        } else if b <= -TAIL_THRESHOLD {
+            std_tail(-b, -a).1
+        } else {
+            consts::LN_SQRT_2PIE
+                + self.truncated.ln_mass()
+                + 0.5 * (self.bound_term(a, 0.0, 1) - self.bound_term(b, 0.0, 1))
+        };
+        Some(std_entropy + self.scale.ln())
+    }
+    /// Returns the skewness of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// c_3 / c_2^(3 / 2)
+    /// ```
+    ///
+    /// where `c_k` is the `k`th central moment of the standardized
+    /// truncated distribution, `c_0 = 1`, `c_1 = 0`,
+    /// `c_k = (k - 1) * c_(k - 2) + ((α - m)^(k - 1) * φ(α) - (β - m)^(k - 1) * φ(β)) / Z - m * c_(k - 1)`
+    /// and `m = (φ(α) - φ(β)) / Z` is its mean
+    fn skewness(&self) -> Option<f64> {
+        let (_, c) = self.std_moments();
+        Some(c[3] / (c[2] * c[2].sqrt()))
+    }
+    /// Returns the excess kurtosis of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// c_4 / c_2^2 - 3
+    /// ```
+    ///
+    /// where `c_k` is the `k`th central moment of the standardized
+    /// truncated distribution, `c_0 = 1`, `c_1 = 0`,
+    /// `c_k = (k - 1) * c_(k - 2) + ((α - m)^(k - 1) * φ(α) - (β - m)^(k - 1) * φ(β)) / Z - m * c_(k - 1)`
+    /// and `m = (φ(α) - φ(β)) / Z` is its mean
+    fn kurtosis(&self) -> Option<f64> {
+        let (_, c) = self.std_moments();
+        Some(c[4] / (c[2] * c[2]) - 3.0)
+    }
+}
+
+impl Median<f64> for TruncatedNormal {
+    /// Returns the median of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ * Φ^-1((Φ(α) + Φ(β)) / 2)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `α` and `β` are the
+    /// standardized bounds and `Φ` is the standard normal cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for TruncatedNormal {
+    /// Returns the mode of the truncated normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min(max(μ, a), b)
+    /// ```
+    ///
+    /// where `μ` is the location and `[a, b]` is the truncation interval
+    fn mode(&self) -> Option<f64> {
+        Some(self.location.max(self.min()).min(self.max()))
+    }
+}
+
+impl Continuous<f64, f64> for TruncatedNormal {
+    /// Calculates the probability density function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// φ((x - μ) / σ) / (σ * Z)
+    /// ```
+    ///
+    /// for `x` in `[a, b]` and zero otherwise, where `μ` is the location,
+    /// `σ` is the scale, `φ` is the standard normal pdf and
+    /// `Z = Φ(β) - Φ(α)`
+    fn pdf(&self, x: f64) -> f64 {
+        self.truncated.pdf(x)
+    }
+
+    /// Calculates the log probability density function for the truncated
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(φ((x - μ) / σ)) - ln(σ) - ln(Z)
+    /// ```
+    ///
+    /// for `x` in `[a, b]` and `-INF` otherwise, where `μ` is the location,
+    /// `σ` is the scale, `φ` is the standard normal pdf and
+    /// `Z = Φ(β) - Φ(α)`
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.truncated.ln_pdf(x)
+    }
+}
+
+/// Returns `E[(Y - a)^k]` for `k = 0, ..., 4` where `Y` is a standard normal
+/// variable truncated to `[a, ∞)` with `a >= TAIL_THRESHOLD`. The ratios
+/// `ρ_k = E[(Y - a)^k] / E[(Y - a)^(k - 1)]` satisfy `ρ_k = k / (a + ρ_(k + 1))`,
+/// the continued fraction of Laplace for the Mills ratio, which is evaluated
+/// backwards
+fn upper_tail_moments(a: f64) -> [f64; 5] {
+    let mut rho = [0.0; 5];
+    let mut r = 0.0;
+    for k in (1..=TAIL_TERMS).rev() {
+        r = k as f64 / (a + r);
+        if k < 5 {
+            rho[k] = r;
+        }
+    }
+    let mut e = [1.0; 5];
+    for k in 1..5 {
+        e[k] = e[k - 1] * rho[k];
+    }
+    e
+}
+
+/// Returns `E[(Y - a)^k]` for `k = 0, ..., 4` together with the entropy of
+/// a standard normal variable `Y` truncated to `[a, b]` with
+/// `a >= TAIL_THRESHOLD`. The interval is split into the tails above `a`
+/// and `b`, whose mass ratio `q = S(b) / S(a)` follows from the Mills ratio
+fn std_tail(a: f64, b: f64) -> ([f64; 5], f64) {
+    let ea = upper_tail_moments(a);
+    if b.is_infinite() {
+        return (ea, 0.5 - (a + ea[1]).ln() + 0.5 * a * ea[1]);
+    }
+    let eb = upper_tail_moments(b);
+    let d = b - a;
+    let q = (-0.5 * d * (a + b)).exp() * (a + ea[1]) / (b + eb[1]);
+    let mut e = [1.0; 5];
+    for k in 1..5 {
+        // E[(Y - a)^k] over [b, ∞) by expanding (Y - b + d)^k
+        let mut shifted = 0.0;
+        let mut binomial = 1.0;
+        for (j, ebj) in eb.iter().enumerate().take(k + 1) {
+            shifted += binomial * d.powi((k - j) as i32) * ebj;
+            binomial *= (k - j) as f64 / (j + 1) as f64;
+        }
+        e[k] = (ea[k] - q * shifted) / (1.0 - q);
+    }
+    let entropy = 0.5 - (a + ea[1]).ln()
+        + (-q).ln_1p()
+        + (a * ea[1] + q * (a * a - b * (b + eb[1]))) / (2.0 * (1.0 - q));
+    (e, entropy)
+}
+
+/// Returns `E[(Y - a)^k]` for `k = 0, ..., 4` together with `ln(I_0)` for a
+/// standard normal variable `Y` truncated to a narrow interval `[a, b]`,
+/// where `I_k = ∫ w^k * exp(-a * w - w^2 / 2) dw` over `[0, b - a]`. The
+/// integrand is expanded as `Σ h_n * w^n` with `h_0 = 1`, `h_1 = -a` and
+/// `(n + 1) * h_(n + 1) = -a * h_n - h_(n - 1)`, so that `E[(Y - a)^k] = I_k / I_0`
+fn std_narrow(a: f64, b: f64) -> ([f64; 5], f64) {
+    let d = b - a;
+    let mut integrals = [0.0; 5];
+    let (mut h_prev, mut h) = (0.0, 1.0);
+    let mut power = d;
+    for n in 0..NARROW_TERMS {
+        for (k, integral) in integrals.iter_mut().enumerate() {
+            *integral += h * power / (n + k + 1) as f64;
+        }
+        let next = (-a * h - h_prev) / (n + 1) as f64;
+        h_prev = h;
+        h = next;
+        power *= d;
+    }
+    let mut e = [1.0; 5];
+    let mut scale = 1.0;
+    for k in 1..5 {
+        scale *= d;
+        e[k] = scale * integrals[k] / integrals[0];
+    }
+    (e, integrals[0].ln())
+}
+
+/// Converts the first four moments about a point into central moments
+fn central_from_shifted(e: &[f64; 5]) -> [f64; 5] {
+    let m = e[1];
+    [
+        1.0,
+        0.0,
+        e[2] - m * m,
+        e[3] - 3.0 * m * e[2] + 2.0 * m.powi(3),
+        e[4] - 4.0 * m * e[3] + 6.0 * m * m * e[2] - 3.0 * m.powi(4),
+    ]
+}
+
+/// Draws a sample from the standard normal distribution truncated to
+/// `[a, b]` following Robert (1995), "Simulation of truncated normal
+/// variables". Intervals containing zero use plain or uniform rejection,
+/// intervals in a tail use uniform rejection when narrow and translated
+/// exponential rejection otherwise
+fn sample_std_unchecked<R: Rng + ?Sized>(r: &mut R, a: f64, b: f64) -> f64 {
+    if b < 0.0 {
+        return -sample_std_unchecked(r, -b, -a);
+    }
+    if a <= 0.0 {
+        if b - a < consts::SQRT_2PI {
+            return sample_uniform_rejection(r, a, b, 0.0);
+        }
+        loop {
+            let z = ziggurat::sample_std_normal(r);
+            if z >= a && z <= b {
+                return z;
+            }
+        }
+    }
+    // optimal rate of the exponential proposal
+    let root = (a * a + 4.0).sqrt();
+    let rate = 0.5 * (a + root);
+    let uniform_width = 2.0 / (a + root) * (0.25 * (a * a - a * root) + 0.5).exp();
+    if b - a < uniform_width {
+        return sample_uniform_rejection(r, a, b, a);
+    }
+    loop {
+        let z = a + ziggurat::sample_exp_1(r) / rate;
+        let d = z - rate;
+        if z <= b && r.gen::<f64>() <= (-0.5 * d * d).exp() {
+            return z;
+        }
+    }
+}
+
+/// Draws `z` uniformly on `[a, b]` and accepts it with probability
+/// `exp((m^2 - z^2) / 2)`, where `m` is the point of `[a, b]` closest to zero
+fn sample_uniform_rejection<R: Rng + ?Sized>(r: &mut R, a: f64, b: f64, m: f64) -> f64 {
+    loop {
+        let z = a + (b - a) * r.gen::<f64>();
+        if r.gen::<f64>() <= (0.5 * (m * m - z * z)).exp() {
+            return z;
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, TruncatedNormal};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, lower: f64, upper: f64) -> TruncatedNormal {
+        let n = TruncatedNormal::new(location, scale, lower, upper);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64, lower: f64, upper: f64) {
+        let n = try_create(location, scale, lower, upper);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+        assert_eq!(lower, n.min());
+        assert_eq!(upper, n.max());
+    }
+
+    fn bad_create_case(location: f64, scale: f64, lower: f64, upper: f64) {
+        let n = TruncatedNormal::new(location, scale, lower, upper);
+        assert!(n.is_err());
+    }
+
+    fn test_case<F>(location: f64, scale: f64, lower: f64, upper: f64, expected: f64, eval: F)
+        where F: Fn(TruncatedNormal) -> f64
+    {
+        let n = try_create(location, scale, lower, upper);
+        let x = eval(n);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, lower: f64, upper: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(TruncatedNormal) -> f64
+    {
+        let n = try_create(location, scale, lower, upper);
+        let x = eval(n);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 1.0, -1.0, 2.0);
+        create_case(1.0, 2.0, 3.0, 3.5);
+        create_case(0.0, 1.0, 40.0, f64::INFINITY);
+        create_case(0.0, 1.0, f64::NEG_INFINITY, -10.0);
+        create_case(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(f64::NAN, 1.0, -1.0, 1.0);
+        bad_create_case(0.0, f64::NAN, -1.0, 1.0);
+        bad_create_case(0.0, 1.0, f64::NAN, 1.0);
+        bad_create_case(0.0, 1.0, -1.0, f64::NAN);
+        bad_create_case(0.0, 0.0, -1.0, 1.0);
+        bad_create_case(0.0, -1.0, -1.0, 1.0);
+        bad_create_case(0.0, 1.0, 1.0, 1.0);
+        bad_create_case(0.0, 1.0, 2.0, -1.0);
+        bad_create_case(0.0, 1.0, 40.0, 40.0);
+        bad_create_case(0.0, 1.0, f64::INFINITY, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: TruncatedNormal| x.mean().unwrap();
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.2296371790913289686154, 1e-11, mean);
+        test_almost(1.0, 2.0, 3.0, 3.5, 3.238320974887486062268, 1e-14, mean);
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.02496884720726372324, 1e-13, mean);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -10.09809323396251196284, 1e-13, mean);
+        test_almost(0.0, 1.0, 3.0, 3.0001, 3.000049997499958443422, 1e-14, mean);
+        test_almost(0.0, 1.0, -50.0, -49.0, -49.02039119883845607132, 1e-13, mean);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY, 0.0, mean);
+    }
+
+    #[test]
+    fn test_variance() {
+        let variance = |x: TruncatedNormal| x.variance().unwrap();
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.5197625392115339359144, 1e-11, variance);
+        test_almost(1.0, 2.0, 3.0, 3.5, 0.02070831025541976239598, 1e-15, variance);
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 0.0006226683785913887734989, 1e-17, variance);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 0.009445377825656261164137, 1e-16, variance);
+        test_almost(0.0, 1.0, 3.0, 3.0001, 8.333333293089477580203e-10, 1e-22, variance);
+        test_almost(0.0, 1.0, -50.0, -49.0, 0.0004154559255830531770391, 1e-17, variance);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY, 1.0, variance);
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: TruncatedNormal| x.entropy().unwrap();
+        test_almost(0.0, 1.0, -1.0, 2.0, 1.005020125496488677006, 1e-10, entropy);
+        test_almost(1.0, 2.0, 3.0, 3.5, -0.6964256186824472248675, 1e-14, entropy);
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, -2.690126536403840959929, 1e-14, entropy);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -1.321880447495238022348, 1e-14, entropy);
+        test_almost(0.0, 1.0, 3.0, 3.0001, -9.210340375724197414336, 1e-14, entropy);
+        test_almost(0.0, 1.0, -50.0, -49.0, -2.892651986918410970638, 1e-14, entropy);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY, 1.418938533204672741781, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let skewness = |x: TruncatedNormal| x.skewness().unwrap();
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.3225459128330915167503, 1e-10, skewness);
+        test_almost(1.0, 2.0, 3.0, 3.5, 0.09711877450696468026304, 1e-13, skewness);
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 1.996276707943284782786, 1e-13, skewness);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -1.946031218891859098138, 1e-13, skewness);
+        test_almost(0.0, 1.0, 3.0, 3.0001, 0.0001039247804173169793252, 1e-13, skewness);
+        test_almost(0.0, 1.0, -50.0, -49.0, -1.997512937610669649375, 1e-13, skewness);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY, 0.0, skewness);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: TruncatedNormal| x.kurtosis().unwrap();
+        test_almost(0.0, 1.0, -1.0, 2.0, -0.7198275420771034143366, 1e-10, kurtosis);
+        test_almost(1.0, 2.0, 3.0, 3.5, -1.18481594406542774241, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 5.970273558536826340172, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 5.580372323665045191527, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, 3.0, 3.0001, -1.199999984742360012822, 1e-13, kurtosis);
+        test_almost(0.0, 1.0, -50.0, -49.0, 5.980130251585784509259, 1e-13, kurtosis);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY, 0.0, kurtosis);
+    }
+
+    #[test]
+    fn test_median() {
+        let median = |x: TruncatedNormal| x.median();
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.1711639180178247728408, 1e-10, median);
+        test_almost(1.0, 2.0, 3.0, 3.5, 3.232547250877212133132, 1e-10, median);
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.01731412676465110614, 1e-12, median);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -10.0684118360814294045, 1e-12, median);
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, f64::INFINITY, 0.0, 1e-15, median);
+    }
+
+    #[test]
+    fn test_mode() {
+        let mode = |x: TruncatedNormal| x.mode().unwrap();
+        test_case(0.0, 1.0, -1.0, 2.0, 0.0, mode);
+        test_case(1.0, 2.0, 3.0, 3.5, 3.0, mode);
+        test_case(0.0, 1.0, 40.0, f64::INFINITY, 40.0, mode);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, -10.0, -10.0, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: TruncatedNormal| x.min();
+        let max = |x: TruncatedNormal| x.max();
+        test_case(0.0, 1.0, -1.0, 2.0, -1.0, min);
+        test_case(0.0, 1.0, -1.0, 2.0, 2.0, max);
+        test_case(0.0, 1.0, 40.0, f64::INFINITY, 40.0, min);
+        test_case(0.0, 1.0, 40.0, f64::INFINITY, f64::INFINITY, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: TruncatedNormal| x.pdf(arg);
+        test_case(0.0, 1.0, -1.0, 2.0, 0.0, pdf(-1.5));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.2955928616500336356268, 1e-10, pdf(-1.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.4873502384695306337221, 1e-10, pdf(0.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.4300850759232247146482, 1e-10, pdf(0.5));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.06595568255870466701141, 1e-10, pdf(2.0));
+        test_case(0.0, 1.0, -1.0, 2.0, 0.0, pdf(3.0));
+        test_almost(1.0, 2.0, 3.0, 3.5, 2.282506670171014690936, 1e-9, pdf(3.0));
+        test_almost(1.0, 2.0, 3.0, 3.5, 1.998629620163966989097, 1e-9, pdf(3.25));
+        test_almost(1.0, 2.0, 3.0, 3.5, 1.722926426449143175369, 1e-9, pdf(3.5));
+        test_case(0.0, 1.0, 40.0, f64::INFINITY, 0.0, pdf(39.0));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.02496884720726372324, 1e-11, pdf(40.0));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 26.8281975168254904438, 1e-11, pdf(40.01));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 1.031346230207479592159e-16, 1e-27, pdf(41.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 2.816830887495400156617e-9, 1e-18, pdf(-12.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 10.09809323396251196284, 1e-9, pdf(-10.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, -10.0, 0.0, pdf(0.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: TruncatedNormal| x.ln_pdf(arg);
+        test_case(0.0, 1.0, -1.0, 2.0, f64::NEG_INFINITY, ln_pdf(-1.5));
+        test_almost(0.0, 1.0, -1.0, 2.0, -1.218772238880210161831, 1e-10, ln_pdf(-1.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, -0.7187722388802101618311, 1e-10, ln_pdf(0.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, -2.718772238880210161831, 1e-10, ln_pdf(2.0));
+        test_almost(1.0, 2.0, 3.0, 3.5, 0.6924617557920329465922, 1e-9, ln_pdf(3.25));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 3.689503480549115424827, 1e-11, ln_pdf(40.0));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, -36.81049651945088457517, 1e-11, ln_pdf(41.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -19.68765338269220216343, 1e-10, ln_pdf(-12.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 2.312346617307797836567, 1e-10, ln_pdf(-10.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, -10.0, f64::NEG_INFINITY, ln_pdf(0.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: TruncatedNormal| x.cdf(arg);
+        test_case(0.0, 1.0, -1.0, 2.0, 0.0, cdf(-1.5));
+        test_case(0.0, 1.0, -1.0, 2.0, 0.0, cdf(-1.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.4169887514289858525111, 1e-10, cdf(0.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.6508804213366271299662, 1e-10, cdf(0.5));
+        test_case(0.0, 1.0, -1.0, 2.0, 1.0, cdf(2.0));
+        test_almost(1.0, 2.0, 3.0, 3.5, 0.5350529162847357558997, 1e-9, cdf(3.25));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 0.3298807901962844752064, 1e-11, cdf(40.01));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 1.0, 1e-15, cdf(41.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 2.331386322603502975693e-10, 1e-19, cdf(-12.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 0.005668096620912254778599, 1e-13, cdf(-10.5));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, -10.0, 1.0, cdf(-10.0));
+        test_almost(0.0, 1.0, 3.0, 3.0001, 0.5000375006227015044518, 1e-9, cdf(3.00005));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: TruncatedNormal| x.sf(arg);
+        test_case(0.0, 1.0, -1.0, 2.0, 1.0, sf(-1.5));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.5830112485710141474889, 1e-10, sf(0.0));
+        test_almost(0.0, 1.0, -1.0, 2.0, 0.3491195786633728700338, 1e-10, sf(0.5));
+        test_case(0.0, 1.0, -1.0, 2.0, 0.0, sf(2.0));
+        test_almost(1.0, 2.0, 3.0, 3.5, 0.4649470837152642441003, 1e-9, sf(3.25));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 0.6701192098037155247936, 1e-11, sf(40.01));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 2.513984854965318702462e-18, 1e-29, sf(41.0));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, 0.9943319033790877452214, 1e-13, sf(-10.5));
+        test_almost(0.0, 1.0, 3.0, 3.0001, 0.4999624993772984955482, 1e-9, sf(3.00005));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: TruncatedNormal| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, -1.0, 2.0, -0.9999999996616968372481, 1e-10, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, -1.0, 2.0, -0.7046478210947451804961, 1e-10, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, -1.0, 2.0, 1.255715364150215322777, 1e-10, inverse_cdf(0.9));
+        test_almost(0.0, 1.0, -1.0, 2.0, 1.985063085944168294684, 1e-10, inverse_cdf(0.999));
+        test_almost(1.0, 2.0, 3.0, 3.5, 3.000000000043811482046, 1e-13, inverse_cdf(1e-10));
+        test_almost(1.0, 2.0, 3.0, 3.5, 3.044302111367537607589, 1e-10, inverse_cdf(0.1));
+        test_almost(1.0, 2.0, 3.0, 3.5, 3.499419697402580995513, 1e-10, inverse_cdf(0.999));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.00000000000249844042, 1e-12, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.00263228320700742903, 1e-12, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.05748745803602166445, 1e-12, inverse_cdf(0.9));
+        test_almost(0.0, 1.0, 40.0, f64::INFINITY, 40.17221588247907633179, 1e-12, inverse_cdf(0.999));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -12.06985799238948586099, 1e-10, inverse_cdf(1e-10));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -10.22552681120220049285, 1e-10, inverse_cdf(0.1));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -10.01042837009184431652, 1e-10, inverse_cdf(0.9));
+        test_almost(0.0, 1.0, f64::NEG_INFINITY, -10.0, -10.00009907766234386258, 1e-10, inverse_cdf(0.999));
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut r = StdRng::seed_from_u64(1);
+        let cases = [
+            (0.0, 1.0, -1.0, 2.0),
+            (0.0, 1.0, -0.1, 0.1),
+            (0.0, 1.0, -5.0, 5.0),
+            (1.0, 2.0, 3.0, 3.5),
+            (0.0, 1.0, 40.0, f64::INFINITY),
+            (0.0, 1.0, f64::NEG_INFINITY, -10.0),
+            (0.0, 1.0, 3.0, 3.0001),
+        ];
+        for &(location, scale, lower, upper) in cases.iter() {
+            let n = try_create(location, scale, lower, upper);
+            let samples: Vec<f64> = (0..10000).map(|_| n.sample(&mut r)).collect();
+            assert!(samples.iter().all(|&x| x >= lower && x <= upper));
+            let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+            let sd = n.std_dev().unwrap();
+            assert!((mean - n.mean().unwrap()).abs() < 5.0 * sd / 100.0);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, -1.0, 2.0), -1.0, 2.0);
+        tests::check_continuous_distribution(&try_create(1.0, 2.0, 3.0, 3.5), 3.0, 3.5);
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, 5.0, f64::INFINITY), 5.0, 15.0);
+        tests::check_continuous_distribution(&try_create(0.0, 1.0, f64::NEG_INFINITY, -5.0), -15.0, -5.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf_round_trip() {
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, -1.0, 2.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(1.0, 2.0, 3.0, 3.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, 40.0, f64::INFINITY), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, f64::NEG_INFINITY, -10.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.0, 1.0, -40.0, -38.0), 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_cdf_far_tail() {
+        let n = try_create(0.0, 1.0, -40.0, -38.0);
+        for &x in [-39.9, -39.0, -38.48, -38.3, -38.01].iter() {
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-12);
+        }
+        let n = try_create(0.0, 1.0, 38.0, 40.0);
+        for &x in [38.01, 38.1, 38.3].iter() {
+            assert_almost_eq!(n.inverse_cdf(n.cdf(x)), x, 1e-12);
+        }
+    }
+}
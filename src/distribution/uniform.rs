@@ -1,6 +1,7 @@
 use crate::distribution::{Continuous, ContinuousCDF};
 use crate::statistics::*;
 use crate::{Result, StatsError};
+use num_complex::Complex;
 use rand::distributions::Uniform as RandUniform;
 use rand::Rng;
 use std::f64;
@@ -80,6 +81,43 @@ impl ContinuousCDF<f64, f64> for Uniform {
             (x - self.min) / (self.max - self.min)
         }
     }
+
+    /// Calculates the survival function for the uniform
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - x) / (max - min)
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            1.0
+        } else if x >= self.max {
+            0.0
+        } else {
+            (self.max - x) / (self.max - self.min)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// uniform distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + p * (max - min)
+    /// ```
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.min + p * (self.max - self.min)
+    }
 }
 
 impl Min<f64> for Uniform {
@@ -135,6 +173,87 @@ impl Distribution<f64> for Uniform {
     fn skewness(&self) -> Option<f64> {
         Some(0.0)
     }
+    /// Returns the excess kurtosis of the uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -6 / 5
+    /// ```
+    fn kurtosis(&self) -> Option<f64> {
+        Some(-1.2)
+    }
+    /// Returns the `k`th central moment of the uniform distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - min)^k / (2^k (k + 1))
+    /// ```
+    ///
+    /// for even `k` and `0` for odd `k`
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        if k % 2 == 1 {
+            Some(0.0)
+        } else {
+            let half = (self.max - self.min) / 2.0;
+            Some(half.powi(k as i32) / (k as f64 + 1.0))
+        }
+    }
+}
+
+impl GeneratingFunction<f64> for Uniform {
+    /// Returns the moment generating function of the uniform distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (e^(t * max) - e^(t * min)) / (t * (max - min))
+    /// ```
+    fn mgf(&self, t: f64) -> Option<f64> {
+        let x = t * (self.max - self.min);
+        if x == 0.0 {
+            Some((t * self.min).exp())
+        } else if x > 0.0 {
+            Some((t * self.max).exp() * -(-x).exp_m1() / x)
+        } else {
+            Some((t * self.min).exp() * x.exp_m1() / x)
+        }
+    }
+    /// Returns the cumulant generating function of the uniform distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((e^(t * max) - e^(t * min)) / (t * (max - min)))
+    /// ```
+    fn cgf(&self, t: f64) -> Option<f64> {
+        let x = t * (self.max - self.min);
+        if x == 0.0 {
+            Some(t * self.min)
+        } else if x > 0.0 {
+            Some(t * self.max + (-(-x).exp_m1() / x).ln())
+        } else {
+            Some(t * self.min + (x.exp_m1() / x).ln())
+        }
+    }
+    /// Returns the characteristic function of the uniform distribution
+    /// at `t`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(it * (min + max) / 2) * sin(h) / h
+    /// ```
+    ///
+    /// where `h = t * (max - min) / 2`
+    fn cf(&self, t: f64) -> Complex<f64> {
+        let h = t * (self.max - self.min) / 2.0;
+        let sinc = if h == 0.0 { 1.0 } else { h.sin() / h };
+        Complex::from_polar(sinc, t * (self.min + self.max) / 2.0)
+    }
 }
 
 impl Median<f64> for Uniform {
@@ -309,6 +428,74 @@ mod tests {
         test_case(0.0, f64::INFINITY, 0.0, skewness);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Uniform| x.kurtosis().unwrap();
+        test_case(-1.0, 3.0, -1.2, kurtosis);
+        test_case(0.0, 10.0, -1.2, kurtosis);
+    }
+
+    #[test]
+    fn test_central_moment() {
+        let central_moment = |k| move |x: Uniform| x.central_moment(k).unwrap();
+        test_case(-1.0, 3.0, 1.0, central_moment(0));
+        test_case(-1.0, 3.0, 0.0, central_moment(1));
+        test_almost(-1.0, 3.0, 4.0 / 3.0, 1e-15, central_moment(2));
+        test_case(-1.0, 3.0, 0.0, central_moment(3));
+        test_case(-1.0, 3.0, 3.2, central_moment(4));
+        test_almost(-1.0, 3.0, 64.0 / 7.0, 1e-14, central_moment(6));
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Uniform| x.moment(k).unwrap();
+        test_case(-1.0, 3.0, 1.0, moment(0));
+        test_case(-1.0, 3.0, 1.0, moment(1));
+        test_almost(-1.0, 3.0, 7.0 / 3.0, 1e-15, moment(2));
+        test_almost(-1.0, 3.0, 12.2, 1e-14, moment(4));
+        test_almost(-1.0, 3.0, 547.0 / 7.0, 1e-13, moment(6));
+    }
+
+    #[test]
+    fn test_mgf() {
+        let mgf = |t| move |x: Uniform| x.mgf(t).unwrap();
+        test_almost(0.0, 1.0, 0.31673764387737868567, 1e-15, mgf(-3.0));
+        test_almost(0.0, 1.0, 1.2974425414002562937, 1e-14, mgf(0.5));
+        test_almost(0.0, 1.0, 2202.5465794806716517, 1e-11, mgf(10.0));
+        test_almost(-2.0, 5.0, 19.210894913658704862, 1e-13, mgf(-3.0));
+        test_almost(-2.0, 5.0, 3.3756041484377231761, 1e-14, mgf(0.5));
+        test_almost(-2.0, 5.0, 74067221836958178058.0, 1e5, mgf(10.0));
+    }
+
+    #[test]
+    fn test_cgf() {
+        let cgf = |t| move |x: Uniform| x.cgf(t).unwrap();
+        test_almost(0.0, 1.0, -1.1496814696108112779, 1e-14, cgf(-3.0));
+        test_almost(0.0, 1.0, 0.26039505099275673752, 1e-15, cgf(0.5));
+        test_almost(0.0, 1.0, 7.6973695060455838268, 1e-14, cgf(10.0));
+        test_almost(-2.0, 5.0, 2.9554775615183209604, 1e-14, cgf(-3.0));
+        test_almost(-2.0, 5.0, 1.2165743152491723707, 1e-14, cgf(0.5));
+        test_almost(-2.0, 5.0, 45.751504757950641011, 1e-13, cgf(10.0));
+    }
+
+    #[test]
+    fn test_cf() {
+        let re = |t| move |x: Uniform| x.cf(t).re;
+        let im = |t| move |x: Uniform| x.cf(t).im;
+        test_almost(0.0, 1.0, 0.047040002686622407367, 1e-15, re(-3.0));
+        test_almost(0.0, 1.0, -0.66333083220014848576, 1e-15, im(-3.0));
+        test_almost(0.0, 1.0, 0.95885107720840600055, 1e-15, re(0.5));
+        test_almost(0.0, 1.0, 0.24483487621925456777, 1e-15, im(0.5));
+        test_almost(0.0, 1.0, -0.05440211108893698134, 1e-15, re(10.0));
+        test_almost(0.0, 1.0, 0.18390715290764524523, 1e-15, im(10.0));
+        test_almost(-2.0, 5.0, 0.017660587712294809191, 1e-15, re(-3.0));
+        test_almost(-2.0, 5.0, -0.081898009500437490209, 1e-15, im(-3.0));
+        test_almost(-2.0, 5.0, 0.41141232254624371449, 1e-15, re(0.5));
+        test_almost(-2.0, 5.0, 0.38327026326144955207, 1e-15, im(0.5));
+        test_almost(-2.0, 5.0, 0.0092938628146242695495, 1e-15, re(10.0));
+        test_almost(-2.0, 5.0, -0.0079554852382674469715, 1e-15, im(10.0));
+    }
+
     #[test]
     fn test_mode() {
         let mode = |x: Uniform| x.mode().unwrap();
@@ -407,6 +594,26 @@ mod tests {
         test_case(0.0, 3.0, 1.0, cdf(5.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Uniform| x.sf(arg);
+        test_case(0.0, 10.0, 1.0, sf(-1.0));
+        test_case(0.0, 10.0, 0.75, sf(2.5));
+        test_case(0.0, 10.0, 0.0, sf(10.0));
+        test_case(-5.0, 5.0, 0.25, sf(2.5));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Uniform| x.inverse_cdf(arg);
+        test_case(0.0, 10.0, 0.0, inverse_cdf(0.0));
+        test_case(0.0, 10.0, 2.5, inverse_cdf(0.25));
+        test_case(-5.0, 5.0, 0.0, inverse_cdf(0.5));
+        test_case(0.0, 10.0, 10.0, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 10.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(-5.0, 100.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(0.0, 10.0), 0.0, 10.0);
@@ -0,0 +1,582 @@
+use crate::distribution::{internal, Continuous, ContinuousCDF};
+use crate::function::bessel;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Concentration above which samples are drawn from the wrapped normal
+/// approximation, since the envelope of the Best-Fisher sampler can no
+/// longer be formed accurately
+const NORMAL_APPROX_CONCENTRATION: f64 = 1e6;
+
+/// Implements the [von Mises](https://en.wikipedia.org/wiki/Von_Mises_distribution)
+/// distribution on the circle, with support `[μ - π, μ + π]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{VonMises, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = VonMises::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.34171048862346315949, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VonMises {
+    location: f64,
+    concentration: f64,
+}
+
+impl VonMises {
+    /// Constructs a new von Mises distribution with a location (μ) of
+    /// `location` and a concentration (κ) of `concentration`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` or `concentration` are `NaN` or
+    /// infinite, or if `concentration < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let mut result = VonMises::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = VonMises::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, concentration: f64) -> Result<VonMises> {
+        if !location.is_finite() || !concentration.is_finite() || concentration < 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(VonMises {
+                location,
+                concentration,
+            })
+        }
+    }
+
+    /// Returns the location of the von Mises distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let n = VonMises::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the concentration of the von Mises distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let n = VonMises::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.concentration(), 2.0);
+    /// ```
+    pub fn concentration(&self) -> f64 {
+        self.concentration
+    }
+
+    /// Returns the mean resultant length of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I1(κ) / I0(κ)
+    /// ```
+    ///
+    /// where `κ` is the concentration and `I0` and `I1` are the modified
+    /// Bessel functions of the first kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let n = VonMises::new(0.0, 0.0).unwrap();
+    /// assert_eq!(n.mean_resultant_length(), 0.0);
+    /// ```
+    pub fn mean_resultant_length(&self) -> f64 {
+        bessel::bessel_i1e(self.concentration) / bessel::bessel_i0e(self.concentration)
+    }
+
+    /// Returns the circular variance of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - I1(κ) / I0(κ)
+    /// ```
+    ///
+    /// where `κ` is the concentration and `I0` and `I1` are the modified
+    /// Bessel functions of the first kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let n = VonMises::new(0.0, 0.0).unwrap();
+    /// assert_eq!(n.circular_variance(), 1.0);
+    /// ```
+    pub fn circular_variance(&self) -> f64 {
+        1.0 - self.mean_resultant_length()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for VonMises {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.location, self.concentration)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for VonMises {
+    /// Calculates the cumulative distribution function for the
+    /// von Mises distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2 + (x - μ) / (2π) + 1 / π * sum(I_n(κ) / I0(κ) * sin(n * (x - μ)) / n, n=1..)
+    /// ```
+    ///
+    /// where `μ` is the location, `κ` is the concentration and `I_n` are the
+    /// modified Bessel functions of the first kind
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            cdf_centered(x - self.location, self.concentration)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// von Mises distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2 - (x - μ) / (2π) - 1 / π * sum(I_n(κ) / I0(κ) * sin(n * (x - μ)) / n, n=1..)
+    /// ```
+    ///
+    /// where `μ` is the location, `κ` is the concentration and `I_n` are the
+    /// modified Bessel functions of the first kind
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            cdf_centered(self.location - x, self.concentration)
+        }
+    }
+}
+
+impl Min<f64> for VonMises {
+    /// Returns the minimum value in the domain of the
+    /// von Mises distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - π
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location - f64::consts::PI
+    }
+}
+
+impl Max<f64> for VonMises {
+    /// Returns the maximum value in the domain of the
+    /// von Mises distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + π
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn max(&self) -> f64 {
+        self.location + f64::consts::PI
+    }
+}
+
+impl Distribution<f64> for VonMises {
+    /// Returns the mean of the von Mises distribution, which is also its
+    /// circular mean
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the von Mises distribution
+    ///
+    /// # Remarks
+    ///
+    /// Always returns `None` since the spread of an angle is measured on the
+    /// circle rather than the line, see `circular_variance`
+    fn variance(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the entropy of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2π * I0(κ)) - κ * I1(κ) / I0(κ)
+    /// ```
+    ///
+    /// where `κ` is the concentration and `I0` and `I1` are the modified
+    /// Bessel functions of the first kind
+    fn entropy(&self) -> Option<f64> {
+        let k = self.concentration;
+        Some(
+            (2.0 * f64::consts::PI * bessel::bessel_i0e(k)).ln()
+                + k * (1.0 - self.mean_resultant_length()),
+        )
+    }
+}
+
+impl Median<f64> for VonMises {
+    /// Returns the median of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for VonMises {
+    /// Returns the mode of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for VonMises {
+    /// Calculates the probability density function for the von Mises
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(κ * cos(x - μ)) / (2π * I0(κ))
+    /// ```
+    ///
+    /// where `μ` is the location, `κ` is the concentration and `I0` is the
+    /// modified Bessel function of the first kind of order zero
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            0.0
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the von Mises
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// κ * cos(x - μ) - ln(2π * I0(κ))
+    /// ```
+    ///
+    /// where `μ` is the location, `κ` is the concentration and `I0` is the
+    /// modified Bessel function of the first kind of order zero
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            f64::NEG_INFINITY
+        } else {
+            // κ * (cos(θ) - 1) is written as -2κ * sin(θ / 2)^2 to keep its
+            // precision for small angles
+            let s = (0.5 * (x - self.location)).sin();
+            -2.0 * self.concentration * s * s
+                - (2.0 * f64::consts::PI * bessel::bessel_i0e(self.concentration)).ln()
+        }
+    }
+}
+
+/// Computes the cdf at `θ` in `[-π, π]` of a von Mises distribution centred
+/// at zero with the given concentration
+fn cdf_centered(theta: f64, concentration: f64) -> f64 {
+    internal::circular_cdf_series(theta, bessel_ratios(concentration))
+}
+
+/// Returns `I_n(κ) / I0(κ)` for `n = 1, 2, ...` up to the point where the
+/// ratios drop below the precision, computed by backward recurrence on
+/// `I_(n-1)(κ) / I_n(κ) = 2n / κ + I_(n+1)(κ) / I_n(κ)`
+fn bessel_ratios(concentration: f64) -> Vec<f64> {
+    // I_n(κ) / I0(κ) falls roughly like e^(-n^2 / (2κ))
+    let len = (80.0 * concentration).sqrt().ceil() as usize + 20;
+    let mut ratios = vec![0.0; len];
+    let mut next = 0.0;
+    for n in (1..=len).rev() {
+        next = concentration / (2.0 * n as f64 + concentration * next);
+        ratios[n - 1] = next;
+    }
+    let mut prod = 1.0;
+    for r in ratios.iter_mut() {
+        prod *= *r;
+        *r = prod;
+    }
+    ratios
+}
+
+/// draws a sample from a von Mises distribution with the given location
+/// and concentration using the rejection sampler of Best and Fisher
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, location: f64, concentration: f64) -> f64 {
+    if concentration < 1e-8 {
+        let u: f64 = rng.gen();
+        return location + f64::consts::PI * (2.0 * u - 1.0);
+    }
+    if concentration > NORMAL_APPROX_CONCENTRATION {
+        let z = super::normal::sample_unchecked(rng, 0.0, concentration.sqrt().recip());
+        return location + internal::wrap_angle(z);
+    }
+
+    let s = if concentration < 1e-5 {
+        1.0 / concentration + concentration
+    } else {
+        let r = 1.0 + (1.0 + 4.0 * concentration * concentration).sqrt();
+        let rho = (r - (2.0 * r).sqrt()) / (2.0 * concentration);
+        (1.0 + rho * rho) / (2.0 * rho)
+    };
+    let w = loop {
+        let u: f64 = rng.gen();
+        let z = (f64::consts::PI * u).cos();
+        let w = (1.0 + s * z) / (s + z);
+        let y = concentration * (s - w);
+        let v: f64 = rng.gen();
+        if y * (2.0 - y) > v || (y / v).ln() + 1.0 - y >= 0.0 {
+            break w;
+        }
+    };
+    let theta = w.clamp(-1.0, 1.0).acos();
+    if rng.gen::<bool>() {
+        location + theta
+    } else {
+        location - theta
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, VonMises};
+    use crate::statistics::*;
+
+    fn try_create(location: f64, concentration: f64) -> VonMises {
+        let n = VonMises::new(location, concentration);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, concentration: f64) {
+        let n = try_create(location, concentration);
+        assert_eq!(location, n.location());
+        assert_eq!(concentration, n.concentration());
+    }
+
+    fn bad_create_case(location: f64, concentration: f64) {
+        let n = VonMises::new(location, concentration);
+        assert!(n.is_err());
+    }
+
+    fn get_value<F>(location: f64, concentration: f64, eval: F) -> f64
+    where
+        F: Fn(VonMises) -> f64,
+    {
+        let n = try_create(location, concentration);
+        eval(n)
+    }
+
+    fn test_case<F>(location: f64, concentration: f64, expected: f64, eval: F)
+    where
+        F: Fn(VonMises) -> f64,
+    {
+        let x = get_value(location, concentration, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, concentration: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: Fn(VonMises) -> f64,
+    {
+        let x = get_value(location, concentration, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.0);
+        create_case(1.0, 2.0);
+        create_case(-3.0, 1000.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, -1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(0.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean_resultant_length() {
+        let r = |x: VonMises| x.mean_resultant_length();
+        test_case(0.0, 0.0, 0.0, r);
+        test_almost(0.0, 1.0, 0.44638996589653450705, 1e-15, r);
+        test_almost(0.0, 50.0, 0.98994896737849775259, 1e-15, r);
+        let v = |x: VonMises| x.circular_variance();
+        test_case(0.0, 0.0, 1.0, v);
+        test_almost(0.0, 1.0, 0.55361003410346549295, 1e-15, v);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: VonMises| x.mean().unwrap();
+        test_case(1.5, 2.0, 1.5, mean);
+        assert!(try_create(1.5, 2.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: VonMises| x.entropy().unwrap();
+        test_almost(0.0, 0.0, 1.8378770664093454836, 1e-15, entropy);
+        test_almost(0.0, 1.0, 1.6274014590199896252, 1e-15, entropy);
+        test_almost(0.0, 50.0, -0.53199580064373756191, 1e-14, entropy);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: VonMises| x.median();
+        let mode = |x: VonMises| x.mode().unwrap();
+        test_case(1.5, 2.0, 1.5, median);
+        test_case(1.5, 2.0, 1.5, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: VonMises| x.min();
+        let max = |x: VonMises| x.max();
+        test_case(1.0, 2.0, 1.0 - f64::consts::PI, min);
+        test_case(1.0, 2.0, 1.0 + f64::consts::PI, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: VonMises| x.pdf(arg);
+        test_case(0.0, 1.0, 0.0, pdf(-4.0));
+        test_case(0.0, 1.0, 0.0, pdf(4.0));
+        test_almost(0.0, 0.0, 0.15915494309189533577, 1e-16, pdf(1.0));
+        test_almost(0.0, 1.0, 0.34171048862346315949, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.082915085473171501204, 1e-16, pdf(2.0));
+        test_almost(1.0, 2.0, 0.014063706052155359648, 1e-17, pdf(-1.5));
+        test_almost(0.0, 50.0, 2.1918713915363261176, 1e-14, pdf(0.1));
+        test_almost(0.0, 50.0, 1.7264747287011318973e-43, 1e-56, pdf(3.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: VonMises| x.ln_pdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(-4.0));
+        test_almost(0.0, 1.0, -2.4899382614636665192, 1e-15, ln_pdf(2.0));
+        test_almost(0.0, 50.0, -98.465077398303422931, 1e-13, ln_pdf(3.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: VonMises| x.cdf(arg);
+        test_case(0.0, 1.0, 0.0, cdf(-4.0));
+        test_case(0.0, 1.0, 1.0, cdf(4.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, cdf(0.0));
+        test_almost(0.0, 0.0, 0.75, 1e-15, cdf(f64::consts::PI / 2.0));
+        test_almost(0.0, 1.0, 0.065759044110016834719, 1e-16, cdf(-2.0));
+        test_almost(0.0, 1.0, 0.79435530743468347999, 1e-15, cdf(1.0));
+        test_almost(1.0, 2.0, 0.26180778558147382215, 1e-15, cdf(0.5));
+        test_almost(0.0, 50.0, 0.75960331643559306512, 1e-15, cdf(0.1));
+        test_almost(0.0, 1000.0, 0.056964590443145655296, 1e-14, cdf(-0.05));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: VonMises| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(-4.0));
+        test_case(0.0, 1.0, 0.0, sf(4.0));
+        test_almost(0.0, 1.0, 0.93424095588998316528, 1e-15, sf(-2.0));
+        test_almost(0.0, 1.0, 0.20564469256531652001, 1e-15, sf(1.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.0, 20.0), 1e-12);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        for &(location, concentration) in &[(1.0, 2.0), (-2.0, 1e-6), (0.5, 200.0), (0.0, 1e7)] {
+            let n = try_create(location, concentration);
+            let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= n.min() && x <= n.max()));
+            assert!(((&samples).resultant_length() - n.mean_resultant_length()).abs() < 0.02);
+            for &q in &[0.1, 0.5, 0.8] {
+                let x = n.inverse_cdf(q);
+                let below = samples.iter().filter(|&&s| s <= x).count() as f64 / count as f64;
+                assert!((below - q).abs() < 0.015);
+            }
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -f64::consts::PI, f64::consts::PI);
+        tests::check_continuous_distribution(&try_create(1.0, 20.0), 1.0 - f64::consts::PI, 1.0 + f64::consts::PI);
+    }
+}
@@ -115,6 +115,84 @@ impl ContinuousCDF<f64, f64> for Weibull {
             -(-x.powf(self.shape) * self.scale_pow_shape_inv).exp_m1()
         }
     }
+
+    /// Calculates the survival function for the weibull
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^-((x/λ)^k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else {
+            (-x.powf(self.shape) * self.scale_pow_shape_inv).exp()
+        }
+    }
+
+    /// Calculates the log of the cumulative distribution function for the
+    /// weibull distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(1 - e^-((x/λ)^k))
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn ln_cdf(&self, x: f64) -> f64 {
+        let t = x.powf(self.shape) * self.scale_pow_shape_inv;
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if t < f64::consts::LN_2 {
+            (-(-t).exp_m1()).ln()
+        } else {
+            (-(-t).exp()).ln_1p()
+        }
+    }
+
+    /// Calculates the log of the survival function for the weibull
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -(x/λ)^k
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else {
+            -x.powf(self.shape) * self.scale_pow_shape_inv
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// weibull distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * (-ln(1 - p))^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        self.scale * (-(-p).ln_1p()).powf(1.0 / self.shape)
+    }
 }
 
 impl Min<f64> for Weibull {
@@ -211,6 +289,40 @@ impl Distribution<f64> for Weibull {
             / sigma3;
         Some(skew)
     }
+    /// Returns the excess kurtosis of the weibull distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (Γ_4 - 4Γ_1 Γ_3 + 6Γ_1^2 Γ_2 - 3Γ_1^4) / (Γ_2 - Γ_1^2)^2 - 3
+    /// ```
+    ///
+    /// where `Γ_i = Γ(1 + i / k)`, `k` is the shape and `Γ` is the gamma
+    /// function
+    fn kurtosis(&self) -> Option<f64> {
+        let g = |i: f64| gamma::gamma(1.0 + i / self.shape);
+        let (g1, g2, g3, g4) = (g(1.0), g(2.0), g(3.0), g(4.0));
+        let var = g2 - g1 * g1;
+        Some(
+            (g4 - 4.0 * g1 * g3 + 6.0 * g1 * g1 * g2 - 3.0 * g1 * g1 * g1 * g1) / (var * var) - 3.0,
+        )
+    }
+    /// Returns the `n`th raw moment of the weibull distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ^n Γ(1 + n / k)
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the scale, and `Γ` is the gamma
+    /// function
+    fn moment(&self, n: u32) -> Option<f64> {
+        if n == 0 {
+            return Some(1.0);
+        }
+        Some(self.scale.powi(n as i32) * gamma::gamma(1.0 + n as f64 / self.shape))
+    }
 }
 
 impl Median<f64> for Weibull {
@@ -388,6 +500,22 @@ mod tests {
         test_almost(10.0, 1.0, 0.013100455073468309147154581687505295026863354547057, 1e-14, variance);
     }
 
+    #[test]
+    fn test_kurtosis() {
+        let kurtosis = |x: Weibull| x.kurtosis().unwrap();
+        test_almost(1.5, 2.0, 1.3904035615957883165, 1e-12, kurtosis);
+        test_almost(1.0, 1.0, 6.0, 1e-12, kurtosis);
+    }
+
+    #[test]
+    fn test_moment() {
+        let moment = |k| move |x: Weibull| x.moment(k).unwrap();
+        test_case(1.5, 2.0, 1.0, moment(0));
+        test_almost(1.5, 2.0, 1.8054905859018672226, 1e-14, moment(1));
+        test_almost(1.5, 2.0, 16.0, 1e-13, moment(3));
+        test_almost(1.5, 2.0, 1536.0, 1e-11, moment(6));
+    }
+
     #[test]
     fn test_entropy() {
         let entropy = |x: Weibull| x.entropy().unwrap();
@@ -483,6 +611,28 @@ mod tests {
         test_case(10.0, 1.0, 1.0, cdf(10.0));
     }
 
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Weibull| x.sf(arg);
+        test_case(2.0, 1.0, 1.0, sf(-1.0));
+        test_case(2.0, 1.0, 1.0, sf(0.0));
+        test_almost(2.0, 1.0, 3.720075976020835962959e-44, 1e-58, sf(10.0));
+        test_case(2.0, 1.0, -100.0, |x: Weibull| x.ln_sf(10.0));
+        test_almost(2.0, 1.0, -3.720075976020835962959e-44, 1e-58, |x: Weibull| x.ln_cdf(10.0));
+        test_case(2.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Weibull| x.inverse_cdf(arg);
+        test_case(2.0, 3.0, 0.0, inverse_cdf(0.0));
+        test_almost(2.0, 3.0, 0.00003000000000075000000004, 1e-19, inverse_cdf(1e-10));
+        test_almost(2.0, 3.0, 2.497663833473093269059, 1e-15, inverse_cdf(0.5));
+        test_case(2.0, 3.0, f64::INFINITY, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(2.0, 3.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(0.5, 10.0), 1e-12);
+    }
+
     #[test]
     fn test_continuous() {
         tests::check_continuous_distribution(&try_create(1.0, 0.2), 0.0, 10.0);
@@ -0,0 +1,344 @@
+use crate::distribution::{internal, Continuous};
+use crate::function::gamma;
+use crate::statistics::{MeanN, Mode, VarianceN};
+use crate::{Result, StatsError};
+use nalgebra::DMatrix;
+use rand::Rng;
+use std::f64;
+use std::f64::consts::LN_2;
+
+/// Implements the [Wishart](https://en.wikipedia.org/wiki/Wishart_distribution)
+/// distribution over symmetric positive-definite matrices using the
+/// "nalgebra" crate for matrix operations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Wishart, Continuous};
+/// use nalgebra::DMatrix;
+/// use statrs::statistics::MeanN;
+///
+/// let w = Wishart::new(3., DMatrix::from_vec(2, 2, vec![1., 0., 0., 1.])).unwrap();
+/// assert_eq!(w.mean().unwrap(), DMatrix::from_vec(2, 2, vec![3., 0., 0., 3.]));
+/// let x = DMatrix::from_vec(2, 2, vec![1., 0., 0., 1.]);
+/// assert!((w.ln_pdf(&x) - (-3.5310242469692908)).abs() < 1e-14);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wishart {
+    freedom: f64,
+    scale: DMatrix<f64>,
+    scale_chol_decomp: DMatrix<f64>,
+    precision: DMatrix<f64>,
+    ln_pdf_const: f64,
+}
+
+impl Wishart {
+    /// Constructs a new Wishart distribution with `freedom` degrees of
+    /// freedom and scale matrix `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `scale` is empty, not symmetric or not
+    /// positive-definite, or has non-finite entries, or if `freedom` is not
+    /// finite or not greater than the dimension less one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Wishart;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let mut result = Wishart::new(2., DMatrix::from_vec(2, 2, vec![2., 1., 1., 2.]));
+    /// assert!(result.is_ok());
+    ///
+    /// result = Wishart::new(1., DMatrix::from_vec(2, 2, vec![2., 1., 1., 2.]));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, scale: DMatrix<f64>) -> Result<Self> {
+        let cholesky_decomp = internal::parse_scale(freedom, &scale)?;
+        let p = scale.nrows();
+        let precision = cholesky_decomp.inverse();
+        let scale_chol_decomp = cholesky_decomp.unpack();
+        let ln_det = internal::ln_det_from_cholesky(&scale_chol_decomp);
+        let ln_pdf_const = -0.5 * freedom * (p as f64 * LN_2 + ln_det)
+            - gamma::ln_multivariate_gamma(0.5 * freedom, p as u64);
+        Ok(Wishart {
+            freedom,
+            scale,
+            scale_chol_decomp,
+            precision,
+            ln_pdf_const,
+        })
+    }
+
+    /// Returns the degrees of freedom of the Wishart distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Wishart;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let w = Wishart::new(3., DMatrix::identity(2, 2)).unwrap();
+    /// assert_eq!(w.freedom(), 3.);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the scale matrix of the Wishart distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Wishart;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let w = Wishart::new(3., DMatrix::identity(2, 2)).unwrap();
+    /// assert_eq!(*w.scale(), DMatrix::identity(2, 2));
+    /// ```
+    pub fn scale(&self) -> &DMatrix<f64> {
+        &self.scale
+    }
+
+    fn dim(&self) -> usize {
+        self.scale.nrows()
+    }
+}
+
+impl ::rand::distributions::Distribution<DMatrix<f64>> for Wishart {
+    /// Samples from the Wishart distribution by the Bartlett decomposition
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// L * A * transpose(A) * transpose(L)
+    /// ```
+    ///
+    /// where `L` is the Cholesky decomposition of the scale matrix and `A`
+    /// is lower triangular with square roots of chi-squared variables with
+    /// `ν - i` degrees of freedom on the `i`th diagonal entry and standard
+    /// normal variables below it
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DMatrix<f64> {
+        let m = &self.scale_chol_decomp * internal::sample_bartlett(rng, self.freedom, self.dim());
+        &m * m.transpose()
+    }
+}
+
+impl MeanN<DMatrix<f64>> for Wishart {
+    /// Returns the mean of the Wishart distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ν * Σ
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom and `Σ` the scale matrix
+    fn mean(&self) -> Option<DMatrix<f64>> {
+        Some(&self.scale * self.freedom)
+    }
+}
+
+impl VarianceN<DMatrix<f64>> for Wishart {
+    /// Returns the variance of each entry of the Wishart distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Var(X_ij) = ν * (Σ_ij^2 + Σ_ii * Σ_jj)
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom and `Σ` the scale matrix
+    fn variance(&self) -> Option<DMatrix<f64>> {
+        Some(internal::elementwise_variance(
+            &self.scale,
+            1.0,
+            1.0,
+            self.freedom,
+        ))
+    }
+}
+
+impl Mode<Option<DMatrix<f64>>> for Wishart {
+    /// Returns the mode of the Wishart distribution
+    ///
+    /// # None
+    ///
+    /// If `ν < p + 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (ν - p - 1) * Σ
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension and `Σ` the
+    /// scale matrix
+    fn mode(&self) -> Option<DMatrix<f64>> {
+        let p = self.dim() as f64;
+        if self.freedom < p + 1.0 {
+            None
+        } else {
+            Some(&self.scale * (self.freedom - p - 1.0))
+        }
+    }
+}
+
+impl<'a> Continuous<&'a DMatrix<f64>, f64> for Wishart {
+    /// Calculates the probability density function for the Wishart
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `0.0` if `x` is not a symmetric positive-definite matrix of
+    /// the right dimension
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// det(x)^((ν - p - 1) / 2) * e^(-tr(inv(Σ) * x) / 2)
+    ///     / (2^(ν * p / 2) * det(Σ)^(ν / 2) * Γ_p(ν / 2))
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `p` the dimension, `Σ` the scale
+    /// matrix and `Γ_p` the multivariate gamma function
+    fn pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the Wishart
+    /// distribution at `x`. Equivalent to pdf(x).ln().
+    fn ln_pdf(&self, x: &'a DMatrix<f64>) -> f64 {
+        let p = self.dim();
+        let cholesky_x = match internal::support_cholesky(x, p) {
+            Some(c) => c,
+            None => return f64::NEG_INFINITY,
+        };
+        let ln_det_x = internal::ln_det_from_cholesky(cholesky_x.l_dirty());
+        // tr(inv(Σ) * x) for symmetric matrices
+        let trace = self.precision.component_mul(x).sum();
+        self.ln_pdf_const + 0.5 * (self.freedom - p as f64 - 1.0) * ln_det_x - 0.5 * trace
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::{ChiSquared, Continuous, Wishart};
+    use crate::distribution::internal::*;
+    use crate::statistics::*;
+    use nalgebra::DMatrix;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn try_create(freedom: f64, scale: DMatrix<f64>) -> Wishart {
+        let w = Wishart::new(freedom, scale);
+        assert!(w.is_ok());
+        w.unwrap()
+    }
+
+    fn bad_create_case(freedom: f64, scale: DMatrix<f64>) {
+        let w = Wishart::new(freedom, scale);
+        assert!(w.is_err());
+    }
+
+    macro_rules! mat2 {
+        ($x11:expr, $x12:expr, $x21:expr, $x22:expr) => (DMatrix::from_vec(2,2,vec![$x11, $x12, $x21, $x22]));
+    }
+
+    fn mat3() -> DMatrix<f64> {
+        DMatrix::from_vec(3, 3, vec![2., 1., 0., 1., 3., 1., 0., 1., 4.])
+    }
+
+    #[test]
+    fn test_create() {
+        let w = try_create(3., mat2![2., 1., 1., 2.]);
+        assert_eq!(w.freedom(), 3.);
+        assert_eq!(*w.scale(), mat2![2., 1., 1., 2.]);
+        try_create(1.5, mat2![2., 1., 1., 2.]);
+        try_create(2.5, mat3());
+        try_create(0.5, DMatrix::from_vec(1, 1, vec![2.]));
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(1., mat2![2., 1., 1., 2.]);
+        bad_create_case(f64::NAN, mat2![2., 1., 1., 2.]);
+        bad_create_case(f64::INFINITY, mat2![2., 1., 1., 2.]);
+        bad_create_case(3., mat2![2., 1., 0., 2.]);
+        bad_create_case(3., mat2![1., 2., 2., 1.]);
+        bad_create_case(3., mat2![1., 0., 0., f64::NAN]);
+        bad_create_case(3., DMatrix::from_vec(2, 3, vec![1., 0., 0., 1., 0., 0.]));
+        bad_create_case(3., DMatrix::from_vec(0, 0, vec![]));
+    }
+
+    #[test]
+    fn test_moments() {
+        let w = try_create(3., mat2![2., 1., 1., 2.]);
+        assert_eq!(w.mean().unwrap(), mat2![6., 3., 3., 6.]);
+        assert_eq!(w.variance().unwrap(), mat2![24., 15., 15., 24.]);
+        assert_eq!(w.mode().unwrap(), mat2![0., 0., 0., 0.]);
+        let w = try_create(5.5, mat3());
+        assert_eq!(w.mode().unwrap(), mat3() * 1.5);
+        assert_eq!(try_create(2.5, mat3()).mode(), None);
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let w = try_create(3., mat2![1., 0., 0., 1.]);
+        assert_almost_eq!(w.ln_pdf(&mat2![1., 0., 0., 1.]), -3.5310242469692907930, 1e-14);
+        let w = try_create(4.5, mat2![2., 1., 1., 2.]);
+        assert_almost_eq!(w.ln_pdf(&mat2![3., -1., -1., 5.]), -7.2245825016081678625, 1e-14);
+        assert_almost_eq!(w.pdf(&mat2![3., -1., -1., 5.]), 0.00072845660507265193699, 1e-17);
+        let w = try_create(2.5, mat3());
+        let x = DMatrix::from_vec(3, 3, vec![4., 2., 1., 2., 6., 0.5, 1., 0.5, 3.]);
+        assert_almost_eq!(w.ln_pdf(&x), -14.855670759653791671, 1e-13);
+        assert_almost_eq!(try_create(0.5, DMatrix::from_vec(1, 1, vec![2.])).ln_pdf(&DMatrix::from_vec(1, 1, vec![3.])),
+            ChiSquared::new(0.5).unwrap().ln_pdf(1.5) - 2f64.ln(), 1e-14);
+    }
+
+    #[test]
+    fn test_ln_pdf_outside_support() {
+        let w = try_create(3., mat2![1., 0., 0., 1.]);
+        assert_eq!(w.ln_pdf(&mat2![1., 2., 2., 1.]), f64::NEG_INFINITY);
+        assert_eq!(w.ln_pdf(&mat2![1., 0.5, 0., 1.]), f64::NEG_INFINITY);
+        assert_eq!(w.ln_pdf(&mat3()), f64::NEG_INFINITY);
+        assert_eq!(w.pdf(&mat2![1., 0., 0., f64::INFINITY]), 0.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        // for any vector v, v' * X * v / (v' * Σ * v) is chi-squared with ν
+        // degrees of freedom
+        let w = try_create(4.5, mat3());
+        let v = DMatrix::from_vec(3, 1, vec![1., -2., 0.5]);
+        let scale = (v.transpose() * mat3() * &v)[(0, 0)];
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let samples = (0..10_000)
+            .map(|_| {
+                let x: DMatrix<f64> = w.sample(&mut rng);
+                assert_eq!(x, x.transpose());
+                (v.transpose() * x * &v)[(0, 0)] / scale
+            })
+            .collect();
+        tests::check_kolmogorov_smirnov(&ChiSquared::new(4.5).unwrap(), samples);
+    }
+
+    #[test]
+    fn test_sample_mean() {
+        let w = try_create(4.5, mat3());
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let count = 10_000;
+        let mut mean = DMatrix::zeros(3, 3);
+        for _ in 0..count {
+            let x: DMatrix<f64> = w.sample(&mut rng);
+            mean += x / count as f64;
+        }
+        let sd = w.variance().unwrap().map(|v| (v / count as f64).sqrt());
+        for (m, (e, s)) in mean.iter().zip(w.mean().unwrap().iter().zip(sd.iter())) {
+            assert!((m - e).abs() < 4.0 * s, "{} not within {} of {}", m, 4.0 * s, e);
+        }
+    }
+}
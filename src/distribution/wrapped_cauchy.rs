@@ -0,0 +1,545 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [wrapped Cauchy](https://en.wikipedia.org/wiki/Wrapped_Cauchy_distribution)
+/// distribution on the circle, with support `[μ - π, μ + π]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{WrappedCauchy, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = WrappedCauchy::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.34440388241708793659, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WrappedCauchy {
+    location: f64,
+    scale: f64,
+}
+
+impl WrappedCauchy {
+    /// Constructs a new wrapped Cauchy distribution with a location (μ) of
+    /// `location` and a scale (γ) of `scale`, which are the location and
+    /// scale of the Cauchy distribution that is wrapped around the circle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` or `scale` are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedCauchy;
+    ///
+    /// let mut result = WrappedCauchy::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = WrappedCauchy::new(0.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<WrappedCauchy> {
+        if !location.is_finite() || !scale.is_finite() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(WrappedCauchy { location, scale })
+        }
+    }
+
+    /// Returns the location of the wrapped Cauchy distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedCauchy;
+    ///
+    /// let n = WrappedCauchy::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the wrapped Cauchy distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedCauchy;
+    ///
+    /// let n = WrappedCauchy::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the mean resultant length of the wrapped Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-γ)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedCauchy;
+    ///
+    /// let n = WrappedCauchy::new(0.0, 2.0f64.ln()).unwrap();
+    /// assert_eq!(n.mean_resultant_length(), 0.5);
+    /// ```
+    pub fn mean_resultant_length(&self) -> f64 {
+        (-self.scale).exp()
+    }
+
+    /// Returns the circular variance of the wrapped Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-γ)
+    /// ```
+    ///
+    /// where `γ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedCauchy;
+    ///
+    /// let n = WrappedCauchy::new(0.0, 2.0f64.ln()).unwrap();
+    /// assert_eq!(n.circular_variance(), 0.5);
+    /// ```
+    pub fn circular_variance(&self) -> f64 {
+        -(-self.scale).exp_m1()
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for WrappedCauchy {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.location, self.scale)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for WrappedCauchy {
+    /// Calculates the cumulative distribution function for the
+    /// wrapped Cauchy distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2 + atan(tan((x - μ) / 2) / tanh(γ / 2)) / π
+    /// ```
+    ///
+    /// where `μ` is the location and `γ` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            cdf_centered(x - self.location, self.scale)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// wrapped Cauchy distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / 2 - atan(tan((x - μ) / 2) / tanh(γ / 2)) / π
+    /// ```
+    ///
+    /// where `μ` is the location and `γ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            cdf_centered(self.location - x, self.scale)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// wrapped Cauchy distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + 2 * atan(tanh(γ / 2) * tan(π * (p - 1 / 2)))
+    /// ```
+    ///
+    /// where `μ` is the location and `γ` is the scale
+    fn inverse_cdf(&self, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("p must be in [0, 1]");
+        }
+        if p == 0.0 {
+            self.min()
+        } else if p == 1.0 {
+            self.max()
+        } else {
+            self.location
+                + 2.0 * ((0.5 * self.scale).tanh() * (f64::consts::PI * (p - 0.5)).tan()).atan()
+        }
+    }
+}
+
+impl Min<f64> for WrappedCauchy {
+    /// Returns the minimum value in the domain of the
+    /// wrapped Cauchy distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - π
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location - f64::consts::PI
+    }
+}
+
+impl Max<f64> for WrappedCauchy {
+    /// Returns the maximum value in the domain of the
+    /// wrapped Cauchy distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + π
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn max(&self) -> f64 {
+        self.location + f64::consts::PI
+    }
+}
+
+impl Distribution<f64> for WrappedCauchy {
+    /// Returns the mean of the wrapped Cauchy distribution, which is also
+    /// its circular mean
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the wrapped Cauchy distribution
+    ///
+    /// # Remarks
+    ///
+    /// Always returns `None` since the spread of an angle is measured on the
+    /// circle rather than the line, see `circular_variance`
+    fn variance(&self) -> Option<f64> {
+        None
+    }
+    /// Returns the entropy of the wrapped Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2π * (1 - e^(-2γ)))
+    /// ```
+    ///
+    /// where `γ` is the scale
+    fn entropy(&self) -> Option<f64> {
+        Some((2.0 * f64::consts::PI * -(-2.0 * self.scale).exp_m1()).ln())
+    }
+}
+
+impl Median<f64> for WrappedCauchy {
+    /// Returns the median of the wrapped Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for WrappedCauchy {
+    /// Returns the mode of the wrapped Cauchy distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for WrappedCauchy {
+    /// Calculates the probability density function for the wrapped Cauchy
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sinh(γ) / (2π * (cosh(γ) - cos(x - μ)))
+    /// ```
+    ///
+    /// where `μ` is the location and `γ` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            0.0
+        } else {
+            // dividing through by cosh(γ / 2)^2 avoids both overflow for
+            // large scales and cancellation for small angles
+            let t = (0.5 * self.scale).tanh();
+            let s = (0.5 * (x - self.location)).sin();
+            t / (2.0 * f64::consts::PI * (t * t + s * s * (1.0 - t * t)))
+        }
+    }
+
+    /// Calculates the log probability density function for the wrapped
+    /// Cauchy distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sinh(γ) / (2π * (cosh(γ) - cos(x - μ))))
+    /// ```
+    ///
+    /// where `μ` is the location and `γ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            f64::NEG_INFINITY
+        } else {
+            self.pdf(x).ln()
+        }
+    }
+}
+
+/// Computes the cdf at `θ` in `[-π, π]` of a wrapped Cauchy distribution
+/// centred at zero with the given scale. Below zero the arctangent is taken
+/// of the reciprocal argument so that the lower tail keeps its relative
+/// accuracy
+fn cdf_centered(theta: f64, scale: f64) -> f64 {
+    let t = (0.5 * scale).tanh();
+    let (sin, cos) = (0.5 * theta).sin_cos();
+    if theta <= 0.0 {
+        (t * cos).atan2(-sin) / f64::consts::PI
+    } else {
+        0.5 + sin.atan2(t * cos) / f64::consts::PI
+    }
+}
+
+/// draws a sample from a wrapped Cauchy distribution with the given location
+/// and scale by inversion
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, location: f64, scale: f64) -> f64 {
+    let u: f64 = rng.gen();
+    location + 2.0 * ((0.5 * scale).tanh() * (f64::consts::PI * (u - 0.5)).tan()).atan()
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, WrappedCauchy};
+    use crate::statistics::*;
+
+    fn try_create(location: f64, scale: f64) -> WrappedCauchy {
+        let n = WrappedCauchy::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = WrappedCauchy::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn get_value<F>(location: f64, scale: f64, eval: F) -> f64
+    where
+        F: Fn(WrappedCauchy) -> f64,
+    {
+        let n = try_create(location, scale);
+        eval(n)
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+    where
+        F: Fn(WrappedCauchy) -> f64,
+    {
+        let x = get_value(location, scale, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: Fn(WrappedCauchy) -> f64,
+    {
+        let x = get_value(location, scale, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(1.0, 2.0);
+        create_case(-3.0, 1000.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 0.0);
+        bad_create_case(0.0, -1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(0.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean_resultant_length() {
+        let r = |x: WrappedCauchy| x.mean_resultant_length();
+        test_almost(0.0, 1.0, 0.36787944117144232160, 1e-16, r);
+        let v = |x: WrappedCauchy| x.circular_variance();
+        test_almost(0.0, 1.0, 0.63212055882855767840, 1e-16, v);
+        test_almost(0.0, 1e-10, 9.9999999995e-11, 1e-25, v);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: WrappedCauchy| x.mean().unwrap();
+        test_case(1.5, 2.0, 1.5, mean);
+        assert!(try_create(1.5, 2.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let entropy = |x: WrappedCauchy| x.entropy().unwrap();
+        test_almost(0.0, 1.0, 1.6924636085404864266, 1e-15, entropy);
+        test_almost(0.0, 0.01, -2.0841292724076890906, 1e-14, entropy);
+        test_almost(0.0, 1000.0, 1.8378770664093454836, 1e-15, entropy);
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: WrappedCauchy| x.median();
+        let mode = |x: WrappedCauchy| x.mode().unwrap();
+        test_case(1.5, 2.0, 1.5, median);
+        test_case(1.5, 2.0, 1.5, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: WrappedCauchy| x.min();
+        let max = |x: WrappedCauchy| x.max();
+        test_case(1.0, 2.0, 1.0 - f64::consts::PI, min);
+        test_case(1.0, 2.0, 1.0 + f64::consts::PI, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: WrappedCauchy| x.pdf(arg);
+        test_case(0.0, 1.0, 0.0, pdf(-4.0));
+        test_case(0.0, 1.0, 0.0, pdf(4.0));
+        test_almost(0.0, 1.0, 0.34440388241708793659, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.095465729135497218609, 1e-16, pdf(2.0));
+        test_almost(1.0, 0.1, 0.0088265387493977277449, 1e-17, pdf(-1.5));
+        test_almost(0.0, 0.01, 31.831253876175456972, 1e-13, pdf(0.0));
+        test_almost(0.0, 1000.0, 0.15915494309189533577, 1e-16, pdf(2.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: WrappedCauchy| x.ln_pdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(-4.0));
+        test_almost(0.0, 1.0, -2.3489879531200375595, 1e-15, ln_pdf(2.0));
+        test_almost(0.0, 0.01, -7.131186579579687207, 1e-14, ln_pdf(3.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        let cdf = |arg: f64| move |x: WrappedCauchy| x.cdf(arg);
+        test_case(0.0, 1.0, 0.0, cdf(-4.0));
+        test_case(0.0, 1.0, 1.0, cdf(4.0));
+        test_almost(0.0, 1.0, 0.5, 1e-16, cdf(0.0));
+        test_almost(0.0, 1.0, 0.091815455871518617473, 1e-16, cdf(-2.0));
+        test_almost(0.0, 1.0, 0.77651181024723701663, 1e-15, cdf(1.0));
+        test_almost(1.0, 0.1, 0.061501333119882886625, 1e-16, cdf(0.5));
+        test_almost(0.0, 0.01, 0.00011286353483590001398, 1e-19, cdf(-3.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: WrappedCauchy| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(-4.0));
+        test_case(0.0, 1.0, 0.0, sf(4.0));
+        test_almost(0.0, 1.0, 0.90818454412848138253, 1e-15, sf(-2.0));
+        test_almost(0.0, 1.0, 0.22348818975276298337, 1e-15, sf(1.0));
+        test_almost(0.0, 0.01, 0.00011286353483590001398, 1e-19, sf(3.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: WrappedCauchy| x.inverse_cdf(arg);
+        test_case(1.0, 2.0, 1.0 - f64::consts::PI, inverse_cdf(0.0));
+        test_case(1.0, 2.0, 1.0, inverse_cdf(0.5));
+        test_case(1.0, 2.0, 1.0 + f64::consts::PI, inverse_cdf(1.0));
+        tests::check_inverse_cdf(&try_create(0.0, 1.0), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.0, 0.05), 1e-12);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let n = try_create(1.0, 0.5);
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|&x| x >= n.min() && x <= n.max()));
+        assert!(((&samples).resultant_length() - n.mean_resultant_length()).abs() < 0.02);
+        let below = samples.iter().filter(|&&x| x <= 1.5).count() as f64 / count as f64;
+        assert!((below - n.cdf(1.5)).abs() < 0.015);
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 1.0), -f64::consts::PI, f64::consts::PI);
+        tests::check_continuous_distribution(&try_create(1.0, 0.2), 1.0 - f64::consts::PI, 1.0 + f64::consts::PI);
+    }
+}
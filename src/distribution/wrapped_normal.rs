@@ -0,0 +1,557 @@
+use crate::consts;
+use crate::distribution::{internal, normal, Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Scale at or above which the density and cdf are summed from their Fourier
+/// series, which then needs fewer than ten terms. Below it the series over
+/// the wrapped copies of the normal density is used, which needs only a few
+/// copies and keeps the relative accuracy of the far tails
+const FOURIER_SCALE_BOUND: f64 = 1.0;
+
+/// Implements the [wrapped normal](https://en.wikipedia.org/wiki/Wrapped_normal_distribution)
+/// distribution on the circle, with support `[μ - π, μ + π]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{WrappedNormal, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = WrappedNormal::new(0.0, 1.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 0.0);
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.39894228253600366172, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WrappedNormal {
+    location: f64,
+    scale: f64,
+}
+
+impl WrappedNormal {
+    /// Constructs a new wrapped normal distribution with a location (μ) of
+    /// `location` and a scale (σ) of `scale`, which are the mean and
+    /// standard deviation of the normal distribution that is wrapped around
+    /// the circle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` or `scale` are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let mut result = WrappedNormal::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = WrappedNormal::new(0.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<WrappedNormal> {
+        if !location.is_finite() || !scale.is_finite() || scale <= 0.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(WrappedNormal { location, scale })
+        }
+    }
+
+    /// Returns the location of the wrapped normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let n = WrappedNormal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the wrapped normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let n = WrappedNormal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the mean resultant length of the wrapped normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-σ^2 / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let n = WrappedNormal::new(0.0, 2.0).unwrap();
+    /// assert_eq!(n.mean_resultant_length(), (-2.0f64).exp());
+    /// ```
+    pub fn mean_resultant_length(&self) -> f64 {
+        (-0.5 * self.scale * self.scale).exp()
+    }
+
+    /// Returns the circular variance of the wrapped normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-σ^2 / 2)
+    /// ```
+    ///
+    /// where `σ` is the scale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::WrappedNormal;
+    ///
+    /// let n = WrappedNormal::new(0.0, 2.0).unwrap();
+    /// assert_eq!(n.circular_variance(), -(-2.0f64).exp_m1());
+    /// ```
+    pub fn circular_variance(&self) -> f64 {
+        -(-0.5 * self.scale * self.scale).exp_m1()
+    }
+
+    /// Returns the Fourier coefficients `e^(-n^2 * σ^2 / 2)` for
+    /// `n = 1, 2, ...`
+    fn coefficients(&self) -> impl Iterator<Item = f64> {
+        let h = -0.5 * self.scale * self.scale;
+        (1..).map(move |n: u64| (h * (n * n) as f64).exp())
+    }
+
+    /// Returns the range `-k..=k` of the wrapped copies `θ + 2πk` of the
+    /// normal density that contribute at `θ` in `[-π, π]`
+    fn copies(&self) -> std::ops::RangeInclusive<i64> {
+        let k = ((9.0 * self.scale + f64::consts::PI) / (2.0 * f64::consts::PI)).ceil() as i64;
+        -k..=k
+    }
+
+    /// Computes the cdf at `θ` in `[-π, π]` of the distribution centred at
+    /// zero
+    fn cdf_centered(&self, theta: f64) -> f64 {
+        if self.scale >= FOURIER_SCALE_BOUND {
+            internal::circular_cdf_series(theta, self.coefficients())
+        } else {
+            // each wrapped copy contributes the normal probability of
+            // [2πk - π, 2πk + θ], which is taken from the tail it lies in
+            let two_pi = 2.0 * f64::consts::PI;
+            self.copies()
+                .map(|k| {
+                    let lo = two_pi * k as f64 - f64::consts::PI;
+                    let hi = two_pi * k as f64 + theta;
+                    if lo > 0.0 {
+                        normal::sf_unchecked(lo, 0.0, self.scale)
+                            - normal::sf_unchecked(hi, 0.0, self.scale)
+                    } else {
+                        normal::cdf_unchecked(hi, 0.0, self.scale)
+                            - normal::cdf_unchecked(lo, 0.0, self.scale)
+                    }
+                })
+                .sum()
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for WrappedNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.location, self.scale)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for WrappedNormal {
+    /// Calculates the cumulative distribution function for the
+    /// wrapped normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(Φ((x - μ + 2πk) / σ) - Φ((2πk - π) / σ), k=-INF..INF)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `Φ` is the standard
+    /// normal cdf. For large scales the equivalent Fourier series is used
+    /// instead
+    ///
+    /// ```ignore
+    /// 1 / 2 + (x - μ) / (2π) + 1 / π * sum(e^(-n^2 * σ^2 / 2) * sin(n * (x - μ)) / n, n=1..)
+    /// ```
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= self.max() {
+            1.0
+        } else {
+            self.cdf_centered(x - self.location)
+        }
+    }
+
+    /// Calculates the survival function for the
+    /// wrapped normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(Φ((μ - x + 2πk) / σ) - Φ((2πk - π) / σ), k=-INF..INF)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `Φ` is the standard
+    /// normal cdf
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            self.cdf_centered(self.location - x)
+        }
+    }
+}
+
+impl Min<f64> for WrappedNormal {
+    /// Returns the minimum value in the domain of the
+    /// wrapped normal distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - π
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location - f64::consts::PI
+    }
+}
+
+impl Max<f64> for WrappedNormal {
+    /// Returns the maximum value in the domain of the
+    /// wrapped normal distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + π
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn max(&self) -> f64 {
+        self.location + f64::consts::PI
+    }
+}
+
+impl Distribution<f64> for WrappedNormal {
+    /// Returns the mean of the wrapped normal distribution, which is also
+    /// its circular mean
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mean(&self) -> Option<f64> {
+        Some(self.location)
+    }
+    /// Returns the variance of the wrapped normal distribution
+    ///
+    /// # Remarks
+    ///
+    /// Always returns `None` since the spread of an angle is measured on the
+    /// circle rather than the line, see `circular_variance`
+    fn variance(&self) -> Option<f64> {
+        None
+    }
+}
+
+impl Median<f64> for WrappedNormal {
+    /// Returns the median of the wrapped normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn median(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Mode<Option<f64>> for WrappedNormal {
+    /// Returns the mode of the wrapped normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for WrappedNormal {
+    /// Calculates the probability density function for the wrapped normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum(e^(-(x - μ + 2πk)^2 / (2σ^2)), k=-INF..INF) / (σ * sqrt(2π))
+    /// ```
+    ///
+    /// where `μ` is the location and `σ` is the scale. For large scales the
+    /// equivalent Fourier series is used instead
+    ///
+    /// ```ignore
+    /// (1 + 2 * sum(e^(-n^2 * σ^2 / 2) * cos(n * (x - μ)), n=1..)) / (2π)
+    /// ```
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            0.0
+        } else if self.scale >= FOURIER_SCALE_BOUND {
+            let theta = x - self.location;
+            let sum: f64 = (1..)
+                .zip(self.coefficients())
+                .take_while(|&(_, rho)| rho >= f64::EPSILON / 4.0)
+                .map(|(n, rho)| rho * (n as f64 * theta).cos())
+                .sum();
+            (1.0 + 2.0 * sum) / (2.0 * f64::consts::PI)
+        } else {
+            self.ln_pdf(x).exp()
+        }
+    }
+
+    /// Calculates the log probability density function for the wrapped
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(sum(e^(-(x - μ + 2πk)^2 / (2σ^2)), k=-INF..INF) / (σ * sqrt(2π)))
+    /// ```
+    ///
+    /// where `μ` is the location and `σ` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.min() || x > self.max() {
+            f64::NEG_INFINITY
+        } else if self.scale >= FOURIER_SCALE_BOUND {
+            self.pdf(x).ln()
+        } else {
+            let theta = x - self.location;
+            self.copies()
+                .map(|k| {
+                    let z = (theta + 2.0 * f64::consts::PI * k as f64) / self.scale;
+                    -0.5 * z * z
+                })
+                .fold(f64::NEG_INFINITY, internal::ln_add_exp)
+                - self.scale.ln()
+                - consts::LN_SQRT_2PI
+        }
+    }
+}
+
+/// draws a sample from a wrapped normal distribution with the given location
+/// and scale by wrapping a normal variate around the circle
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, location: f64, scale: f64) -> f64 {
+    location + internal::wrap_angle(normal::sample_unchecked(rng, 0.0, scale))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, WrappedNormal};
+    use crate::statistics::*;
+
+    fn try_create(location: f64, scale: f64) -> WrappedNormal {
+        let n = WrappedNormal::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn create_case(location: f64, scale: f64) {
+        let n = try_create(location, scale);
+        assert_eq!(location, n.location());
+        assert_eq!(scale, n.scale());
+    }
+
+    fn bad_create_case(location: f64, scale: f64) {
+        let n = WrappedNormal::new(location, scale);
+        assert!(n.is_err());
+    }
+
+    fn get_value<F>(location: f64, scale: f64, eval: F) -> f64
+    where
+        F: Fn(WrappedNormal) -> f64,
+    {
+        let n = try_create(location, scale);
+        eval(n)
+    }
+
+    fn test_case<F>(location: f64, scale: f64, expected: f64, eval: F)
+    where
+        F: Fn(WrappedNormal) -> f64,
+    {
+        let x = get_value(location, scale, eval);
+        assert_eq!(expected, x);
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+    where
+        F: Fn(WrappedNormal) -> f64,
+    {
+        let x = get_value(location, scale, eval);
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        create_case(0.0, 0.1);
+        create_case(1.0, 2.0);
+        create_case(-3.0, 1000.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(0.0, 0.0);
+        bad_create_case(0.0, -1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(0.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_mean_resultant_length() {
+        let r = |x: WrappedNormal| x.mean_resultant_length();
+        test_almost(0.0, 1.0, 0.60653065971263342360, 1e-16, r);
+        let v = |x: WrappedNormal| x.circular_variance();
+        test_almost(0.0, 1.0, 0.39346934028736657640, 1e-16, v);
+        test_almost(0.0, 1e-6, 4.99999999999875e-13, 1e-27, v);
+    }
+
+    #[test]
+    fn test_mean() {
+        let mean = |x: WrappedNormal| x.mean().unwrap();
+        test_case(1.5, 2.0, 1.5, mean);
+        assert!(try_create(1.5, 2.0).variance().is_none());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        let median = |x: WrappedNormal| x.median();
+        let mode = |x: WrappedNormal| x.mode().unwrap();
+        test_case(1.5, 2.0, 1.5, median);
+        test_case(1.5, 2.0, 1.5, mode);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let min = |x: WrappedNormal| x.min();
+        let max = |x: WrappedNormal| x.max();
+        test_case(1.0, 2.0, 1.0 - f64::consts::PI, min);
+        test_case(1.0, 2.0, 1.0 + f64::consts::PI, max);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let pdf = |arg: f64| move |x: WrappedNormal| x.pdf(arg);
+        test_case(0.0, 1.0, 0.0, pdf(-4.0));
+        test_case(0.0, 1.0, 0.0, pdf(4.0));
+        test_almost(0.0, 1.0, 0.39894228253600366172, 1e-15, pdf(0.0));
+        test_almost(0.0, 1.0, 0.006252444312039641076, 1e-15, pdf(3.0));
+        test_almost(0.0, 0.999, 0.0061993280559052857225, 1e-15, pdf(3.0));
+        test_almost(1.0, 2.5, 0.14795083720372836933, 1e-15, pdf(-1.5));
+        test_almost(0.0, 0.3, 1.0648266850745073626, 1e-14, pdf(0.2));
+        test_almost(0.0, 0.05, 1.1041896724319770946e-86, 1e-99, pdf(-1.0));
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let ln_pdf = |arg: f64| move |x: WrappedNormal| x.ln_pdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_pdf(-4.0));
+        test_almost(0.0, 1.0, -2.9181716849634893823, 1e-14, ln_pdf(2.0));
+        test_almost(0.0, 0.01, -44996.313768347214708, 1e-10, ln_pdf(3.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        // the normal cdf is only accurate to about 1e-10 relative
+        let cdf = |arg: f64| move |x: WrappedNormal| x.cdf(arg);
+        test_case(0.0, 1.0, 0.0, cdf(-4.0));
+        test_case(0.0, 1.0, 1.0, cdf(4.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, cdf(0.0));
+        test_almost(0.0, 1.0, 0.022740920117841146223, 1e-15, cdf(-2.0));
+        test_almost(0.0, 0.999, 0.022633222235324526028, 1e-10, cdf(-2.0));
+        test_almost(0.0, 2.0, 0.69545284832879992747, 1e-15, cdf(1.0));
+        test_almost(1.0, 0.3, 0.047790352272814701723, 1e-10, cdf(0.5));
+        test_almost(0.0, 0.3, 9.8658764503769589217e-10, 1e-19, cdf(-1.8));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: WrappedNormal| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(-4.0));
+        test_case(0.0, 1.0, 0.0, sf(4.0));
+        test_almost(0.0, 1.0, 0.97725907988215885378, 1e-15, sf(-2.0));
+        test_almost(0.0, 2.0, 0.30454715167120007253, 1e-15, sf(1.0));
+        test_almost(0.0, 0.3, 9.8658764503769589217e-10, 1e-19, sf(1.8));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_inverse_cdf(&try_create(0.0, 1.5), 1e-12);
+        tests::check_inverse_cdf(&try_create(2.0, 0.5), 1e-10);
+    }
+
+    #[test]
+    fn test_sample() {
+        use ::rand::distributions::Distribution as RandDistribution;
+        use ::rand::SeedableRng;
+
+        let mut rng = ::rand::rngs::StdRng::seed_from_u64(1);
+        let count = 20_000;
+        for &(location, scale) in &[(1.0, 0.5), (-2.0, 2.0)] {
+            let n = try_create(location, scale);
+            let samples: Vec<f64> = (0..count).map(|_| n.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= n.min() && x <= n.max()));
+            assert!(((&samples).resultant_length() - n.mean_resultant_length()).abs() < 0.02);
+            let x = location + 0.5;
+            let below = samples.iter().filter(|&&s| s <= x).count() as f64 / count as f64;
+            assert!((below - n.cdf(x)).abs() < 0.015);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        tests::check_continuous_distribution(&try_create(0.0, 0.5), -f64::consts::PI, f64::consts::PI);
+        tests::check_continuous_distribution(&try_create(1.0, 1.5), 1.0 - f64::consts::PI, 1.0 + f64::consts::PI);
+    }
+}
@@ -0,0 +1,570 @@
+use crate::distribution::{internal, Discrete, DiscreteCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements a [zero-inflated](https://en.wikipedia.org/wiki/Zero-inflated_model)
+/// distribution, which is zero with probability `π` and otherwise draws
+/// from a discrete parent distribution such as `Poisson`,
+/// `NegativeBinomial` or `Binomial`
+///
+/// # Remarks
+///
+/// The moments are implemented through `Distribution` or
+/// `DiscreteDistribution`, whichever the parent implements
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Discrete, Poisson, ZeroInflated};
+/// use statrs::statistics::Distribution;
+///
+/// let n = ZeroInflated::new(Poisson::new(2.0).unwrap(), 0.25).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.5);
+/// assert!((n.pmf(0) - (0.25 + 0.75 * (-2.0f64).exp())).abs() < 1e-15);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ZeroInflated<D> {
+    dist: D,
+    pi: f64,
+}
+
+impl<D> ZeroInflated<D> {
+    /// Constructs a new zero-inflated distribution that is zero with
+    /// probability `pi` and otherwise draws from `dist`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pi` is `NaN`, less than `0.0` or greater than
+    /// `1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Poisson, ZeroInflated};
+    ///
+    /// let mut result = ZeroInflated::new(Poisson::new(2.0).unwrap(), 0.25);
+    /// assert!(result.is_ok());
+    ///
+    /// result = ZeroInflated::new(Poisson::new(2.0).unwrap(), 1.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, pi: f64) -> Result<ZeroInflated<D>> {
+        if !(0.0..=1.0).contains(&pi) {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(ZeroInflated { dist, pi })
+        }
+    }
+
+    /// Returns the parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Poisson, ZeroInflated};
+    ///
+    /// let d = Poisson::new(2.0).unwrap();
+    /// let n = ZeroInflated::new(d, 0.25).unwrap();
+    /// assert_eq!(*n.parent(), d);
+    /// ```
+    pub fn parent(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the probability `π` of a structural zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Poisson, ZeroInflated};
+    ///
+    /// let n = ZeroInflated::new(Poisson::new(2.0).unwrap(), 0.25).unwrap();
+    /// assert_eq!(n.pi(), 0.25);
+    /// ```
+    pub fn pi(&self) -> f64 {
+        self.pi
+    }
+
+    /// Returns the central moments up to `moments.len() - 1` from the
+    /// parent's mean and central moments
+    fn central_moments(&self, mean: f64, moments: &[f64]) -> Vec<f64> {
+        internal::zero_mixture_central_moments(1.0 - self.pi, mean, moments)
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<u64> for ZeroInflated<D>
+where
+    D: ::rand::distributions::Distribution<u64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        if rng.gen::<f64>() < self.pi {
+            0
+        } else {
+            self.dist.sample(rng)
+        }
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<f64> for ZeroInflated<D>
+where
+    D: ::rand::distributions::Distribution<u64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        <Self as ::rand::distributions::Distribution<u64>>::sample(self, rng) as f64
+    }
+}
+
+impl<D> DiscreteCDF<u64, f64> for ZeroInflated<D>
+where
+    D: DiscreteCDF<u64, f64>,
+{
+    /// Calculates the cumulative distribution function for the
+    /// zero-inflated distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// π + (1 - π) * F(x)
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution
+    fn cdf(&self, x: u64) -> f64 {
+        self.pi + (1.0 - self.pi) * self.dist.cdf(x)
+    }
+
+    /// Calculates the survival function for the
+    /// zero-inflated distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * S(x)
+    /// ```
+    ///
+    /// where `S` is the survival function of the parent distribution
+    fn sf(&self, x: u64) -> f64 {
+        (1.0 - self.pi) * self.dist.sf(x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// zero-inflated distribution at `p`, i.e. the smallest `k` such that
+    /// `cdf(k) >= p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The search starts from the parent quantile at `(p - π) / (1 - π)`
+    fn inverse_cdf(&self, p: f64) -> u64 {
+        let guess = if p > self.pi && p < 1.0 {
+            self.dist.inverse_cdf((p - self.pi) / (1.0 - self.pi))
+        } else {
+            self.min()
+        };
+        internal::discrete_inverse_cdf(self, p, guess)
+    }
+}
+
+impl<D> Min<u64> for ZeroInflated<D> {
+    /// Returns the minimum value in the domain of the
+    /// zero-inflated distribution representable by a 64-bit
+    /// integer
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> u64 {
+        0
+    }
+}
+
+impl<D> Max<u64> for ZeroInflated<D>
+where
+    D: Max<u64>,
+{
+    /// Returns the maximum value of the parent distribution
+    fn max(&self) -> u64 {
+        self.dist.max()
+    }
+}
+
+impl<D> Distribution<f64> for ZeroInflated<D>
+where
+    D: Distribution<f64> + ::rand::distributions::Distribution<u64>,
+{
+    /// Returns the mean of the zero-inflated distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * μ
+    /// ```
+    ///
+    /// where `μ` is the mean of the parent distribution
+    fn mean(&self) -> Option<f64> {
+        self.dist.mean().map(|m| (1.0 - self.pi) * m)
+    }
+    /// Returns the variance of the zero-inflated distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * (σ^2 + π * μ^2)
+    /// ```
+    ///
+    /// where `μ` and `σ^2` are the mean and variance of the parent
+    /// distribution
+    fn variance(&self) -> Option<f64> {
+        self.central_moment(2)
+    }
+    /// Returns the skewness of the zero-inflated distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_3 / m_2^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the zero-inflated
+    /// distribution
+    fn skewness(&self) -> Option<f64> {
+        let variance = self.central_moment(2)?;
+        if variance == 0.0 {
+            return None;
+        }
+        Some(self.central_moment(3)? / (variance * variance.sqrt()))
+    }
+    /// Returns the excess kurtosis of the zero-inflated distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_4 / m_2^2 - 3
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the zero-inflated
+    /// distribution
+    fn kurtosis(&self) -> Option<f64> {
+        let variance = self.central_moment(2)?;
+        if variance == 0.0 {
+            return None;
+        }
+        Some(self.central_moment(4)? / (variance * variance) - 3.0)
+    }
+    /// Returns the `k`th central moment of the zero-inflated distribution,
+    /// if the parent's central moments up to order `k` are known
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * Σ_j C(k, j) * c_j * (πμ)^(k - j) + π * (-(1 - π) * μ)^k
+    /// ```
+    ///
+    /// where `μ` and `c_j` are the mean and `j`th central moment of the
+    /// parent distribution
+    fn central_moment(&self, k: u32) -> Option<f64> {
+        let mean = self.dist.mean()?;
+        let moments = (0..=k)
+            .map(|j| self.dist.central_moment(j))
+            .collect::<Option<Vec<f64>>>()?;
+        Some(self.central_moments(mean, &moments)[k as usize])
+    }
+}
+
+impl<D> DiscreteDistribution<f64> for ZeroInflated<D>
+where
+    D: DiscreteDistribution<f64>,
+{
+    /// Returns the mean of the zero-inflated distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * μ
+    /// ```
+    ///
+    /// where `μ` is the mean of the parent distribution
+    fn mean(&self) -> Option<f64> {
+        self.dist.mean().map(|m| (1.0 - self.pi) * m)
+    }
+    /// Returns the variance of the zero-inflated distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - π) * (σ^2 + π * μ^2)
+    /// ```
+    ///
+    /// where `μ` and `σ^2` are the mean and variance of the parent
+    /// distribution
+    fn variance(&self) -> Option<f64> {
+        let moments = [1.0, 0.0, self.dist.variance()?];
+        Some(self.central_moments(self.dist.mean()?, &moments)[2])
+    }
+    /// Returns the skewness of the zero-inflated distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_3 / m_2^(3 / 2)
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the zero-inflated
+    /// distribution
+    fn skewness(&self) -> Option<f64> {
+        let variance = self.dist.variance()?;
+        let third = self.dist.skewness()? * variance * variance.sqrt();
+        let m = self.central_moments(self.dist.mean()?, &[1.0, 0.0, variance, third]);
+        if m[2] == 0.0 {
+            return None;
+        }
+        Some(m[3] / (m[2] * m[2].sqrt()))
+    }
+    /// Returns the excess kurtosis of the zero-inflated distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if the variance is zero, e.g. when `π == 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m_4 / m_2^2 - 3
+    /// ```
+    ///
+    /// where `m_k` is the `k`th central moment of the zero-inflated
+    /// distribution
+    fn kurtosis(&self) -> Option<f64> {
+        let variance = self.dist.variance()?;
+        let third = self.dist.skewness()? * variance * variance.sqrt();
+        let fourth = (self.dist.kurtosis()? + 3.0) * variance * variance;
+        let m = self.central_moments(self.dist.mean()?, &[1.0, 0.0, variance, third, fourth]);
+        if m[2] == 0.0 {
+            return None;
+        }
+        Some(m[4] / (m[2] * m[2]) - 3.0)
+    }
+}
+
+impl<D> Discrete<u64, f64> for ZeroInflated<D>
+where
+    D: Discrete<u64, f64>,
+{
+    /// Calculates the probability mass function for the zero-inflated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x == 0 {
+    ///     π + (1 - π) * p(0)
+    /// } else {
+    ///     (1 - π) * p(x)
+    /// }
+    /// ```
+    ///
+    /// where `p` is the pmf of the parent distribution
+    fn pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            self.pi + (1.0 - self.pi) * self.dist.pmf(0)
+        } else {
+            (1.0 - self.pi) * self.dist.pmf(x)
+        }
+    }
+
+    /// Calculates the log probability mass function for the zero-inflated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x == 0 {
+    ///     ln(π + (1 - π) * p(0))
+    /// } else {
+    ///     ln(1 - π) + ln(p(x))
+    /// }
+    /// ```
+    ///
+    /// where `p` is the pmf of the parent distribution
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x == 0 {
+            self.pmf(0).ln()
+        } else {
+            (-self.pi).ln_1p() + self.dist.ln_pmf(x)
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{Binomial, Discrete, DiscreteCDF, NegativeBinomial, Poisson, ZeroInflated};
+    use crate::distribution::internal::*;
+
+    fn try_create<D>(dist: D, pi: f64) -> ZeroInflated<D> {
+        let n = ZeroInflated::new(dist, pi);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case<D>(dist: D, pi: f64) {
+        let n = ZeroInflated::new(dist, pi);
+        assert!(n.is_err());
+    }
+
+    fn poisson() -> ZeroInflated<Poisson> {
+        try_create(Poisson::new(2.5).unwrap(), 0.3)
+    }
+
+    fn negative_binomial() -> ZeroInflated<NegativeBinomial> {
+        try_create(NegativeBinomial::new(3.0, 0.4).unwrap(), 0.2)
+    }
+
+    fn binomial() -> ZeroInflated<Binomial> {
+        try_create(Binomial::new(0.6, 10).unwrap(), 0.15)
+    }
+
+    #[test]
+    fn test_create() {
+        let n = poisson();
+        assert_eq!(*n.parent(), Poisson::new(2.5).unwrap());
+        assert_eq!(n.pi(), 0.3);
+        try_create(Poisson::new(2.5).unwrap(), 0.0);
+        try_create(Poisson::new(2.5).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_bad_create() {
+        bad_create_case(Poisson::new(2.5).unwrap(), -0.1);
+        bad_create_case(Poisson::new(2.5).unwrap(), 1.1);
+        bad_create_case(Poisson::new(2.5).unwrap(), f64::NAN);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = poisson();
+        assert_almost_eq!(n.mean().unwrap(), 1.75, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 3.0625, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), 0.81632653061224489796, 1e-14);
+        assert_almost_eq!(n.kurtosis().unwrap(), 0.099125364431486880466, 1e-13);
+
+        let n = negative_binomial();
+        assert_almost_eq!(n.mean().unwrap(), 3.6, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 12.24, 1e-13);
+        assert_almost_eq!(n.skewness().unwrap(), 1.2038528133532973291, 1e-13);
+        assert_almost_eq!(n.kurtosis().unwrap(), 1.8239138792772010765, 1e-12);
+
+        let n = binomial();
+        assert_almost_eq!(n.mean().unwrap(), 5.1, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 6.63, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), -0.83050964304542933259, 1e-13);
+        assert_almost_eq!(n.kurtosis().unwrap(), -0.17287388328112310013, 1e-12);
+    }
+
+    #[test]
+    fn test_moments_without_inflation() {
+        let d = Poisson::new(2.5).unwrap();
+        let n = try_create(d, 0.0);
+        assert_eq!(n.mean(), d.mean());
+        assert_almost_eq!(n.variance().unwrap(), d.variance().unwrap(), 1e-15);
+        assert_almost_eq!(n.skewness().unwrap(), d.skewness().unwrap(), 1e-15);
+        assert_almost_eq!(n.kurtosis().unwrap(), d.kurtosis().unwrap(), 1e-14);
+    }
+
+    #[test]
+    fn test_moments_all_zeros() {
+        let n = try_create(Poisson::new(2.5).unwrap(), 1.0);
+        assert_eq!(n.variance(), Some(0.0));
+        assert_eq!(n.skewness(), None);
+        assert_eq!(n.kurtosis(), None);
+        let n = try_create(NegativeBinomial::new(3.0, 0.4).unwrap(), 1.0);
+        assert_eq!(n.variance(), Some(0.0));
+        assert_eq!(n.skewness(), None);
+        assert_eq!(n.kurtosis(), None);
+    }
+
+    #[test]
+    fn test_pmf() {
+        let n = poisson();
+        assert_almost_eq!(n.pmf(0), 0.35745949903672915662, 1e-15);
+        assert_almost_eq!(n.pmf(3), 0.14963411207481551203, 1e-15);
+        let n = negative_binomial();
+        assert_almost_eq!(n.pmf(0), 0.2512, 1e-15);
+        assert_almost_eq!(n.pmf(5), 0.083607552, 1e-14);
+        let n = binomial();
+        assert_almost_eq!(n.pmf(0), 0.15008912896, 1e-15);
+        assert_almost_eq!(n.pmf(10), 0.00513962496, 1e-15);
+        assert_eq!(n.pmf(11), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let n = poisson();
+        assert_almost_eq!(n.ln_pmf(0), -1.0287332126735549836, 1e-14);
+        assert_almost_eq!(n.ln_pmf(3), -1.8995622175443221842, 1e-14);
+        let n = negative_binomial();
+        assert_almost_eq!(n.ln_pmf(10), -3.8906172425701562385, 1e-13);
+        assert_eq!(binomial().ln_pmf(11), f64::NEG_INFINITY);
+        assert_eq!(try_create(Poisson::new(2.5).unwrap(), 1.0).ln_pmf(3), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = poisson();
+        assert_almost_eq!(n.cdf(0), 0.35745949903672915662, 1e-15);
+        assert_almost_eq!(n.cdf(3), 0.83030329319314617463, 1e-15);
+        assert_almost_eq!(n.sf(3), 0.16969670680685382537, 1e-15);
+        assert_almost_eq!(n.sf(20), 2.8829872081374533525e-13, 1e-25);
+        let n = negative_binomial();
+        assert_almost_eq!(n.cdf(10), 0.95367807188992, 1e-14);
+        assert_almost_eq!(n.sf(10), 0.04632192811008, 1e-14);
+        let n = binomial();
+        assert_almost_eq!(n.cdf(5), 0.46186223104, 1e-15);
+        assert_eq!(n.cdf(10), 1.0);
+        assert_eq!(n.sf(10), 0.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        tests::check_discrete_inverse_cdf(&poisson());
+        tests::check_discrete_inverse_cdf(&negative_binomial());
+        tests::check_discrete_inverse_cdf(&binomial());
+        assert_eq!(poisson().inverse_cdf(0.35), 0);
+        assert_eq!(poisson().inverse_cdf(0.36), 1);
+    }
+
+    #[test]
+    fn test_discrete() {
+        tests::check_discrete_distribution(&poisson(), 20);
+        tests::check_discrete_distribution(&negative_binomial(), 60);
+        tests::check_discrete_distribution(&binomial(), 10);
+    }
+
+    #[test]
+    fn test_sample() {
+        tests::check_discrete_samples(&poisson());
+        tests::check_discrete_samples(&binomial());
+        tests::check_goodness_of_fit(&poisson(), 0, 12, 10_000);
+        tests::check_goodness_of_fit(&negative_binomial(), 0, 30, 10_000);
+        tests::check_goodness_of_fit(&binomial(), 0, 10, 10_000);
+    }
+}
@@ -0,0 +1,356 @@
+//! Provides the [modified Bessel
+//! functions](https://en.wikipedia.org/wiki/Bessel_function#Modified_Bessel_functions:_I%CE%B1,_K%CE%B1)
+//! and related functions
+
+use crate::consts;
+use std::f64;
+
+/// Argument below which `K1` is evaluated from its power series, above it
+/// the continued fraction is used instead
+const K1_SERIES_BOUND: f64 = 2.0;
+
+/// Argument below which `I0` and `I1` are evaluated from their power series,
+/// above it the asymptotic expansion is used instead. The smallest term of
+/// the expansion is of order `e^(-2x)` there, far below the precision
+const I_SERIES_BOUND: f64 = 30.0;
+
+/// Computes the modified Bessel function of the first kind of order zero,
+/// `I0(x)`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `x` is `NaN`. `I0(x)` grows like `e^|x|` so it
+/// overflows for `|x| > ~713`; use `bessel_i0e` when large arguments are
+/// expected.
+pub fn bessel_i0(x: f64) -> f64 {
+    let x = x.abs();
+    if x.is_nan() || x == f64::INFINITY {
+        x
+    } else if x <= I_SERIES_BOUND {
+        i_series(0.0, x)
+    } else {
+        i_asymptotic(0.0, x) * x.exp()
+    }
+}
+
+/// Computes the exponentially scaled modified Bessel function of the first
+/// kind of order zero, `e^-|x| * I0(x)`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `x` is `NaN`
+pub fn bessel_i0e(x: f64) -> f64 {
+    let x = x.abs();
+    if x.is_nan() {
+        f64::NAN
+    } else if x <= I_SERIES_BOUND {
+        i_series(0.0, x) * (-x).exp()
+    } else {
+        i_asymptotic(0.0, x)
+    }
+}
+
+/// Computes the modified Bessel function of the first kind of order one,
+/// `I1(x)`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `x` is `NaN`. `I1(x)` is odd and grows like `e^|x|`
+/// so it overflows for `|x| > ~713`; use `bessel_i1e` when large arguments
+/// are expected.
+pub fn bessel_i1(x: f64) -> f64 {
+    let ax = x.abs();
+    let i1 = if x.is_nan() || ax == f64::INFINITY {
+        ax
+    } else if ax <= I_SERIES_BOUND {
+        i_series(1.0, ax)
+    } else {
+        i_asymptotic(1.0, ax) * ax.exp()
+    };
+    if x < 0.0 {
+        -i1
+    } else {
+        i1
+    }
+}
+
+/// Computes the exponentially scaled modified Bessel function of the first
+/// kind of order one, `e^-|x| * I1(x)`
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if `x` is `NaN`
+pub fn bessel_i1e(x: f64) -> f64 {
+    let ax = x.abs();
+    let i1e = if x.is_nan() {
+        f64::NAN
+    } else if ax <= I_SERIES_BOUND {
+        i_series(1.0, ax) * (-ax).exp()
+    } else {
+        i_asymptotic(1.0, ax)
+    };
+    if x < 0.0 {
+        -i1e
+    } else {
+        i1e
+    }
+}
+
+/// Evaluates `I_n(x)` for `n` zero or one and `x >= 0` from the power series
+///
+/// ```ignore
+/// I_n(x) = (x / 2)^n * Σ(x^2 / 4)^k / (k! * (k + n)!)
+/// ```
+///
+/// whose terms are all positive
+fn i_series(n: f64, x: f64) -> f64 {
+    let y = 0.25 * x * x;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 0.0;
+    loop {
+        k += 1.0;
+        term *= y / (k * (k + n));
+        sum += term;
+        if term < f64::EPSILON * sum {
+            break;
+        }
+    }
+    if n == 0.0 {
+        sum
+    } else {
+        0.5 * x * sum
+    }
+}
+
+/// Evaluates `e^-x * I_n(x)` for `n` zero or one and large `x` from the
+/// asymptotic expansion
+///
+/// ```ignore
+/// e^-x * I_n(x) = 1 / sqrt(2πx) * Σ(-1)^k * a_k(n) / x^k
+/// ```
+///
+/// from Abramowitz and Stegun 9.7.1, where
+/// `a_k(n) = (4n^2 - 1^2) * (4n^2 - 3^2) * ... * (4n^2 - (2k - 1)^2) / (k! * 8^k)`
+fn i_asymptotic(n: f64, x: f64) -> f64 {
+    let mu = 4.0 * n * n;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 0.0;
+    loop {
+        k += 1.0;
+        let odd = 2.0 * k - 1.0;
+        term *= -(mu - odd * odd) / (8.0 * k * x);
+        sum += term;
+        if term.abs() < f64::EPSILON * sum {
+            break;
+        }
+    }
+    sum / (2.0 * f64::consts::PI * x).sqrt()
+}
+
+/// Computes the modified Bessel function of the second kind of order one,
+/// `K1(x)`
+///
+/// # Remarks
+///
+/// Returns `f64::INFINITY` for `x == 0.0` and `f64::NAN` if `x < 0.0`.
+/// `K1(x)` decays like `e^-x` so it underflows for `x > ~705`; use
+/// `bessel_k1e` when large arguments are expected.
+pub fn bessel_k1(x: f64) -> f64 {
+    if x <= K1_SERIES_BOUND {
+        k1_series(x)
+    } else {
+        k1e_continued_fraction(x) * (-x).exp()
+    }
+}
+
+/// Computes the exponentially scaled modified Bessel function of the second
+/// kind of order one, `e^x * K1(x)`
+///
+/// # Remarks
+///
+/// Returns `f64::INFINITY` for `x == 0.0` and `f64::NAN` if `x < 0.0`
+pub fn bessel_k1e(x: f64) -> f64 {
+    if x <= K1_SERIES_BOUND {
+        k1_series(x) * x.exp()
+    } else {
+        k1e_continued_fraction(x)
+    }
+}
+
+/// Evaluates `K1(x)` for small `x` using the series expansion
+///
+/// ```ignore
+/// K1(x) = 1 / x + ln(x / 2) * I1(x)
+///     - (x / 4) * Σ(ψ(k + 1) + ψ(k + 2)) * (x^2 / 4)^k / (k! * (k + 1)!)
+/// ```
+///
+/// from Abramowitz and Stegun 9.6.11, where `ψ` is the digamma function
+fn k1_series(x: f64) -> f64 {
+    if x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::INFINITY;
+    }
+
+    let y = 0.25 * x * x;
+    // ψ(k + 1) + ψ(k + 2) for k = 0
+    let mut psi = 1.0 - 2.0 * consts::EULER_MASCHERONI;
+    let mut term = 1.0;
+    let mut i1 = 1.0;
+    let mut sum = psi;
+    let mut k = 0.0;
+    loop {
+        k += 1.0;
+        term *= y / (k * (k + 1.0));
+        psi += 1.0 / k + 1.0 / (k + 1.0);
+        i1 += term;
+        sum += psi * term;
+        if term < f64::EPSILON * i1 {
+            break;
+        }
+    }
+    1.0 / x + 0.5 * x * (i1 * (0.5 * x).ln() - 0.5 * sum)
+}
+
+/// Evaluates `e^x * K1(x)` for `x >= 2` using Steed's method on the
+/// continued fraction of Temme. Implementation from
+/// <br />
+/// <div>
+/// <i>"Numerical Recipes in C: The Art of Scientific Computing", 2nd
+/// Edition</i> - Press, W.H. et al. 1992, Section 6.7
+/// </div>
+fn k1e_continued_fraction(x: f64) -> f64 {
+    let max_iter = 1000;
+
+    let mut b = 2.0 * (1.0 + x);
+    let mut d = 1.0 / b;
+    let mut h = d;
+    let mut delh = d;
+    let mut q1 = 0.0;
+    let mut q2 = 1.0;
+    let a1 = 0.25;
+    let mut q = a1;
+    let mut c = a1;
+    let mut a = -a1;
+    let mut s = 1.0 + q * delh;
+    for i in 2..max_iter {
+        let i = i as f64;
+        a -= 2.0 * (i - 1.0);
+        c = -a * c / i;
+        let qnew = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = qnew;
+        q += c * qnew;
+        b += 2.0;
+        d = 1.0 / (b + a * d);
+        delh *= b * d - 1.0;
+        h += delh;
+        let dels = q * delh;
+        s += dels;
+        if (dels / s).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    let k0e = (f64::consts::FRAC_PI_2 / x).sqrt() / s;
+    k0e * (x + 0.5 - a1 * h) / x
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    #[test]
+    fn test_bessel_i0() {
+        assert!(super::bessel_i0(f64::NAN).is_nan());
+        assert_eq!(super::bessel_i0(0.0), 1.0);
+        assert_eq!(super::bessel_i0(f64::INFINITY), f64::INFINITY);
+        assert_almost_eq!(super::bessel_i0(1e-5), 1.000000000025, 1e-16);
+        assert_almost_eq!(super::bessel_i0(0.5), 1.0634833707413235193, 1e-15);
+        assert_almost_eq!(super::bessel_i0(-1.0), 1.2660658777520083356, 1e-15);
+        assert_almost_eq!(super::bessel_i0(5.0), 27.239871823604446895, 1e-13);
+        assert_almost_eq!(super::bessel_i0(29.9), 708478330489.01452607, 1e-3);
+        assert_almost_eq!(super::bessel_i0(30.1), 862432920031.77921249, 1e-3);
+        assert_almost_eq!(super::bessel_i0(100.0), 1.0737517071310738235e+42, 1e27);
+    }
+
+    #[test]
+    fn test_bessel_i0e() {
+        assert!(super::bessel_i0e(f64::NAN).is_nan());
+        assert_eq!(super::bessel_i0e(0.0), 1.0);
+        assert_eq!(super::bessel_i0e(f64::INFINITY), 0.0);
+        assert_almost_eq!(super::bessel_i0e(1e-5), 0.99999000007499958333, 1e-15);
+        assert_almost_eq!(super::bessel_i0e(1.0), 0.4657596075936404365, 1e-15);
+        assert_almost_eq!(super::bessel_i0e(-5.0), 0.18354081260932835307, 1e-15);
+        assert_almost_eq!(super::bessel_i0e(29.9), 0.073269219046001907707, 1e-16);
+        assert_almost_eq!(super::bessel_i0e(30.1), 0.073023294131060941854, 1e-16);
+        assert_almost_eq!(super::bessel_i0e(50.0), 0.05656162664745419253, 1e-16);
+        assert_almost_eq!(super::bessel_i0e(700.0), 0.015081295651531357587, 1e-16);
+    }
+
+    #[test]
+    fn test_bessel_i1() {
+        assert!(super::bessel_i1(f64::NAN).is_nan());
+        assert_eq!(super::bessel_i1(0.0), 0.0);
+        assert_eq!(super::bessel_i1(f64::INFINITY), f64::INFINITY);
+        assert_eq!(super::bessel_i1(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_almost_eq!(super::bessel_i1(1e-5), 5.000000000062500409e-6, 1e-20);
+        assert_almost_eq!(super::bessel_i1(0.5), 0.25789430539089631636, 1e-16);
+        assert_almost_eq!(super::bessel_i1(1.0), 0.56515910399248502721, 1e-15);
+        assert_almost_eq!(super::bessel_i1(-1.0), -0.56515910399248502721, 1e-15);
+        assert_almost_eq!(super::bessel_i1(5.0), 24.335642142450527199, 1e-13);
+        assert_almost_eq!(super::bessel_i1(29.9), 696528308361.09269442, 1e-3);
+        assert_almost_eq!(super::bessel_i1(30.1), 847983630191.54142663, 1e-3);
+        assert_almost_eq!(super::bessel_i1(100.0), 1.0683693903381624812e+42, 1e27);
+    }
+
+    #[test]
+    fn test_bessel_i1e() {
+        assert!(super::bessel_i1e(f64::NAN).is_nan());
+        assert_eq!(super::bessel_i1e(0.0), 0.0);
+        assert_almost_eq!(super::bessel_i1e(1e-5), 4.9999500003124989507e-6, 1e-20);
+        assert_almost_eq!(super::bessel_i1e(0.5), 0.15642080318487169714, 1e-16);
+        assert_almost_eq!(super::bessel_i1e(-5.0), -0.16397226694454235693, 1e-15);
+        assert_almost_eq!(super::bessel_i1e(29.9), 0.072033374911868787814, 1e-16);
+        assert_almost_eq!(super::bessel_i1e(30.1), 0.071799854351014333186, 1e-16);
+        assert_almost_eq!(super::bessel_i1e(100.0), 0.039744153025130252674, 1e-16);
+        assert_almost_eq!(super::bessel_i1e(700.0), 0.015070519444716846949, 1e-16);
+    }
+
+    #[test]
+    fn test_bessel_k1() {
+        assert!(super::bessel_k1(f64::NAN).is_nan());
+        assert!(super::bessel_k1(-1.0).is_nan());
+        assert_eq!(super::bessel_k1(0.0), f64::INFINITY);
+        assert_almost_eq!(super::bessel_k1(1e-10), 9999999999.9999999988, 1e-5);
+        assert_almost_eq!(super::bessel_k1(0.1), 9.8538447808706061348, 1e-14);
+        assert_almost_eq!(super::bessel_k1(0.5), 1.6564411200033008937, 1e-15);
+        assert_almost_eq!(super::bessel_k1(1.0), 0.60190723019723457474, 1e-15);
+        assert_almost_eq!(super::bessel_k1(2.0), 0.13986588181652242728, 1e-15);
+        assert_almost_eq!(super::bessel_k1(3.0), 0.040156431128194184377, 1e-16);
+        assert_almost_eq!(super::bessel_k1(10.0), 0.000018648773453825584597, 1e-19);
+        assert_almost_eq!(super::bessel_k1(50.0), 3.4441022267175556126e-23, 1e-37);
+        assert_almost_eq!(super::bessel_k1(700.0), 4.6731107967079661091e-306, 1e-320);
+        assert_eq!(super::bessel_k1(1e4), 0.0);
+    }
+
+    #[test]
+    fn test_bessel_k1e() {
+        assert!(super::bessel_k1e(f64::NAN).is_nan());
+        assert!(super::bessel_k1e(-1.0).is_nan());
+        assert_eq!(super::bessel_k1e(0.0), f64::INFINITY);
+        assert_almost_eq!(super::bessel_k1e(1e-10), 10000000000.999999999, 1e-5);
+        assert_almost_eq!(super::bessel_k1e(0.1), 10.890182683049696574, 1e-14);
+        assert_almost_eq!(super::bessel_k1e(0.5), 2.7310097082117857054, 1e-15);
+        assert_almost_eq!(super::bessel_k1e(1.0), 1.6361534862632582465, 1e-15);
+        assert_almost_eq!(super::bessel_k1e(2.0), 1.0334768470686885732, 1e-15);
+        assert_almost_eq!(super::bessel_k1e(3.0), 0.80656348012878690333, 1e-15);
+        assert_almost_eq!(super::bessel_k1e(10.0), 0.41076657059578875113, 1e-15);
+        assert_almost_eq!(super::bessel_k1e(50.0), 0.1785665585588155746, 1e-15);
+        assert_almost_eq!(super::bessel_k1e(700.0), 0.047396187653494544137, 1e-16);
+        assert_almost_eq!(super::bessel_k1e(1e4), 0.012533611351270505734, 1e-16);
+    }
+}
@@ -3,7 +3,6 @@
 
 use crate::error::StatsError;
 use crate::function::gamma;
-use crate::is_zero;
 use crate::prec;
 use crate::Result;
 use std::f64;
@@ -118,7 +117,7 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
     } else if x < 0.0 || x > 1.0 {
         Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
     } else {
-        let bt = if is_zero(x) || ulps_eq!(x, 1.0) {
+        let bt = if x == 0.0 || x == 1.0 {
             0.0
         } else {
             (gamma::ln_gamma(a + b) - gamma::ln_gamma(a) - gamma::ln_gamma(b)
@@ -295,7 +294,7 @@ pub fn inv_beta_reg(mut a: f64, mut b: f64, mut x: f64) -> f64 {
         x = 1.0 - x;
     }
 
-    p = (-(x * x).ln()).sqrt();
+    p = (-2.0 * x.ln()).sqrt();
     q = p - (2.30753 + 0.27061 * p) / (1.0 + (0.99229 + 0.04481 * p) * p);
 
     if 1.0 < a && 1.0 < b {
@@ -392,6 +391,25 @@ pub fn inv_beta_reg(mut a: f64, mut b: f64, mut x: f64) -> f64 {
         qprev = q;
     }
 
+    // The iteration above converges in absolute terms, which leaves few
+    // correct digits when the result lies close to zero. A few Newton steps
+    // on ln(I(x, p, q)) against ln(x) restore the relative accuracy.
+    for _ in 0..10 {
+        if p <= 0.0 || p >= 1.0 {
+            break;
+        }
+        let ln_ix = beta_reg(a, b, p).ln();
+        if !ln_ix.is_finite() {
+            break;
+        }
+        let ln_pdf = (a - 1.0) * p.ln() + (b - 1.0) * (-p).ln_1p() - ln_beta;
+        let step = (ln_ix - x.ln()) * (ln_ix - p.ln() - ln_pdf).exp();
+        p *= (-step).exp();
+        if step.abs() <= 4.0 * prec::F64_PREC {
+            break;
+        }
+    }
+
     if flip {
         1.0 - p
     } else {
@@ -560,6 +578,16 @@ mod tests {
         assert_eq!(super::beta_reg(2.5, 2.5, 1.0), 1.0);
     }
 
+    #[test]
+    fn test_beta_reg_near_bounds() {
+        assert_eq!(super::beta_reg(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(super::beta_reg(2.0, 0.5, 1.0), 1.0);
+        // x within machine epsilon of 0 or 1 used to be rounded onto the bound
+        assert_almost_eq!(super::beta_reg(2.0, 3.0, 1e-20), 6e-40, 1e-54);
+        assert_almost_eq!(super::beta_reg(0.5, 2.0, 1e-18), 1.5e-9, 1e-23);
+        assert_almost_eq!(1.0 - super::beta_reg(2.0, 0.5, 1.0 - f64::EPSILON / 2.0), 1.580506819158526133521e-8, 1e-15);
+    }
+
     #[test]
     #[should_panic]
     fn test_beta_reg_a_lte_0() {
@@ -603,4 +631,17 @@ mod tests {
     fn test_checked_beta_reg_x_gt_1() {
         assert!(super::checked_beta_reg(1.0, 1.0, 2.0).is_err());
     }
+
+    #[test]
+    fn test_inv_beta_reg() {
+        assert_eq!(super::inv_beta_reg(2.0, 5.0, 0.0), 0.0);
+        assert_eq!(super::inv_beta_reg(2.0, 5.0, 1.0), 1.0);
+        assert_almost_eq!(super::inv_beta_reg(2.0, 5.0, 0.5), 0.2644499832956599623241, 1e-15);
+        // small probabilities used to keep only absolute accuracy, and below
+        // ~1e-154 the initial guess overflowed and the iteration never ended
+        assert_almost_eq!(super::inv_beta_reg(50.0, 30.0, 1e-100), 0.003719458407112630023108, 1e-16);
+        assert_almost_eq!(super::inv_beta_reg(0.5, 0.5, 1e-10), 2.467401100272339654688e-20, 1e-33);
+        assert_almost_eq!(super::inv_beta_reg(0.5, 2.5, 1e-20), 3.469782797257977639434e-41, 1e-54);
+        assert_almost_eq!(super::inv_beta_reg(2.0, 5.0, 1e-300), 2.581988897471611256786e-151, 1e-163);
+    }
 }
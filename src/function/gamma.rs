@@ -3,9 +3,11 @@
 
 use crate::consts;
 use crate::error::StatsError;
+use crate::function::erf;
 use crate::is_zero;
 use crate::prec;
 use crate::Result;
+use num_complex::Complex;
 use std::f64;
 
 /// Auxiliary variable when evaluating the `gamma_ln` function
@@ -57,6 +59,22 @@ pub fn ln_gamma(x: f64) -> f64 {
     }
 }
 
+/// Computes the logarithm of the gamma function at a complex `z` with
+/// `Re(z) >= 0.5`, using the same Lanczos approximation as `ln_gamma`.
+/// The imaginary part is only determined up to a multiple of `2π`, so
+/// the result is meant to be exponentiated
+pub(crate) fn ln_gamma_complex(z: Complex<f64>) -> Complex<f64> {
+    let s = GAMMA_DK
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(Complex::from(GAMMA_DK[0]), |s, t| {
+            s + t.1 / (z + (t.0 as f64 - 1.0))
+        });
+
+    s.ln() + consts::LN_2_SQRT_E_OVER_PI + (z - 0.5) * ((z - 0.5 + GAMMA_R) / f64::consts::E).ln()
+}
+
 /// Computes the gamma function with an accuracy
 /// of 16 floating point digits. The implementation
 /// is derived from "An Analysis of the Lanczos Gamma Approximation",
@@ -85,6 +103,37 @@ pub fn gamma(x: f64) -> f64 {
     }
 }
 
+/// Computes the logarithm of the multivariate gamma function
+/// `Gamma_p(a) = pi^(p(p-1)/4) * prod(Gamma(a + (1 - j) / 2), j=1..p)`
+/// of dimension `p`, built on `ln_gamma`
+///
+/// # Panics
+///
+/// if `a <= (p - 1) / 2`
+pub fn ln_multivariate_gamma(a: f64, p: u64) -> f64 {
+    checked_ln_multivariate_gamma(a, p).unwrap()
+}
+
+/// Computes the logarithm of the multivariate gamma function
+/// `Gamma_p(a) = pi^(p(p-1)/4) * prod(Gamma(a + (1 - j) / 2), j=1..p)`
+/// of dimension `p`, built on `ln_gamma`
+///
+/// # Errors
+///
+/// if `a <= (p - 1) / 2`
+pub fn checked_ln_multivariate_gamma(a: f64, p: u64) -> Result<f64> {
+    let bound = 0.5 * (p as f64 - 1.0);
+    if a <= bound {
+        return Err(StatsError::ArgGt("a", bound));
+    }
+    let pf = p as f64;
+    Ok(
+        (1..=p).fold(0.25 * pf * (pf - 1.0) * consts::LN_PI, |acc, j| {
+            acc + ln_gamma(a + 0.5 * (1.0 - j as f64))
+        }),
+    )
+}
+
 /// Computes the upper incomplete gamma function
 /// `Gamma(a,x) = int(exp(-t)t^(a-1), t=0..x) for a > 0, x > 0`
 /// where `a` is the argument for the gamma function and
@@ -275,10 +324,6 @@ pub fn checked_gamma_lr(a: f64, x: f64) -> Result<f64> {
     if prec::almost_eq(a, 0.0, prec::DEFAULT_F64_ACC) {
         return Ok(1.0);
     }
-    if prec::almost_eq(x, 0.0, prec::DEFAULT_F64_ACC) {
-        return Ok(0.0);
-    }
-
     let ax = a * x.ln() - x - ln_gamma(a);
     if ax < -709.78271289338399 {
         if a < x {
@@ -346,6 +391,155 @@ pub fn checked_gamma_lr(a: f64, x: f64) -> Result<f64> {
     Ok(1.0 - ax.exp() * ans)
 }
 
+/// Computes the inverse of the lower incomplete regularized gamma function,
+/// i.e. the `x` for which `P(a, x) = p`, where `a` is the argument for the
+/// gamma function and `p` is the probability.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`. Returns `0.0` if
+/// `p == 0.0` and `f64::INFINITY` if `p == 1.0`
+///
+/// # Panics
+///
+/// if `a` is not in `(0, +inf)` or `p` is not in `[0, 1]`
+pub fn inv_gamma_lr(a: f64, p: f64) -> f64 {
+    checked_inv_gamma_lr(a, p).unwrap()
+}
+
+/// Computes the inverse of the lower incomplete regularized gamma function,
+/// i.e. the `x` for which `P(a, x) = p`, where `a` is the argument for the
+/// gamma function and `p` is the probability.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`. Returns `0.0` if
+/// `p == 0.0` and `f64::INFINITY` if `p == 1.0`
+///
+/// # Errors
+///
+/// if `a` is not in `(0, +inf)` or `p` is not in `[0, 1]`
+pub fn checked_inv_gamma_lr(a: f64, p: f64) -> Result<f64> {
+    if a.is_nan() || p.is_nan() {
+        return Ok(f64::NAN);
+    }
+    if a <= 0.0 || a == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("a", 0.0, f64::INFINITY));
+    }
+    if !(0.0..=1.0).contains(&p) {
+        return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+    }
+    Ok(inv_gamma_reg(a, p, 1.0 - p))
+}
+
+/// Computes the inverse of the upper incomplete regularized gamma function,
+/// i.e. the `x` for which `Q(a, x) = q`, where `a` is the argument for the
+/// gamma function and `q` is the probability.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`. Returns
+/// `f64::INFINITY` if `q == 0.0` and `0.0` if `q == 1.0`
+///
+/// # Panics
+///
+/// if `a` is not in `(0, +inf)` or `q` is not in `[0, 1]`
+pub fn inv_gamma_ur(a: f64, q: f64) -> f64 {
+    checked_inv_gamma_ur(a, q).unwrap()
+}
+
+/// Computes the inverse of the upper incomplete regularized gamma function,
+/// i.e. the `x` for which `Q(a, x) = q`, where `a` is the argument for the
+/// gamma function and `q` is the probability.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`. Returns
+/// `f64::INFINITY` if `q == 0.0` and `0.0` if `q == 1.0`
+///
+/// # Errors
+///
+/// if `a` is not in `(0, +inf)` or `q` is not in `[0, 1]`
+pub fn checked_inv_gamma_ur(a: f64, q: f64) -> Result<f64> {
+    if a.is_nan() || q.is_nan() {
+        return Ok(f64::NAN);
+    }
+    if a <= 0.0 || a == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("a", 0.0, f64::INFINITY));
+    }
+    if !(0.0..=1.0).contains(&q) {
+        return Err(StatsError::ArgIntervalIncl("q", 0.0, 1.0));
+    }
+    Ok(inv_gamma_reg(a, 1.0 - q, q))
+}
+
+// Solves `P(a, x) = p` (equivalently `Q(a, x) = q`) for `x` where `p + q = 1`.
+// Whichever of `p` and `q` is smaller drives the iteration so that
+// the result is accurate deep in either tail.
+//
+// The initial guess is the Wilson–Hilferty approximation for `a > 1` and
+// the approximation from Numerical Recipes (3rd ed., section 6.2.1) for
+// `a <= 1`, which is then refined by Halley's method using
+//
+// P'(a, x) = x^(a - 1) * e^(-x) / Γ(a)
+// P''(a, x) / P'(a, x) = (a - 1) / x - 1
+fn inv_gamma_reg(a: f64, p: f64, q: f64) -> f64 {
+    if p <= 0.0 {
+        return 0.0;
+    }
+    if q <= 0.0 {
+        return f64::INFINITY;
+    }
+
+    let lower = p <= q;
+    let ln_gamma_a = ln_gamma(a);
+    let mut x = if a > 1.0 {
+        let z = if lower {
+            -f64::consts::SQRT_2 * erf::erfc_inv(2.0 * p)
+        } else {
+            f64::consts::SQRT_2 * erf::erfc_inv(2.0 * q)
+        };
+        let w = 1.0 - 1.0 / (9.0 * a) + z / (3.0 * a.sqrt());
+        if w > 0.0 {
+            a * w * w * w
+        } else {
+            // leading term of the series P(a, x) ~ x^a / Γ(a + 1)
+            ((p.ln() + ln_gamma(a + 1.0)) / a).exp()
+        }
+    } else {
+        let t = 1.0 - a * (0.253 + a * 0.12);
+        if p < t {
+            (p / t).powf(1.0 / a)
+        } else {
+            1.0 - (q / (1.0 - t)).ln()
+        }
+    };
+
+    for _ in 0..100 {
+        if x <= 0.0 || x.is_infinite() {
+            break;
+        }
+        let err = if lower {
+            gamma_lr(a, x) - p
+        } else {
+            q - gamma_ur(a, x)
+        };
+        if err == 0.0 {
+            break;
+        }
+        // err / P'(a, x) evaluated in log space since both may underflow
+        let ln_dens = (a - 1.0) * x.ln() - x - ln_gamma_a;
+        let u = err.signum() * (err.abs().ln() - ln_dens).exp();
+        let step = u / (1.0 - 0.5 * f64::min(1.0, u * ((a - 1.0) / x - 1.0)));
+        let next = if x - step <= 0.0 { 0.5 * x } else { x - step };
+        if (next - x).abs() <= 4.0 * prec::F64_PREC * next {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
 /// Computes the Digamma function which is defined as the derivative of
 /// the log of the gamma function. The implementation is based on
 /// "Algorithm AS 103", Jose Bernardo, Applied Statistics, Volume 25, Number 3
@@ -424,6 +618,7 @@ fn signum(x: f64) -> f64 {
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
+    use num_complex::Complex;
     use std::f64::{self, consts};
 
     #[test]
@@ -487,6 +682,19 @@ mod tests {
         assert_almost_eq!(super::ln_gamma(1.001e+7), 1.51342135323817913130119829455205139905331697084416059779e+8, 1e-13);
     }
 
+    #[test]
+    fn test_ln_gamma_complex() {
+        let gamma = |re: f64, im: f64| super::ln_gamma_complex(Complex::new(re, im)).exp();
+        assert_almost_eq!(gamma(1.0, -2.0).re, 0.15190400267003613745, 1e-15);
+        assert_almost_eq!(gamma(1.0, -2.0).im, -0.019804880161854981972, 1e-15);
+        assert_almost_eq!(gamma(0.5, 3.0).re, 0.02144567055243064606, 1e-15);
+        assert_almost_eq!(gamma(0.5, 3.0).im, 0.0068653648372616779142, 1e-15);
+        assert_almost_eq!(gamma(10.0, 0.5).re, 154065.27781660977856, 1e-7);
+        assert_almost_eq!(gamma(10.0, 0.5).im, 323311.49566673749653, 1e-7);
+        assert_almost_eq!(gamma(4.5, 0.0).re, 11.631728396567448929, 1e-13);
+        assert_eq!(gamma(4.5, 0.0).im, 0.0);
+    }
+
     #[test]
     fn test_gamma_lr() {
         assert!(super::gamma_lr(f64::NAN, f64::NAN).is_nan());
@@ -520,6 +728,14 @@ mod tests {
         assert_eq!(super::gamma_lr(1e+50, 1e+52), 1.0);
     }
 
+    #[test]
+    fn test_gamma_lr_small_x() {
+        // x below ~1e-15 used to be rounded to zero
+        assert_almost_eq!(super::gamma_lr(1.0, 1e-16), 9.9999999999999995e-17, 1e-30);
+        assert_almost_eq!(super::gamma_lr(0.5, 1e-20), 1.128379167095512573892e-10, 1e-24);
+        assert_almost_eq!(super::gamma_lr(3.0, 1e-16), 1.666666666666666666667e-49, 1e-62);
+    }
+
     #[test]
     #[should_panic]
     fn test_gamma_lr_a_lower_bound() {
@@ -703,6 +919,151 @@ mod tests {
         assert!(super::checked_gamma_ur(1.0, f64::INFINITY).is_err());
     }
 
+    #[test]
+    fn test_inv_gamma_lr() {
+        assert!(super::inv_gamma_lr(f64::NAN, f64::NAN).is_nan());
+        assert_eq!(super::inv_gamma_lr(1.5, 0.0), 0.0);
+        assert_eq!(super::inv_gamma_lr(1.5, 1.0), f64::INFINITY);
+        assert_almost_eq!(super::inv_gamma_lr(0.1, 0.5), 0.0005933911044602261735775, 1e-17);
+        assert_almost_eq!(super::inv_gamma_lr(0.1, 1e-10), 6.073048362407960182033e-101, 1e-113);
+        assert_almost_eq!(super::inv_gamma_lr(1.0, 0.5), 0.6931471805599453094172, 1e-15);
+        assert_almost_eq!(super::inv_gamma_lr(1.5, 0.1), 0.2921871870775916280795, 1e-15);
+        assert_almost_eq!(super::inv_gamma_lr(2.5, 0.9), 4.618178449890559225718, 1e-14);
+        assert_almost_eq!(super::inv_gamma_lr(5.5, 1e-5), 0.3650366819102239780944, 1e-15);
+        assert_almost_eq!(super::inv_gamma_lr(5.5, 0.99), 12.36248515565914134669, 1e-13);
+        assert_almost_eq!(super::inv_gamma_lr(100.0, 0.5), 99.66686491931548874369, 1e-11);
+        assert_almost_eq!(super::inv_gamma_lr(100.0, 1e-50), 13.7675391592367357568, 1e-12);
+        assert_almost_eq!(super::inv_gamma_lr(500.0, 0.999), 571.9585463098395916859, 1e-11);
+        assert_eq!(super::inv_gamma_lr(0.001, 0.3), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_lr_a_lower_bound() {
+        super::inv_gamma_lr(0.0, 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_lr_a_upper_bound() {
+        super::inv_gamma_lr(f64::INFINITY, 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_lr_p_lower_bound() {
+        super::inv_gamma_lr(1.0, -0.1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_lr_p_upper_bound() {
+        super::inv_gamma_lr(1.0, 1.1);
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_lr_a_lower_bound() {
+        assert!(super::checked_inv_gamma_lr(0.0, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_lr_a_upper_bound() {
+        assert!(super::checked_inv_gamma_lr(f64::INFINITY, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_lr_p_lower_bound() {
+        assert!(super::checked_inv_gamma_lr(1.0, -0.1).is_err());
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_lr_p_upper_bound() {
+        assert!(super::checked_inv_gamma_lr(1.0, 1.1).is_err());
+    }
+
+    #[test]
+    fn test_inv_gamma_ur() {
+        assert!(super::inv_gamma_ur(f64::NAN, f64::NAN).is_nan());
+        assert_eq!(super::inv_gamma_ur(1.5, 0.0), f64::INFINITY);
+        assert_eq!(super::inv_gamma_ur(1.5, 1.0), 0.0);
+        assert_almost_eq!(super::inv_gamma_ur(0.1, 0.5), 0.0005933911044602261735775, 1e-17);
+        assert_almost_eq!(super::inv_gamma_ur(1.0, 1e-100), 230.2585092994045684018, 1e-12);
+        assert_almost_eq!(super::inv_gamma_ur(1.5, 0.1), 3.125694315585161598201, 1e-14);
+        assert_almost_eq!(super::inv_gamma_ur(2.5, 1e-10), 27.78119925911925142928, 1e-13);
+        assert_almost_eq!(super::inv_gamma_ur(5.5, 0.99), 1.52674205332034000244, 1e-14);
+        assert_almost_eq!(super::inv_gamma_ur(100.0, 1e-20), 222.6569212611091812688, 1e-12);
+        assert_almost_eq!(super::inv_gamma_ur(500.0, 0.5), 499.6667062016904843743, 1e-11);
+        assert_almost_eq!(super::inv_gamma_ur(0.5, 1e-300), 686.9363156111970685717, 1e-11);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_ur_a_lower_bound() {
+        super::inv_gamma_ur(0.0, 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_ur_a_upper_bound() {
+        super::inv_gamma_ur(f64::INFINITY, 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_ur_q_lower_bound() {
+        super::inv_gamma_ur(1.0, -0.1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_ur_q_upper_bound() {
+        super::inv_gamma_ur(1.0, 1.1);
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_ur_a_lower_bound() {
+        assert!(super::checked_inv_gamma_ur(0.0, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_ur_a_upper_bound() {
+        assert!(super::checked_inv_gamma_ur(f64::INFINITY, 0.5).is_err());
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_ur_q_lower_bound() {
+        assert!(super::checked_inv_gamma_ur(1.0, -0.1).is_err());
+    }
+
+    #[test]
+    fn test_checked_inv_gamma_ur_q_upper_bound() {
+        assert!(super::checked_inv_gamma_ur(1.0, 1.1).is_err());
+    }
+
+    #[test]
+    fn test_ln_multivariate_gamma() {
+        assert!(super::ln_multivariate_gamma(f64::NAN, 2).is_nan());
+        assert_eq!(super::ln_multivariate_gamma(2.5, 0), 0.0);
+        assert_almost_eq!(super::ln_multivariate_gamma(2.5, 1), super::ln_gamma(2.5), 1e-15);
+        assert_almost_eq!(super::ln_multivariate_gamma(0.75, 2), 2.0636684190540729159, 1e-14);
+        assert_almost_eq!(super::ln_multivariate_gamma(2.5, 3), 1.8809954616117741985, 1e-14);
+        assert_almost_eq!(super::ln_multivariate_gamma(10.0, 5), 58.893841851237401205, 1e-12);
+        assert_almost_eq!(super::ln_multivariate_gamma(150.0, 10), 5913.4271224528455758, 1e-10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_multivariate_gamma_bad_a() {
+        super::ln_multivariate_gamma(1.0, 3);
+    }
+
+    #[test]
+    fn test_checked_ln_multivariate_gamma() {
+        assert!(super::checked_ln_multivariate_gamma(1.0, 3).is_err());
+        assert!(super::checked_ln_multivariate_gamma(0.5, 2).is_err());
+        assert!(super::checked_ln_multivariate_gamma(0.51, 2).is_ok());
+    }
+
     #[test]
     fn test_gamma_ui() {
         assert!(super::gamma_ui(f64::NAN, f64::NAN).is_nan());
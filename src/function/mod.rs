@@ -1,6 +1,7 @@
 //! Provides a host of special statistical functions (e.g. the beta function or
 //! the error function)
 
+pub mod bessel;
 pub mod beta;
 pub mod erf;
 pub mod evaluate;
@@ -9,3 +10,4 @@ pub mod factorial;
 pub mod gamma;
 pub mod harmonic;
 pub mod logistic;
+pub mod owens_t;
@@ -238,6 +238,44 @@ where
             f64::NAN
         }
     }
+
+    fn circular_mean(self) -> f64 {
+        let mut i = 0.0;
+        let mut sin = 0.0;
+        let mut cos = 0.0;
+        for x in self {
+            let borrow = *x.borrow();
+            i += 1.0;
+            sin += (borrow.sin() - sin) / i;
+            cos += (borrow.cos() - cos) / i;
+        }
+        if i > 0.0 {
+            sin.atan2(cos)
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn resultant_length(self) -> f64 {
+        let mut i = 0.0;
+        let mut sin = 0.0;
+        let mut cos = 0.0;
+        for x in self {
+            let borrow = *x.borrow();
+            i += 1.0;
+            sin += (borrow.sin() - sin) / i;
+            cos += (borrow.cos() - cos) / i;
+        }
+        if i > 0.0 {
+            sin.hypot(cos)
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn circular_variance(self) -> f64 {
+        1.0 - self.resultant_length()
+    }
 }
 
 #[rustfmt::skip]
@@ -381,6 +419,9 @@ mod tests {
         assert!(data.quadratic_mean().is_nan());
         assert!(data.variance().is_nan());
         assert!(data.population_variance().is_nan());
+        assert!(data.circular_mean().is_nan());
+        assert!(data.resultant_length().is_nan());
+        assert!(data.circular_variance().is_nan());
     }
 
     // TODO: test github issue 137 (Math.NET)
@@ -395,6 +436,28 @@ mod tests {
         assert_almost_eq!((&longer).quadratic_mean(), (0.21875f64).sqrt(), 1e-14);
     }
 
+    #[test]
+    fn test_circular_statistics() {
+        let data = [0.1, 0.5, -0.3, 6.0, 2.0 * consts::PI + 0.2];
+        // reference values computed with mpmath
+        assert_almost_eq!((&data).circular_mean(), 0.042546826248821122619, 1e-15);
+        assert_almost_eq!((&data).resultant_length(), 0.95449581547566650262, 1e-15);
+        assert_almost_eq!((&data).circular_variance(), 0.045504184524333497382, 1e-15);
+
+        // angles either side of the branch cut average across it
+        let wrapped = [3.0, -3.0, 3.1];
+        assert_almost_eq!((&wrapped).circular_mean(), 3.1276361964263835736, 1e-15);
+        let opposite = [3.0, -3.0];
+        assert_almost_eq!((&opposite).circular_mean(), consts::PI, 1e-15);
+    }
+
+    #[test]
+    fn test_circular_statistics_of_uniform_angles() {
+        let data = (0..12).map(|i| i as f64 * consts::PI / 6.0).collect::<Vec<f64>>();
+        assert_almost_eq!((&data).resultant_length(), 0.0, 1e-15);
+        assert_almost_eq!((&data).circular_variance(), 1.0, 1e-15);
+    }
+
     #[test]
     fn test_quadratic_mean_of_sinusoidal() {
         let data = InfiniteSinusoidal::default(64.0, 16.0, 2.0).take(128).collect::<Vec<f64>>();
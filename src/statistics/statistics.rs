@@ -424,4 +424,104 @@ pub trait Statistics<T> {
     /// # }
     /// ```
     fn quadratic_mean(self) -> T;
+
+    /// Evaluates the circular mean of the data, treating each entry as an
+    /// angle in radians. The result lies in `[-π, π]`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`. The
+    /// circular mean is not well defined when the resultant length is zero
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// atan2(sum(sin(x_i)), sum(cos(x_i)))
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use std::f64::consts::PI;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x: [f64; 0] = [];
+    /// assert!(x.circular_mean().is_nan());
+    ///
+    /// let y = [0.9 * PI, -0.9 * PI];
+    /// assert_almost_eq!(y.circular_mean().abs(), PI, 1e-15);
+    /// # }
+    /// ```
+    fn circular_mean(self) -> T;
+
+    /// Evaluates the mean resultant length of the data, treating each entry
+    /// as an angle in radians. The result lies in `[0, 1]`, with `1` when all
+    /// the angles coincide
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(sum(cos(x_i))^2 + sum(sin(x_i))^2) / n
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use std::f64::consts::PI;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x: [f64; 0] = [];
+    /// assert!(x.resultant_length().is_nan());
+    ///
+    /// let y = [0.0, 0.5 * PI];
+    /// assert_almost_eq!(y.resultant_length(), 0.5f64.sqrt(), 1e-15);
+    /// # }
+    /// ```
+    fn resultant_length(self) -> T;
+
+    /// Evaluates the circular variance of the data, treating each entry as
+    /// an angle in radians. The result lies in `[0, 1]`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - R
+    /// ```
+    ///
+    /// where `R` is the mean resultant length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #[macro_use]
+    /// extern crate statrs;
+    ///
+    /// use std::f64::consts::PI;
+    /// use statrs::statistics::Statistics;
+    ///
+    /// # fn main() {
+    /// let x = [1.0, 1.0 + 2.0 * PI];
+    /// assert_almost_eq!(x.circular_variance(), 0.0, 1e-15);
+    ///
+    /// let y = [0.0, PI];
+    /// assert_almost_eq!(y.circular_variance(), 1.0, 1e-15);
+    /// # }
+    /// ```
+    fn circular_variance(self) -> T;
 }
@@ -3,6 +3,7 @@ use ::nalgebra::{
     base::{dimension::DimName, MatrixN, VectorN},
     DefaultAllocator, Dim, DimMin, U1,
 };
+use ::num_complex::Complex;
 use ::num_traits::float::Float;
 
 const STEPS: usize = 1_000;
@@ -61,6 +62,10 @@ pub trait DiscreteDistribution<T: Float>: ::rand::distributions::Distribution<u6
     fn skewness(&self) -> Option<T> {
         None
     }
+    /// Returns the excess kurtosis, if it exists.
+    fn kurtosis(&self) -> Option<T> {
+        None
+    }
 }
 
 pub trait Distribution<T: Float>: ::rand::distributions::Distribution<T> {
@@ -166,6 +171,131 @@ pub trait Distribution<T: Float>: ::rand::distributions::Distribution<T> {
     fn skewness(&self) -> Option<T> {
         None
     }
+    /// Returns the excess kurtosis, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(-1.2, n.kurtosis().unwrap());
+    /// ```
+    fn kurtosis(&self) -> Option<T> {
+        None
+    }
+    /// Returns the `k`th raw moment `E[X^k]`, if it exists.
+    /// The default implementation expands the central moments
+    /// about the mean, so it covers every `k` for which
+    /// `central_moment` is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert!((n.moment(2).unwrap() - 1.0 / 3.0).abs() < 1e-15);
+    /// ```
+    fn moment(&self, k: u32) -> Option<T> {
+        if k == 0 {
+            return Some(T::one());
+        }
+        let mean = self.mean()?;
+        let mut sum = T::zero();
+        let mut coeff = T::one();
+        for j in 0..=k {
+            if j != 1 {
+                sum = sum + coeff * self.central_moment(j)? * mean.powi((k - j) as i32);
+            }
+            coeff = coeff * T::from(k - j).unwrap() / T::from(j + 1).unwrap();
+        }
+        Some(sum)
+    }
+    /// Returns the `k`th central moment `E[(X - μ)^k]`, if it exists.
+    /// The default implementation derives the moments up to the
+    /// fourth from the variance, skewness and kurtosis and returns
+    /// `None` for higher orders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::Distribution;
+    /// use statrs::distribution::Uniform;
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(1.0 / 12.0, n.central_moment(2).unwrap());
+    /// ```
+    fn central_moment(&self, k: u32) -> Option<T> {
+        match k {
+            0 => Some(T::one()),
+            1 => self.mean().map(|_| T::zero()),
+            2 => self.variance(),
+            3 => {
+                let var = self.variance()?;
+                Some(self.skewness()? * var * var.sqrt())
+            }
+            4 => {
+                let var = self.variance()?;
+                Some((self.kurtosis()? + T::from(3.0).unwrap()) * var * var)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The `GeneratingFunction` trait specifies the moment generating,
+/// cumulant generating and characteristic functions of a univariate
+/// distribution
+pub trait GeneratingFunction<T: Float> {
+    /// Returns the moment generating function `E[e^(tX)]` evaluated at `t`,
+    /// or `None` if the expectation does not converge at `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::GeneratingFunction;
+    /// use statrs::distribution::Exp;
+    ///
+    /// let n = Exp::new(2.0).unwrap();
+    /// assert_eq!(Some(2.0), n.mgf(1.0));
+    /// assert_eq!(None, n.mgf(2.0));
+    /// ```
+    fn mgf(&self, t: T) -> Option<T>;
+    /// Returns the cumulant generating function `ln(E[e^(tX)])` evaluated
+    /// at `t`, or `None` if the moment generating function does not
+    /// converge at `t`. The default implementation takes the logarithm
+    /// of `mgf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::GeneratingFunction;
+    /// use statrs::distribution::Normal;
+    ///
+    /// let n = Normal::new(1.0, 2.0).unwrap();
+    /// assert_eq!(Some(1.0), n.cgf(0.5));
+    /// ```
+    fn cgf(&self, t: T) -> Option<T> {
+        self.mgf(t).map(|m| m.ln())
+    }
+    /// Returns the characteristic function `E[e^(itX)]` evaluated at `t`.
+    /// Unlike the moment generating function it exists for every
+    /// distribution and every real `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::statistics::GeneratingFunction;
+    /// use statrs::distribution::Cauchy;
+    ///
+    /// let n = Cauchy::new(0.0, 1.0).unwrap();
+    /// assert_eq!(None, n.mgf(1.0));
+    /// assert_eq!((-1.0f64).exp(), n.cf(1.0).re);
+    /// ```
+    fn cf(&self, t: T) -> Complex<T>;
 }
 
 /// The `Mean` trait implements the calculation of a mean.